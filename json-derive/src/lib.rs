@@ -0,0 +1,427 @@
+//! Derive macros for the `ToJson` and `FromJson` traits of the `json`
+//! crate. Use them through its `derive` feature rather than directly.
+//!
+//! Containers accept `#[json(rename_all = "...")]`, with any of
+//! `lowercase`, `UPPERCASE`, `PascalCase`, `camelCase`, `snake_case`,
+//! `SCREAMING_SNAKE_CASE` and `kebab-case`; it renames the fields of a
+//! struct or the variants of an enum. Fields accept:
+//!
+//! - `#[json(rename = "name")]` to use another key, also valid on variants;
+//! - `#[json(default)]` to use `Default::default()` when the key is missing;
+//! - `#[json(skip_serializing_if = "path")]` to omit the key when the
+//!   function at `path` returns `true` for the field;
+//! - `#[json(flatten)]` to merge the entries of the field into the object.
+
+extern crate proc_macro;
+
+use proc_macro2::{Span, TokenStream};
+use quote::{format_ident, quote};
+use syn::{
+    parse_macro_input, parse_quote, Attribute, Data, DeriveInput, Error, Field, Fields, Generics,
+    Ident, LitStr, Path,
+};
+
+#[proc_macro_derive(ToJson, attributes(json))]
+pub fn derive_to_json(input: proc_macro::TokenStream) -> proc_macro::TokenStream {
+    let input = parse_macro_input!(input as DeriveInput);
+    to_json(&input)
+        .unwrap_or_else(Error::into_compile_error)
+        .into()
+}
+
+#[proc_macro_derive(FromJson, attributes(json))]
+pub fn derive_from_json(input: proc_macro::TokenStream) -> proc_macro::TokenStream {
+    let input = parse_macro_input!(input as DeriveInput);
+    from_json(&input)
+        .unwrap_or_else(Error::into_compile_error)
+        .into()
+}
+
+/// Options given by `#[json(...)]` attributes; each item only accepts the
+/// ones that make sense for it.
+#[derive(Default)]
+struct Options {
+    rename: Option<String>,
+    rename_all: Option<CaseStyle>,
+    default: bool,
+    skip_serializing_if: Option<Path>,
+    flatten: bool,
+}
+
+impl Options {
+    fn parse(attributes: &[Attribute], allowed: &[&str]) -> Result<Self, Error> {
+        let mut options = Options::default();
+        for attribute in attributes.iter().filter(|a| a.path().is_ident("json")) {
+            attribute.parse_nested_meta(|meta| {
+                let name = meta
+                    .path
+                    .get_ident()
+                    .map(Ident::to_string)
+                    .unwrap_or_default();
+                if !allowed.contains(&name.as_str()) {
+                    return Err(meta.error(format!(
+                        "unsupported json attribute, expected one of: {}",
+                        allowed.join(", ")
+                    )));
+                }
+                match name.as_str() {
+                    "rename" => options.rename = Some(meta.value()?.parse::<LitStr>()?.value()),
+                    "rename_all" => {
+                        let style: LitStr = meta.value()?.parse()?;
+                        options.rename_all = Some(CaseStyle::parse(&style)?);
+                    }
+                    "default" => options.default = true,
+                    "skip_serializing_if" => {
+                        let path: LitStr = meta.value()?.parse()?;
+                        options.skip_serializing_if = Some(path.parse()?);
+                    }
+                    _ => options.flatten = true,
+                }
+                Ok(())
+            })?;
+        }
+        Ok(options)
+    }
+}
+
+const CONTAINER: &[&str] = &["rename_all"];
+const VARIANT: &[&str] = &["rename"];
+const FIELD: &[&str] = &["rename", "default", "skip_serializing_if", "flatten"];
+
+#[derive(Clone, Copy)]
+enum CaseStyle {
+    Lower,
+    Upper,
+    Pascal,
+    Camel,
+    Snake,
+    ScreamingSnake,
+    Kebab,
+}
+
+impl CaseStyle {
+    fn parse(style: &LitStr) -> Result<Self, Error> {
+        Ok(match style.value().as_str() {
+            "lowercase" => CaseStyle::Lower,
+            "UPPERCASE" => CaseStyle::Upper,
+            "PascalCase" => CaseStyle::Pascal,
+            "camelCase" => CaseStyle::Camel,
+            "snake_case" => CaseStyle::Snake,
+            "SCREAMING_SNAKE_CASE" => CaseStyle::ScreamingSnake,
+            "kebab-case" => CaseStyle::Kebab,
+            _ => return Err(Error::new(style.span(), "unknown case style")),
+        })
+    }
+
+    /// Renames a Rust identifier, either a `snake_case` field or a
+    /// `PascalCase` variant.
+    fn apply(self, name: &str) -> String {
+        let mut words: Vec<String> = Vec::new();
+        for part in name.trim_start_matches("r#").split('_') {
+            for c in part.chars() {
+                match words.last_mut() {
+                    Some(word) if !c.is_uppercase() || word.is_empty() => word.push(c),
+                    _ => words.push(c.to_string()),
+                }
+            }
+            words.push(String::new());
+        }
+        words.retain(|word| !word.is_empty());
+        let capitalize = |word: &String| -> String {
+            let mut chars = word.chars();
+            chars
+                .next()
+                .map(|c| {
+                    c.to_uppercase()
+                        .chain(chars.flat_map(char::to_lowercase))
+                        .collect()
+                })
+                .unwrap_or_default()
+        };
+        match self {
+            CaseStyle::Lower => words.concat().to_lowercase(),
+            CaseStyle::Upper => words.concat().to_uppercase(),
+            CaseStyle::Pascal => words.iter().map(capitalize).collect(),
+            CaseStyle::Camel => {
+                let pascal: String = words.iter().map(capitalize).collect();
+                let mut chars = pascal.chars();
+                chars
+                    .next()
+                    .map(|c| c.to_lowercase().chain(chars).collect())
+                    .unwrap_or_default()
+            }
+            CaseStyle::Snake => words.join("_").to_lowercase(),
+            CaseStyle::ScreamingSnake => words.join("_").to_uppercase(),
+            CaseStyle::Kebab => words.join("-").to_lowercase(),
+        }
+    }
+}
+
+/// The key or tag used for an item named `ident`.
+fn json_name(ident: &Ident, options: &Options, rename_all: Option<CaseStyle>) -> String {
+    let name = ident.to_string();
+    match (&options.rename, rename_all) {
+        (Some(rename), _) => rename.clone(),
+        (None, Some(style)) => style.apply(&name),
+        (None, None) => name.trim_start_matches("r#").to_string(),
+    }
+}
+
+/// Requires every type parameter to implement `bound`.
+fn bounded(generics: &Generics, bound: TokenStream) -> Generics {
+    let mut generics = generics.clone();
+    let parameters: Vec<Ident> = generics.type_params().map(|p| p.ident.clone()).collect();
+    let clause = generics.make_where_clause();
+    for parameter in parameters {
+        clause.predicates.push(parse_quote!(#parameter: #bound));
+    }
+    generics
+}
+
+/// A named field with its options and JSON key.
+struct NamedField<'a> {
+    ident: &'a Ident,
+    key: String,
+    options: Options,
+}
+
+fn named_fields<'a>(
+    fields: impl Iterator<Item = &'a Field>,
+    rename_all: Option<CaseStyle>,
+) -> Result<Vec<NamedField<'a>>, Error> {
+    fields
+        .map(|field| {
+            let ident = field.ident.as_ref().unwrap();
+            let options = Options::parse(&field.attrs, FIELD)?;
+            let key = json_name(ident, &options, rename_all);
+            Ok(NamedField {
+                ident,
+                key,
+                options,
+            })
+        })
+        .collect()
+}
+
+/// Rejects attributes on the fields of tuple structs and variants.
+fn check_unnamed(fields: &Fields) -> Result<(), Error> {
+    if let Fields::Unnamed(unnamed) = fields {
+        for field in &unnamed.unnamed {
+            Options::parse(&field.attrs, &[])?;
+        }
+    }
+    Ok(())
+}
+
+fn to_json(input: &DeriveInput) -> Result<TokenStream, Error> {
+    let name = &input.ident;
+    let container = Options::parse(&input.attrs, CONTAINER)?;
+    let generics = bounded(&input.generics, quote!(::json::ToJson));
+    let (impl_generics, type_generics, where_clause) = generics.split_for_impl();
+    let body = match &input.data {
+        Data::Struct(data) => {
+            let (pattern, value) = write_fields(&data.fields, container.rename_all)?;
+            quote! {
+                let #name #pattern = self;
+                #value
+            }
+        }
+        Data::Enum(data) => {
+            let mut arms = Vec::new();
+            for variant in &data.variants {
+                let ident = &variant.ident;
+                let options = Options::parse(&variant.attrs, VARIANT)?;
+                let tag = json_name(ident, &options, container.rename_all);
+                let (pattern, value) = write_fields(&variant.fields, None)?;
+                arms.push(match variant.fields {
+                    Fields::Unit => quote! {
+                        #name::#ident => ::json::Value::String(#tag.to_string()),
+                    },
+                    _ => quote! {
+                        #name::#ident #pattern => ::json::__private::tagged(#tag, #value),
+                    },
+                });
+            }
+            quote! {
+                match self {
+                    #(#arms)*
+                }
+            }
+        }
+        Data::Union(_) => {
+            return Err(Error::new(Span::call_site(), "unions can't derive ToJson"));
+        }
+    };
+    Ok(quote! {
+        impl #impl_generics ::json::ToJson for #name #type_generics #where_clause {
+            fn to_json(&self) -> ::json::Value {
+                #body
+            }
+        }
+    })
+}
+
+/// Returns a pattern binding the fields by reference and an expression
+/// building their value from those bindings.
+fn write_fields(
+    fields: &Fields,
+    rename_all: Option<CaseStyle>,
+) -> Result<(TokenStream, TokenStream), Error> {
+    check_unnamed(fields)?;
+    Ok(match fields {
+        Fields::Named(named) => {
+            let fields = named_fields(named.named.iter(), rename_all)?;
+            let idents = fields.iter().map(|field| field.ident);
+            let inserts = fields.iter().map(|field| {
+                let NamedField {
+                    ident,
+                    key,
+                    options,
+                } = field;
+                let insert = if options.flatten {
+                    quote!(::json::__private::flatten(&mut object, ::json::ToJson::to_json(#ident));)
+                } else {
+                    quote!(object.insert(#key.to_string(), ::json::ToJson::to_json(#ident));)
+                };
+                match &options.skip_serializing_if {
+                    Some(skip) => quote!(if !#skip(#ident) { #insert }),
+                    None => insert,
+                }
+            });
+            (
+                quote!({ #(#idents),* }),
+                quote! {{
+                    let mut object = ::json::JsonObject::new();
+                    #(#inserts)*
+                    ::json::Value::Object(object)
+                }},
+            )
+        }
+        Fields::Unnamed(unnamed) => {
+            let bindings: Vec<_> = (0..unnamed.unnamed.len())
+                .map(|i| format_ident!("field{}", i))
+                .collect();
+            let value = if bindings.len() == 1 {
+                quote!(::json::ToJson::to_json(field0))
+            } else {
+                quote!(::json::Value::Array(
+                    vec![#(::json::ToJson::to_json(#bindings)),*]
+                ))
+            };
+            (quote!((#(#bindings),*)), value)
+        }
+        Fields::Unit => (quote!(), quote!(::json::Value::Null)),
+    })
+}
+
+fn from_json(input: &DeriveInput) -> Result<TokenStream, Error> {
+    let name = &input.ident;
+    let label = name.to_string();
+    let container = Options::parse(&input.attrs, CONTAINER)?;
+    let generics = bounded(&input.generics, quote!(::json::FromJson));
+    let (impl_generics, type_generics, where_clause) = generics.split_for_impl();
+    let body = match &input.data {
+        Data::Struct(data) => read_fields(
+            quote!(#name),
+            &label,
+            &data.fields,
+            container.rename_all,
+            quote!(value),
+        )?,
+        Data::Enum(data) => {
+            let mut arms = Vec::new();
+            for variant in &data.variants {
+                let ident = &variant.ident;
+                let options = Options::parse(&variant.attrs, VARIANT)?;
+                let tag = json_name(ident, &options, container.rename_all);
+                let path = format!("{}::{}", label, ident);
+                arms.push(match variant.fields {
+                    Fields::Unit => quote! {
+                        (#tag, None) => Ok(#name::#ident),
+                    },
+                    _ => {
+                        let read = read_fields(
+                            quote!(#name::#ident),
+                            &path,
+                            &variant.fields,
+                            None,
+                            quote!(content),
+                        )?;
+                        quote! {
+                            (#tag, Some(content)) => (|| -> Result<Self, String> { #read })()
+                                .map_err(|e| ::json::__private::nested(#tag, e)),
+                        }
+                    }
+                });
+            }
+            quote! {
+                match ::json::__private::variant(value, #label)? {
+                    #(#arms)*
+                    (variant, _) => Err(::json::__private::unknown_variant(variant, #label)),
+                }
+            }
+        }
+        Data::Union(_) => {
+            return Err(Error::new(
+                Span::call_site(),
+                "unions can't derive FromJson",
+            ));
+        }
+    };
+    Ok(quote! {
+        impl #impl_generics ::json::FromJson for #name #type_generics #where_clause {
+            fn from_json(value: &::json::Value) -> Result<Self, String> {
+                #body
+            }
+        }
+    })
+}
+
+/// Returns an expression reading `fields` out of `source` into `path`.
+fn read_fields(
+    path: TokenStream,
+    label: &str,
+    fields: &Fields,
+    rename_all: Option<CaseStyle>,
+    source: TokenStream,
+) -> Result<TokenStream, Error> {
+    check_unnamed(fields)?;
+    Ok(match fields {
+        Fields::Named(named) => {
+            let fields = named_fields(named.named.iter(), rename_all)?;
+            let reads = fields.iter().map(|field| {
+                let NamedField {
+                    ident,
+                    key,
+                    options,
+                } = field;
+                if options.flatten {
+                    quote!(#ident: ::json::FromJson::from_json(#source)?,)
+                } else if options.default {
+                    quote!(#ident: ::json::__private::field_or_default(object, #key)?,)
+                } else {
+                    quote!(#ident: ::json::__private::field(object, #key)?,)
+                }
+            });
+            quote! {
+                let object = ::json::__private::object(#source, #label)?;
+                Ok(#path { #(#reads)* })
+            }
+        }
+        Fields::Unnamed(unnamed) if unnamed.unnamed.len() == 1 => quote! {
+            Ok(#path(::json::FromJson::from_json(#source)?))
+        },
+        Fields::Unnamed(unnamed) => {
+            let length = unnamed.unnamed.len();
+            let indexes = 0..length;
+            quote! {
+                let values = ::json::__private::array(#source, #label, #length)?;
+                Ok(#path(#(::json::__private::element(values, #indexes)?),*))
+            }
+        }
+        Fields::Unit => quote! {
+            ::json::__private::null(#source, #label)?;
+            Ok(#path)
+        },
+    })
+}
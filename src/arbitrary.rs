@@ -0,0 +1,47 @@
+#[cfg(test)]
+mod tests;
+
+use crate::{JsonObject, Value};
+use arbitrary::{Arbitrary, Result, Unstructured};
+
+/// Containers deeper than this are never generated, so that fuzzers don't
+/// waste their input on deeply nested documents.
+const MAX_DEPTH: usize = 8;
+
+/// Generates any document; numbers are always finite since JSON can't
+/// represent the others.
+impl<'a> Arbitrary<'a> for Value {
+    fn arbitrary(u: &mut Unstructured<'a>) -> Result<Self> {
+        arbitrary_value(u, 0)
+    }
+}
+
+fn arbitrary_value(u: &mut Unstructured<'_>, depth: usize) -> Result<Value> {
+    let kinds = if depth < MAX_DEPTH { 6 } else { 4 };
+    Ok(match u.choose_index(kinds)? {
+        0 => Value::Null,
+        1 => Value::Bool(u.arbitrary()?),
+        2 => {
+            let number: f64 = u.arbitrary()?;
+            Value::Number(if number.is_finite() { number } else { 0.0 })
+        }
+        3 => Value::String(u.arbitrary()?),
+        4 => {
+            let mut values = Vec::new();
+            u.arbitrary_loop(None, Some(16), |u| {
+                values.push(arbitrary_value(u, depth + 1)?);
+                Ok(std::ops::ControlFlow::Continue(()))
+            })?;
+            Value::Array(values)
+        }
+        _ => {
+            let mut object = JsonObject::new();
+            u.arbitrary_loop(None, Some(16), |u| {
+                let key = u.arbitrary()?;
+                object.insert(key, arbitrary_value(u, depth + 1)?);
+                Ok(std::ops::ControlFlow::Continue(()))
+            })?;
+            Value::Object(object)
+        }
+    })
+}
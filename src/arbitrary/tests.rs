@@ -0,0 +1,15 @@
+use crate::Value;
+use arbitrary::{Arbitrary, Unstructured};
+
+#[test]
+fn arbitrary_values() {
+    let data: Vec<u8> = (0..4096u32).map(|i| (i * 7919 % 251) as u8).collect();
+    let mut u = Unstructured::new(&data);
+    let mut kinds = std::collections::HashSet::new();
+    while !u.is_empty() {
+        let value = Value::arbitrary(&mut u).unwrap();
+        assert!(value.stats().max_depth <= 8);
+        kinds.insert(value.type_name());
+    }
+    assert!(kinds.len() > 3);
+}
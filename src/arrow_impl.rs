@@ -0,0 +1,67 @@
+#[cfg(test)]
+mod tests;
+
+use crate::{to_string, Column, Columns, Value};
+use arrow_array::{
+    ArrayRef, BooleanArray, Float64Array, Int64Array, RecordBatch, RecordBatchOptions, StringArray,
+};
+use arrow_schema::Schema;
+use std::sync::Arc;
+
+impl Column {
+    /// Converts the column into the Arrow array of the matching type, with
+    /// [`Column::Mixed`] values becoming strings holding their JSON text.
+    pub fn to_arrow(&self) -> ArrayRef {
+        match self {
+            Column::Bool(values) => Arc::new(BooleanArray::from(values.clone())),
+            Column::Integer(values) => Arc::new(Int64Array::from(values.clone())),
+            Column::Number(values) => Arc::new(Float64Array::from(values.clone())),
+            Column::String(values) => Arc::new(StringArray::from(values.clone())),
+            Column::Mixed(values) => Arc::new(StringArray::from(
+                values
+                    .iter()
+                    .map(|value| match value {
+                        Value::Null => None,
+                        value => Some(to_string(value)),
+                    })
+                    .collect::<Vec<_>>(),
+            )),
+        }
+    }
+}
+
+impl Columns {
+    /// Converts the columns into an Arrow record batch with a nullable
+    /// field per column.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    ///# fn main() -> Result<(),String> {
+    ///let rows = json::parse(r#"[{ "id": 1, "name": "a" }, { "id": 2 }]"#)?;
+    ///let batch = rows.to_columns()?.to_record_batch()?;
+    ///
+    ///assert_eq!(batch.num_rows(), 2);
+    ///assert_eq!(batch.schema().field(0).data_type(), &arrow_schema::DataType::Int64);
+    ///assert_eq!(batch.column(1).null_count(), 1);
+    ///# Ok(())
+    ///# }
+    /// ```
+    pub fn to_record_batch(&self) -> Result<RecordBatch, String> {
+        if self.fields.is_empty() {
+            let options = RecordBatchOptions::new().with_row_count(Some(self.rows));
+            return RecordBatch::try_new_with_options(
+                Arc::new(Schema::empty()),
+                Vec::new(),
+                &options,
+            )
+            .map_err(|e| e.to_string());
+        }
+        RecordBatch::try_from_iter(
+            self.fields
+                .iter()
+                .map(|(name, column)| (name.as_str(), column.to_arrow())),
+        )
+        .map_err(|e| e.to_string())
+    }
+}
@@ -0,0 +1,34 @@
+use crate::parse;
+use arrow_array::{Array, StringArray};
+use arrow_schema::DataType;
+
+#[test]
+fn record_batch() {
+    let rows = parse(r#"[{ "b": true, "f": 0.5, "m": [1] }, { "b": false, "f": null, "m": "x" }]"#)
+        .unwrap();
+    let batch = rows.to_columns().unwrap().to_record_batch().unwrap();
+    let types: Vec<&DataType> = batch
+        .schema_ref()
+        .fields()
+        .iter()
+        .map(|f| f.data_type())
+        .collect();
+    assert_eq!(
+        types,
+        [&DataType::Boolean, &DataType::Float64, &DataType::Utf8]
+    );
+    let mixed = batch
+        .column(2)
+        .as_any()
+        .downcast_ref::<StringArray>()
+        .unwrap();
+    assert_eq!(mixed.value(0), "[1]");
+    assert_eq!(batch.column(1).null_count(), 1);
+    let empty = parse("[{}, {}]")
+        .unwrap()
+        .to_columns()
+        .unwrap()
+        .to_record_batch()
+        .unwrap();
+    assert_eq!((empty.num_rows(), empty.num_columns()), (2, 0));
+}
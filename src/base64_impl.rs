@@ -0,0 +1,36 @@
+#[cfg(test)]
+mod tests;
+
+use crate::convert::mismatch;
+use crate::Value;
+use base64::engine::general_purpose::STANDARD;
+use base64::Engine;
+
+impl Value {
+    /// Encodes bytes as a standard, padded base64 string.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    ///# fn main() -> Result<(),String> {
+    ///let value = json::Value::from_bytes_base64(b"hello");
+    ///
+    ///assert_eq!(json::to_string(&value), r#""aGVsbG8=""#);
+    ///assert_eq!(value.as_bytes_base64()?, b"hello");
+    ///# Ok(())
+    ///# }
+    /// ```
+    pub fn from_bytes_base64(bytes: &[u8]) -> Value {
+        Value::String(STANDARD.encode(bytes))
+    }
+
+    /// Decodes a standard, padded base64 string.
+    pub fn as_bytes_base64(&self) -> Result<Vec<u8>, String> {
+        match self {
+            Value::String(value) => STANDARD
+                .decode(value)
+                .map_err(|e| format!("invalid base64: {}", e)),
+            value => Err(mismatch("base64 string", value)),
+        }
+    }
+}
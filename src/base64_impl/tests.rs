@@ -0,0 +1,24 @@
+use crate::{parse, Value};
+
+#[test]
+fn round_trip() {
+    let bytes: Vec<u8> = (0..=255).collect();
+    assert_eq!(
+        Value::from_bytes_base64(&bytes).as_bytes_base64(),
+        Ok(bytes)
+    );
+    assert_eq!(Value::from_bytes_base64(&[]), Value::String(String::new()));
+}
+
+#[test]
+fn errors() {
+    assert_eq!(
+        Value::Null.as_bytes_base64(),
+        Err("expected base64 string, found null".to_string())
+    );
+    assert!(parse(r#""aGVsbG8""#)
+        .unwrap()
+        .as_bytes_base64()
+        .unwrap_err()
+        .starts_with("invalid base64: "));
+}
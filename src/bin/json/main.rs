@@ -0,0 +1,454 @@
+//! The `json` command line tool, built with the `cli` feature.
+
+#[cfg(test)]
+mod tests;
+
+use std::fs;
+use std::io::{self, BufRead, BufReader, Read, Write};
+use std::process;
+
+const USAGE: &str = "\
+usage: json <command> [<args>]
+
+commands:
+  validate [<file>...]  checks that every file, or stdin, holds valid JSON
+  fmt [<file>...]       pretty-prints JSON
+      --indent <n>      indents by n spaces instead of 2
+      --sort-keys       sorts object keys
+      --check           only reports files that aren't formatted
+      -i                rewrites files in place
+  minify [<file>...]    removes all whitespace
+      -i                rewrites files in place
+  get <expr> [<file>...]
+                        prints the values at a JSON Pointer such as /a/0,
+                        or matching a path such as a[*].b, **.id or $..id,
+                        one per line
+      -r                prints strings without quotes
+  diff <from> <to>      lists the differences between two documents
+      --rfc6902         prints them as a JSON Patch instead
+  patch <doc> <patch>   applies a JSON Patch
+      -i                rewrites the document in place
+  lines [<file>...]     filters newline-delimited JSON, one line at a time
+      --where <expr>=<value>
+                        keeps lines with a value equal to <value>, read as
+                        JSON or else as a string, at <expr> as for get
+      --select <expr>,...
+                        keeps only the values at these expressions
+      --count           prints the number of lines kept instead
+
+Files named - are read from stdin, which is also read when no file is given.
+Commands checking their input, such as validate, fmt --check and diff, exit
+with status 1 when the check fails.
+";
+
+/// Where commands read their input and write their output.
+struct Io<'a> {
+    stdin: &'a mut dyn Read,
+    stdout: &'a mut dyn Write,
+    stderr: &'a mut dyn Write,
+}
+
+fn main() {
+    let args: Vec<String> = std::env::args().skip(1).collect();
+    let code = run(
+        &args,
+        &mut Io {
+            stdin: &mut io::stdin().lock(),
+            stdout: &mut io::stdout().lock(),
+            stderr: &mut io::stderr().lock(),
+        },
+    );
+    process::exit(code);
+}
+
+/// Runs the command `args` spell out and returns the exit status: 0 on
+/// success, 1 when the input fails the command's check and 2 on errors.
+fn run(args: &[String], io: &mut Io) -> i32 {
+    let result = match args.first().map(String::as_str) {
+        Some("validate") => validate(&args[1..], io),
+        Some("fmt") => fmt(&args[1..], io),
+        Some("minify") => minify(&args[1..], io),
+        Some("get") => get(&args[1..], io),
+        Some("diff") => diff(&args[1..], io),
+        Some("patch") => patch(&args[1..], io),
+        Some("lines") => lines(&args[1..], io),
+        Some("-h") | Some("--help") | Some("help") => write!(io.stdout, "{}", USAGE)
+            .map(|_| 0)
+            .map_err(|e| e.to_string()),
+        Some(command) => Err(format!("unknown command {:?}\n\n{}", command, USAGE)),
+        None => Err(format!("missing command\n\n{}", USAGE)),
+    };
+    result.unwrap_or_else(|e| {
+        let _ = writeln!(io.stderr, "json: {}", e.trim_end());
+        2
+    })
+}
+
+/// Reads a whole file, or stdin for `-`.
+fn read(path: &str, io: &mut Io) -> Result<String, String> {
+    let mut text = String::new();
+    if path == "-" {
+        io.stdin
+            .read_to_string(&mut text)
+            .map_err(|e| format!("<stdin>: {}", e))?;
+    } else {
+        text = fs::read_to_string(path).map_err(|e| format!("{}: {}", path, e))?;
+    }
+    Ok(text)
+}
+
+/// How error messages name a file.
+fn display(path: &str) -> &str {
+    if path == "-" {
+        "<stdin>"
+    } else {
+        path
+    }
+}
+
+/// The options and files given to a command.
+struct Args<'a> {
+    flags: Vec<&'a str>,
+    values: Vec<(&'a str, &'a str)>,
+    files: Vec<&'a str>,
+}
+
+impl<'a> Args<'a> {
+    /// Reads `args`, accepting the options without a value in `flags` and
+    /// those with one in `valued`. Stdin stands for the files when there
+    /// are none.
+    fn parse(args: &'a [String], flags: &[&str], valued: &[&str]) -> Result<Self, String> {
+        let mut parsed = Args {
+            flags: Vec::new(),
+            values: Vec::new(),
+            files: Vec::new(),
+        };
+        let mut args = args.iter().map(String::as_str);
+        while let Some(arg) = args.next() {
+            if flags.contains(&arg) {
+                parsed.flags.push(arg);
+            } else if valued.contains(&arg) {
+                let value = args
+                    .next()
+                    .ok_or_else(|| format!("missing value for {}", arg))?;
+                parsed.values.push((arg, value));
+            } else if arg.starts_with('-') && arg != "-" {
+                return Err(format!("unknown option {:?}", arg));
+            } else {
+                parsed.files.push(arg);
+            }
+        }
+        if parsed.files.is_empty() {
+            parsed.files.push("-");
+        }
+        Ok(parsed)
+    }
+
+    fn flag(&self, name: &str) -> bool {
+        self.flags.contains(&name)
+    }
+
+    fn value(&self, name: &str) -> Option<&'a str> {
+        self.all(name).last()
+    }
+
+    /// Every value given to an option that may be repeated.
+    fn all<'s>(&'s self, name: &'s str) -> impl Iterator<Item = &'a str> + 's {
+        self.values
+            .iter()
+            .filter(move |(option, _)| *option == name)
+            .map(|(_, value)| *value)
+    }
+}
+
+/// Reads and parses a file, or stdin for `-`.
+fn read_value(path: &str, io: &mut Io) -> Result<json::Value, String> {
+    json::parse_located(read(path, io)?.as_str()).map_err(|e| format!("{}: {}", display(path), e))
+}
+
+/// Writes the text a command made of `path`: back to it with `-i`, and to
+/// stdout otherwise.
+fn write(path: &str, text: &str, in_place: bool, io: &mut Io) -> Result<(), String> {
+    if in_place {
+        if path == "-" {
+            return Err("cannot rewrite stdin in place".to_string());
+        }
+        fs::write(path, text).map_err(|e| format!("{}: {}", path, e))
+    } else {
+        io.stdout
+            .write_all(text.as_bytes())
+            .map_err(|e| e.to_string())
+    }
+}
+
+fn validate(args: &[String], io: &mut Io) -> Result<i32, String> {
+    let mut code = 0;
+    for path in Args::parse(args, &[], &[])?.files {
+        if let Err(e) = json::parse_located(read(path, io)?.as_str()) {
+            writeln!(io.stderr, "{}: {}", display(path), e).map_err(|e| e.to_string())?;
+            code = 1;
+        }
+    }
+    Ok(code)
+}
+
+fn fmt(args: &[String], io: &mut Io) -> Result<i32, String> {
+    let args = Args::parse(args, &["--sort-keys", "--check", "-i"], &["--indent"])?;
+    let indent = match args.value("--indent") {
+        Some(indent) => indent
+            .parse()
+            .map_err(|_| format!("invalid indent {:?}", indent))?,
+        None => 2,
+    };
+    let mut code = 0;
+    for path in &args.files {
+        let text = read(path, io)?;
+        let mut value =
+            json::parse_located(text.as_str()).map_err(|e| format!("{}: {}", display(path), e))?;
+        if args.flag("--sort-keys") {
+            value.sort_keys();
+        }
+        let formatted = reindent(&json::to_string_pretty(&value), indent) + "\n";
+        if !args.flag("--check") {
+            write(path, &formatted, args.flag("-i"), io)?;
+        } else if formatted != text {
+            writeln!(io.stderr, "{}: not formatted", display(path)).map_err(|e| e.to_string())?;
+            code = 1;
+        }
+    }
+    Ok(code)
+}
+
+/// Changes the two space indentation of pretty-printed JSON, which never
+/// holds raw line breaks within strings, to `width` spaces.
+fn reindent(pretty: &str, width: usize) -> String {
+    if width == 2 {
+        return pretty.to_string();
+    }
+    let lines: Vec<String> = pretty
+        .lines()
+        .map(|line| {
+            let content = line.trim_start_matches(' ');
+            let depth = (line.len() - content.len()) / 2;
+            " ".repeat(depth * width) + content
+        })
+        .collect();
+    lines.join("\n")
+}
+
+fn minify(args: &[String], io: &mut Io) -> Result<i32, String> {
+    let args = Args::parse(args, &["-i"], &[])?;
+    for path in &args.files {
+        let value = read_value(path, io)?;
+        write(path, &(json::to_string(&value) + "\n"), args.flag("-i"), io)?;
+    }
+    Ok(0)
+}
+
+fn get(args: &[String], io: &mut Io) -> Result<i32, String> {
+    let (expression, args) = match args.split_first() {
+        Some((expression, args)) if !expression.starts_with('-') || expression == "-" => {
+            (expression, args)
+        }
+        _ => return Err("missing expression".to_string()),
+    };
+    let args = Args::parse(args, &["-r"], &[])?;
+    let expression = Expression::parse(expression)?;
+    let mut code = 1;
+    for path in &args.files {
+        let value = read_value(path, io)?;
+        for (_, found) in expression.find(&value) {
+            code = 0;
+            match found {
+                json::Value::String(text) if args.flag("-r") => writeln!(io.stdout, "{}", text),
+                found => writeln!(io.stdout, "{}", json::to_string(found)),
+            }
+            .map_err(|e| e.to_string())?;
+        }
+    }
+    Ok(code)
+}
+
+/// A JSON Pointer, or a path pattern as [`json::Value::select`] takes.
+enum Expression<'a> {
+    Pointer(&'a str),
+    Pattern(json::PathPattern),
+}
+
+impl<'a> Expression<'a> {
+    fn parse(expression: &'a str) -> Result<Self, String> {
+        Ok(match path_pattern(expression)? {
+            Some(pattern) => Expression::Pattern(pattern),
+            None => Expression::Pointer(expression),
+        })
+    }
+
+    fn find<'v>(&self, value: &'v json::Value) -> Vec<(json::JsonPointer, &'v json::Value)> {
+        match self {
+            Expression::Pointer(pointer) => value
+                .pointer(*pointer)
+                .and_then(|found| Some((json::JsonPointer::parse(pointer).ok()?, found)))
+                .into_iter()
+                .collect(),
+            Expression::Pattern(pattern) => value.select_pattern(pattern),
+        }
+    }
+}
+
+/// The path pattern `expression` stands for, unless it is a JSON Pointer
+/// without wildcards. Pointers with `*` tokens keep their RFC 6901 meaning,
+/// and JSONPath expressions are translated, without support for filters,
+/// slices and unions.
+fn path_pattern(expression: &str) -> Result<Option<json::PathPattern>, String> {
+    if expression.starts_with('/') && expression.split('/').any(|token| token == "*") {
+        return json::PathPattern::from_pointer(expression).map(Some);
+    }
+    if expression.is_empty() || expression.starts_with('/') {
+        return Ok(None);
+    }
+    let path = match expression.strip_prefix('$') {
+        Some(path) => path,
+        None => return json::PathPattern::parse(expression).map(Some),
+    };
+    if path.contains("[?") || path.contains(':') || path.contains(',') {
+        return Err(format!("unsupported JSONPath expression {:?}", expression));
+    }
+    json::PathPattern::parse(
+        &path
+            .replace("..", ".**.")
+            .replace("['", "[")
+            .replace("']", "]")
+            .replace("[\"", "[")
+            .replace("\"]", "]"),
+    )
+    .map(Some)
+}
+
+/// The two files a command compares or combines.
+fn file_pair<'a>(args: &Args<'a>) -> Result<(&'a str, &'a str), String> {
+    match args.files[..] {
+        [first, second] => Ok((first, second)),
+        _ => Err("expected two files".to_string()),
+    }
+}
+
+fn diff(args: &[String], io: &mut Io) -> Result<i32, String> {
+    let args = Args::parse(args, &["--rfc6902"], &[])?;
+    let (from, to) = file_pair(&args)?;
+    let (from, to) = (read_value(from, io)?, read_value(to, io)?);
+    let output = if args.flag("--rfc6902") {
+        let patch = json::patch::Patch::diff(&from, &to);
+        if patch.0.is_empty() {
+            return Ok(0);
+        }
+        json::to_string_pretty(&patch.to_value()) + "\n"
+    } else {
+        let differences = from.diff(&to);
+        if differences.is_empty() {
+            return Ok(0);
+        }
+        differences
+            .iter()
+            .map(|difference| format!("{}\n", difference))
+            .collect()
+    };
+    io.stdout
+        .write_all(output.as_bytes())
+        .map_err(|e| e.to_string())?;
+    Ok(1)
+}
+
+fn patch(args: &[String], io: &mut Io) -> Result<i32, String> {
+    let args = Args::parse(args, &["-i"], &[])?;
+    let (path, patch) = file_pair(&args)?;
+    let mut document = read_value(path, io)?;
+    let patch = json::patch::Patch::from_value(read_value(patch, io)?)
+        .map_err(|e| format!("{}: {}", display(patch), e))?;
+    patch.apply(&mut document)?;
+    let text = json::to_string_pretty(&document) + "\n";
+    write(path, &text, args.flag("-i"), io)?;
+    Ok(0)
+}
+
+fn lines(args: &[String], io: &mut Io) -> Result<i32, String> {
+    let args = Args::parse(args, &["--count"], &["--where", "--select"])?;
+    let mut conditions = Vec::new();
+    for condition in args.all("--where") {
+        let (expression, value) = match condition.find('=') {
+            Some(i) => (&condition[..i], &condition[i + 1..]),
+            None => return Err(format!("expected <expr>=<value>, found {:?}", condition)),
+        };
+        let value = json::parse(value).unwrap_or_else(|_| json::Value::String(value.to_string()));
+        conditions.push((Expression::parse(expression)?, value));
+    }
+    let selections = match args.value("--select") {
+        Some(expressions) => Some(
+            expressions
+                .split(',')
+                .map(Expression::parse)
+                .collect::<Result<Vec<_>, _>>()?,
+        ),
+        None => None,
+    };
+    let mut count = 0;
+    for path in &args.files {
+        let mut reader: Box<dyn BufRead + '_> = if *path == "-" {
+            Box::new(BufReader::new(&mut *io.stdin))
+        } else {
+            Box::new(BufReader::new(
+                fs::File::open(path).map_err(|e| format!("{}: {}", path, e))?,
+            ))
+        };
+        let mut line = String::new();
+        let mut number = 0;
+        loop {
+            line.clear();
+            number += 1;
+            let read = reader
+                .read_line(&mut line)
+                .map_err(|e| format!("{}: {}", display(path), e))?;
+            if read == 0 {
+                break;
+            }
+            if line.trim().is_empty() {
+                continue;
+            }
+            let value = json::parse(line.as_str())
+                .map_err(|e| format!("{}: line {}: {}", display(path), number, e))?;
+            let mut kept = true;
+            for (expression, expected) in &conditions {
+                kept &= expression
+                    .find(&value)
+                    .iter()
+                    .any(|(_, found)| *found == expected);
+            }
+            if !kept {
+                continue;
+            }
+            count += 1;
+            if args.flag("--count") {
+                continue;
+            }
+            let output = match &selections {
+                Some(selections) => project(&value, selections),
+                None => value,
+            };
+            writeln!(io.stdout, "{}", json::to_string(&output)).map_err(|e| e.to_string())?;
+        }
+    }
+    if args.flag("--count") {
+        writeln!(io.stdout, "{}", count).map_err(|e| e.to_string())?;
+    }
+    Ok(0)
+}
+
+/// The parts of `value` that `selections` find, kept at their location as
+/// [`json::Value::project`] does.
+fn project(value: &json::Value, selections: &[Expression]) -> json::Value {
+    let pointers: Vec<json::JsonPointer> = selections
+        .iter()
+        .flat_map(|selection| selection.find(value))
+        .map(|(pointer, _)| pointer)
+        .collect();
+    value.project(&pointers)
+}
@@ -0,0 +1,226 @@
+use super::{run, Io};
+
+/// Runs the tool on `stdin`, returning its exit status, stdout and stderr.
+fn json(args: &[&str], stdin: &str) -> (i32, String, String) {
+    let args: Vec<String> = args.iter().map(|arg| arg.to_string()).collect();
+    let (mut stdout, mut stderr) = (Vec::new(), Vec::new());
+    let code = run(
+        &args,
+        &mut Io {
+            stdin: &mut stdin.as_bytes(),
+            stdout: &mut stdout,
+            stderr: &mut stderr,
+        },
+    );
+    (
+        code,
+        String::from_utf8(stdout).unwrap(),
+        String::from_utf8(stderr).unwrap(),
+    )
+}
+
+#[test]
+fn validate() {
+    assert_eq!(
+        json(&["validate"], r#"{ "a": [1, 2] }"#),
+        (0, String::new(), String::new())
+    );
+    assert_eq!(
+        json(&["validate", "-"], "{\n  \"a\": [1,\n"),
+        (
+            1,
+            String::new(),
+            "<stdin>: line 3, column 1: empty string\n".to_string()
+        )
+    );
+    let (code, _, stderr) = json(&["validate", "missing.json"], "");
+    assert_eq!(code, 2);
+    assert!(stderr.starts_with("json: missing.json: "));
+}
+
+#[test]
+fn usage() {
+    let (code, _, stderr) = json(&["frobnicate"], "");
+    assert_eq!(code, 2);
+    assert!(stderr.starts_with("json: unknown command \"frobnicate\"\n\nusage: json"));
+    assert_eq!(
+        json(&["validate", "--strict"], "").2,
+        "json: unknown option \"--strict\"\n"
+    );
+    assert_eq!(json(&["--help"], "").0, 0);
+}
+
+#[test]
+fn fmt() {
+    let input = r#"{"b":[1,{}],"a":"x"}"#;
+    assert_eq!(
+        json(&["fmt", "--indent", "4", "--sort-keys"], input).1,
+        "{\n    \"a\": \"x\",\n    \"b\": [\n        1,\n        {}\n    ]\n}\n"
+    );
+    assert_eq!(
+        json(&["fmt", "--check"], input),
+        (1, String::new(), "<stdin>: not formatted\n".to_string())
+    );
+    assert_eq!(json(&["fmt", "--check"], "[\n  1\n]\n").0, 0);
+    assert_eq!(
+        json(&["fmt", "--indent"], input).2,
+        "json: missing value for --indent\n"
+    );
+    assert_eq!(
+        json(&["fmt", "-i"], input).2,
+        "json: cannot rewrite stdin in place\n"
+    );
+}
+
+#[test]
+fn minify_in_place() {
+    let path = std::env::temp_dir().join(format!("json-minify-{}.json", std::process::id()));
+    std::fs::write(&path, "{ \"a\": [1, 2] }").unwrap();
+    let path_arg = path.to_str().unwrap();
+    assert_eq!(
+        json(&["minify", "-i", path_arg], ""),
+        (0, String::new(), String::new())
+    );
+    let minified = std::fs::read_to_string(&path).unwrap();
+    std::fs::remove_file(&path).unwrap();
+    assert_eq!(minified, "{\"a\":[1,2]}\n");
+    assert_eq!(json(&["minify"], "[1, 2]\n").1, "[1,2]\n");
+}
+
+#[test]
+fn get() {
+    let input = r#"{ "users": [{ "name": "John", "id": 1 }, { "name": "Jane", "id": 2 }] }"#;
+    let get = |args: &[&str]| {
+        let mut args = args.to_vec();
+        args.insert(0, "get");
+        json(&args, input)
+    };
+    assert_eq!(get(&["/users/1"]).1, "{\"name\":\"Jane\",\"id\":2}\n");
+    assert_eq!(get(&["users[*].name"]).1, "\"John\"\n\"Jane\"\n");
+    assert_eq!(get(&["$.users[*]['name']", "-r"]).1, "John\nJane\n");
+    assert_eq!(get(&["$..id"]).1, "1\n2\n");
+    assert_eq!(get(&["/users/*/id"]).1, "1\n2\n");
+    assert_eq!(get(&["/missing"]), (1, String::new(), String::new()));
+    assert_eq!(
+        get(&["$.users[?(@.id > 1)]"]).2,
+        "json: unsupported JSONPath expression \"$.users[?(@.id > 1)]\"\n"
+    );
+    assert_eq!(get(&[]).2, "json: missing expression\n");
+}
+
+/// Writes `text` to a file named after `name` in the temporary directory.
+fn temp_file(name: &str, text: &str) -> String {
+    let path = std::env::temp_dir().join(format!("json-{}-{}", std::process::id(), name));
+    std::fs::write(&path, text).unwrap();
+    path.to_str().unwrap().to_string()
+}
+
+#[test]
+fn diff() {
+    let from = temp_file("diff-from.json", r#"{ "port": 80, "tags": ["a"] }"#);
+    let to = temp_file("diff-to.json", r#"{ "port": "80", "tags": [] }"#);
+    assert_eq!(
+        json(&["diff", &from, &to], ""),
+        (
+            1,
+            "/port: expected number 80, found string \"80\"\n/tags/0: removed \"a\"\n".to_string(),
+            String::new()
+        )
+    );
+    let (code, patch, _) = json(&["diff", "--rfc6902", &from, &to], "");
+    assert_eq!(code, 1);
+    let patch = temp_file("diff.patch.json", &patch);
+    assert_eq!(
+        json(&["patch", &from, &patch], "").1,
+        "{\n  \"port\": \"80\",\n  \"tags\": []\n}\n"
+    );
+    assert_eq!(
+        json(&["diff", &from, &from], ""),
+        (0, String::new(), String::new())
+    );
+    assert_eq!(json(&["diff", &from], "").2, "json: expected two files\n");
+    for path in [from, to, patch].iter() {
+        std::fs::remove_file(path).unwrap();
+    }
+}
+
+#[test]
+fn patch_conflict() {
+    let patch = temp_file(
+        "conflict.patch.json",
+        r#"[{ "op": "remove", "path": "/a" }]"#,
+    );
+    assert_eq!(
+        json(&["patch", "-", &patch], "{}").2,
+        "json: operation 0 at \"/a\": path not found\n"
+    );
+    std::fs::remove_file(patch).unwrap();
+}
+
+#[test]
+fn get_pointer_wildcards() {
+    let input = r#"{ "a.b": [1, 2], "a": { "b": [3] }, "c/d": { "e": 4 } }"#;
+    assert_eq!(json(&["get", "/a.b/*"], input).1, "1\n2\n");
+    assert_eq!(json(&["get", "/c~1d/*"], input).1, "4\n");
+    assert_eq!(
+        json(&["get", "/a~2/*"], input).2,
+        "json: /a~2/* is not a valid json pointer\n"
+    );
+}
+
+#[test]
+fn lines() {
+    let input = concat!(
+        r#"{ "level": "error", "code": 500, "user": { "id": 1, "name": "a" } }"#,
+        "\n\n",
+        r#"{ "level": "info", "code": 200, "user": { "id": 2 } }"#,
+        "\n",
+        r#"{ "level": "error", "code": 404 }"#,
+    );
+    assert_eq!(
+        json(
+            &[
+                "lines",
+                "--where",
+                "level=error",
+                "--select",
+                "/code,user.id"
+            ],
+            input
+        )
+        .1,
+        "{\"code\":500,\"user\":{\"id\":1}}\n{\"code\":404}\n"
+    );
+    assert_eq!(
+        json(
+            &[
+                "lines",
+                "--where",
+                "/level=error",
+                "--where",
+                "code=500",
+                "--count"
+            ],
+            input
+        )
+        .1,
+        "1\n"
+    );
+    assert_eq!(json(&["lines", "--count"], input).1, "3\n");
+    assert_eq!(
+        json(
+            &["lines", "--select", "/items/1/id,/a~1b"],
+            r#"{ "items": [{ "id": 1 }, { "id": 2, "x": 0 }], "a/b": true }"#
+        )
+        .1,
+        "{\"items\":[null,{\"id\":2}],\"a/b\":true}\n"
+    );
+    assert_eq!(
+        json(&["lines"], "{}\n[1,\n").2,
+        "json: <stdin>: line 2: empty string\n"
+    );
+    assert_eq!(
+        json(&["lines", "--where", "level"], input).2,
+        "json: expected <expr>=<value>, found \"level\"\n"
+    );
+}
@@ -0,0 +1,640 @@
+#[cfg(test)]
+mod tests;
+
+use crate::convert::{at, mismatch};
+use crate::{JsonObject, Value};
+use base64::alphabet::STANDARD;
+use base64::engine::general_purpose::{GeneralPurpose, GeneralPurposeConfig};
+use base64::engine::DecodePaddingMode;
+use base64::Engine;
+use std::convert::{TryFrom, TryInto};
+
+/// Nesting beyond which decoding gives up rather than overflow the stack.
+const MAX_DEPTH: usize = 128;
+
+/// Binary data is standard base64; padding is written but optional on input.
+const BASE64: GeneralPurpose = GeneralPurpose::new(
+    &STANDARD,
+    GeneralPurposeConfig::new().with_decode_padding_mode(DecodePaddingMode::Indifferent),
+);
+
+const DOUBLE: u8 = 0x01;
+const STRING: u8 = 0x02;
+const DOCUMENT: u8 = 0x03;
+const ARRAY: u8 = 0x04;
+const BINARY: u8 = 0x05;
+const UNDEFINED: u8 = 0x06;
+const OBJECT_ID: u8 = 0x07;
+const BOOLEAN: u8 = 0x08;
+const DATE_TIME: u8 = 0x09;
+const NULL: u8 = 0x0a;
+const REGEX: u8 = 0x0b;
+const DB_POINTER: u8 = 0x0c;
+const CODE: u8 = 0x0d;
+const SYMBOL: u8 = 0x0e;
+const CODE_WITH_SCOPE: u8 = 0x0f;
+const INT32: u8 = 0x10;
+const TIMESTAMP: u8 = 0x11;
+const INT64: u8 = 0x12;
+const DECIMAL128: u8 = 0x13;
+const MIN_KEY: u8 = 0xff;
+const MAX_KEY: u8 = 0x7f;
+
+/// Encodes an object as a BSON document. Integral numbers become an int32
+/// or, if they don't fit, an int64 and others a double. Objects in one of
+/// the extended JSON forms [`from_bson`] produces become the BSON type they
+/// stand for, so documents read from BSON are written back unchanged.
+///
+/// # Examples
+///
+/// ```
+///# fn main() -> Result<(),String> {
+///let value = json::parse(r#"{ "_id": { "$oid": "65f1a2b3c4d5e6f708192a3b" }, "n": 1 }"#)?;
+///let bytes = json::to_bson(&value)?;
+///
+///assert_eq!(bytes[4], 0x07);
+///assert_eq!(json::from_bson(&bytes)?, value);
+///assert_eq!(json::to_bson(&json::parse("[1]")?), Err("expected object, found array".to_string()));
+///# Ok(())
+///# }
+/// ```
+pub fn to_bson(value: &Value) -> Result<Vec<u8>, String> {
+    match value {
+        Value::Object(object) => {
+            let mut output = Vec::new();
+            encode_document(
+                &mut output,
+                object.iter().map(|(key, value)| (key.as_str(), value)),
+            )?;
+            Ok(output)
+        }
+        value => Err(mismatch("object", value)),
+    }
+}
+
+fn encode_document<'a, K: AsRef<str>>(
+    output: &mut Vec<u8>,
+    entries: impl Iterator<Item = (K, &'a Value)>,
+) -> Result<(), String> {
+    let start = output.len();
+    output.extend([0; 4]);
+    for (key, value) in entries {
+        let key = key.as_ref();
+        let kind = output.len();
+        output.push(0);
+        cstring(output, key).map_err(|e| at(key, e))?;
+        output[kind] = encode_element(output, value).map_err(|e| at(key, e))?;
+    }
+    output.push(0);
+    patch_length(output, start);
+    Ok(())
+}
+
+/// Fills in the int32 length reserved at `start` for what follows it.
+fn patch_length(output: &mut [u8], start: usize) {
+    let length = (output.len() - start) as i32;
+    output[start..start + 4].copy_from_slice(&length.to_le_bytes());
+}
+
+fn cstring(output: &mut Vec<u8>, value: &str) -> Result<(), String> {
+    if value.contains('\0') {
+        return Err(format!("expected no nul bytes, found {:?}", value));
+    }
+    output.extend(value.as_bytes());
+    output.push(0);
+    Ok(())
+}
+
+fn string(output: &mut Vec<u8>, value: &str) {
+    output.extend((value.len() as i32 + 1).to_le_bytes());
+    output.extend(value.as_bytes());
+    output.push(0);
+}
+
+/// Writes the value of an element and returns its type.
+fn encode_element(output: &mut Vec<u8>, value: &Value) -> Result<u8, String> {
+    Ok(match value {
+        Value::Null => NULL,
+        Value::Bool(value) => {
+            output.push(*value as u8);
+            BOOLEAN
+        }
+        Value::Number(n) if n.fract() == 0.0 && *n >= i32::MIN as f64 && *n <= i32::MAX as f64 => {
+            output.extend((*n as i32).to_le_bytes());
+            INT32
+        }
+        Value::Number(n) if n.fract() == 0.0 && *n >= i64::MIN as f64 && *n < i64::MAX as f64 => {
+            output.extend((*n as i64).to_le_bytes());
+            INT64
+        }
+        Value::Number(n) => {
+            output.extend(n.to_le_bytes());
+            DOUBLE
+        }
+        Value::String(value) => {
+            string(output, value);
+            STRING
+        }
+        Value::Array(values) => {
+            encode_document(
+                output,
+                values.iter().enumerate().map(|(i, v)| (i.to_string(), v)),
+            )?;
+            ARRAY
+        }
+        Value::Object(object) => match encode_extended(output, object)? {
+            Some(kind) => kind,
+            None => {
+                encode_document(
+                    output,
+                    object.iter().map(|(key, value)| (key.as_str(), value)),
+                )?;
+                DOCUMENT
+            }
+        },
+    })
+}
+
+/// Writes an object in one of the extended JSON forms as the type it stands
+/// for and returns that type, or `None` for any other object.
+fn encode_extended(output: &mut Vec<u8>, object: &JsonObject) -> Result<Option<u8>, String> {
+    if object.len() == 2 && object.contains_key("$code") && object.contains_key("$scope") {
+        let start = output.len();
+        output.extend([0; 4]);
+        let code = str(object.get("$code").unwrap()).map_err(|e| at("$code", e))?;
+        string(output, code);
+        match object.get("$scope").unwrap() {
+            Value::Object(scope) => {
+                encode_document(output, scope.iter().map(|(k, v)| (k.as_str(), v)))
+                    .map_err(|e| at("$scope", e))?
+            }
+            value => return Err(at("$scope", mismatch("object", value))),
+        }
+        patch_length(output, start);
+        return Ok(Some(CODE_WITH_SCOPE));
+    }
+    let (key, value) = match object.iter().next() {
+        Some((key, value)) if object.len() == 1 => (key.as_str(), value),
+        _ => return Ok(None),
+    };
+    let kind = match key {
+        "$oid" => OBJECT_ID,
+        "$date" => DATE_TIME,
+        "$numberLong" => INT64,
+        "$numberDecimal" => DECIMAL128,
+        "$binary" => BINARY,
+        "$regularExpression" => REGEX,
+        "$timestamp" => TIMESTAMP,
+        "$code" => CODE,
+        "$symbol" => SYMBOL,
+        "$dbPointer" => DB_POINTER,
+        "$minKey" => MIN_KEY,
+        "$maxKey" => MAX_KEY,
+        _ => return Ok(None),
+    };
+    encode_payload(output, kind, value).map_err(|e| at(key, e))?;
+    Ok(Some(kind))
+}
+
+fn encode_payload(output: &mut Vec<u8>, kind: u8, value: &Value) -> Result<(), String> {
+    match kind {
+        OBJECT_ID => output.extend(object_id(value)?),
+        DATE_TIME | INT64 => output.extend(long(value)?.to_le_bytes()),
+        DECIMAL128 => output.extend(parse_decimal128(str(value)?)?.to_le_bytes()),
+        BINARY => {
+            let fields = fields(value, &["base64", "subType"])?;
+            let bytes = str(fields[0])
+                .and_then(decode_base64)
+                .map_err(|e| at("base64", e))?;
+            let subtype = str(fields[1])
+                .and_then(|text| {
+                    u8::from_str_radix(text, 16)
+                        .map_err(|_| format!("expected hexadecimal byte, found {:?}", text))
+                })
+                .map_err(|e| at("subType", e))?;
+            // The old binary subtype repeats the length inside the data.
+            let inner = if subtype == 2 { 4 } else { 0 };
+            output.extend(((bytes.len() + inner) as i32).to_le_bytes());
+            output.push(subtype);
+            if subtype == 2 {
+                output.extend((bytes.len() as i32).to_le_bytes());
+            }
+            output.extend(bytes);
+        }
+        REGEX => {
+            let fields = fields(value, &["pattern", "options"])?;
+            let pattern = str(fields[0]).map_err(|e| at("pattern", e))?;
+            cstring(output, pattern).map_err(|e| at("pattern", e))?;
+            let options = str(fields[1]).map_err(|e| at("options", e))?;
+            cstring(output, options).map_err(|e| at("options", e))?;
+        }
+        TIMESTAMP => {
+            let fields = fields(value, &["t", "i"])?;
+            let time = uint32(fields[0]).map_err(|e| at("t", e))?;
+            let increment = uint32(fields[1]).map_err(|e| at("i", e))?;
+            output.extend(((time as u64) << 32 | increment as u64).to_le_bytes());
+        }
+        CODE | SYMBOL => string(output, str(value)?),
+        DB_POINTER => {
+            let fields = fields(value, &["$ref", "$id"])?;
+            let namespace = str(fields[0]).map_err(|e| at("$ref", e))?;
+            let id = self::fields(fields[1], &["$oid"])
+                .and_then(|id| object_id(id[0]).map_err(|e| at("$oid", e)))
+                .map_err(|e| at("$id", e))?;
+            string(output, namespace);
+            output.extend(id);
+        }
+        _ => {}
+    }
+    Ok(())
+}
+
+fn str(value: &Value) -> Result<&str, String> {
+    match value {
+        Value::String(value) => Ok(value),
+        value => Err(mismatch("string", value)),
+    }
+}
+
+fn uint32(value: &Value) -> Result<u32, String> {
+    match value {
+        Value::Number(n) if n.fract() == 0.0 && *n >= 0.0 && *n <= u32::MAX as f64 => Ok(*n as u32),
+        value => Err(mismatch("uint32", value)),
+    }
+}
+
+/// Reads an int64 given as a number or, as extended JSON writes those that
+/// don't fit a double, as a string of digits or a `$numberLong`.
+fn long(value: &Value) -> Result<i64, String> {
+    match value {
+        Value::Number(n) if n.fract() == 0.0 && *n >= i64::MIN as f64 && *n < i64::MAX as f64 => {
+            Ok(*n as i64)
+        }
+        Value::String(text) => text
+            .parse()
+            .map_err(|_| format!("expected int64, found {:?}", text)),
+        Value::Object(object) if object.len() == 1 && object.contains_key("$numberLong") => {
+            long(object.get("$numberLong").unwrap()).map_err(|e| at("$numberLong", e))
+        }
+        value => Err(mismatch("int64", value)),
+    }
+}
+
+fn object_id(value: &Value) -> Result<[u8; 12], String> {
+    let text = str(value)?;
+    let mut id = [0; 12];
+    if text.len() != 24 || !text.bytes().all(|b| b.is_ascii_hexdigit()) {
+        return Err(format!("expected 24 hexadecimal digits, found {:?}", text));
+    }
+    for (i, byte) in id.iter_mut().enumerate() {
+        *byte = u8::from_str_radix(&text[2 * i..2 * i + 2], 16).unwrap();
+    }
+    Ok(id)
+}
+
+/// The values of an object's keys, which must be exactly those given.
+fn fields<'a>(value: &'a Value, keys: &[&str]) -> Result<Vec<&'a Value>, String> {
+    let object = match value {
+        Value::Object(object) => object,
+        value => return Err(mismatch("object", value)),
+    };
+    if let Some((key, _)) = object.iter().find(|(key, _)| !keys.contains(&key.as_str())) {
+        return Err(at(key, "not allowed".to_string()));
+    }
+    keys.iter()
+        .map(|key| {
+            object
+                .get(key)
+                .ok_or_else(|| at(key, "missing".to_string()))
+        })
+        .collect()
+}
+
+fn decode_base64(text: &str) -> Result<Vec<u8>, String> {
+    BASE64
+        .decode(text)
+        .map_err(|_| format!("expected base64, found {:?}", text))
+}
+
+/// Decodes a BSON document into an object. Types JSON has no counterpart
+/// for become objects in the relaxed extended JSON forms MongoDB tools use:
+///
+/// - an ObjectId becomes `{"$oid": "<24 hex digits>"}`,
+/// - a date becomes `{"$date": {"$numberLong": "<milliseconds since the epoch>"}}`,
+/// - binary data becomes `{"$binary": {"base64": "...", "subType": "<hex>"}}`,
+/// - an int64 a double can't hold exactly becomes `{"$numberLong": "..."}`,
+/// - a decimal128 becomes `{"$numberDecimal": "..."}`,
+/// - and likewise for `$regularExpression`, `$timestamp`, `$code` (with
+///   `$scope`), `$symbol`, `$dbPointer`, `$minKey` and `$maxKey`.
+///
+/// Undefined and non-finite doubles become `null`.
+///
+/// # Examples
+///
+/// ```
+///# fn main() -> Result<(),String> {
+///let mut bytes = vec![16, 0, 0, 0, 0x09, b't', 0];
+///bytes.extend(1_700_000_000_000i64.to_le_bytes());
+///bytes.push(0);
+///
+///assert_eq!(
+///    json::from_bson(&bytes)?,
+///    json::parse(r#"{ "t": { "$date": { "$numberLong": "1700000000000" } } }"#)?
+///);
+///# Ok(())
+///# }
+/// ```
+pub fn from_bson(bytes: &[u8]) -> Result<Value, String> {
+    let mut decoder = Decoder { bytes, offset: 0 };
+    let value = decoder.document(0)?;
+    if decoder.offset != bytes.len() {
+        return Err(format!(
+            "unexpected bytes after value at offset {}",
+            decoder.offset
+        ));
+    }
+    Ok(Value::Object(value.into_iter().collect()))
+}
+
+/// An object with the given entries, for the extended JSON forms.
+fn extended<const N: usize>(entries: [(&str, Value); N]) -> Value {
+    Value::Object(
+        IntoIterator::into_iter(entries)
+            .map(|(key, value)| (key.to_string(), value))
+            .collect(),
+    )
+}
+
+fn hex(bytes: &[u8]) -> String {
+    bytes.iter().map(|byte| format!("{:02x}", byte)).collect()
+}
+
+struct Decoder<'a> {
+    bytes: &'a [u8],
+    offset: usize,
+}
+
+impl<'a> Decoder<'a> {
+    fn take(&mut self, length: usize) -> Result<&'a [u8], String> {
+        let end = self
+            .offset
+            .checked_add(length)
+            .filter(|end| *end <= self.bytes.len());
+        match end {
+            Some(end) => {
+                let taken = &self.bytes[self.offset..end];
+                self.offset = end;
+                Ok(taken)
+            }
+            None => Err("unexpected end of input".to_string()),
+        }
+    }
+
+    fn array<const N: usize>(&mut self) -> Result<[u8; N], String> {
+        Ok(self.take(N)?.try_into().unwrap())
+    }
+
+    /// Reads an int32 length, which counts itself when `counted` is set.
+    fn length(&mut self, counted: bool) -> Result<usize, String> {
+        let start = self.offset;
+        let length = i32::from_le_bytes(self.array()?);
+        let skipped = if counted { 4 } else { 0 };
+        usize::try_from(length)
+            .ok()
+            .filter(|length| *length >= skipped)
+            .map(|length| length - skipped)
+            .ok_or_else(|| format!("invalid length {} at offset {}", length, start))
+    }
+
+    fn utf8(&self, bytes: &'a [u8], start: usize) -> Result<&'a str, String> {
+        std::str::from_utf8(bytes)
+            .map_err(|_| format!("invalid utf-8 in string at offset {}", start))
+    }
+
+    fn cstring(&mut self) -> Result<&'a str, String> {
+        let start = self.offset;
+        let length = self.bytes[start..]
+            .iter()
+            .position(|byte| *byte == 0)
+            .ok_or("unexpected end of input")?;
+        let bytes = self.take(length + 1)?;
+        self.utf8(&bytes[..length], start)
+    }
+
+    fn string(&mut self) -> Result<String, String> {
+        let length = self.length(false)?;
+        let start = self.offset;
+        match self.take(length)?.split_last() {
+            Some((0, bytes)) => Ok(self.utf8(bytes, start)?.to_string()),
+            _ => Err(format!("unterminated string at offset {}", start)),
+        }
+    }
+
+    fn document(&mut self, depth: usize) -> Result<Vec<(String, Value)>, String> {
+        if depth > MAX_DEPTH {
+            return Err("too deeply nested".to_string());
+        }
+        let start = self.offset;
+        let end = self.length(false)? + start;
+        let mut entries = Vec::new();
+        loop {
+            let offset = self.offset;
+            let kind = self.take(1)?[0];
+            if kind == 0 {
+                break;
+            }
+            let key = self.cstring()?.to_string();
+            entries.push((key, self.element(kind, offset, depth)?));
+        }
+        if self.offset != end {
+            return Err(format!("document length mismatch at offset {}", start));
+        }
+        Ok(entries)
+    }
+
+    fn element(&mut self, kind: u8, offset: usize, depth: usize) -> Result<Value, String> {
+        Ok(match kind {
+            DOUBLE => match f64::from_le_bytes(self.array()?) {
+                n if n.is_finite() => Value::Number(n),
+                _ => Value::Null,
+            },
+            STRING => Value::String(self.string()?),
+            DOCUMENT => Value::Object(self.document(depth + 1)?.into_iter().collect()),
+            ARRAY => Value::Array(
+                self.document(depth + 1)?
+                    .into_iter()
+                    .map(|(_, value)| value)
+                    .collect(),
+            ),
+            BINARY => {
+                let length = self.length(false)?;
+                let subtype = self.take(1)?[0];
+                let mut bytes = self.take(length)?;
+                if subtype == 2 && bytes.len() >= 4 {
+                    bytes = &bytes[4..];
+                }
+                extended([(
+                    "$binary",
+                    extended([
+                        ("base64", Value::String(BASE64.encode(bytes))),
+                        ("subType", Value::String(format!("{:02x}", subtype))),
+                    ]),
+                )])
+            }
+            UNDEFINED | NULL => Value::Null,
+            OBJECT_ID => extended([("$oid", Value::String(hex(self.take(12)?)))]),
+            BOOLEAN => match self.take(1)?[0] {
+                0 => Value::Bool(false),
+                1 => Value::Bool(true),
+                byte => {
+                    return Err(format!(
+                        "invalid boolean {} at offset {}",
+                        byte,
+                        self.offset - 1
+                    ))
+                }
+            },
+            DATE_TIME => {
+                let millis = i64::from_le_bytes(self.array()?);
+                extended([(
+                    "$date",
+                    extended([("$numberLong", Value::String(millis.to_string()))]),
+                )])
+            }
+            REGEX => {
+                let pattern = Value::String(self.cstring()?.to_string());
+                let options = Value::String(self.cstring()?.to_string());
+                extended([(
+                    "$regularExpression",
+                    extended([("pattern", pattern), ("options", options)]),
+                )])
+            }
+            DB_POINTER => {
+                let namespace = Value::String(self.string()?);
+                let id = extended([("$oid", Value::String(hex(self.take(12)?)))]);
+                extended([("$dbPointer", extended([("$ref", namespace), ("$id", id)]))])
+            }
+            CODE => extended([("$code", Value::String(self.string()?))]),
+            SYMBOL => extended([("$symbol", Value::String(self.string()?))]),
+            CODE_WITH_SCOPE => {
+                let start = self.offset;
+                let end = self.length(false)? + start;
+                let code = Value::String(self.string()?);
+                let scope = Value::Object(self.document(depth + 1)?.into_iter().collect());
+                if self.offset != end {
+                    return Err(format!(
+                        "code with scope length mismatch at offset {}",
+                        start
+                    ));
+                }
+                extended([("$code", code), ("$scope", scope)])
+            }
+            INT32 => Value::Number(i32::from_le_bytes(self.array()?) as f64),
+            TIMESTAMP => {
+                let timestamp = u64::from_le_bytes(self.array()?);
+                extended([(
+                    "$timestamp",
+                    extended([
+                        ("t", Value::Number((timestamp >> 32) as f64)),
+                        ("i", Value::Number(timestamp as u32 as f64)),
+                    ]),
+                )])
+            }
+            INT64 => match i64::from_le_bytes(self.array()?) {
+                n if n.unsigned_abs() <= 1 << 53 => Value::Number(n as f64),
+                n => extended([("$numberLong", Value::String(n.to_string()))]),
+            },
+            DECIMAL128 => {
+                let decimal = decimal128_string(u128::from_le_bytes(self.array()?));
+                extended([("$numberDecimal", Value::String(decimal))])
+            }
+            MIN_KEY => extended([("$minKey", Value::Number(1.0))]),
+            MAX_KEY => extended([("$maxKey", Value::Number(1.0))]),
+            kind => {
+                return Err(format!(
+                    "unsupported element type {:#04x} at offset {}",
+                    kind, offset
+                ))
+            }
+        })
+    }
+}
+
+const DECIMAL128_BIAS: i64 = 6176;
+
+/// Formats an IEEE 754 decimal128 the way the BSON specification does.
+fn decimal128_string(bits: u128) -> String {
+    let sign = if bits >> 127 == 1 { "-" } else { "" };
+    let (exponent, coefficient) = match (bits >> 122) & 0x1f {
+        0x1f => return "NaN".to_string(),
+        0x1e => return format!("{}Infinity", sign),
+        // Such coefficients exceed the 34 digits allowed and read as zero.
+        combination if combination >> 3 == 0b11 => ((bits >> 111) & 0x3fff, 0),
+        _ => ((bits >> 113) & 0x3fff, bits & ((1 << 113) - 1)),
+    };
+    let coefficient = if coefficient < 10u128.pow(34) {
+        coefficient
+    } else {
+        0
+    };
+    let exponent = exponent as i64 - DECIMAL128_BIAS;
+    let digits = coefficient.to_string();
+    let adjusted = exponent + digits.len() as i64 - 1;
+    if exponent > 0 || adjusted < -6 {
+        let (first, rest) = digits.split_at(1);
+        let point = if rest.is_empty() { "" } else { "." };
+        format!("{}{}{}{}E{:+}", sign, first, point, rest, adjusted)
+    } else if exponent == 0 {
+        format!("{}{}", sign, digits)
+    } else {
+        let point = digits.len() as i64 + exponent;
+        if point > 0 {
+            let (whole, fraction) = digits.split_at(point as usize);
+            format!("{}{}.{}", sign, whole, fraction)
+        } else {
+            format!("{}0.{}{}", sign, "0".repeat(-point as usize), digits)
+        }
+    }
+}
+
+/// Parses a decimal string into an IEEE 754 decimal128, without rounding.
+fn parse_decimal128(text: &str) -> Result<u128, String> {
+    let error = || format!("expected decimal128, found {:?}", text);
+    let (sign, rest) = match text.strip_prefix('-') {
+        Some(rest) => (1 << 127, rest),
+        None => (0, text.strip_prefix('+').unwrap_or(text)),
+    };
+    if rest.eq_ignore_ascii_case("nan") {
+        return Ok(0x7c << 120);
+    }
+    if rest.eq_ignore_ascii_case("inf") || rest.eq_ignore_ascii_case("infinity") {
+        return Ok(sign | 0x78 << 120);
+    }
+    let (mantissa, exponent) = match rest.find(['e', 'E']) {
+        Some(i) => (
+            &rest[..i],
+            rest[i + 1..].parse::<i64>().map_err(|_| error())?,
+        ),
+        None => (rest, 0),
+    };
+    let (whole, fraction) = mantissa.split_once('.').unwrap_or((mantissa, ""));
+    let digits = format!("{}{}", whole, fraction);
+    if digits.is_empty() || !digits.bytes().all(|b| b.is_ascii_digit()) {
+        return Err(error());
+    }
+    let mut digits = digits.trim_start_matches('0');
+    let mut exponent = exponent.saturating_sub(fraction.len() as i64);
+    while digits.len() > 34 && digits.ends_with('0') {
+        digits = &digits[..digits.len() - 1];
+        exponent = exponent.saturating_add(1);
+    }
+    let coefficient: u128 = if digits.is_empty() {
+        0
+    } else {
+        digits.parse().map_err(|_| error())?
+    };
+    if digits.len() > 34 || !(-DECIMAL128_BIAS..=6111).contains(&exponent) {
+        return Err(error());
+    }
+    Ok(sign | ((exponent + DECIMAL128_BIAS) as u128) << 113 | coefficient)
+}
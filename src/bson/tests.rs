@@ -0,0 +1,93 @@
+use super::{decimal128_string, from_bson, parse_decimal128, to_bson};
+use crate::{parse, JsonObject, Value};
+
+#[test]
+fn round_trip() {
+    let value = parse(
+        r#"{ "s": "héllo", "n": [1, -2147483649, 0.5, null, true], "o": {},
+             "id": { "$oid": "65f1a2b3c4d5e6f708192a3b" },
+             "at": { "$date": { "$numberLong": "-1000" } },
+             "big": { "$numberLong": "9007199254740993" },
+             "bin": { "$binary": { "base64": "AQID", "subType": "00" } },
+             "old": { "$binary": { "base64": "+/8=", "subType": "02" } },
+             "re": { "$regularExpression": { "pattern": "^a", "options": "i" } },
+             "ts": { "$timestamp": { "t": 1700000000, "i": 7 } },
+             "d": { "$numberDecimal": "-12.50" },
+             "js": { "$code": "x + 1", "$scope": { "x": 1 } },
+             "ptr": { "$dbPointer": { "$ref": "db.c", "$id": { "$oid": "65f1a2b3c4d5e6f708192a3b" } } },
+             "keys": [{ "$minKey": 1 }, { "$maxKey": 1 }, { "$symbol": "s" }, { "$code": "f()" }] }"#,
+    )
+    .unwrap();
+    assert_eq!(from_bson(&to_bson(&value).unwrap()), Ok(value));
+}
+
+#[test]
+fn spec_bytes() {
+    // {"hello": "world"} from the BSON specification.
+    let bytes = b"\x16\x00\x00\x00\x02hello\x00\x06\x00\x00\x00world\x00\x00";
+    let value = parse(r#"{ "hello": "world" }"#).unwrap();
+    assert_eq!(to_bson(&value).unwrap(), bytes);
+    assert_eq!(from_bson(bytes), Ok(value));
+    let date = parse(r#"{ "t": { "$date": 5 } }"#).unwrap();
+    assert_eq!(
+        from_bson(&to_bson(&date).unwrap()),
+        parse(r#"{ "t": { "$date": { "$numberLong": "5" } } }"#)
+    );
+}
+
+#[test]
+fn decimal128() {
+    for text in [
+        "0",
+        "-0",
+        "1.05",
+        "-12.50",
+        "0.000001",
+        "1E-7",
+        "1.2E+3",
+        "NaN",
+        "-Infinity",
+    ] {
+        assert_eq!(decimal128_string(parse_decimal128(text).unwrap()), text);
+    }
+    assert_eq!(decimal128_string(parse_decimal128("1e3").unwrap()), "1E+3");
+    assert_eq!(decimal128_string(parse_decimal128(".5").unwrap()), "0.5");
+    assert_eq!(parse_decimal128("0.1").unwrap(), 1 | 6175 << 113);
+    assert!(parse_decimal128("1.2.3").is_err());
+    assert!(parse_decimal128(&"1".repeat(35)).is_err());
+}
+
+#[test]
+fn errors() {
+    let invalid = |json: &str| to_bson(&parse(json).unwrap()).unwrap_err();
+    assert_eq!(
+        invalid(r#"{ "a": { "_id": { "$oid": "xyz" } } }"#),
+        r#"/a/_id/$oid: expected 24 hexadecimal digits, found "xyz""#
+    );
+    assert_eq!(
+        invalid(r#"{ "b": { "$binary": { "base64": "AA" } } }"#),
+        "/b/$binary/subType: missing"
+    );
+    assert_eq!(
+        invalid(r#"{ "b": { "$binary": { "base64": "A-_=", "subType": "00" } } }"#),
+        r#"/b/$binary/base64: expected base64, found "A-_=""#
+    );
+    let mut nul = JsonObject::new();
+    nul.insert("k\0".to_string(), Value::Null);
+    assert_eq!(
+        to_bson(&Value::Object(nul)),
+        Err("/k\0: expected no nul bytes, found \"k\\0\"".to_string())
+    );
+    assert_eq!(
+        from_bson(b"\x0c\x00\x00\x00\x14a\x00\x00\x00\x00\x00\x00"),
+        Err("unsupported element type 0x14 at offset 4".to_string())
+    );
+    assert_eq!(
+        from_bson(b"\x05\x00\x00\x00"),
+        Err("unexpected end of input".to_string())
+    );
+    assert_eq!(
+        from_bson(b"\x06\x00\x00\x00\x00\x00"),
+        Err("document length mismatch at offset 0".to_string())
+    );
+}
@@ -0,0 +1,295 @@
+#[cfg(test)]
+mod tests;
+
+use crate::{to_string, JsonObject, Value};
+use base64::engine::general_purpose::URL_SAFE_NO_PAD;
+use base64::Engine;
+use std::convert::{TryFrom, TryInto};
+
+/// Nesting beyond which decoding gives up rather than overflow the stack.
+const MAX_DEPTH: usize = 128;
+
+/// Encodes a value as CBOR (RFC 8949). Integral numbers become CBOR
+/// integers and others the shortest float that holds them exactly.
+///
+/// # Examples
+///
+/// ```
+///# fn main() -> Result<(),String> {
+///let value = json::parse(r#"{ "a": [1, -2.5, null] }"#)?;
+///let bytes = json::to_cbor(&value);
+///
+///assert_eq!(bytes, [0xa1, 0x61, b'a', 0x83, 0x01, 0xfa, 0xc0, 0x20, 0x00, 0x00, 0xf6]);
+///assert_eq!(json::from_cbor(&bytes)?, value);
+///# Ok(())
+///# }
+/// ```
+pub fn to_cbor(value: &Value) -> Vec<u8> {
+    let mut output = Vec::new();
+    encode(&mut output, value);
+    output
+}
+
+fn head(output: &mut Vec<u8>, major: u8, argument: u64) {
+    let major = major << 5;
+    match argument {
+        0..=23 => output.push(major | argument as u8),
+        24..=0xff => output.extend([major | 24, argument as u8]),
+        0x100..=0xffff => {
+            output.push(major | 25);
+            output.extend((argument as u16).to_be_bytes());
+        }
+        0x1_0000..=0xffff_ffff => {
+            output.push(major | 26);
+            output.extend((argument as u32).to_be_bytes());
+        }
+        _ => {
+            output.push(major | 27);
+            output.extend(argument.to_be_bytes());
+        }
+    }
+}
+
+fn encode(output: &mut Vec<u8>, value: &Value) {
+    match value {
+        Value::Null => output.push(0xf6),
+        Value::Bool(false) => output.push(0xf4),
+        Value::Bool(true) => output.push(0xf5),
+        Value::Number(n) if n.fract() == 0.0 && *n >= 0.0 && *n < u64::MAX as f64 => {
+            head(output, 0, *n as u64)
+        }
+        Value::Number(n) if n.fract() == 0.0 && *n < 0.0 && *n >= -(u64::MAX as f64) => {
+            head(output, 1, (-1.0 - *n) as u64)
+        }
+        Value::Number(n) if (*n as f32) as f64 == *n || n.is_nan() => {
+            output.push(0xfa);
+            output.extend((*n as f32).to_be_bytes());
+        }
+        Value::Number(n) => {
+            output.push(0xfb);
+            output.extend(n.to_be_bytes());
+        }
+        Value::String(value) => {
+            head(output, 3, value.len() as u64);
+            output.extend(value.as_bytes());
+        }
+        Value::Array(values) => {
+            head(output, 4, values.len() as u64);
+            for value in values {
+                encode(output, value);
+            }
+        }
+        Value::Object(object) => {
+            head(output, 5, object.len() as u64);
+            for (key, value) in object.iter() {
+                head(output, 3, key.len() as u64);
+                output.extend(key.as_bytes());
+                encode(output, value);
+            }
+        }
+    }
+}
+
+/// Decodes a single CBOR data item, mapped to JSON as RFC 8949 section
+/// 6.1 suggests: byte strings become base64url strings without padding,
+/// tags are dropped in favor of their content, `undefined` and non-finite
+/// floats become `null`, and map keys that aren't strings become the JSON
+/// text of the key.
+///
+/// # Examples
+///
+/// ```
+///# fn main() -> Result<(),String> {
+///// {1: h'0102', "t": 1(1700000000)}
+///let bytes = [0xa2, 0x01, 0x42, 0x01, 0x02, 0x61, b't', 0xc1, 0x1a, 0x65, 0x53, 0xf1, 0x00];
+///
+///assert_eq!(json::from_cbor(&bytes)?, json::parse(r#"{ "1": "AQI", "t": 1700000000 }"#)?);
+///# Ok(())
+///# }
+/// ```
+pub fn from_cbor(bytes: &[u8]) -> Result<Value, String> {
+    let mut decoder = Decoder { bytes, offset: 0 };
+    let value = decoder.value(0)?;
+    if decoder.offset != bytes.len() {
+        return Err(format!(
+            "unexpected bytes after value at offset {}",
+            decoder.offset
+        ));
+    }
+    Ok(value)
+}
+
+struct Decoder<'a> {
+    bytes: &'a [u8],
+    offset: usize,
+}
+
+/// A data item's major type and argument, `None` for indefinite lengths.
+struct Head {
+    major: u8,
+    info: u8,
+    argument: Option<u64>,
+}
+
+impl Decoder<'_> {
+    fn take(&mut self, length: usize) -> Result<&[u8], String> {
+        let end = self
+            .offset
+            .checked_add(length)
+            .filter(|end| *end <= self.bytes.len());
+        match end {
+            Some(end) => {
+                let taken = &self.bytes[self.offset..end];
+                self.offset = end;
+                Ok(taken)
+            }
+            None => Err("unexpected end of input".to_string()),
+        }
+    }
+
+    fn head(&mut self) -> Result<Head, String> {
+        let initial = self.take(1)?[0];
+        let (major, info) = (initial >> 5, initial & 0x1f);
+        let argument = match info {
+            0..=23 => Some(info as u64),
+            24 => Some(self.take(1)?[0] as u64),
+            25 => Some(u16::from_be_bytes(self.take(2)?.try_into().unwrap()) as u64),
+            26 => Some(u32::from_be_bytes(self.take(4)?.try_into().unwrap()) as u64),
+            27 => Some(u64::from_be_bytes(self.take(8)?.try_into().unwrap())),
+            31 if major >= 2 && major != 6 => None,
+            _ => {
+                return Err(format!(
+                    "invalid additional information {} at offset {}",
+                    info,
+                    self.offset - 1
+                ))
+            }
+        };
+        Ok(Head {
+            major,
+            info,
+            argument,
+        })
+    }
+
+    /// Whether the next byte is the "break" ending an indefinite length item.
+    fn at_break(&mut self) -> Result<bool, String> {
+        match self.bytes.get(self.offset) {
+            Some(0xff) => {
+                self.offset += 1;
+                Ok(true)
+            }
+            Some(_) => Ok(false),
+            None => Err("unexpected end of input".to_string()),
+        }
+    }
+
+    fn length(&self, argument: u64) -> Result<usize, String> {
+        usize::try_from(argument)
+            .ok()
+            .filter(|length| *length <= self.bytes.len() - self.offset)
+            .ok_or_else(|| "unexpected end of input".to_string())
+    }
+
+    fn bytes(&mut self, head: &Head) -> Result<Vec<u8>, String> {
+        match head.argument {
+            Some(length) => {
+                let length = self.length(length)?;
+                Ok(self.take(length)?.to_vec())
+            }
+            None => {
+                let mut bytes = Vec::new();
+                while !self.at_break()? {
+                    let chunk = self.head()?;
+                    if chunk.major != head.major || chunk.argument.is_none() {
+                        return Err("invalid chunk in indefinite length string".to_string());
+                    }
+                    bytes.extend(self.bytes(&chunk)?);
+                }
+                Ok(bytes)
+            }
+        }
+    }
+
+    fn value(&mut self, depth: usize) -> Result<Value, String> {
+        if depth > MAX_DEPTH {
+            return Err("too deeply nested".to_string());
+        }
+        let head = self.head()?;
+        Ok(match (head.major, head.argument) {
+            (0, Some(n)) => Value::Number(n as f64),
+            (1, Some(n)) => Value::Number(-1.0 - n as f64),
+            (2, _) => Value::String(URL_SAFE_NO_PAD.encode(self.bytes(&head)?)),
+            (3, _) => Value::String(
+                String::from_utf8(self.bytes(&head)?)
+                    .map_err(|_| "invalid utf-8 in text string")?,
+            ),
+            (4, Some(length)) => {
+                let length = self.length(length)?;
+                let values = (0..length).map(|_| self.value(depth + 1));
+                Value::Array(values.collect::<Result<_, _>>()?)
+            }
+            (4, None) => {
+                let mut values = Vec::new();
+                while !self.at_break()? {
+                    values.push(self.value(depth + 1)?);
+                }
+                Value::Array(values)
+            }
+            (5, length) => {
+                let mut object = JsonObject::new();
+                let mut remaining = match length {
+                    Some(length) => Some(self.length(length)?),
+                    None => None,
+                };
+                loop {
+                    match &mut remaining {
+                        Some(0) => break,
+                        Some(n) => *n -= 1,
+                        None if self.at_break()? => break,
+                        None => {}
+                    }
+                    let key = match self.value(depth + 1)? {
+                        Value::String(key) => key,
+                        key => to_string(&key),
+                    };
+                    let value = self.value(depth + 1)?;
+                    object.insert(key, value);
+                }
+                Value::Object(object)
+            }
+            (6, _) => self.value(depth + 1)?,
+            (7, Some(n)) => match (head.info, n) {
+                (_, 20) if head.info < 24 => Value::Bool(false),
+                (_, 21) if head.info < 24 => Value::Bool(true),
+                (_, 22) | (_, 23) if head.info < 24 => Value::Null,
+                (25, bits) => float(half(bits as u16)),
+                (26, bits) => float(f32::from_bits(bits as u32) as f64),
+                (27, bits) => float(f64::from_bits(bits)),
+                _ => return Err(format!("unsupported simple value {}", n)),
+            },
+            _ => return Err("unexpected break".to_string()),
+        })
+    }
+}
+
+fn float(value: f64) -> Value {
+    if value.is_finite() {
+        Value::Number(value)
+    } else {
+        Value::Null
+    }
+}
+
+/// Converts an IEEE 754 half-precision float.
+fn half(bits: u16) -> f64 {
+    let sign = if bits & 0x8000 != 0 { -1.0 } else { 1.0 };
+    let exponent = ((bits >> 10) & 0x1f) as i32;
+    let mantissa = (bits & 0x3ff) as f64;
+    sign * match exponent {
+        0 => mantissa * 2f64.powi(-24),
+        31 if mantissa == 0.0 => f64::INFINITY,
+        31 => f64::NAN,
+        _ => (1.0 + mantissa / 1024.0) * 2f64.powi(exponent - 15),
+    }
+}
@@ -0,0 +1,56 @@
+use super::{from_cbor, to_cbor};
+use crate::parse;
+
+#[test]
+fn round_trip() {
+    let value = parse(
+        r#"{ "ints": [0, 23, 24, 255, 65536, -1, -1000, 4294967296], "floats": [0.1, 1e300, -0.5],
+             "s": "héllo", "o": {}, "b": [true, false, null] }"#,
+    )
+    .unwrap();
+    assert_eq!(from_cbor(&to_cbor(&value)), Ok(value));
+    assert_eq!(to_cbor(&parse("1000").unwrap()), [0x19, 0x03, 0xe8]);
+    assert_eq!(to_cbor(&parse("-1000").unwrap()), [0x39, 0x03, 0xe7]);
+}
+
+#[test]
+fn decoding() {
+    // Examples from RFC 8949 appendix A.
+    let cases: &[(&[u8], &str)] = &[
+        (&[0xf9, 0x3c, 0x00], "1"),
+        (&[0xf9, 0xc4, 0x00], "-4"),
+        (&[0xf9, 0x7c, 0x00], "null"),
+        (&[0xf7], "null"),
+        (
+            &[0x5f, 0x42, 0x01, 0x02, 0x43, 0x03, 0x04, 0x05, 0xff],
+            r#""AQIDBAU""#,
+        ),
+        (&[0x7f, 0x62, b'a', b'b', 0x61, b'c', 0xff], r#""abc""#),
+        (&[0x9f, 0x01, 0x82, 0x02, 0x03, 0xff], "[1, [2, 3]]"),
+        (
+            &[0xbf, 0x61, b'a', 0x01, 0xf5, 0x02, 0xff],
+            r#"{ "a": 1, "true": 2 }"#,
+        ),
+    ];
+    for (bytes, expected) in cases {
+        assert_eq!(from_cbor(bytes), Ok(parse(*expected).unwrap()));
+    }
+}
+
+#[test]
+fn errors() {
+    assert_eq!(from_cbor(&[]), Err("unexpected end of input".to_string()));
+    assert_eq!(
+        from_cbor(&[0x82, 0x01]),
+        Err("unexpected end of input".to_string())
+    );
+    assert_eq!(
+        from_cbor(&[0x01, 0x02]),
+        Err("unexpected bytes after value at offset 1".to_string())
+    );
+    assert_eq!(
+        from_cbor(&[0x9b, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff]),
+        Err("unexpected end of input".to_string())
+    );
+    assert!(from_cbor(&[0x81; 1000]).is_err());
+}
@@ -0,0 +1,334 @@
+#[cfg(test)]
+mod tests;
+
+use crate::schema::infer_schema;
+use crate::Value;
+
+/// Settings of [`rust_types`].
+#[derive(Debug, PartialEq, Clone)]
+pub struct Options {
+    /// Name of the type generated for the sample itself.
+    pub root_name: String,
+    /// Traits derived by every generated type.
+    pub derives: Vec<String>,
+    /// Also derives `Serialize` and `Deserialize` and adds `#[serde(rename)]`
+    /// wherever a Rust name differs from the JSON one.
+    pub serde: bool,
+}
+
+impl Default for Options {
+    fn default() -> Self {
+        Self {
+            root_name: "Root".to_string(),
+            derives: vec![
+                "Debug".to_string(),
+                "Clone".to_string(),
+                "PartialEq".to_string(),
+            ],
+            serde: false,
+        }
+    }
+}
+
+/// Generates Rust type definitions able to hold `sample`, starting with the
+/// root type. Objects become structs named after their key, arrays become
+/// `Vec`s whose element types merge every element seen, absent or `null`
+/// fields become `Option`s and values of mixed types fall back to
+/// [`json::Value`](Value).
+///
+/// # Examples
+///
+/// ```
+///# fn main() -> Result<(),String> {
+///use json::codegen::{rust_types, Options};
+///
+///let sample = json::parse(r#"{
+///    "id": 7,
+///    "userName": "John",
+///    "addresses": [{ "city": "Rome", "zip": null }, { "city": "Milan" }]
+///}"#)?;
+///
+///let types = rust_types(&sample, &Options::default());
+///assert!(types.starts_with("#[derive(Debug, Clone, PartialEq)]\npub struct Root {\n    pub id: i64,\n"));
+///assert!(types.contains("    pub user_name: String,\n    pub addresses: Vec<Address>,\n"));
+///assert!(types.contains("pub struct Address {\n    pub city: String,\n    pub zip: Option<json::Value>,\n}"));
+///# Ok(())
+///# }
+/// ```
+pub fn rust_types(sample: &Value, options: &Options) -> String {
+    let schema = infer_schema(std::iter::once(sample));
+    let mut generator = Generator {
+        options,
+        names: Vec::new(),
+        definitions: Vec::new(),
+    };
+    let root = generator.type_of(&schema, &options.root_name);
+    if schema.pointer("/type") != Some(&Value::String("object".to_string())) {
+        let name = generator.reserve(&options.root_name);
+        generator
+            .definitions
+            .insert(0, format!("pub type {} = {};\n", name, root));
+    }
+    generator.definitions.join("\n")
+}
+
+struct Generator<'a> {
+    options: &'a Options,
+    names: Vec<String>,
+    definitions: Vec<String>,
+}
+
+impl Generator<'_> {
+    /// Returns the Rust type for values described by `schema`, emitting the
+    /// definitions it needs. `name` is the JSON name the type derives from.
+    fn type_of(&mut self, schema: &Value, name: &str) -> String {
+        let types: Vec<&str> = match schema.pointer("/type") {
+            Some(Value::String(single)) => vec![single],
+            Some(Value::Array(types)) => types
+                .iter()
+                .filter_map(|t| match t {
+                    Value::String(t) => Some(t.as_str()),
+                    _ => None,
+                })
+                .collect(),
+            _ => Vec::new(),
+        };
+        let nullable = types.contains(&"null");
+        let types: Vec<&str> = types.into_iter().filter(|t| *t != "null").collect();
+        let rust = match types.as_slice() {
+            ["integer"] => "i64".to_string(),
+            ["number"] => "f64".to_string(),
+            ["boolean"] => "bool".to_string(),
+            ["string"] => match schema.pointer("/enum") {
+                Some(Value::Array(values)) => self.enumeration(name, values),
+                _ => "String".to_string(),
+            },
+            ["array"] => match schema.pointer("/items") {
+                Some(items) => format!("Vec<{}>", self.type_of(items, &singular(name))),
+                None => "Vec<json::Value>".to_string(),
+            },
+            ["object"] => self.structure(name, schema),
+            _ => "json::Value".to_string(),
+        };
+        if nullable {
+            format!("Option<{}>", rust)
+        } else {
+            rust
+        }
+    }
+
+    fn structure(&mut self, name: &str, schema: &Value) -> String {
+        let name = self.reserve(name);
+        let slot = self.definitions.len();
+        self.definitions.push(String::new());
+        let mut body = String::new();
+        if let Some(Value::Object(properties)) = schema.pointer("/properties") {
+            for (key, property) in properties.iter() {
+                let required = match schema.pointer("/required") {
+                    Some(Value::Array(required)) => required.contains(&Value::String(key.clone())),
+                    _ => false,
+                };
+                let mut rust = self.type_of(property, key);
+                if !required && !rust.starts_with("Option<") {
+                    rust = format!("Option<{}>", rust);
+                }
+                let field = field_name(key);
+                if self.options.serde && field.trim_start_matches("r#") != key {
+                    body.push_str(&format!("    #[serde(rename = {:?})]\n", key));
+                }
+                body.push_str(&format!("    pub {}: {},\n", field, rust));
+            }
+        }
+        self.definitions[slot] = format!("{}pub struct {} {{\n{}}}\n", self.derive(), name, body);
+        name
+    }
+
+    fn enumeration(&mut self, name: &str, values: &[Value]) -> String {
+        let name = self.reserve(name);
+        let mut body = String::new();
+        for value in values {
+            if let Value::String(value) = value {
+                let variant = type_name(value);
+                if self.options.serde && &variant != value {
+                    body.push_str(&format!("    #[serde(rename = {:?})]\n", value));
+                }
+                body.push_str(&format!("    {},\n", variant));
+            }
+        }
+        let definition = format!("{}pub enum {} {{\n{}}}\n", self.derive(), name, body);
+        self.definitions.push(definition);
+        name
+    }
+
+    fn derive(&self) -> String {
+        let mut derives = self.options.derives.clone();
+        if self.options.serde {
+            derives.push("Serialize".to_string());
+            derives.push("Deserialize".to_string());
+        }
+        if derives.is_empty() {
+            String::new()
+        } else {
+            format!("#[derive({})]\n", derives.join(", "))
+        }
+    }
+
+    /// Turns `name` into a type name not used by any other definition.
+    fn reserve(&mut self, name: &str) -> String {
+        let base = type_name(name);
+        let mut name = base.clone();
+        let mut suffix = 2;
+        while self.names.contains(&name) {
+            name = format!("{}{}", base, suffix);
+            suffix += 1;
+        }
+        self.names.push(name.clone());
+        name
+    }
+}
+
+const KEYWORDS: &[&str] = &[
+    "abstract", "as", "async", "await", "become", "box", "break", "const", "continue", "do", "dyn",
+    "else", "enum", "extern", "false", "final", "fn", "for", "if", "impl", "in", "let", "loop",
+    "macro", "match", "mod", "move", "mut", "override", "priv", "pub", "ref", "return", "static",
+    "struct", "trait", "true", "try", "type", "typeof", "unsafe", "unsized", "use", "virtual",
+    "where", "while", "yield",
+];
+
+/// Splits a JSON name into lowercase words at separators and at the
+/// boundaries of `camelCase`.
+pub(crate) fn words(name: &str) -> Vec<String> {
+    let mut words = Vec::new();
+    let mut current = String::new();
+    let mut previous_lower = false;
+    for c in name.chars() {
+        if !c.is_alphanumeric() {
+            previous_lower = false;
+            if !current.is_empty() {
+                words.push(std::mem::take(&mut current));
+            }
+            continue;
+        }
+        if c.is_uppercase() && previous_lower {
+            words.push(std::mem::take(&mut current));
+        }
+        previous_lower = c.is_lowercase() || c.is_numeric();
+        current.extend(c.to_lowercase());
+    }
+    if !current.is_empty() {
+        words.push(current);
+    }
+    words
+}
+
+fn type_name(name: &str) -> String {
+    let name: String = words(name)
+        .iter()
+        .map(|word| {
+            let mut chars = word.chars();
+            chars
+                .next()
+                .map(|first| first.to_uppercase().chain(chars).collect::<String>())
+                .unwrap_or_default()
+        })
+        .collect();
+    match name.chars().next() {
+        None => "Value".to_string(),
+        Some(c) if c.is_numeric() => format!("Value{}", name),
+        _ if name == "Self" => "Self_".to_string(),
+        _ => name,
+    }
+}
+
+fn field_name(name: &str) -> String {
+    let name = words(name).join("_");
+    match name.as_str() {
+        "" => "value".to_string(),
+        "self" | "super" | "crate" => format!("{}_", name),
+        name if KEYWORDS.contains(&name) => format!("r#{}", name),
+        name if name.starts_with(|c: char| c.is_numeric()) => format!("_{}", name),
+        name => name.to_string(),
+    }
+}
+
+/// Names the elements of an array after the array itself.
+fn singular(name: &str) -> String {
+    if let Some(stem) = name.strip_suffix("ies") {
+        format!("{}y", stem)
+    } else if name.ends_with("sses") || name.ends_with("xes") {
+        name[..name.len() - 2].to_string()
+    } else if name.ends_with('s') && !name.ends_with("ss") && name.len() > 1 {
+        name[..name.len() - 1].to_string()
+    } else {
+        format!("{}Item", name)
+    }
+}
+
+impl Value {
+    /// Generates Rust source code evaluating to this value, handy to turn a
+    /// document into a test fixture.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    ///# fn main() -> Result<(),String> {
+    ///let value = json::parse(r#"{ "tags": [true, null] }"#)?;
+    ///let literal = value.to_rust_literal();
+    ///
+    ///assert!(literal.starts_with("json::Value::Object(vec![\n    (\"tags\".to_string(), json::Value::Array(vec![\n"));
+    ///assert!(literal.ends_with("    ])),\n].into_iter().collect())"));
+    ///# Ok(())
+    ///# }
+    /// ```
+    pub fn to_rust_literal(&self) -> String {
+        let mut output = String::new();
+        write_literal(&mut output, self, 0);
+        output
+    }
+}
+
+fn write_literal(output: &mut String, value: &Value, depth: usize) {
+    let indent = "    ".repeat(depth + 1);
+    match value {
+        Value::Null => output.push_str("json::Value::Null"),
+        Value::Bool(value) => output.push_str(&format!("json::Value::Bool({})", value)),
+        Value::Number(value) => {
+            let literal = if value.is_nan() {
+                "f64::NAN".to_string()
+            } else if value.is_infinite() {
+                let sign = if *value < 0.0 { "-" } else { "" };
+                format!("{}f64::INFINITY", sign)
+            } else {
+                format!("{:?}", value)
+            };
+            output.push_str(&format!("json::Value::Number({})", literal));
+        }
+        Value::String(value) => {
+            output.push_str(&format!("json::Value::String({:?}.to_string())", value))
+        }
+        Value::Array(values) if values.is_empty() => output.push_str("json::Value::Array(vec![])"),
+        Value::Array(values) => {
+            output.push_str("json::Value::Array(vec![\n");
+            for value in values {
+                output.push_str(&indent);
+                write_literal(output, value, depth + 1);
+                output.push_str(",\n");
+            }
+            output.push_str(&"    ".repeat(depth));
+            output.push_str("])");
+        }
+        Value::Object(object) if object.is_empty() => {
+            output.push_str("json::Value::Object(json::JsonObject::new())")
+        }
+        Value::Object(object) => {
+            output.push_str("json::Value::Object(vec![\n");
+            for (key, value) in object.iter() {
+                output.push_str(&format!("{}({:?}.to_string(), ", indent, key));
+                write_literal(output, value, depth + 1);
+                output.push_str("),\n");
+            }
+            output.push_str(&"    ".repeat(depth));
+            output.push_str("].into_iter().collect())");
+        }
+    }
+}
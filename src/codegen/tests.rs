@@ -0,0 +1,100 @@
+use super::{field_name, rust_types, singular, type_name, Options};
+use crate::parse;
+
+#[test]
+fn names() {
+    assert_eq!(type_name("user_profile"), "UserProfile");
+    assert_eq!(type_name("HTTPStatus"), "Httpstatus");
+    assert_eq!(type_name("2fa"), "Value2fa");
+    assert_eq!(field_name("userName"), "user_name");
+    assert_eq!(field_name("type"), "r#type");
+    assert_eq!(field_name("self"), "self_");
+    assert_eq!(field_name("@id"), "id");
+    assert_eq!(singular("categories"), "category");
+    assert_eq!(singular("boxes"), "box");
+    assert_eq!(singular("users"), "user");
+    assert_eq!(singular("data"), "dataItem");
+}
+
+#[test]
+fn serde_renames_and_enums() {
+    let sample = parse(
+        r#"{ "items": [
+            { "type": "in-stock", "price": 1.5 },
+            { "type": "in-stock", "price": 2 },
+            { "type": "sold", "price": 3, "note": "x" },
+            { "type": "sold", "price": 4 }
+        ] }"#,
+    )
+    .unwrap();
+    let options = Options {
+        root_name: "Catalog".to_string(),
+        derives: vec!["Debug".to_string()],
+        serde: true,
+    };
+    assert_eq!(
+        rust_types(&sample, &options),
+        r#"#[derive(Debug, Serialize, Deserialize)]
+pub struct Catalog {
+    pub items: Vec<Item>,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct Item {
+    pub r#type: Type,
+    pub price: f64,
+    pub note: Option<String>,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub enum Type {
+    #[serde(rename = "in-stock")]
+    InStock,
+    #[serde(rename = "sold")]
+    Sold,
+}
+"#
+    );
+}
+
+#[test]
+fn scalar_roots_and_name_clashes() {
+    let options = Options::default();
+    assert_eq!(
+        rust_types(&parse("[1, null]").unwrap(), &options),
+        "pub type Root = Vec<Option<i64>>;\n"
+    );
+    let sample = parse(r#"{ "a": { "root": {} }, "b": [1, "x"] }"#).unwrap();
+    let types = rust_types(
+        &sample,
+        &Options {
+            derives: Vec::new(),
+            ..Options::default()
+        },
+    );
+    assert_eq!(
+        types,
+        "pub struct Root {\n    pub a: A,\n    pub b: Vec<json::Value>,\n}\n\n\
+         pub struct A {\n    pub root: Root2,\n}\n\n\
+         pub struct Root2 {\n}\n"
+    );
+}
+
+#[test]
+fn rust_literals() {
+    let value = parse(r#"[{}, [], 1e21, -0.5, "a\"b"]"#).unwrap();
+    assert_eq!(
+        value.to_rust_literal(),
+        r#"json::Value::Array(vec![
+    json::Value::Object(json::JsonObject::new()),
+    json::Value::Array(vec![]),
+    json::Value::Number(1e21),
+    json::Value::Number(-0.5),
+    json::Value::String("a\"b".to_string()),
+])"#
+    );
+    assert_eq!(
+        crate::Value::Number(f64::NEG_INFINITY).to_rust_literal(),
+        "json::Value::Number(-f64::INFINITY)"
+    );
+}
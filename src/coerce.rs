@@ -0,0 +1,147 @@
+#[cfg(test)]
+mod tests;
+
+use crate::Value;
+
+/// What [`Value::coerce_with`] converts strings into.
+#[derive(Debug, PartialEq, Clone, Copy)]
+pub struct CoercionRules {
+    /// Strings holding a JSON number, e.g. `"42"` or `"-1.5e3"`, become
+    /// numbers. Those with leading zeros, such as zip codes, are kept.
+    pub numbers: bool,
+    /// `"true"` and `"false"` become booleans.
+    pub booleans: bool,
+    /// `"null"` becomes `null`.
+    pub null: bool,
+    /// Empty strings become `null`.
+    pub empty_as_null: bool,
+}
+
+impl Default for CoercionRules {
+    fn default() -> Self {
+        CoercionRules {
+            numbers: true,
+            booleans: true,
+            null: true,
+            empty_as_null: true,
+        }
+    }
+}
+
+/// Whether `value` follows the JSON grammar for numbers.
+pub(crate) fn is_number_literal(value: &str) -> bool {
+    let value = value.strip_prefix('-').unwrap_or(value);
+    let digits = |s: &str| s.len() - s.trim_start_matches(|c: char| c.is_ascii_digit()).len();
+    let integer = digits(value);
+    if integer == 0 || (integer > 1 && value.starts_with('0')) {
+        return false;
+    }
+    let mut rest = &value[integer..];
+    if let Some(fraction) = rest.strip_prefix('.') {
+        let length = digits(fraction);
+        if length == 0 {
+            return false;
+        }
+        rest = &fraction[length..];
+    }
+    if let Some(exponent) = rest.strip_prefix(['e', 'E']) {
+        let exponent = exponent.strip_prefix(['+', '-']).unwrap_or(exponent);
+        let length = digits(exponent);
+        if length == 0 {
+            return false;
+        }
+        rest = &exponent[length..];
+    }
+    rest.is_empty()
+}
+
+impl Value {
+    /// Reads a number, or a string holding one once trimmed.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    ///# fn main() -> Result<(),String> {
+    ///assert_eq!(json::parse(r#"" 42 ""#)?.coerce_to_number()?, 42.0);
+    ///assert_eq!(json::parse("1.5")?.coerce_to_number()?, 1.5);
+    ///assert_eq!(
+    ///    json::parse(r#""4 2""#)?.coerce_to_number(),
+    ///    Err(r#"cannot coerce "4 2" to number"#.to_string())
+    ///);
+    ///# Ok(())
+    ///# }
+    /// ```
+    pub fn coerce_to_number(&self) -> Result<f64, String> {
+        match self {
+            Value::Number(value) => Ok(*value),
+            Value::String(value) if is_number_literal(value.trim()) => {
+                Ok(value.trim().parse().unwrap())
+            }
+            value => Err(format!(
+                "cannot coerce {} to number",
+                crate::to_string(value)
+            )),
+        }
+    }
+
+    /// Reads a boolean, `1` or `0`, or one of the strings `true`, `yes`,
+    /// `on`, `1`, `false`, `no`, `off` and `0` in any case once trimmed.
+    pub fn coerce_to_bool(&self) -> Result<bool, String> {
+        match self {
+            Value::Bool(value) => return Ok(*value),
+            Value::Number(value) if *value == 1.0 => return Ok(true),
+            Value::Number(value) if *value == 0.0 => return Ok(false),
+            Value::String(value) => match value.trim().to_ascii_lowercase().as_str() {
+                "true" | "yes" | "on" | "1" => return Ok(true),
+                "false" | "no" | "off" | "0" => return Ok(false),
+                _ => {}
+            },
+            _ => {}
+        }
+        Err(format!("cannot coerce {} to bool", crate::to_string(self)))
+    }
+
+    /// Converts, in place, the strings found anywhere in this value into
+    /// the types they spell out, as allowed by `rules`. Object keys are left
+    /// alone.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    ///# fn main() -> Result<(),String> {
+    ///let mut form = json::parse(r#"{ "age": "42", "zip": "02134", "admin": "false", "note": "" }"#)?;
+    ///
+    ///form.coerce_with(&json::CoercionRules::default());
+    ///assert_eq!(form, json::parse(r#"{ "age": 42, "zip": "02134", "admin": false, "note": null }"#)?);
+    ///# Ok(())
+    ///# }
+    /// ```
+    pub fn coerce_with(&mut self, rules: &CoercionRules) {
+        match self {
+            Value::String(value) => {
+                let coerced = match value.as_str() {
+                    "" if rules.empty_as_null => Value::Null,
+                    "null" if rules.null => Value::Null,
+                    "true" if rules.booleans => Value::Bool(true),
+                    "false" if rules.booleans => Value::Bool(false),
+                    number if rules.numbers && is_number_literal(number) => {
+                        Value::Number(number.parse().unwrap())
+                    }
+                    _ => return,
+                };
+                *self = coerced;
+            }
+            Value::Array(values) => {
+                for value in values {
+                    value.coerce_with(rules);
+                }
+            }
+            Value::Object(object) => {
+                for (_, value) in object.iter_mut() {
+                    value.coerce_with(rules);
+                }
+            }
+            _ => {}
+        }
+    }
+}
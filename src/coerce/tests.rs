@@ -0,0 +1,41 @@
+use super::{is_number_literal, CoercionRules};
+use crate::{parse, Value};
+
+#[test]
+fn number_literals() {
+    for valid in &["0", "-0", "12", "1.5", "1e3", "-2.5E-3"] {
+        assert!(is_number_literal(valid), "{}", valid);
+    }
+    for invalid in &[
+        "", "-", "01", "1.", ".5", "+1", "1e", "0x10", "NaN", "inf", " 1",
+    ] {
+        assert!(!is_number_literal(invalid), "{}", invalid);
+    }
+}
+
+#[test]
+fn scalars() {
+    assert_eq!(parse(r#""-0.5""#).unwrap().coerce_to_number(), Ok(-0.5));
+    assert!(Value::Null.coerce_to_number().is_err());
+    assert_eq!(parse(r#"" Yes ""#).unwrap().coerce_to_bool(), Ok(true));
+    assert_eq!(parse("0").unwrap().coerce_to_bool(), Ok(false));
+    assert_eq!(
+        parse("2").unwrap().coerce_to_bool(),
+        Err("cannot coerce 2 to bool".to_string())
+    );
+}
+
+#[test]
+fn rules() {
+    let mut value = parse(r#"[["1", "true", "null", ""], { "1": "x" }]"#).unwrap();
+    let rules = CoercionRules {
+        booleans: false,
+        empty_as_null: false,
+        ..CoercionRules::default()
+    };
+    value.coerce_with(&rules);
+    assert_eq!(
+        value,
+        parse(r#"[[1, "true", null, ""], { "1": "x" }]"#).unwrap()
+    );
+}
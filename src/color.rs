@@ -0,0 +1,84 @@
+#[cfg(test)]
+mod tests;
+
+use crate::ser::{write_pretty, KeyOrder};
+use crate::Value;
+use std::env;
+use std::io::{stdout, IsTerminal};
+
+pub(crate) const RESET: &str = "\u{1b}[0m";
+
+/// ANSI escape sequences used by [`Value::to_string_colored`]. An empty
+/// sequence leaves that part of the output uncolored.
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub struct Theme {
+    pub key: String,
+    pub string: String,
+    pub number: String,
+    /// Used for `true`, `false` and `null`.
+    pub literal: String,
+    pub punctuation: String,
+}
+
+impl Default for Theme {
+    /// Blue keys, green strings, yellow numbers, magenta literals and dimmed
+    /// punctuation.
+    fn default() -> Self {
+        Self {
+            key: "\u{1b}[34m".to_string(),
+            string: "\u{1b}[32m".to_string(),
+            number: "\u{1b}[33m".to_string(),
+            literal: "\u{1b}[35m".to_string(),
+            punctuation: "\u{1b}[2m".to_string(),
+        }
+    }
+}
+
+impl Theme {
+    /// A theme without any color.
+    pub fn plain() -> Self {
+        Self {
+            key: String::new(),
+            string: String::new(),
+            number: String::new(),
+            literal: String::new(),
+            punctuation: String::new(),
+        }
+    }
+
+    /// The default theme when standard output is a terminal, the plain one
+    /// when it is redirected or when the `NO_COLOR` environment variable is
+    /// set.
+    pub fn detect() -> Self {
+        let no_color = env::var_os("NO_COLOR").is_some_and(|value| !value.is_empty());
+        if stdout().is_terminal() && !no_color {
+            Self::default()
+        } else {
+            Self::plain()
+        }
+    }
+}
+
+impl Value {
+    /// Pretty prints this value like [`to_string_pretty`](crate::to_string_pretty),
+    /// colored with `theme`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    ///# fn main() -> Result<(),String> {
+    ///use json::Theme;
+    ///
+    ///let value = json::parse(r#"{ "ok": true }"#)?;
+    ///println!("{}", value.to_string_colored(&Theme::detect()));
+    ///
+    ///assert_eq!(value.to_string_colored(&Theme::plain()), json::to_string_pretty(&value));
+    ///# Ok(())
+    ///# }
+    /// ```
+    pub fn to_string_colored(&self, theme: &Theme) -> String {
+        let mut output = String::new();
+        write_pretty(&mut output, self, 0, theme, &KeyOrder::Insertion, None);
+        output
+    }
+}
@@ -0,0 +1,18 @@
+use super::Theme;
+use crate::parse;
+
+#[test]
+fn colored() {
+    let theme = Theme {
+        key: "<k>".to_string(),
+        string: "<s>".to_string(),
+        number: "<n>".to_string(),
+        literal: "<l>".to_string(),
+        punctuation: String::new(),
+    };
+    let value = parse(r#"{ "a": ["x", 1, null, []] }"#).unwrap();
+    assert_eq!(
+        value.to_string_colored(&theme),
+        "{\n  <k>\"a\"\u{1b}[0m: [\n    <s>\"x\"\u{1b}[0m,\n    <n>1\u{1b}[0m,\n    <l>null\u{1b}[0m,\n    []\n  ]\n}"
+    );
+}
@@ -0,0 +1,164 @@
+#[cfg(test)]
+mod tests;
+
+use crate::convert::{at, mismatch};
+use crate::Value;
+
+/// The values one key takes across the rows of [`Value::to_columns`], with
+/// `None` where a row lacks the key or holds `null`.
+#[derive(Debug, PartialEq, Clone)]
+pub enum Column {
+    Bool(Vec<Option<bool>>),
+    /// Numbers that are all integers within the range of `i64`.
+    Integer(Vec<Option<i64>>),
+    Number(Vec<Option<f64>>),
+    String(Vec<Option<String>>),
+    /// Values of different types, arrays or objects, or only `null`, kept
+    /// as they are with `null` where a row lacks the key.
+    Mixed(Vec<Value>),
+}
+
+impl Column {
+    pub fn len(&self) -> usize {
+        match self {
+            Column::Bool(values) => values.len(),
+            Column::Integer(values) => values.len(),
+            Column::Number(values) => values.len(),
+            Column::String(values) => values.len(),
+            Column::Mixed(values) => values.len(),
+        }
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+}
+
+/// An array of objects split into a column per key.
+#[derive(Debug, PartialEq, Clone, Default)]
+pub struct Columns {
+    /// Number of rows, which is the length of every column.
+    pub rows: usize,
+    /// The columns, named after their key, in the order the keys first
+    /// appear.
+    pub fields: Vec<(String, Column)>,
+}
+
+impl Columns {
+    pub fn get(&self, name: &str) -> Option<&Column> {
+        self.fields
+            .iter()
+            .find(|(field, _)| field == name)
+            .map(|(_, column)| column)
+    }
+}
+
+impl Value {
+    /// Splits an array of objects into typed columns, one per key found in
+    /// any of the objects, so they can be processed without matching on
+    /// every value. A column's type is the one its non-null values share,
+    /// [`Column::Mixed`] if they don't.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    ///# fn main() -> Result<(),String> {
+    ///let rows = json::parse(r#"[{ "id": 1, "price": 2.5, "tag": "a" }, { "id": 2, "price": 3, "extra": [] }]"#)?;
+    ///let columns = rows.to_columns()?;
+    ///
+    ///assert_eq!(columns.rows, 2);
+    ///assert_eq!(columns.get("id"), Some(&json::Column::Integer(vec![Some(1), Some(2)])));
+    ///assert_eq!(columns.get("price"), Some(&json::Column::Number(vec![Some(2.5), Some(3.0)])));
+    ///assert_eq!(columns.get("tag"), Some(&json::Column::String(vec![Some("a".to_string()), None])));
+    ///# Ok(())
+    ///# }
+    /// ```
+    pub fn to_columns(&self) -> Result<Columns, String> {
+        let rows = match self {
+            Value::Array(rows) => rows,
+            value => return Err(mismatch("array", value)),
+        };
+        let mut names: Vec<&str> = Vec::new();
+        for (i, row) in rows.iter().enumerate() {
+            match row {
+                Value::Object(object) => {
+                    for (key, _) in object.iter() {
+                        if !names.contains(&key.as_str()) {
+                            names.push(key);
+                        }
+                    }
+                }
+                row => return Err(at(&i.to_string(), mismatch("object", row))),
+            }
+        }
+        let fields = names
+            .into_iter()
+            .map(|name| {
+                let values: Vec<Option<&Value>> = rows
+                    .iter()
+                    .map(|row| match row {
+                        Value::Object(object) => object.get(name).filter(|v| **v != Value::Null),
+                        _ => None,
+                    })
+                    .collect();
+                (name.to_string(), column(&values))
+            })
+            .collect();
+        Ok(Columns {
+            rows: rows.len(),
+            fields,
+        })
+    }
+}
+
+fn column(values: &[Option<&Value>]) -> Column {
+    if values.iter().all(Option::is_none) {
+        return Column::Mixed(vec![Value::Null; values.len()]);
+    }
+    let bools: Option<Vec<Option<bool>>> = values
+        .iter()
+        .map(|value| match value {
+            None => Some(None),
+            Some(Value::Bool(value)) => Some(Some(*value)),
+            Some(_) => None,
+        })
+        .collect();
+    if let Some(bools) = bools {
+        return Column::Bool(bools);
+    }
+    let numbers: Option<Vec<Option<f64>>> = values
+        .iter()
+        .map(|value| match value {
+            None => Some(None),
+            Some(Value::Number(n)) => Some(Some(*n)),
+            Some(_) => None,
+        })
+        .collect();
+    if let Some(numbers) = numbers {
+        let integers = numbers
+            .iter()
+            .flatten()
+            .all(|n| n.fract() == 0.0 && n.abs() < i64::MAX as f64);
+        if integers {
+            return Column::Integer(numbers.into_iter().map(|n| n.map(|n| n as i64)).collect());
+        }
+        return Column::Number(numbers);
+    }
+    let strings: Option<Vec<Option<String>>> = values
+        .iter()
+        .map(|value| match value {
+            None => Some(None),
+            Some(Value::String(value)) => Some(Some(value.clone())),
+            Some(_) => None,
+        })
+        .collect();
+    match strings {
+        Some(strings) => Column::String(strings),
+        None => Column::Mixed(
+            values
+                .iter()
+                .map(|value| value.cloned().unwrap_or(Value::Null))
+                .collect(),
+        ),
+    }
+}
@@ -0,0 +1,52 @@
+use crate::{parse, Column, Value};
+
+#[test]
+fn column_types() {
+    let rows = parse(
+        r#"[{ "b": true, "n": 1e300, "m": 1, "o": { "x": 1 }, "z": null },
+            { "b": null, "n": 2, "m": "1" },
+            { "b": false }]"#,
+    )
+    .unwrap();
+    let columns = rows.to_columns().unwrap();
+    let names: Vec<&str> = columns
+        .fields
+        .iter()
+        .map(|(name, _)| name.as_str())
+        .collect();
+    assert_eq!(names, ["b", "n", "m", "o", "z"]);
+    assert_eq!(
+        columns.get("b"),
+        Some(&Column::Bool(vec![Some(true), None, Some(false)]))
+    );
+    assert_eq!(
+        columns.get("n"),
+        Some(&Column::Number(vec![Some(1e300), Some(2.0), None]))
+    );
+    assert_eq!(
+        columns.get("m"),
+        Some(&Column::Mixed(vec![
+            Value::Number(1.0),
+            Value::String("1".to_string()),
+            Value::Null
+        ]))
+    );
+    assert_eq!(columns.get("z"), Some(&Column::Mixed(vec![Value::Null; 3])));
+    assert!(columns
+        .fields
+        .iter()
+        .all(|(_, column)| column.len() == columns.rows));
+}
+
+#[test]
+fn errors() {
+    assert_eq!(parse("[]").unwrap().to_columns().map(|c| c.rows), Ok(0));
+    assert_eq!(
+        parse("[{}, []]").unwrap().to_columns(),
+        Err("/1: expected object, found array".to_string())
+    );
+    assert_eq!(
+        parse("{}").unwrap().to_columns(),
+        Err("expected array, found object".to_string())
+    );
+}
@@ -0,0 +1,200 @@
+#[cfg(test)]
+mod tests;
+
+use crate::pointer::{array_index, pointer_text, AsPointer};
+use crate::{JsonObject, Value};
+
+impl Value {
+    /// # Examples
+    ///
+    /// ```
+    ///# fn main() -> Result<(),String> {
+    ///let prices = json::parse("[1, 2, 3]")?;
+    ///
+    ///let doubled = prices.map_array(|price| json::Value::Number(price.to_number().unwrap() * 2.0))?;
+    ///assert_eq!(doubled, json::parse("[2, 4, 6]")?);
+    ///# Ok(())
+    ///# }
+    /// ```
+    pub fn map_array<F: FnMut(Value) -> Value>(self, f: F) -> Result<Value, String> {
+        match self {
+            Value::Array(values) => Ok(Value::Array(values.into_iter().map(f).collect())),
+            _ => Err("not a json array".to_string()),
+        }
+    }
+
+    pub fn filter_array<F: FnMut(&Value) -> bool>(self, mut f: F) -> Result<Value, String> {
+        match self {
+            Value::Array(values) => Ok(Value::Array(
+                values.into_iter().filter(|value| f(value)).collect(),
+            )),
+            _ => Err("not a json array".to_string()),
+        }
+    }
+
+    pub fn map_object_values<F: FnMut(&str, Value) -> Value>(
+        self,
+        mut f: F,
+    ) -> Result<Value, String> {
+        Ok(Value::Object(
+            self.to_object()?
+                .into_iter()
+                .map(|(key, value)| {
+                    let value = f(&key, value);
+                    (key, value)
+                })
+                .collect(),
+        ))
+    }
+
+    pub fn filter_object<F: FnMut(&str, &Value) -> bool>(self, mut f: F) -> Result<Value, String> {
+        Ok(Value::Object(
+            self.to_object()?
+                .into_iter()
+                .filter(|(key, value)| f(key, value))
+                .collect::<JsonObject>(),
+        ))
+    }
+}
+
+impl Value {
+    /// Partitions an array into an object of arrays keyed by the value each
+    /// element holds at `path`, a key name or a JSON Pointer, given as text
+    /// starting with `/` or as a [`JsonPointer`](crate::JsonPointer). Strings
+    /// are used as is, other values by their JSON text, and elements missing
+    /// the path are grouped with those holding `null`. Groups appear in order
+    /// of first occurrence.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    ///# fn main() -> Result<(),String> {
+    ///let items = json::parse(r#"[
+    ///    { "name": "apple", "category": "fruit" },
+    ///    { "name": "leek", "category": "vegetable" },
+    ///    { "name": "pear", "category": "fruit" }
+    ///]"#)?;
+    ///
+    ///let groups = items.group_by("category")?;
+    ///assert_eq!(
+    ///    groups.get("fruit"),
+    ///    Some(&json::parse(r#"[
+    ///        { "name": "apple", "category": "fruit" },
+    ///        { "name": "pear", "category": "fruit" }
+    ///    ]"#)?)
+    ///);
+    ///# Ok(())
+    ///# }
+    /// ```
+    pub fn group_by<P: AsPointer + ?Sized>(&self, path: &P) -> Result<JsonObject, String> {
+        let values = match self {
+            Value::Array(values) => values,
+            _ => return Err("not a json array".to_string()),
+        };
+        let mut groups = JsonObject::new();
+        for value in values {
+            let key = match value.field(path) {
+                Some(Value::String(key)) => key.clone(),
+                Some(key) => crate::to_string(key),
+                None => "null".to_string(),
+            };
+            match groups.get_mut(&key) {
+                Some(Value::Array(group)) => group.push(value.clone()),
+                _ => {
+                    groups.insert(key, Value::Array(vec![value.clone()]));
+                }
+            }
+        }
+        Ok(groups)
+    }
+}
+
+impl Value {
+    /// Builds a copy of `self` holding only the locations named by
+    /// `pointers`, keeping their nesting. Array elements that are skipped
+    /// over are replaced with `null` and missing pointers are ignored.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    ///# fn main() -> Result<(),String> {
+    ///let value = json::parse(r#"{
+    ///    "id": 7,
+    ///    "user": { "name": "John", "password": "hunter2" },
+    ///    "stats": { "total": 3, "history": [1, 2] }
+    ///}"#)?;
+    ///
+    ///assert_eq!(
+    ///    value.project(&["/id", "/user/name", "/stats/total"]),
+    ///    json::parse(r#"{ "id": 7, "user": { "name": "John" }, "stats": { "total": 3 } }"#)?
+    ///);
+    ///# Ok(())
+    ///# }
+    /// ```
+    pub fn project<P: AsPointer>(&self, pointers: &[P]) -> Value {
+        let mut projection = match self {
+            Value::Object(_) => Value::Object(JsonObject::new()),
+            Value::Array(_) => Value::Array(Vec::new()),
+            _ => Value::Null,
+        };
+        for pointer in pointers {
+            if let Ok(tokens) = pointer.pointer_tokens() {
+                if let Some(value) = self.lookup(&tokens) {
+                    place(&mut projection, self, &tokens, value.clone());
+                }
+            }
+        }
+        projection
+    }
+
+    /// Like [`Value::project`] but returns a flat object keyed by the
+    /// pointers that were found.
+    pub fn project_flat<P: AsPointer>(&self, pointers: &[P]) -> JsonObject {
+        pointers
+            .iter()
+            .filter_map(|pointer| {
+                let value = self.pointer(pointer)?.clone();
+                Some((pointer_text(pointer).ok()?, value))
+            })
+            .collect()
+    }
+}
+
+/// Copies `value` into `target` at `tokens`, creating the containers found
+/// along the same path in `source`.
+fn place(target: &mut Value, source: &Value, tokens: &[String], value: Value) {
+    let (token, rest) = match tokens.split_first() {
+        Some(split) => split,
+        None => {
+            *target = value;
+            return;
+        }
+    };
+    match source {
+        Value::Object(object) => {
+            if !matches!(target, Value::Object(_)) {
+                *target = Value::Object(JsonObject::new());
+            }
+            if let Value::Object(target) = target {
+                if !target.contains_key(token) {
+                    target.insert(token.clone(), Value::Null);
+                }
+                let child = target.get_mut(token).unwrap();
+                place(child, object.get(token).unwrap(), rest, value);
+            }
+        }
+        Value::Array(values) => {
+            if !matches!(target, Value::Array(_)) {
+                *target = Value::Array(Vec::new());
+            }
+            if let Value::Array(target) = target {
+                let index = array_index(token).unwrap();
+                if target.len() <= index {
+                    target.resize(index + 1, Value::Null);
+                }
+                place(&mut target[index], &values[index], rest, value);
+            }
+        }
+        _ => unreachable!("pointer resolved through a scalar"),
+    }
+}
@@ -0,0 +1,120 @@
+use crate::{parse, JsonPointer, Value};
+
+#[test]
+fn map_array() {
+    let value = parse(r#"["a", "b"]"#).unwrap();
+    assert_eq!(
+        value.map_array(|v| Value::Array(vec![v])),
+        Ok(parse(r#"[["a"], ["b"]]"#).unwrap())
+    );
+    assert_eq!(
+        Value::Null.map_array(|v| v),
+        Err("not a json array".to_string())
+    );
+}
+
+#[test]
+fn filter_array() {
+    let value = parse(r#"[1, null, 2, null]"#).unwrap();
+    assert_eq!(
+        value.filter_array(|v| *v != Value::Null),
+        Ok(parse("[1, 2]").unwrap())
+    );
+}
+
+#[test]
+fn map_object_values() {
+    let value = parse(r#"{ "a": 1, "b": 2 }"#).unwrap();
+    assert_eq!(
+        value.map_object_values(|key, _| Value::String(key.to_uppercase())),
+        Ok(parse(r#"{ "a": "A", "b": "B" }"#).unwrap())
+    );
+}
+
+#[test]
+fn filter_object() {
+    let value = parse(r#"{ "id": 1, "_internal": true, "name": null }"#).unwrap();
+    assert_eq!(
+        value.filter_object(|key, value| !key.starts_with('_') && *value != Value::Null),
+        Ok(parse(r#"{ "id": 1 }"#).unwrap())
+    );
+    assert_eq!(
+        parse("[]").unwrap().filter_object(|_, _| true),
+        Err("not a json object".to_string())
+    );
+}
+
+#[test]
+fn group_by() {
+    let value = parse(
+        r#"[
+            { "id": 1, "meta": { "level": 2 } },
+            { "id": 2, "meta": { "level": "2" } },
+            { "id": 3, "meta": {} },
+            { "id": 4, "meta": { "level": null } },
+            { "id": 5, "meta": { "level": 2 } }
+        ]"#,
+    )
+    .unwrap();
+    let groups = value.group_by("/meta/level").unwrap();
+    let summary: Vec<(String, usize)> = groups
+        .iter()
+        .map(|(key, group)| match group {
+            Value::Array(group) => (key.clone(), group.len()),
+            _ => unreachable!(),
+        })
+        .collect();
+    assert_eq!(summary, vec![("2".to_string(), 3), ("null".to_string(), 2)]);
+    assert_eq!(
+        Value::Null.group_by("id"),
+        Err("not a json array".to_string())
+    );
+}
+
+#[test]
+fn project() {
+    let value = parse(
+        r#"{ "a": [{ "x": 1, "y": 2 }, { "x": 3, "y": 4 }], "b": { "c": true, "d": false } }"#,
+    )
+    .unwrap();
+    assert_eq!(
+        value.project(&["/a/1/y", "/b/c", "/b/missing", "/a/0/x", "broken"]),
+        parse(r#"{ "a": [{ "x": 1 }, { "y": 4 }], "b": { "c": true } }"#).unwrap()
+    );
+    assert_eq!(value.project::<&str>(&[]), parse("{}").unwrap());
+    assert_eq!(value.project(&[""]), value);
+}
+
+#[test]
+fn project_flat() {
+    let value = parse(r#"{ "a": [{ "x": 1 }], "b": { "c": true } }"#).unwrap();
+    let flat = value.project_flat(&["/b/c", "/a/0/x", "/missing"]);
+    let entries: Vec<(&String, &Value)> = flat.iter().collect();
+    assert_eq!(
+        entries,
+        vec![
+            (&"/b/c".to_string(), &Value::Bool(true)),
+            (&"/a/0/x".to_string(), &Value::Number(1.0)),
+        ]
+    );
+}
+
+#[test]
+fn json_pointer_arguments() {
+    let value = parse(r#"{ "a/b": { "c": 1 }, "d": 2 }"#).unwrap();
+    let pointer: JsonPointer = vec!["a/b", "c"].into_iter().collect();
+    assert_eq!(
+        value.project(&[&pointer]),
+        parse(r#"{ "a/b": { "c": 1 } }"#).unwrap()
+    );
+    let flat = value.project_flat(&[pointer]);
+    assert_eq!(flat.get("/a~1b/c"), Some(&Value::Number(1.0)));
+
+    let items = parse(r#"[{ "k/1": "x" }, { "k/1": "y" }, { "k/1": "x" }]"#).unwrap();
+    let key: JsonPointer = vec!["k/1"].into_iter().collect();
+    let groups = items.group_by(&key).unwrap();
+    assert_eq!(
+        groups.get("x").and_then(|group| group.pointer("/1/k~11")),
+        Some(&Value::String("x".to_string()))
+    );
+}
@@ -0,0 +1,552 @@
+//! Configuration assembled from several documents, such as defaults, system
+//! and user files and command line overrides.
+
+#[cfg(test)]
+mod tests;
+
+use crate::convert::{at, mismatch};
+use crate::pointer::{array_index, escape_token, pointer_text, AsPointer};
+use crate::reference::{join, DocumentStore};
+use crate::{CoercionRules, JsonObject, Value};
+use std::collections::BTreeMap;
+
+/// The result of [`layer_with_sources`]: the merged document and, keyed by
+/// JSON Pointer, the index of the layer that supplied each of its values.
+/// Objects are merged key by key and thus only their members are listed;
+/// any other value, arrays included, is listed as a whole.
+#[derive(Debug, PartialEq, Clone)]
+pub struct Layered {
+    pub value: Value,
+    pub sources: BTreeMap<String, usize>,
+}
+
+impl Layered {
+    /// The layer that supplied the value at `pointer`, or the array holding
+    /// it. Returns `None` for missing values and merged objects.
+    pub fn source<P: AsPointer + ?Sized>(&self, pointer: &P) -> Option<usize> {
+        self.value.pointer(pointer)?;
+        let pointer = pointer_text(pointer).ok()?;
+        let mut pointer = pointer.as_str();
+        loop {
+            if let Some(index) = self.sources.get(pointer) {
+                return Some(*index);
+            }
+            pointer = &pointer[..pointer.rfind('/')?];
+        }
+    }
+}
+
+/// Deep merges `layers` in order, later layers winning, like
+/// [`Value::merge`] does.
+///
+/// # Examples
+///
+/// ```
+///# fn main() -> Result<(),String> {
+///let defaults = json::parse(r#"{ "server": { "host": "localhost", "port": 80 } }"#)?;
+///let user = json::parse(r#"{ "server": { "port": 8080 }, "debug": true }"#)?;
+///
+///assert_eq!(
+///    json::config::layer(&[defaults, user]),
+///    json::parse(r#"{ "server": { "host": "localhost", "port": 8080 }, "debug": true }"#)?
+///);
+///# Ok(())
+///# }
+/// ```
+pub fn layer(layers: &[Value]) -> Value {
+    layer_with_sources(layers).value
+}
+
+/// Merges `layers` like [`layer`], recording which layer supplied each
+/// value. No layers at all make a `null` document.
+///
+/// # Examples
+///
+/// ```
+///# fn main() -> Result<(),String> {
+///let defaults = json::parse(r#"{ "server": { "host": "localhost", "port": 80 } }"#)?;
+///let user = json::parse(r#"{ "server": { "port": 8080 } }"#)?;
+///
+///let layered = json::config::layer_with_sources(&[defaults, user]);
+///assert_eq!(layered.source("/server/host"), Some(0));
+///assert_eq!(layered.source("/server/port"), Some(1));
+///assert_eq!(layered.source("/server"), None);
+///# Ok(())
+///# }
+/// ```
+pub fn layer_with_sources(layers: &[Value]) -> Layered {
+    let mut layered = Layered {
+        value: Value::Null,
+        sources: BTreeMap::new(),
+    };
+    for (index, layer) in layers.iter().enumerate() {
+        layer_at(
+            &mut layered.value,
+            layer,
+            index,
+            &mut String::new(),
+            &mut layered.sources,
+        );
+    }
+    layered
+}
+
+fn layer_at(
+    target: &mut Value,
+    layer: &Value,
+    index: usize,
+    path: &mut String,
+    sources: &mut BTreeMap<String, usize>,
+) {
+    match (target, layer) {
+        (Value::Object(target), Value::Object(layer)) => {
+            for (key, value) in layer.iter() {
+                let length = path.len();
+                path.push('/');
+                path.push_str(&escape_token(key));
+                match target.get_mut(key) {
+                    Some(target) => layer_at(target, value, index, path, sources),
+                    None => {
+                        record(value, index, path, sources);
+                        target.insert(key.clone(), value.clone());
+                    }
+                }
+                path.truncate(length);
+            }
+        }
+        (target, layer) => {
+            let prefix = format!("{}/", path);
+            sources.retain(|source, _| source != path && !source.starts_with(&prefix));
+            record(layer, index, path, sources);
+            *target = layer.clone();
+        }
+    }
+}
+
+fn record(value: &Value, index: usize, path: &mut String, sources: &mut BTreeMap<String, usize>) {
+    match value {
+        Value::Object(object) if !object.is_empty() => {
+            for (key, value) in object.iter() {
+                let length = path.len();
+                path.push('/');
+                path.push_str(&escape_token(key));
+                record(value, index, path, sources);
+                path.truncate(length);
+            }
+        }
+        _ => {
+            sources.insert(path.clone(), index);
+        }
+    }
+}
+
+/// How [`overlay_env`] maps environment variables onto configuration values.
+#[derive(Debug, PartialEq, Clone)]
+pub struct EnvOptions {
+    /// Only variables starting with it are read, and it is stripped.
+    pub prefix: String,
+    /// What separates nested keys in variable names.
+    pub separator: String,
+    /// Applied to values replacing nothing, `null`, an array or an object.
+    pub coercion: CoercionRules,
+}
+
+impl EnvOptions {
+    pub fn new(prefix: &str) -> Self {
+        EnvOptions {
+            prefix: prefix.to_string(),
+            separator: "__".to_string(),
+            coercion: CoercionRules {
+                numbers: true,
+                booleans: true,
+                null: false,
+                empty_as_null: false,
+            },
+        }
+    }
+}
+
+/// Overlays the variables of this process's environment on `config`, as
+/// [`overlay_vars`] does.
+pub fn overlay_env(config: &mut Value, options: &EnvOptions) -> Result<(), String> {
+    overlay_vars(config, std::env::vars(), options)
+}
+
+/// Overlays the variables named after `options.prefix` on `config`:
+/// `APP_SERVER__PORT=9090` sets `/server/port` when the prefix is `APP_`.
+/// Keys match existing ones regardless of case, are otherwise created in
+/// lower case, and may be array indexes. Values replacing a number or a
+/// boolean are converted to that type, those replacing a string are kept as
+/// they are, and any other is coerced with `options.coercion`. Variables
+/// are applied sorted by name.
+///
+/// # Examples
+///
+/// ```
+///# fn main() -> Result<(),String> {
+///use json::config::{overlay_vars, EnvOptions};
+///
+///let mut config = json::parse(r#"{ "server": { "port": 80, "debug": false, "name": "api" } }"#)?;
+///let vars = vec![
+///    ("APP_SERVER__PORT".to_string(), "9090".to_string()),
+///    ("APP_SERVER__DEBUG".to_string(), "on".to_string()),
+///    ("APP_SERVER__NAME".to_string(), "42".to_string()),
+///    ("APP_SERVER__TIMEOUT".to_string(), "1.5".to_string()),
+///    ("HOME".to_string(), "/root".to_string()),
+///];
+///
+///overlay_vars(&mut config, vars, &EnvOptions::new("APP_"))?;
+///assert_eq!(
+///    config,
+///    json::parse(r#"{ "server": { "port": 9090, "debug": true, "name": "42", "timeout": 1.5 } }"#)?
+///);
+///# Ok(())
+///# }
+/// ```
+pub fn overlay_vars<I: IntoIterator<Item = (String, String)>>(
+    config: &mut Value,
+    vars: I,
+    options: &EnvOptions,
+) -> Result<(), String> {
+    let mut vars: Vec<(String, String)> = vars
+        .into_iter()
+        .filter(|(name, _)| name.starts_with(&options.prefix))
+        .collect();
+    vars.sort();
+    for (name, value) in vars {
+        overlay_var(config, &name, value, options).map_err(|e| format!("{}: {}", name, e))?;
+    }
+    Ok(())
+}
+
+fn overlay_var(
+    config: &mut Value,
+    name: &str,
+    value: String,
+    options: &EnvOptions,
+) -> Result<(), String> {
+    let mut target = config;
+    let mut path = String::new();
+    for key in name[options.prefix.len()..].split(options.separator.as_str()) {
+        if key.is_empty() {
+            return Err("expected a key between separators".to_string());
+        }
+        if let Value::Null = target {
+            *target = Value::Object(JsonObject::new());
+        }
+        target = match target {
+            Value::Object(object) => {
+                let key = match object.keys().find(|k| k.eq_ignore_ascii_case(key)) {
+                    Some(existing) => existing.clone(),
+                    None => key.to_ascii_lowercase(),
+                };
+                path.push('/');
+                path.push_str(&escape_token(&key));
+                if !object.contains_key(&key) {
+                    object.insert(key.clone(), Value::Null);
+                }
+                object.get_mut(&key).unwrap()
+            }
+            Value::Array(values) => match array_index(key) {
+                Some(index) if index < values.len() => {
+                    path.push('/');
+                    path.push_str(key);
+                    &mut values[index]
+                }
+                _ => return Err(format!("no element {} in array at {}", key, pointer(&path))),
+            },
+            target => {
+                return Err(format!(
+                    "expected object at {}, found {}",
+                    pointer(&path),
+                    target.type_name()
+                ))
+            }
+        };
+    }
+    *target = match target {
+        Value::Number(_) => Value::Number(Value::String(value).coerce_to_number()?),
+        Value::Bool(_) => Value::Bool(Value::String(value).coerce_to_bool()?),
+        Value::String(_) => Value::String(value),
+        _ => {
+            let mut value = Value::String(value);
+            value.coerce_with(&options.coercion);
+            value
+        }
+    };
+    Ok(())
+}
+
+fn pointer(path: &str) -> &str {
+    if path.is_empty() {
+        "/"
+    } else {
+        path
+    }
+}
+
+impl Value {
+    /// Expands, in place, the `${NAME}` placeholders found in string values
+    /// with what `resolve` returns for `NAME`. `${NAME:-default}` falls back
+    /// to `default` when `NAME` resolves to nothing or to an empty string,
+    /// and `$${` stands for a literal `${`. Object keys are left alone.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    ///# fn main() -> Result<(),String> {
+    ///let mut config = json::parse(r#"{ "url": "http://${HOST}:${PORT:-80}/", "note": "costs $${PRICE}" }"#)?;
+    ///let resolve = |name: &str| match name {
+    ///    "HOST" => Some("localhost".to_string()),
+    ///    _ => None,
+    ///};
+    ///
+    ///config.interpolate(resolve)?;
+    ///assert_eq!(
+    ///    config,
+    ///    json::parse(r#"{ "url": "http://localhost:80/", "note": "costs ${PRICE}" }"#)?
+    ///);
+    ///assert_eq!(
+    ///    json::parse(r#"{ "path": ["${HOME}"] }"#)?.interpolate(resolve),
+    ///    Err("/path/0: unresolved variable HOME".to_string())
+    ///);
+    ///# Ok(())
+    ///# }
+    /// ```
+    pub fn interpolate<F: FnMut(&str) -> Option<String>>(
+        &mut self,
+        mut resolve: F,
+    ) -> Result<(), String> {
+        interpolate_at(self, &mut resolve, &mut String::new())
+    }
+
+    /// Expands placeholders with the variables of this process's
+    /// environment, as [`Value::interpolate`] does.
+    pub fn interpolate_env(&mut self) -> Result<(), String> {
+        self.interpolate(|name| std::env::var(name).ok())
+    }
+}
+
+fn interpolate_at<F: FnMut(&str) -> Option<String>>(
+    value: &mut Value,
+    resolve: &mut F,
+    path: &mut String,
+) -> Result<(), String> {
+    match value {
+        Value::String(text) if text.contains("${") => {
+            *text = expand(text, resolve).map_err(|e| format!("{}: {}", pointer(path), e))?;
+        }
+        Value::Array(values) => {
+            for (i, value) in values.iter_mut().enumerate() {
+                let length = path.len();
+                path.push_str(&format!("/{}", i));
+                interpolate_at(value, resolve, path)?;
+                path.truncate(length);
+            }
+        }
+        Value::Object(object) => {
+            for (key, value) in object.iter_mut() {
+                let length = path.len();
+                path.push('/');
+                path.push_str(&escape_token(key));
+                interpolate_at(value, resolve, path)?;
+                path.truncate(length);
+            }
+        }
+        _ => {}
+    }
+    Ok(())
+}
+
+fn expand<F: FnMut(&str) -> Option<String>>(text: &str, resolve: &mut F) -> Result<String, String> {
+    let mut output = String::with_capacity(text.len());
+    let mut rest = text;
+    while let Some(start) = rest.find("${") {
+        if rest[..start].ends_with('$') {
+            output.push_str(&rest[..start - 1]);
+            output.push_str("${");
+            rest = &rest[start + 2..];
+            continue;
+        }
+        output.push_str(&rest[..start]);
+        let end = match rest[start..].find('}') {
+            Some(end) => start + end,
+            None => return Err(format!("unclosed placeholder {}", &rest[start..])),
+        };
+        let placeholder = &rest[start + 2..end];
+        let (name, default) = match placeholder.find(":-") {
+            Some(i) => (&placeholder[..i], Some(&placeholder[i + 2..])),
+            None => (placeholder, None),
+        };
+        if name.is_empty() {
+            return Err(format!("expected a variable name in ${{{}}}", placeholder));
+        }
+        match (resolve(name), default) {
+            (Some(value), Some(default)) if value.is_empty() => output.push_str(default),
+            (Some(value), _) => output.push_str(&value),
+            (None, Some(default)) => output.push_str(default),
+            (None, None) => return Err(format!("unresolved variable {}", name)),
+        }
+        rest = &rest[end + 1..];
+    }
+    output.push_str(rest);
+    Ok(output)
+}
+
+/// Where [`load`] reads documents from.
+pub trait Loader {
+    /// Reads the document at `path`, already resolved against the path of
+    /// the document including it.
+    fn load(&mut self, path: &str) -> Result<Value, String>;
+}
+
+/// Reads documents from the file system.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct FileLoader;
+
+impl Loader for FileLoader {
+    fn load(&mut self, path: &str) -> Result<Value, String> {
+        let text = std::fs::read_to_string(path).map_err(|e| e.to_string())?;
+        crate::parse(text.as_str())
+    }
+}
+
+impl Loader for DocumentStore {
+    fn load(&mut self, path: &str) -> Result<Value, String> {
+        self.get(path)
+            .cloned()
+            .ok_or_else(|| "no such document".to_string())
+    }
+}
+
+impl<F: FnMut(&str) -> Result<Value, String>> Loader for F {
+    fn load(&mut self, path: &str) -> Result<Value, String> {
+        self(path)
+    }
+}
+
+/// Loads the document at `path`, replacing every `{ "$include": "other.json" }`
+/// object by the document it names, resolved relative to the including one.
+/// Members next to `$include` are merged over the included document, which
+/// must then be an object. Includes are resolved recursively; cycles fail.
+///
+/// # Examples
+///
+/// ```
+///# fn main() -> Result<(),String> {
+///use json::reference::DocumentStore;
+///
+///let mut files = DocumentStore::new();
+///files.insert("conf/app.json", json::parse(r#"{
+///    "logging": { "$include": "common/logging.json", "level": "debug" }
+///}"#)?);
+///files.insert("conf/common/logging.json", json::parse(r#"{ "level": "info", "file": "app.log" }"#)?);
+///
+///assert_eq!(
+///    json::config::load("conf/app.json", &mut files)?,
+///    json::parse(r#"{ "logging": { "level": "debug", "file": "app.log" } }"#)?
+///);
+///# Ok(())
+///# }
+/// ```
+pub fn load<L: Loader>(path: &str, loader: &mut L) -> Result<Value, String> {
+    load_at(path, loader, &mut Vec::new())
+}
+
+/// Resolves the includes of `value`, a document read from `base`, as
+/// [`load`] does.
+pub fn resolve_includes<L: Loader>(
+    value: &Value,
+    base: &str,
+    loader: &mut L,
+) -> Result<Value, String> {
+    let mut loading = vec![base.to_string()];
+    include_at(value, base, loader, &mut loading)
+}
+
+fn load_at<L: Loader>(
+    path: &str,
+    loader: &mut L,
+    loading: &mut Vec<String>,
+) -> Result<Value, String> {
+    if loading.iter().any(|loaded| loaded == path) {
+        return Err(format!("circular $include {:?}", path));
+    }
+    let document = loader.load(path).map_err(|e| format!("{}: {}", path, e))?;
+    loading.push(path.to_string());
+    let resolved = include_at(&document, path, loader, loading);
+    loading.pop();
+    resolved
+}
+
+fn include_at<L: Loader>(
+    value: &Value,
+    base: &str,
+    loader: &mut L,
+    loading: &mut Vec<String>,
+) -> Result<Value, String> {
+    match value {
+        Value::Object(object) if object.contains_key("$include") => {
+            let path = match object.get("$include") {
+                Some(Value::String(path)) => normalize(&join(base, path).0),
+                Some(value) => return Err(at("$include", mismatch("string", value))),
+                None => unreachable!(),
+            };
+            let mut included = load_at(&path, loader, loading)?;
+            let members: JsonObject = object
+                .iter()
+                .filter(|(key, _)| *key != "$include")
+                .map(|(key, value)| {
+                    Ok((
+                        key.clone(),
+                        include_at(value, base, loader, loading).map_err(|e| at(key, e))?,
+                    ))
+                })
+                .collect::<Result<_, String>>()?;
+            if !members.is_empty() {
+                if let Value::Object(_) = included {
+                    included.merge(Value::Object(members));
+                } else {
+                    return Err(at(
+                        "$include",
+                        format!("{}: {}", path, mismatch("object", &included)),
+                    ));
+                }
+            }
+            Ok(included)
+        }
+        Value::Object(object) => object
+            .iter()
+            .map(|(key, value)| {
+                let value = include_at(value, base, loader, loading).map_err(|e| at(key, e))?;
+                Ok((key.clone(), value))
+            })
+            .collect::<Result<_, String>>()
+            .map(Value::Object),
+        Value::Array(values) => values
+            .iter()
+            .enumerate()
+            .map(|(i, value)| {
+                include_at(value, base, loader, loading).map_err(|e| at(&i.to_string(), e))
+            })
+            .collect::<Result<_, _>>()
+            .map(Value::Array),
+        value => Ok(value.clone()),
+    }
+}
+
+/// Removes the `.` and `..` segments of a path, where they can be.
+fn normalize(path: &str) -> String {
+    let mut segments: Vec<&str> = Vec::new();
+    for segment in path.split('/') {
+        match (segment, segments.last()) {
+            (".", _) => {}
+            ("..", Some(&"")) if segments.len() == 1 => {}
+            ("..", Some(last)) if !last.is_empty() && *last != ".." => {
+                segments.pop();
+            }
+            (segment, _) => segments.push(segment),
+        }
+    }
+    segments.join("/")
+}
@@ -0,0 +1,166 @@
+use super::{layer, layer_with_sources, load, overlay_vars, resolve_includes, EnvOptions};
+use crate::reference::DocumentStore;
+use crate::{parse, Value};
+
+#[test]
+fn later_layers_win() {
+    let layers = [
+        parse(r#"{ "a": { "b": 1, "c": [1, 2] }, "d": "x" }"#).unwrap(),
+        parse(r#"{ "a": 2 }"#).unwrap(),
+        parse(r#"{ "a": { "e": null }, "d": {} }"#).unwrap(),
+    ];
+    assert_eq!(
+        layer(&layers),
+        parse(r#"{ "a": { "e": null }, "d": {} }"#).unwrap()
+    );
+    assert_eq!(layer(&[]), Value::Null);
+}
+
+#[test]
+fn sources() {
+    let layered = layer_with_sources(&[
+        parse(r#"{ "a": { "b": 1, "c": [1, 2] }, "d": "x", "e/f": {} }"#).unwrap(),
+        parse(r#"{ "a": { "c": [3] }, "d": { "g": true } }"#).unwrap(),
+    ]);
+    let sources: Vec<_> = layered
+        .sources
+        .iter()
+        .map(|(pointer, index)| (pointer.as_str(), *index))
+        .collect();
+    assert_eq!(
+        sources,
+        vec![("/a/b", 0), ("/a/c", 1), ("/d/g", 1), ("/e~1f", 0)]
+    );
+    assert_eq!(layered.source("/a/c/0"), Some(1));
+    assert_eq!(layered.source("/a/c/1"), None);
+    assert_eq!(layered.source("/e~1f"), Some(0));
+    assert_eq!(layered.source(""), None);
+}
+
+fn vars(vars: &[(&str, &str)]) -> Vec<(String, String)> {
+    vars.iter()
+        .map(|(name, value)| (name.to_string(), value.to_string()))
+        .collect()
+}
+
+#[test]
+fn env_overlay() {
+    let mut config = parse(r#"{ "Server": { "hosts": ["a", "b"] }, "debug": null }"#).unwrap();
+    let mut options = EnvOptions::new("MY_APP_");
+    options.separator = ".".to_string();
+    overlay_vars(
+        &mut config,
+        vars(&[
+            ("MY_APP_SERVER.HOSTS.1", "c"),
+            ("MY_APP_DEBUG", "true"),
+            ("MY_APP_LOG.LEVEL", "null"),
+        ]),
+        &options,
+    )
+    .unwrap();
+    assert_eq!(
+        config,
+        parse(
+            r#"{ "Server": { "hosts": ["a", "c"] }, "debug": true, "log": { "level": "null" } }"#
+        )
+        .unwrap()
+    );
+}
+
+#[test]
+fn env_overlay_errors() {
+    let options = EnvOptions::new("APP_");
+    let overlay = |config: &str, vars: &[(&str, &str)]| {
+        overlay_vars(&mut parse(config).unwrap(), self::vars(vars), &options)
+    };
+    assert_eq!(
+        overlay(r#"{ "port": 80 }"#, &[("APP_PORT", "http")]),
+        Err(r#"APP_PORT: cannot coerce "http" to number"#.to_string())
+    );
+    assert_eq!(
+        overlay("{}", &[("APP_A", "1"), ("APP_A__B", "2")]),
+        Err("APP_A__B: expected object at /a, found number".to_string())
+    );
+    assert_eq!(
+        overlay(r#"{ "a": [] }"#, &[("APP_A__0", "1")]),
+        Err("APP_A__0: no element 0 in array at /a".to_string())
+    );
+    assert_eq!(
+        overlay("{}", &[("APP_A____B", "1")]),
+        Err("APP_A____B: expected a key between separators".to_string())
+    );
+}
+
+#[test]
+fn interpolation() {
+    let resolve = |name: &str| match name {
+        "A" => Some("1".to_string()),
+        "EMPTY" => Some(String::new()),
+        _ => None,
+    };
+    let expand = |text: &str| {
+        let mut value = Value::String(text.to_string());
+        value.interpolate(resolve).map(|_| value)
+    };
+    assert_eq!(
+        expand("${A}${A}-${EMPTY}-${EMPTY:-x}-${B:-}-$$${A}-$"),
+        Ok(Value::String("11--x--$${A}-$".to_string()))
+    );
+    assert_eq!(
+        expand("${A"),
+        Err("/: unclosed placeholder ${A".to_string())
+    );
+    assert_eq!(
+        expand("${:-x}"),
+        Err("/: expected a variable name in ${:-x}".to_string())
+    );
+    let mut value = parse(r#"{ "${A}": 1 }"#).unwrap();
+    assert_eq!(value.interpolate(resolve), Ok(()));
+    assert_eq!(value, parse(r#"{ "${A}": 1 }"#).unwrap());
+}
+
+#[test]
+fn includes() {
+    let mut files = DocumentStore::new();
+    let mut add = |path: &str, json: &str| files.insert(path, parse(json).unwrap());
+    add(
+        "a.json",
+        r#"{ "b": { "$include": "dir/b.json" }, "list": [{ "$include": "c.json" }] }"#,
+    );
+    add("dir/b.json", r#"{ "$include": "../c.json", "d": [1] }"#);
+    add("c.json", r#"{ "c": true }"#);
+    add("loop.json", r#"{ "x": [{ "$include": "loop.json" }] }"#);
+    add("scalar.json", "1");
+    assert_eq!(
+        load("a.json", &mut files),
+        parse(r#"{ "b": { "c": true, "d": [1] }, "list": [{ "c": true }] }"#)
+    );
+    assert_eq!(
+        load("loop.json", &mut files),
+        Err(r#"/x/0: circular $include "loop.json""#.to_string())
+    );
+    assert_eq!(
+        resolve_includes(
+            &parse(r#"{ "$include": "scalar.json", "a": 1 }"#).unwrap(),
+            "",
+            &mut files
+        ),
+        Err("/$include: scalar.json: expected object, found number".to_string())
+    );
+    assert_eq!(super::normalize("../a/./b/../../c"), "../c");
+    assert_eq!(super::normalize("/a/../../b"), "/b");
+    let mut calls = Vec::new();
+    let mut loader = |path: &str| {
+        calls.push(path.to_string());
+        Err("not found".to_string())
+    };
+    assert_eq!(
+        resolve_includes(
+            &parse(r#"[{ "$include": "x.json" }]"#).unwrap(),
+            "conf/",
+            &mut loader
+        ),
+        Err("/0: conf/x.json: not found".to_string())
+    );
+    assert_eq!(calls, vec!["conf/x.json"]);
+}
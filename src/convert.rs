@@ -0,0 +1,284 @@
+#[cfg(test)]
+mod tests;
+
+use crate::extract::ExtractError;
+use crate::pointer::escape_token;
+use crate::Value;
+use std::collections::HashMap;
+use std::convert::TryFrom;
+
+pub(crate) fn mismatch(expected: &str, found: &Value) -> String {
+    format!("expected {}, found {}", expected, found.type_name())
+}
+
+/// Prefixes an error message with the token of the element that failed,
+/// building up a JSON Pointer as errors bubble out of nested containers.
+pub(crate) fn at(token: &str, error: String) -> String {
+    if error.starts_with('/') {
+        format!("/{}{}", escape_token(token), error)
+    } else {
+        format!("/{}: {}", escape_token(token), error)
+    }
+}
+
+impl TryFrom<&Value> for bool {
+    type Error = String;
+
+    fn try_from(value: &Value) -> Result<Self, Self::Error> {
+        match value {
+            Value::Bool(value) => Ok(*value),
+            value => Err(mismatch("bool", value)),
+        }
+    }
+}
+
+impl TryFrom<&Value> for f64 {
+    type Error = String;
+
+    fn try_from(value: &Value) -> Result<Self, Self::Error> {
+        match value {
+            Value::Number(value) => Ok(*value),
+            value => Err(mismatch("number", value)),
+        }
+    }
+}
+
+impl TryFrom<&Value> for String {
+    type Error = String;
+
+    fn try_from(value: &Value) -> Result<Self, Self::Error> {
+        match value {
+            Value::String(value) => Ok(value.clone()),
+            value => Err(mismatch("string", value)),
+        }
+    }
+}
+
+macro_rules! try_from_integer {
+    ($($integer:ty),*) => {
+        $(
+            impl TryFrom<&Value> for $integer {
+                type Error = String;
+
+                fn try_from(value: &Value) -> Result<Self, Self::Error> {
+                    let number = match value {
+                        Value::Number(number) => *number,
+                        value => return Err(mismatch(stringify!($integer), value)),
+                    };
+                    // `MAX as f64 + 1.0` is exact for 32-bit types and rounds
+                    // to the next power of two for 64-bit ones.
+                    if number.fract() != 0.0
+                        || number < <$integer>::MIN as f64
+                        || number >= <$integer>::MAX as f64 + 1.0
+                    {
+                        return Err(format!(
+                            "expected {}, found number {}",
+                            stringify!($integer),
+                            number
+                        ));
+                    }
+                    Ok(number as $integer)
+                }
+            }
+
+            impl TryFrom<Value> for $integer {
+                type Error = String;
+
+                fn try_from(value: Value) -> Result<Self, Self::Error> {
+                    <$integer>::try_from(&value)
+                }
+            }
+        )*
+    };
+}
+
+try_from_integer!(i8, i16, i32, i64, isize, u8, u16, u32, u64, usize);
+
+impl TryFrom<Value> for bool {
+    type Error = String;
+
+    fn try_from(value: Value) -> Result<Self, Self::Error> {
+        bool::try_from(&value)
+    }
+}
+
+impl TryFrom<Value> for f64 {
+    type Error = String;
+
+    fn try_from(value: Value) -> Result<Self, Self::Error> {
+        f64::try_from(&value)
+    }
+}
+
+impl TryFrom<Value> for String {
+    type Error = String;
+
+    fn try_from(value: Value) -> Result<Self, Self::Error> {
+        match value {
+            Value::String(value) => Ok(value),
+            value => Err(mismatch("string", &value)),
+        }
+    }
+}
+
+/// Collections fail with the location of the element that failed, relative
+/// to the collection, as an [`ExtractError`].
+impl<T: TryFrom<Value>> TryFrom<Value> for Vec<T>
+where
+    T::Error: Into<ExtractError>,
+{
+    type Error = ExtractError;
+
+    fn try_from(value: Value) -> Result<Self, Self::Error> {
+        match value {
+            Value::Array(values) => values
+                .into_iter()
+                .enumerate()
+                .map(|(i, value)| T::try_from(value).map_err(|e| e.into().at(&i.to_string())))
+                .collect(),
+            value => Err(mismatch("array", &value).into()),
+        }
+    }
+}
+
+impl<'a, T: TryFrom<&'a Value>> TryFrom<&'a Value> for Vec<T>
+where
+    T::Error: Into<ExtractError>,
+{
+    type Error = ExtractError;
+
+    fn try_from(value: &'a Value) -> Result<Self, Self::Error> {
+        match value {
+            Value::Array(values) => values
+                .iter()
+                .enumerate()
+                .map(|(i, value)| T::try_from(value).map_err(|e| e.into().at(&i.to_string())))
+                .collect(),
+            value => Err(mismatch("array", value).into()),
+        }
+    }
+}
+
+impl<T: TryFrom<Value>> TryFrom<Value> for HashMap<String, T>
+where
+    T::Error: Into<ExtractError>,
+{
+    type Error = ExtractError;
+
+    fn try_from(value: Value) -> Result<Self, Self::Error> {
+        match value {
+            Value::Object(object) => object
+                .into_iter()
+                .map(|(key, value)| match T::try_from(value) {
+                    Ok(value) => Ok((key, value)),
+                    Err(e) => Err(e.into().at(&key)),
+                })
+                .collect(),
+            value => Err(mismatch("object", &value).into()),
+        }
+    }
+}
+
+impl<'a, T: TryFrom<&'a Value>> TryFrom<&'a Value> for HashMap<String, T>
+where
+    T::Error: Into<ExtractError>,
+{
+    type Error = ExtractError;
+
+    fn try_from(value: &'a Value) -> Result<Self, Self::Error> {
+        match value {
+            Value::Object(object) => object
+                .iter()
+                .map(|(key, value)| match T::try_from(value) {
+                    Ok(value) => Ok((key.clone(), value)),
+                    Err(e) => Err(e.into().at(key)),
+                })
+                .collect(),
+            value => Err(mismatch("object", value).into()),
+        }
+    }
+}
+
+/// The largest integer a JavaScript number holds exactly, `2^53 - 1`.
+pub(crate) const MAX_SAFE_INTEGER: f64 = 9_007_199_254_740_991.0;
+
+impl Value {
+    /// Whether this is a number that is integral and within the range of
+    /// `i64`. Above `2^53` the number may already have been rounded when
+    /// parsed; [`Value::fits_js_safe_integer`] tells whether it is exact.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    ///# fn main() -> Result<(),String> {
+    ///use std::convert::TryFrom;
+    ///
+    ///let value = json::parse("9007199254740993")?;
+    ///assert!(value.is_exact_i64());
+    ///assert!(!value.fits_js_safe_integer());
+    ///assert_eq!(i64::try_from(&value).ok(), Some(9007199254740992));
+    ///# Ok(())
+    ///# }
+    /// ```
+    pub fn is_exact_i64(&self) -> bool {
+        i64::try_from(self).is_ok()
+    }
+
+    /// Whether this is a number that is integral and within the range of
+    /// `u64`. As with [`Value::is_exact_i64`], numbers above `2^53` may have
+    /// been rounded when parsed.
+    pub fn is_exact_u64(&self) -> bool {
+        u64::try_from(self).is_ok()
+    }
+
+    /// Whether this is an integer JavaScript represents exactly, one between
+    /// `-(2^53 - 1)` and `2^53 - 1`. Larger integers may have been rounded
+    /// when parsed, and may be rounded again by a JavaScript consumer.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    ///# fn main() -> Result<(),String> {
+    ///assert!(json::parse("9007199254740991")?.fits_js_safe_integer());
+    ///assert!(!json::parse("9007199254740992")?.fits_js_safe_integer());
+    ///assert!(!json::parse("1.5")?.fits_js_safe_integer());
+    ///# Ok(())
+    ///# }
+    /// ```
+    pub fn fits_js_safe_integer(&self) -> bool {
+        match self {
+            Value::Number(number) => number.fract() == 0.0 && number.abs() <= MAX_SAFE_INTEGER,
+            _ => false,
+        }
+    }
+
+    /// Converts a number to `i64`, refusing fractional numbers and integers
+    /// beyond the [safe range](Value::fits_js_safe_integer), whose parsed
+    /// value may already differ from the one written.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    ///# fn main() -> Result<(),String> {
+    ///assert_eq!(json::parse("-42")?.as_i64_checked(), Ok(-42));
+    ///assert_eq!(
+    ///    json::parse("12345678901234567890")?.as_i64_checked(),
+    ///    Err("expected a safe integer, found number 12345678901234567000".to_string())
+    ///);
+    ///assert_eq!(
+    ///    json::parse("0.5")?.as_i64_checked(),
+    ///    Err("expected a safe integer, found number 0.5".to_string())
+    ///);
+    ///# Ok(())
+    ///# }
+    /// ```
+    pub fn as_i64_checked(&self) -> Result<i64, String> {
+        match self {
+            Value::Number(number) if self.fits_js_safe_integer() => Ok(*number as i64),
+            Value::Number(number) => {
+                Err(format!("expected a safe integer, found number {}", number))
+            }
+            value => Err(mismatch("number", value)),
+        }
+    }
+}
@@ -0,0 +1,101 @@
+use crate::{parse, Value};
+use std::collections::HashMap;
+use std::convert::TryFrom;
+
+#[test]
+fn primitives() {
+    assert_eq!(bool::try_from(Value::Bool(true)), Ok(true));
+    assert_eq!(f64::try_from(&Value::Number(1.5)), Ok(1.5));
+    assert_eq!(
+        String::try_from(Value::String("a".to_string())),
+        Ok("a".to_string())
+    );
+    assert_eq!(
+        bool::try_from(Value::Null),
+        Err("expected bool, found null".to_string())
+    );
+    assert_eq!(
+        String::try_from(&Value::Number(1.0)),
+        Err("expected string, found number".to_string())
+    );
+}
+
+#[test]
+fn integers() {
+    assert_eq!(u8::try_from(Value::Number(255.0)), Ok(255));
+    assert_eq!(
+        i64::try_from(Value::Number(-9007199254740991.0)),
+        Ok(-9007199254740991)
+    );
+    assert_eq!(
+        u8::try_from(Value::Number(256.0)),
+        Err("expected u8, found number 256".to_string())
+    );
+    assert_eq!(
+        u32::try_from(Value::Number(-1.0)),
+        Err("expected u32, found number -1".to_string())
+    );
+    assert_eq!(
+        i32::try_from(Value::Number(1.5)),
+        Err("expected i32, found number 1.5".to_string())
+    );
+    assert!(i64::try_from(Value::Number(9223372036854775808.0)).is_err());
+    assert_eq!(
+        usize::try_from(&Value::Bool(true)),
+        Err("expected usize, found bool".to_string())
+    );
+}
+
+#[test]
+fn collections() {
+    let value = parse(r#"{ "a": [1, 2], "b": [] }"#).unwrap();
+    let expected: HashMap<String, Vec<u16>> =
+        vec![("a".to_string(), vec![1, 2]), ("b".to_string(), vec![])]
+            .into_iter()
+            .collect();
+    assert_eq!(
+        HashMap::<String, Vec<u16>>::try_from(&value),
+        Ok(expected.clone())
+    );
+    assert_eq!(HashMap::<String, Vec<u16>>::try_from(value), Ok(expected));
+}
+
+#[test]
+fn collection_errors_name_the_element() {
+    let value = parse(r#"{ "a/b": [1, "2"] }"#).unwrap();
+    let error = HashMap::<String, Vec<u16>>::try_from(&value).unwrap_err();
+    assert_eq!(error.to_string(), "/a~1b/1: expected u16, found string");
+    let error = Vec::<bool>::try_from(value).unwrap_err();
+    assert!(error.path.is_root());
+    assert_eq!(error.message, "expected array, found object");
+}
+
+#[test]
+fn collection_errors_keep_keys_whole() {
+    let value = parse(r#"{ "a: b": [true, 2] }"#).unwrap();
+    let error = HashMap::<String, Vec<bool>>::try_from(value).unwrap_err();
+    assert_eq!(error.path.tokens(), ["a: b", "1"]);
+    assert_eq!(error.message, "expected bool, found number");
+}
+
+#[test]
+fn exact_integers() {
+    let two_pow_63 = Value::Number(9_223_372_036_854_775_808.0);
+    assert!(Value::Number(-9_223_372_036_854_775_808.0).is_exact_i64());
+    assert!(!two_pow_63.is_exact_i64());
+    assert!(two_pow_63.is_exact_u64());
+    assert!(!Value::Number(-1.0).is_exact_u64());
+    assert!(!Value::Number(0.1).is_exact_i64());
+    assert!(!Value::String("1".to_string()).is_exact_u64());
+
+    assert!(Value::Number(-super::MAX_SAFE_INTEGER).fits_js_safe_integer());
+    assert!(!Value::Number(f64::INFINITY).fits_js_safe_integer());
+    assert_eq!(
+        Value::Number(super::MAX_SAFE_INTEGER).as_i64_checked(),
+        Ok(9_007_199_254_740_991)
+    );
+    assert_eq!(
+        Value::Null.as_i64_checked(),
+        Err("expected number, found null".to_string())
+    );
+}
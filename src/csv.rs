@@ -0,0 +1,240 @@
+#[cfg(test)]
+mod tests;
+
+use crate::convert::{at, mismatch};
+use crate::{to_string, CoercionRules, JsonObject, Value};
+use std::io::{Read, Write};
+
+/// How [`from_csv`] reads records.
+#[derive(Debug, PartialEq, Clone, Copy)]
+pub struct CsvOptions {
+    /// The character between fields, `,` by default.
+    pub delimiter: char,
+    /// Whether the first record names the fields, making the others objects
+    /// keyed by those names. When off, every record becomes an array of its
+    /// fields. On by default.
+    pub headers: bool,
+    /// How fields are converted from strings. `None`, the default, keeps
+    /// every field a string.
+    pub coercion: Option<CoercionRules>,
+    /// Whether headers such as `a.b[0]`, which [`to_csv`] writes for nested
+    /// values, are rebuilt into nested values with [`Value::unflatten`].
+    /// Empty fields are then left out, so that records can differ in shape,
+    /// and fields holding `[]` or `{}` become empty containers again.
+    pub unflatten: bool,
+}
+
+impl Default for CsvOptions {
+    fn default() -> Self {
+        CsvOptions {
+            delimiter: ',',
+            headers: true,
+            coercion: None,
+            unflatten: false,
+        }
+    }
+}
+
+/// Reads CSV as RFC 4180 describes it into an array with an object per
+/// record, keyed by the header record. Fields may be quoted, with `""`
+/// standing for a quote inside them, records end with `\n` or `\r\n`, and
+/// blank lines and a leading byte order mark are skipped. Every record must
+/// have as many fields as the header.
+///
+/// # Examples
+///
+/// ```
+///# fn main() -> Result<(),String> {
+///let csv = "name,age,city\nJohn,42,\"Paris, TX\"\nJane,,Oslo\n";
+///let options = json::CsvOptions { coercion: Some(json::CoercionRules::default()), ..Default::default() };
+///
+///assert_eq!(
+///    json::from_csv(csv.as_bytes(), &options)?,
+///    json::parse(r#"[{ "name": "John", "age": 42, "city": "Paris, TX" }, { "name": "Jane", "age": null, "city": "Oslo" }]"#)?
+///);
+///# Ok(())
+///# }
+/// ```
+pub fn from_csv<R: Read>(mut reader: R, options: &CsvOptions) -> Result<Value, String> {
+    let mut text = String::new();
+    reader
+        .read_to_string(&mut text)
+        .map_err(|e| e.to_string())?;
+    let text = text.strip_prefix('\u{feff}').unwrap_or(&text);
+    let mut records = records(text, options.delimiter)?;
+    let headers = if options.headers && !records.is_empty() {
+        Some(records.remove(0).1)
+    } else {
+        None
+    };
+    let mut rows = Vec::new();
+    for (line, fields) in records {
+        let mut row = match &headers {
+            Some(headers) if headers.len() != fields.len() => {
+                return Err(format!(
+                    "line {}: expected {} fields, found {}",
+                    line,
+                    headers.len(),
+                    fields.len()
+                ))
+            }
+            Some(headers) => {
+                let entries = headers.iter().cloned().zip(fields);
+                if options.unflatten {
+                    let object = entries
+                        .filter(|(_, field)| !field.is_empty())
+                        .map(|(header, field)| match field.as_str() {
+                            "[]" => (header, Value::Array(Vec::new())),
+                            "{}" => (header, Value::Object(JsonObject::new())),
+                            _ => (header, Value::String(field)),
+                        })
+                        .collect();
+                    Value::unflatten(object).map_err(|e| format!("line {}: {}", line, e))?
+                } else {
+                    Value::Object(
+                        entries
+                            .map(|(header, field)| (header, Value::String(field)))
+                            .collect(),
+                    )
+                }
+            }
+            None => Value::Array(fields.into_iter().map(Value::String).collect()),
+        };
+        if let Some(rules) = &options.coercion {
+            row.coerce_with(rules);
+        }
+        rows.push(row);
+    }
+    Ok(Value::Array(rows))
+}
+
+/// Splits CSV text into records of fields, each with the line it starts on.
+fn records(text: &str, delimiter: char) -> Result<Vec<(usize, Vec<String>)>, String> {
+    let mut records = Vec::new();
+    let mut record = Vec::new();
+    let mut field = String::new();
+    let (mut line, mut start) = (1, 1);
+    let (mut quoted, mut in_quotes) = (false, false);
+    let mut chars = text.chars().peekable();
+    loop {
+        match chars.next() {
+            None if in_quotes => return Err(format!("line {}: unterminated quoted field", start)),
+            None => {
+                if !record.is_empty() || !field.is_empty() || quoted {
+                    record.push(field);
+                    records.push((start, record));
+                }
+                return Ok(records);
+            }
+            Some('"') if in_quotes => {
+                if chars.peek() == Some(&'"') {
+                    chars.next();
+                    field.push('"');
+                } else {
+                    in_quotes = false;
+                }
+            }
+            Some(c) if in_quotes => {
+                if c == '\n' {
+                    line += 1;
+                }
+                field.push(c);
+            }
+            Some('"') if field.is_empty() && !quoted => {
+                in_quotes = true;
+                quoted = true;
+            }
+            Some(c) if c == delimiter => {
+                record.push(std::mem::take(&mut field));
+                quoted = false;
+            }
+            Some('\r') if chars.peek() == Some(&'\n') => {}
+            Some('\n') => {
+                let blank = record.is_empty() && field.is_empty() && !quoted;
+                record.push(std::mem::take(&mut field));
+                if blank {
+                    record.clear();
+                } else {
+                    records.push((start, std::mem::take(&mut record)));
+                }
+                quoted = false;
+                line += 1;
+                start = line;
+            }
+            Some(c) => field.push(c),
+        }
+    }
+}
+
+/// Writes an array of objects as CSV, with a header record naming every
+/// key found in the objects, in the order they first appear. Nested values
+/// are spread over several columns named as [`Value::flatten`] names them,
+/// e.g. `address.city` or `tags[0]`, so that [`CsvOptions::unflatten`]
+/// reads them back. Strings are written as they are, quoted when they hold
+/// a comma, quote or line break, `null` and missing keys as empty fields
+/// and other values as their JSON text.
+///
+/// # Examples
+///
+/// ```
+///# fn main() -> Result<(),String> {
+///let value = json::parse(r#"[{ "id": 1, "tags": ["a"] }, { "id": 2, "note": "x, \"y\"" }]"#)?;
+///let mut csv = Vec::new();
+///json::to_csv(&value, &mut csv)?;
+///
+///assert_eq!(String::from_utf8(csv).unwrap(), "id,tags[0],note\n1,a,\n2,,\"x, \"\"y\"\"\"\n");
+///# Ok(())
+///# }
+/// ```
+pub fn to_csv<W: Write>(value: &Value, mut writer: W) -> Result<(), String> {
+    let rows = match value {
+        Value::Array(rows) => rows,
+        value => return Err(mismatch("array", value)),
+    };
+    let mut flat = Vec::new();
+    for (i, row) in rows.iter().enumerate() {
+        match row {
+            Value::Object(_) => flat.push(row.flatten()),
+            row => return Err(at(&i.to_string(), mismatch("object", row))),
+        }
+    }
+    let mut headers: Vec<&str> = Vec::new();
+    for row in &flat {
+        for (key, _) in row.iter() {
+            if !headers.contains(&key.as_str()) {
+                headers.push(key);
+            }
+        }
+    }
+    let mut output = String::new();
+    write_record(&mut output, headers.iter().map(|header| header.to_string()));
+    for row in &flat {
+        write_record(
+            &mut output,
+            headers.iter().map(|header| match row.get(header) {
+                None | Some(Value::Null) => String::new(),
+                Some(Value::String(value)) => value.clone(),
+                Some(value) => to_string(value),
+            }),
+        );
+    }
+    writer
+        .write_all(output.as_bytes())
+        .map_err(|e| e.to_string())
+}
+
+fn write_record(output: &mut String, fields: impl Iterator<Item = String>) {
+    for (i, field) in fields.enumerate() {
+        if i > 0 {
+            output.push(',');
+        }
+        if field.contains([',', '"', '\r', '\n']) {
+            output.push('"');
+            output.push_str(&field.replace('"', "\"\""));
+            output.push('"');
+        } else {
+            output.push_str(&field);
+        }
+    }
+    output.push('\n');
+}
@@ -0,0 +1,76 @@
+use super::{from_csv, to_csv, CsvOptions};
+use crate::{parse, CoercionRules, Value};
+
+fn csv(value: &Value) -> String {
+    let mut output = Vec::new();
+    to_csv(value, &mut output).unwrap();
+    String::from_utf8(output).unwrap()
+}
+
+#[test]
+fn quoting() {
+    let text = "\u{feff}a;b\r\n\"x;\"\"y\"\"\";\"line\nbreak\"\r\n\r\n\"\";2";
+    let options = CsvOptions {
+        delimiter: ';',
+        ..Default::default()
+    };
+    let rows = from_csv(text.as_bytes(), &options).unwrap();
+    assert_eq!(
+        rows.pointer("/0/a"),
+        Some(&Value::String("x;\"y\"".to_string()))
+    );
+    assert_eq!(
+        rows.pointer("/0/b"),
+        Some(&Value::String("line\nbreak".to_string()))
+    );
+    assert_eq!(
+        rows.pointer("/1"),
+        parse(r#"{ "a": "", "b": "2" }"#).ok().as_ref()
+    );
+    let options = CsvOptions {
+        headers: false,
+        ..Default::default()
+    };
+    assert_eq!(
+        from_csv("1,2\n3,4".as_bytes(), &options),
+        parse(r#"[["1", "2"], ["3", "4"]]"#)
+    );
+}
+
+#[test]
+fn nested_round_trip() {
+    let value = parse(
+        r#"[{ "id": 1, "user": { "name": "John", "tags": ["a", "b"] }, "ok": true },
+            { "id": 2, "user": { "name": "Jane, Jr.", "tags": [] }, "ok": false }]"#,
+    )
+    .unwrap();
+    let text = csv(&value);
+    assert_eq!(
+        text,
+        "id,user.name,user.tags[0],user.tags[1],ok,user.tags\n1,John,a,b,true,\n2,\"Jane, Jr.\",,,false,[]\n"
+    );
+    let options = CsvOptions {
+        coercion: Some(CoercionRules::default()),
+        unflatten: true,
+        ..Default::default()
+    };
+    assert_eq!(from_csv(text.as_bytes(), &options), Ok(value));
+}
+
+#[test]
+fn errors() {
+    let options = CsvOptions::default();
+    assert_eq!(
+        from_csv("a,b\n1\n".as_bytes(), &options),
+        Err("line 2: expected 2 fields, found 1".to_string())
+    );
+    assert_eq!(
+        from_csv("a\n\"1\n".as_bytes(), &options),
+        Err("line 2: unterminated quoted field".to_string())
+    );
+    assert_eq!(from_csv("".as_bytes(), &options), parse("[]"));
+    assert_eq!(
+        to_csv(&parse("[{}, 1]").unwrap(), Vec::new()),
+        Err("/1: expected object, found number".to_string())
+    );
+}
@@ -0,0 +1,196 @@
+#[cfg(test)]
+mod tests;
+
+use crate::convert::mismatch;
+use crate::{FromJson, ToJson, Value};
+
+fn string<'a>(value: &'a Value, expected: &str) -> Result<&'a str, String> {
+    match value {
+        Value::String(value) => Ok(value),
+        value => Err(mismatch(expected, value)),
+    }
+}
+
+fn invalid(expected: &str, value: &str, error: impl std::fmt::Display) -> String {
+    format!("expected {}, found {:?}: {}", expected, value, error)
+}
+
+const DATE_TIME: &str = "RFC 3339 date-time";
+const DATE: &str = "date";
+
+/// Splits a `YYYY-MM-DD` date into its parts.
+fn date_parts(value: &str) -> Result<(i32, u8, u8), String> {
+    let parts: Vec<&str> = value.split('-').collect();
+    let digits = |part: &str, len| part.len() == len && part.bytes().all(|b| b.is_ascii_digit());
+    match parts.as_slice() {
+        [year, month, day] if digits(year, 4) && digits(month, 2) && digits(day, 2) => Ok((
+            year.parse().unwrap(),
+            month.parse().unwrap(),
+            day.parse().unwrap(),
+        )),
+        _ => Err(invalid(DATE, value, "not in YYYY-MM-DD format")),
+    }
+}
+
+#[cfg(feature = "chrono")]
+mod chrono_support {
+    use super::*;
+    use chrono::{DateTime, FixedOffset, NaiveDate, SecondsFormat, TimeZone};
+
+    impl Value {
+        /// Parses an RFC 3339 string such as `"2024-05-01T12:30:00+02:00"`.
+        ///
+        /// # Examples
+        ///
+        /// ```
+        ///# fn main() -> Result<(),String> {
+        ///use chrono::{Datelike, Timelike};
+        ///
+        ///let event = json::parse(r#"{ "at": "2024-05-01T12:30:00+02:00", "on": "2024-05-01" }"#)?;
+        ///let at = event.pointer("/at").unwrap().as_datetime()?;
+        ///
+        ///assert_eq!((at.hour(), at.offset().local_minus_utc()), (12, 7200));
+        ///assert_eq!(event.pointer("/on").unwrap().as_date()?.day(), 1);
+        ///assert_eq!(json::Value::from(at), json::parse(r#""2024-05-01T12:30:00+02:00""#)?);
+        ///# Ok(())
+        ///# }
+        /// ```
+        pub fn as_datetime(&self) -> Result<DateTime<FixedOffset>, String> {
+            let value = string(self, DATE_TIME)?;
+            DateTime::parse_from_rfc3339(value).map_err(|e| invalid(DATE_TIME, value, e))
+        }
+
+        /// Parses a `YYYY-MM-DD` string.
+        pub fn as_date(&self) -> Result<NaiveDate, String> {
+            let value = string(self, DATE)?;
+            let (year, month, day) = date_parts(value)?;
+            NaiveDate::from_ymd_opt(year, month as u32, day as u32)
+                .ok_or_else(|| invalid(DATE, value, "no such day"))
+        }
+    }
+
+    /// Written in RFC 3339, with as many fractional digits as needed.
+    impl<Tz: TimeZone> From<DateTime<Tz>> for Value {
+        fn from(value: DateTime<Tz>) -> Self {
+            Value::String(value.to_rfc3339_opts(SecondsFormat::AutoSi, true))
+        }
+    }
+
+    impl From<NaiveDate> for Value {
+        fn from(value: NaiveDate) -> Self {
+            Value::String(value.format("%Y-%m-%d").to_string())
+        }
+    }
+
+    impl<Tz: TimeZone> ToJson for DateTime<Tz> {
+        fn to_json(&self) -> Value {
+            Value::from(self.clone())
+        }
+    }
+
+    impl FromJson for DateTime<FixedOffset> {
+        fn from_json(value: &Value) -> Result<Self, String> {
+            value.as_datetime()
+        }
+    }
+
+    impl FromJson for DateTime<chrono::Utc> {
+        fn from_json(value: &Value) -> Result<Self, String> {
+            value
+                .as_datetime()
+                .map(|value| value.with_timezone(&chrono::Utc))
+        }
+    }
+
+    impl ToJson for NaiveDate {
+        fn to_json(&self) -> Value {
+            Value::from(*self)
+        }
+    }
+
+    impl FromJson for NaiveDate {
+        fn from_json(value: &Value) -> Result<Self, String> {
+            value.as_date()
+        }
+    }
+}
+
+#[cfg(feature = "time")]
+mod time_support {
+    use super::*;
+    use std::convert::TryFrom;
+    use time::format_description::well_known::Rfc3339;
+    use time::{Date, Month, OffsetDateTime};
+
+    impl Value {
+        /// Parses an RFC 3339 string into a `time` date-time.
+        ///
+        /// # Examples
+        ///
+        /// ```
+        ///# fn main() -> Result<(),String> {
+        ///let at = json::parse(r#""2024-05-01T12:30:00Z""#)?.as_offset_datetime()?;
+        ///
+        ///assert_eq!(at.hour(), 12);
+        ///assert_eq!(json::parse(r#""2024-02-30""#)?.as_time_date().is_err(), true);
+        ///# Ok(())
+        ///# }
+        /// ```
+        pub fn as_offset_datetime(&self) -> Result<OffsetDateTime, String> {
+            let value = string(self, DATE_TIME)?;
+            OffsetDateTime::parse(value, &Rfc3339).map_err(|e| invalid(DATE_TIME, value, e))
+        }
+
+        /// Parses a `YYYY-MM-DD` string into a `time` date.
+        pub fn as_time_date(&self) -> Result<Date, String> {
+            let value = string(self, DATE)?;
+            let (year, month, day) = date_parts(value)?;
+            Month::try_from(month)
+                .and_then(|month| Date::from_calendar_date(year, month, day))
+                .map_err(|e| invalid(DATE, value, e))
+        }
+    }
+
+    /// Written in RFC 3339, except for the years it can't represent which
+    /// are written as `time` displays them.
+    impl From<OffsetDateTime> for Value {
+        fn from(value: OffsetDateTime) -> Self {
+            Value::String(value.format(&Rfc3339).unwrap_or_else(|_| value.to_string()))
+        }
+    }
+
+    impl From<Date> for Value {
+        fn from(value: Date) -> Self {
+            Value::String(format!(
+                "{:04}-{:02}-{:02}",
+                value.year(),
+                value.month() as u8,
+                value.day()
+            ))
+        }
+    }
+
+    impl ToJson for OffsetDateTime {
+        fn to_json(&self) -> Value {
+            Value::from(*self)
+        }
+    }
+
+    impl FromJson for OffsetDateTime {
+        fn from_json(value: &Value) -> Result<Self, String> {
+            value.as_offset_datetime()
+        }
+    }
+
+    impl ToJson for Date {
+        fn to_json(&self) -> Value {
+            Value::from(*self)
+        }
+    }
+
+    impl FromJson for Date {
+        fn from_json(value: &Value) -> Result<Self, String> {
+            value.as_time_date()
+        }
+    }
+}
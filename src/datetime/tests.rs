@@ -0,0 +1,45 @@
+use crate::{parse, FromJson, ToJson};
+
+#[cfg(feature = "chrono")]
+#[test]
+fn chrono() {
+    use crate::Value;
+    use chrono::{DateTime, NaiveDate, TimeZone, Utc};
+
+    let at = Utc.with_ymd_and_hms(2024, 1, 2, 3, 4, 5).unwrap();
+    assert_eq!(at.to_json(), parse(r#""2024-01-02T03:04:05Z""#).unwrap());
+    assert_eq!(<DateTime<Utc>>::from_json(&at.to_json()), Ok(at));
+    let date = NaiveDate::from_ymd_opt(2024, 2, 29).unwrap();
+    assert_eq!(NaiveDate::from_json(&date.to_json()), Ok(date));
+
+    assert_eq!(
+        Value::Number(1.0).as_datetime(),
+        Err("expected RFC 3339 date-time, found number".to_string())
+    );
+    assert!(parse(r#""2024-01-02 03:04""#)
+        .unwrap()
+        .as_datetime()
+        .is_err());
+    assert_eq!(
+        parse(r#""2023-02-29""#).unwrap().as_date(),
+        Err(r#"expected date, found "2023-02-29": no such day"#.to_string())
+    );
+    assert_eq!(
+        parse(r#""2023-2-1""#).unwrap().as_date(),
+        Err(r#"expected date, found "2023-2-1": not in YYYY-MM-DD format"#.to_string())
+    );
+}
+
+#[cfg(feature = "time")]
+#[test]
+fn time() {
+    use time::{Date, Month, OffsetDateTime};
+
+    let at = OffsetDateTime::from_unix_timestamp(1_700_000_000).unwrap();
+    assert_eq!(at.to_json(), parse(r#""2023-11-14T22:13:20Z""#).unwrap());
+    assert_eq!(OffsetDateTime::from_json(&at.to_json()), Ok(at));
+    let date = Date::from_calendar_date(2024, Month::February, 29).unwrap();
+    assert_eq!(date.to_json(), parse(r#""2024-02-29""#).unwrap());
+    assert_eq!(Date::from_json(&date.to_json()), Ok(date));
+    assert!(parse(r#""2024-13-01""#).unwrap().as_time_date().is_err());
+}
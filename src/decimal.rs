@@ -0,0 +1,69 @@
+#[cfg(test)]
+mod tests;
+
+use crate::convert::mismatch;
+use crate::{FromJson, ToJson, Value};
+use rust_decimal::Decimal;
+use std::str::FromStr;
+
+impl Value {
+    /// Converts a number, or a string holding one, to a [`Decimal`].
+    ///
+    /// Numbers are held as `f64`, so parsing has already rounded their
+    /// literal and the conversion is not lossless. The decimal is built from
+    /// the shortest literal that reads back as the same `f64`: `0.1` gives
+    /// `0.1` rather than `0.1000000000000000055511151231257827`, but
+    /// `0.10000000000000001` gives `0.1` too. Only literals of at most 15
+    /// significant digits are sure to come back unchanged; send longer ones
+    /// as strings.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    ///# fn main() -> Result<(),String> {
+    ///use rust_decimal::Decimal;
+    ///
+    ///let order = json::parse(r#"{ "price": 0.1, "total": "12345678901234567890.25" }"#)?;
+    ///let price = order.pointer("/price").unwrap().as_decimal()?;
+    ///let total = order.pointer("/total").unwrap().as_decimal()?;
+    ///
+    ///assert_eq!(price * Decimal::from(3), Decimal::new(3, 1));
+    ///assert_eq!(total.to_string(), "12345678901234567890.25");
+    ///# Ok(())
+    ///# }
+    /// ```
+    pub fn as_decimal(&self) -> Result<Decimal, String> {
+        let literal = match self {
+            Value::Number(value) if value.is_finite() => value.to_string(),
+            Value::String(value) => value.clone(),
+            value => return Err(mismatch("decimal", value)),
+        };
+        Decimal::from_str_exact(&literal)
+            .map_err(|e| format!("expected decimal, found {:?}: {}", literal, e))
+    }
+}
+
+/// Gives a number when an `f64` holds the decimal exactly, as read back by
+/// [`Value::as_decimal`], and the decimal's literal as a string otherwise.
+impl From<Decimal> for Value {
+    fn from(value: Decimal) -> Self {
+        let literal = value.to_string();
+        let number = f64::from_str(&literal).unwrap();
+        match Value::Number(number).as_decimal() {
+            Ok(read_back) if read_back == value => Value::Number(number),
+            _ => Value::String(literal),
+        }
+    }
+}
+
+impl ToJson for Decimal {
+    fn to_json(&self) -> Value {
+        Value::from(*self)
+    }
+}
+
+impl FromJson for Decimal {
+    fn from_json(value: &Value) -> Result<Self, String> {
+        value.as_decimal()
+    }
+}
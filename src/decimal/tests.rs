@@ -0,0 +1,33 @@
+use crate::{parse, to_string, Value};
+use rust_decimal::Decimal;
+use std::str::FromStr;
+
+#[test]
+fn exact_literals() {
+    for literal in &["0.1", "19.99", "-0.07", "1000000", "123456.789"] {
+        let value = parse(*literal).unwrap();
+        let decimal = value.as_decimal().unwrap();
+        assert_eq!(decimal, Decimal::from_str(literal).unwrap());
+        assert_eq!(to_string(&Value::from(decimal)), *literal);
+    }
+}
+
+#[test]
+fn inexact_decimals_stay_strings() {
+    for literal in &["12345678901234567890.25", "0.10000000000000001"] {
+        let decimal = Decimal::from_str(literal).unwrap();
+        let value = Value::from(decimal);
+        assert_eq!(value, Value::String(literal.to_string()));
+        assert_eq!(value.as_decimal(), Ok(decimal));
+    }
+}
+
+#[test]
+fn errors() {
+    assert_eq!(
+        Value::Bool(true).as_decimal(),
+        Err("expected decimal, found bool".to_string())
+    );
+    assert!(parse("1e40").unwrap().as_decimal().is_err());
+    assert!(parse(r#""ten""#).unwrap().as_decimal().is_err());
+}
@@ -0,0 +1,120 @@
+#[cfg(test)]
+mod tests;
+
+use crate::highlight::{highlight, TokenClass};
+
+/// How much of the input [`detect`] looks at.
+const PREFIX_LENGTH: usize = 64 * 1024;
+
+/// The flavors of JSON text [`detect`] tells apart.
+#[derive(Debug, PartialEq, Eq, Clone, Copy, Hash)]
+pub enum Dialect {
+    Json,
+    /// Values separated by line breaks, one per line.
+    Ndjson,
+    /// JSON with `//` and `/* */` comments.
+    JsonWithComments,
+    /// JSON with JSON5 extensions, such as single quoted strings, unquoted
+    /// keys, hexadecimal numbers or trailing commas.
+    Json5,
+}
+
+/// What [`detect`] found out about some input.
+#[derive(Debug, PartialEq, Eq, Clone, Copy, Hash)]
+pub struct ContentKind {
+    pub dialect: Dialect,
+    /// The [type name](crate::Value::type_name) of the first value, if any.
+    pub top_level: Option<&'static str>,
+}
+
+/// Guesses the dialect of `input` from its first 64 KiB. Input matching
+/// none of them, such as plain invalid JSON, is reported as
+/// [`Dialect::Json`].
+///
+/// # Examples
+///
+/// ```
+///use json::{detect, Dialect};
+///
+///assert_eq!(detect(r#"{ "a": 1 }"#).dialect, Dialect::Json);
+///assert_eq!(detect("{ \"a\": 1 }\n{ \"a\": 2 }\n").dialect, Dialect::Ndjson);
+///assert_eq!(detect("// settings\n[1, 2]").dialect, Dialect::JsonWithComments);
+///assert_eq!(detect("{ a: 'b', }").dialect, Dialect::Json5);
+///assert_eq!(detect(" [true]").top_level, Some("array"));
+///assert_eq!(detect("  ").top_level, None);
+/// ```
+pub fn detect(input: &str) -> ContentKind {
+    let mut end = input.len().min(PREFIX_LENGTH);
+    while !input.is_char_boundary(end) {
+        end -= 1;
+    }
+    let input = &input[..end];
+    let tokens = highlight(input);
+    let (mut comments, mut json5, mut lines) = (false, false, false);
+    let mut top_level = None;
+    let mut depth = 0usize;
+    let mut previous: Option<&str> = None;
+    let mut value_end = None;
+    for (span, class) in &tokens {
+        let text = &input[span.clone()];
+        if *class == TokenClass::Comment {
+            comments = true;
+            continue;
+        }
+        if depth == 0 {
+            if let Some(end) = value_end {
+                lines |= input[end..span.start].contains('\n');
+            }
+        }
+        match (class, text) {
+            (TokenClass::Punctuation, "{") | (TokenClass::Punctuation, "[") => {
+                if depth == 0 && top_level.is_none() {
+                    top_level = Some(if text == "{" { "object" } else { "array" });
+                }
+                depth += 1;
+            }
+            (TokenClass::Punctuation, "}") | (TokenClass::Punctuation, "]") => {
+                json5 |= previous == Some(",");
+                depth = depth.saturating_sub(1);
+            }
+            (TokenClass::Error, text) => json5 |= is_json5(text),
+            (class, text) if depth == 0 && top_level.is_none() => {
+                top_level = match class {
+                    TokenClass::String => Some("string"),
+                    TokenClass::Number => Some("number"),
+                    TokenClass::Literal if text == "null" => Some("null"),
+                    TokenClass::Literal => Some("bool"),
+                    _ => None,
+                };
+            }
+            _ => {}
+        }
+        if depth == 0 {
+            value_end = Some(span.end);
+        }
+        previous = Some(text);
+    }
+    let dialect = if json5 {
+        Dialect::Json5
+    } else if comments {
+        Dialect::JsonWithComments
+    } else if lines {
+        Dialect::Ndjson
+    } else {
+        Dialect::Json
+    };
+    ContentKind { dialect, top_level }
+}
+
+/// Whether a token that isn't valid JSON is valid JSON5.
+fn is_json5(text: &str) -> bool {
+    let number = text.trim_start_matches(['+', '-']);
+    text.starts_with('\'')
+        || ["Infinity", "NaN"].contains(&number)
+        || number.starts_with("0x")
+        || number.starts_with("0X")
+        || (number.contains('.') && number.trim_matches('.').bytes().all(|b| b.is_ascii_digit()))
+        || text
+            .chars()
+            .all(|c| c.is_alphanumeric() || c == '_' || c == '$')
+}
@@ -0,0 +1,27 @@
+use super::{detect, Dialect};
+
+#[test]
+fn dialects() {
+    let dialect = |input: &str| detect(input).dialect;
+    assert_eq!(dialect("[1,\n2]\n"), Dialect::Json);
+    assert_eq!(dialect("1 2"), Dialect::Json);
+    assert_eq!(dialect("\"a\"\n\"b\""), Dialect::Ndjson);
+    assert_eq!(dialect("{}\n/* c */{}"), Dialect::JsonWithComments);
+    assert_eq!(dialect("[0x1F]"), Dialect::Json5);
+    assert_eq!(dialect("[.5, 5.]"), Dialect::Json5);
+    assert_eq!(dialect("[-Infinity]"), Dialect::Json5);
+    assert_eq!(dialect("[1, 2, ]"), Dialect::Json5);
+    assert_eq!(dialect("{ \"a\": @ }"), Dialect::Json);
+}
+
+#[test]
+fn top_level() {
+    assert_eq!(detect("// c\n\"a\"").top_level, Some("string"));
+    assert_eq!(detect("-1").top_level, Some("number"));
+    assert_eq!(detect("false").top_level, Some("bool"));
+    assert_eq!(detect("null\n{}").top_level, Some("null"));
+    assert_eq!(
+        detect("{\"é\": ".repeat(20000).as_str()).top_level,
+        Some("object")
+    );
+}
@@ -0,0 +1,239 @@
+#[cfg(test)]
+mod tests;
+
+use crate::pointer::escape_token;
+use crate::{to_string, JsonPointer, Value};
+use std::fmt;
+
+#[derive(Debug, PartialEq, Clone, Copy)]
+pub enum DifferenceKind {
+    Added,
+    Removed,
+    Changed,
+    TypeMismatch,
+}
+
+/// A single location where two values differ. `left` is the value found in
+/// the original document and `right` the one found in the other document.
+#[derive(Debug, PartialEq, Clone)]
+pub struct Difference {
+    pub path: String,
+    pub kind: DifferenceKind,
+    pub left: Option<Value>,
+    pub right: Option<Value>,
+}
+
+impl fmt::Display for Difference {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let path = if self.path.is_empty() {
+            "/"
+        } else {
+            &self.path
+        };
+        match (self.kind, &self.left, &self.right) {
+            (DifferenceKind::Added, _, Some(right)) => {
+                write!(f, "{}: added {}", path, to_string(right))
+            }
+            (DifferenceKind::Removed, Some(left), _) => {
+                write!(f, "{}: removed {}", path, to_string(left))
+            }
+            (DifferenceKind::Changed, Some(left), Some(right)) => write!(
+                f,
+                "{}: changed from {} to {}",
+                path,
+                to_string(left),
+                to_string(right)
+            ),
+            (DifferenceKind::TypeMismatch, Some(left), Some(right)) => write!(
+                f,
+                "{}: expected {} {}, found {} {}",
+                path,
+                left.type_name(),
+                to_string(left),
+                right.type_name(),
+                to_string(right)
+            ),
+            (kind, _, _) => write!(f, "{}: {:?}", path, kind),
+        }
+    }
+}
+
+impl Value {
+    /// Lists every location where `other` differs from `self`, in document
+    /// order with object keys sorted.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    ///# fn main() -> Result<(),String> {
+    ///let before = json::parse(r#"{ "name": "John", "age": 42 }"#)?;
+    ///let after = json::parse(r#"{ "name": "Jane", "age": "42" }"#)?;
+    ///
+    ///let report: Vec<String> = before.diff(&after).iter().map(|d| d.to_string()).collect();
+    ///assert_eq!(
+    ///    report,
+    ///    vec![
+    ///        r#"/age: expected number 42, found string "42""#,
+    ///        r#"/name: changed from "John" to "Jane""#,
+    ///    ]
+    ///);
+    ///# Ok(())
+    ///# }
+    /// ```
+    pub fn diff(&self, other: &Value) -> Vec<Difference> {
+        let mut differences = Vec::new();
+        diff_at(&mut String::new(), self, other, &mut differences);
+        differences
+    }
+}
+
+fn diff_at(path: &mut String, left: &Value, right: &Value, differences: &mut Vec<Difference>) {
+    match (left, right) {
+        (Value::Object(left), Value::Object(right)) => {
+            let mut keys: Vec<&String> = left.iter().map(|(k, _)| k).collect();
+            keys.extend(
+                right
+                    .iter()
+                    .map(|(k, _)| k)
+                    .filter(|k| !left.contains_key(k)),
+            );
+            keys.sort();
+            for key in keys {
+                let length = path.len();
+                path.push('/');
+                path.push_str(&escape_token(key));
+                diff_entry(path, left.get(key), right.get(key), differences);
+                path.truncate(length);
+            }
+        }
+        (Value::Array(left), Value::Array(right)) => {
+            for i in 0..left.len().max(right.len()) {
+                let length = path.len();
+                path.push_str(&format!("/{}", i));
+                diff_entry(path, left.get(i), right.get(i), differences);
+                path.truncate(length);
+            }
+        }
+        (left, right) if left == right => {}
+        (left, right) => differences.push(Difference {
+            path: path.clone(),
+            kind: if left.type_name() == right.type_name() {
+                DifferenceKind::Changed
+            } else {
+                DifferenceKind::TypeMismatch
+            },
+            left: Some(left.clone()),
+            right: Some(right.clone()),
+        }),
+    }
+}
+
+fn diff_entry(
+    path: &mut String,
+    left: Option<&Value>,
+    right: Option<&Value>,
+    differences: &mut Vec<Difference>,
+) {
+    match (left, right) {
+        (Some(left), Some(right)) => diff_at(path, left, right, differences),
+        (left, right) => differences.push(Difference {
+            path: path.clone(),
+            kind: if left.is_some() {
+                DifferenceKind::Removed
+            } else {
+                DifferenceKind::Added
+            },
+            left: left.cloned(),
+            right: right.cloned(),
+        }),
+    }
+}
+
+impl Value {
+    /// Returns the location of the first difference [`Value::diff`] would
+    /// report, without collecting the others.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    ///# fn main() -> Result<(),String> {
+    ///let left = json::parse(r#"{ "users": [{ "name": "John" }, { "name": "Jane" }] }"#)?;
+    ///let right = json::parse(r#"{ "users": [{ "name": "John" }, { "name": "Joan" }] }"#)?;
+    ///
+    ///let path = left.first_difference(&right).unwrap();
+    ///assert_eq!(path.to_string(), "/users/1/name");
+    ///assert_eq!(left.first_difference(&left.clone()), None);
+    ///# Ok(())
+    ///# }
+    /// ```
+    pub fn first_difference(&self, other: &Value) -> Option<JsonPointer> {
+        let mut path = JsonPointer::root();
+        if first_difference_at(&mut path, self, other) {
+            Some(path)
+        } else {
+            None
+        }
+    }
+}
+
+/// Leaves `path` pointing at the first difference and returns `true` if any.
+fn first_difference_at(path: &mut JsonPointer, left: &Value, right: &Value) -> bool {
+    match (left, right) {
+        (Value::Object(left), Value::Object(right)) => {
+            let mut keys: Vec<&String> = left.iter().map(|(k, _)| k).collect();
+            keys.extend(
+                right
+                    .iter()
+                    .map(|(k, _)| k)
+                    .filter(|k| !left.contains_key(k)),
+            );
+            keys.sort();
+            for key in keys {
+                path.push(key.as_str());
+                match (left.get(key), right.get(key)) {
+                    (Some(left), Some(right)) if !first_difference_at(path, left, right) => {}
+                    _ => return true,
+                }
+                path.pop();
+            }
+            false
+        }
+        (Value::Array(left), Value::Array(right)) => {
+            for i in 0..left.len().max(right.len()) {
+                path.push(i.to_string());
+                match (left.get(i), right.get(i)) {
+                    (Some(left), Some(right)) if !first_difference_at(path, left, right) => {}
+                    _ => return true,
+                }
+                path.pop();
+            }
+            false
+        }
+        (left, right) => left != right,
+    }
+}
+
+impl Value {
+    /// Lists what prevents `self` from including `expected`: everything
+    /// [`expected.diff(self)`](Value::diff) reports except the keys and
+    /// trailing array elements only `self` has.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    ///# fn main() -> Result<(),String> {
+    ///let response = json::parse(r#"{ "id": 7, "name": "John", "roles": ["admin", "dev"] }"#)?;
+    ///
+    ///assert!(response.include_diff(&json::parse(r#"{ "roles": ["admin"] }"#)?).is_empty());
+    ///let differences = response.include_diff(&json::parse(r#"{ "id": 8, "age": 3 }"#)?);
+    ///assert_eq!(differences[0].to_string(), "/age: removed 3");
+    ///assert_eq!(differences[1].to_string(), "/id: changed from 8 to 7");
+    ///# Ok(())
+    ///# }
+    /// ```
+    pub fn include_diff(&self, expected: &Value) -> Vec<Difference> {
+        let mut differences = expected.diff(self);
+        differences.retain(|difference| difference.kind != DifferenceKind::Added);
+        differences
+    }
+}
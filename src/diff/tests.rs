@@ -0,0 +1,90 @@
+use super::{Difference, DifferenceKind};
+use crate::{parse, JsonPointer, Value};
+
+#[test]
+fn equal() {
+    let value = parse(r#"{ "a": [1, { "b": null }] }"#).unwrap();
+    assert_eq!(value.diff(&value.clone()), vec![]);
+}
+
+#[test]
+fn differences() {
+    let left = parse(r#"{ "a": [1, 2, 3], "b": { "c": true }, "d/e": 1 }"#).unwrap();
+    let right = parse(r#"{ "a": [1, 5], "b": { "c": "true", "f": null } }"#).unwrap();
+    assert_eq!(
+        left.diff(&right),
+        vec![
+            Difference {
+                path: "/a/1".to_string(),
+                kind: DifferenceKind::Changed,
+                left: Some(Value::Number(2.0)),
+                right: Some(Value::Number(5.0)),
+            },
+            Difference {
+                path: "/a/2".to_string(),
+                kind: DifferenceKind::Removed,
+                left: Some(Value::Number(3.0)),
+                right: None,
+            },
+            Difference {
+                path: "/b/c".to_string(),
+                kind: DifferenceKind::TypeMismatch,
+                left: Some(Value::Bool(true)),
+                right: Some(Value::String("true".to_string())),
+            },
+            Difference {
+                path: "/b/f".to_string(),
+                kind: DifferenceKind::Added,
+                left: None,
+                right: Some(Value::Null),
+            },
+            Difference {
+                path: "/d~1e".to_string(),
+                kind: DifferenceKind::Removed,
+                left: Some(Value::Number(1.0)),
+                right: None,
+            },
+        ]
+    );
+}
+
+#[test]
+fn display() {
+    let report: Vec<String> = parse(r#"[1, "a\"b"]"#)
+        .unwrap()
+        .diff(&parse(r#"[2]"#).unwrap())
+        .iter()
+        .map(|d| d.to_string())
+        .collect();
+    assert_eq!(
+        report,
+        vec![
+            "/0: changed from 1 to 2".to_string(),
+            r#"/1: removed "a\"b""#.to_string(),
+        ]
+    );
+    assert_eq!(
+        parse("1").unwrap().diff(&parse("null").unwrap())[0].to_string(),
+        "/: expected number 1, found null null"
+    );
+}
+
+#[test]
+fn first_difference() {
+    let left = parse(r#"{ "a": [1, 2], "b/c": { "d": true } }"#).unwrap();
+    assert_eq!(left.first_difference(&left.clone()), None);
+    assert_eq!(
+        left.first_difference(&parse(r#"{ "a": [1, 2], "b/c": { "d": false } }"#).unwrap())
+            .map(|p| p.to_string()),
+        Some("/b~1c/d".to_string())
+    );
+    assert_eq!(
+        left.first_difference(&parse(r#"{ "a": [1], "b/c": {} }"#).unwrap())
+            .map(|p| p.to_string()),
+        Some("/a/1".to_string())
+    );
+    assert_eq!(
+        left.first_difference(&parse("[]").unwrap()),
+        Some(JsonPointer::root())
+    );
+}
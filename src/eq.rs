@@ -0,0 +1,177 @@
+#[cfg(test)]
+mod tests;
+
+use crate::glob::PathPattern;
+use crate::{JsonPointer, Value};
+
+/// Relaxations applied by [`Value::eq_with`].
+#[derive(Debug, PartialEq, Clone, Default)]
+pub struct EqOptions {
+    /// Numbers are equal when their absolute difference is at most this much.
+    pub float_tolerance: f64,
+    /// Arrays are compared as multisets, ignoring element order.
+    pub unordered_arrays: bool,
+    /// Patterns of locations that are never compared, which may be missing on
+    /// either side. Inside unordered arrays, indexes refer to the elements of
+    /// `self`.
+    pub ignored_paths: Vec<PathPattern>,
+}
+
+impl Value {
+    /// # Examples
+    ///
+    /// ```
+    ///# fn main() -> Result<(),String> {
+    ///use json::eq::EqOptions;
+    ///
+    ///let expected = json::parse(r#"{ "ids": [1, 2], "total": 0.3, "requestId": "a" }"#)?;
+    ///let actual = json::parse(r#"{ "ids": [2, 1], "total": 0.30000001, "requestId": "b" }"#)?;
+    ///
+    ///assert!(expected.eq_with(
+    ///    &actual,
+    ///    &EqOptions {
+    ///        float_tolerance: 1e-6,
+    ///        unordered_arrays: true,
+    ///        ignored_paths: vec![json::PathPattern::from_pointer("/requestId")?],
+    ///    }
+    ///));
+    ///# Ok(())
+    ///# }
+    /// ```
+    pub fn eq_with(&self, other: &Value, options: &EqOptions) -> bool {
+        eq_at(&mut JsonPointer::root(), self, other, options)
+    }
+}
+
+impl Value {
+    /// Compares `self` with `other` as [`Value::eq_with`] does with
+    /// [`EqOptions::ignored_paths`] and no other relaxation. Patterns starting
+    /// with `/` are JSON Pointers in which a `*` token matches any key or
+    /// index; the others are path patterns as [`Value::redact`] takes them.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    ///# fn main() -> Result<(),String> {
+    ///let expected = json::parse(r#"{ "meta": { "generated_at": 1 }, "items": [{ "id": 1, "etag": "a" }] }"#)?;
+    ///let actual = json::parse(r#"{ "meta": { "generated_at": 2 }, "items": [{ "id": 1 }] }"#)?;
+    ///
+    ///assert!(expected.eq_ignoring(&actual, &["/meta/generated_at", "/items/*/etag"])?);
+    ///assert!(!expected.eq_ignoring(&actual, &["/meta/generated_at"])?);
+    ///# Ok(())
+    ///# }
+    /// ```
+    pub fn eq_ignoring(&self, other: &Value, patterns: &[&str]) -> Result<bool, String> {
+        let patterns = patterns
+            .iter()
+            .map(|pattern| {
+                if pattern.starts_with('/') {
+                    PathPattern::from_pointer(pattern)
+                } else {
+                    PathPattern::parse(pattern)
+                }
+            })
+            .collect::<Result<Vec<_>, _>>()?;
+        let options = EqOptions {
+            ignored_paths: patterns,
+            ..EqOptions::default()
+        };
+        Ok(self.eq_with(other, &options))
+    }
+}
+
+fn ignored(path: &JsonPointer, options: &EqOptions) -> bool {
+    let tokens = path.tokens();
+    options
+        .ignored_paths
+        .iter()
+        .any(|pattern| pattern.matches(tokens))
+}
+
+fn eq_at(path: &mut JsonPointer, left: &Value, right: &Value, options: &EqOptions) -> bool {
+    if ignored(path, options) {
+        return true;
+    }
+    match (left, right) {
+        (Value::Number(left), Value::Number(right)) => {
+            left == right || (left - right).abs() <= options.float_tolerance
+        }
+        (Value::Array(left), Value::Array(right)) if left.len() == right.len() => {
+            if options.unordered_arrays {
+                // With a tolerance, equality is not transitive, so the first
+                // candidate found is not necessarily the right one.
+                let candidates: Vec<Vec<usize>> = left
+                    .iter()
+                    .enumerate()
+                    .map(|(i, left)| {
+                        with_token(path, &i.to_string(), |path| {
+                            (0..right.len())
+                                .filter(|&j| eq_at(path, left, &right[j], options))
+                                .collect()
+                        })
+                    })
+                    .collect();
+                perfect_matching(&candidates, right.len())
+            } else {
+                left.iter()
+                    .zip(right)
+                    .enumerate()
+                    .all(|(i, (left, right))| {
+                        with_token(path, &i.to_string(), |path| {
+                            eq_at(path, left, right, options)
+                        })
+                    })
+            }
+        }
+        (Value::Object(left), Value::Object(right)) => {
+            let mut keys = left
+                .iter()
+                .map(|(k, _)| k)
+                .chain(right.iter().map(|(k, _)| k));
+            keys.all(|key| {
+                with_token(path, key, |path| match (left.get(key), right.get(key)) {
+                    (Some(left), Some(right)) => eq_at(path, left, right, options),
+                    _ => ignored(path, options),
+                })
+            })
+        }
+        (left, right) => left == right,
+    }
+}
+
+/// Whether every left element can be paired with a distinct right one among
+/// its `candidates`, found with augmenting paths.
+fn perfect_matching(candidates: &[Vec<usize>], right_len: usize) -> bool {
+    let mut matched: Vec<Option<usize>> = vec![None; right_len];
+    (0..candidates.len()).all(|i| augment(i, candidates, &mut matched, &mut vec![false; right_len]))
+}
+
+fn augment(
+    i: usize,
+    candidates: &[Vec<usize>],
+    matched: &mut [Option<usize>],
+    visited: &mut [bool],
+) -> bool {
+    for &j in &candidates[i] {
+        if visited[j] {
+            continue;
+        }
+        visited[j] = true;
+        let free = match matched[j] {
+            Some(k) => augment(k, candidates, matched, visited),
+            None => true,
+        };
+        if free {
+            matched[j] = Some(i);
+            return true;
+        }
+    }
+    false
+}
+
+fn with_token<T>(path: &mut JsonPointer, token: &str, f: impl FnOnce(&mut JsonPointer) -> T) -> T {
+    path.push(token);
+    let result = f(path);
+    path.pop();
+    result
+}
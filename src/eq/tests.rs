@@ -0,0 +1,148 @@
+use super::EqOptions;
+use crate::{parse, JsonPointer, PathPattern};
+
+#[test]
+fn default_options_are_strict() {
+    let left = parse(r#"{ "a": [1, 2], "b": 0.1 }"#).unwrap();
+    assert!(left.eq_with(
+        &parse(r#"{ "b": 0.1, "a": [1, 2] }"#).unwrap(),
+        &EqOptions::default()
+    ));
+    assert!(!left.eq_with(
+        &parse(r#"{ "a": [2, 1], "b": 0.1 }"#).unwrap(),
+        &EqOptions::default()
+    ));
+    assert!(!left.eq_with(&parse(r#"{ "a": [1, 2] }"#).unwrap(), &EqOptions::default()));
+}
+
+#[test]
+fn float_tolerance() {
+    let options = EqOptions {
+        float_tolerance: 0.01,
+        ..EqOptions::default()
+    };
+    assert!(parse("[1.0]")
+        .unwrap()
+        .eq_with(&parse("[1.005]").unwrap(), &options));
+    assert!(!parse("[1.0]")
+        .unwrap()
+        .eq_with(&parse("[1.02]").unwrap(), &options));
+}
+
+#[test]
+fn unordered_arrays_with_tolerance() {
+    let options = EqOptions {
+        float_tolerance: 0.15,
+        unordered_arrays: true,
+        ..EqOptions::default()
+    };
+    assert!(parse("[1.1, 1.0]")
+        .unwrap()
+        .eq_with(&parse("[1.0, 1.2]").unwrap(), &options));
+    assert!(!parse("[1.1, 1.1]")
+        .unwrap()
+        .eq_with(&parse("[1.0, 1.3]").unwrap(), &options));
+}
+
+#[test]
+fn unordered_arrays() {
+    let options = EqOptions {
+        unordered_arrays: true,
+        ..EqOptions::default()
+    };
+    let left = parse(r#"[1, 1, { "a": [true, false] }]"#).unwrap();
+    assert!(left.eq_with(
+        &parse(r#"[{ "a": [false, true] }, 1, 1]"#).unwrap(),
+        &options
+    ));
+    assert!(!left.eq_with(
+        &parse(r#"[{ "a": [false, true] }, 1, 2]"#).unwrap(),
+        &options
+    ));
+}
+
+#[test]
+fn ignored_paths() {
+    let options = EqOptions {
+        ignored_paths: vec![
+            PathPattern::from_pointer("/meta/date").unwrap(),
+            JsonPointer::parse("/items/1").unwrap().into(),
+        ],
+        ..EqOptions::default()
+    };
+    let left = parse(r#"{ "meta": { "date": 1 }, "items": [1, 2] }"#).unwrap();
+    assert!(left.eq_with(
+        &parse(r#"{ "meta": {}, "items": [1, 3] }"#).unwrap(),
+        &options
+    ));
+    assert!(!left.eq_with(
+        &parse(r#"{ "meta": {}, "items": [2, 3] }"#).unwrap(),
+        &options
+    ));
+}
+
+#[test]
+fn ignored_path_patterns() {
+    let options = EqOptions {
+        float_tolerance: 0.5,
+        ignored_paths: vec![PathPattern::parse("items[*].at").unwrap()],
+        ..EqOptions::default()
+    };
+    let left = parse(r#"{ "items": [{ "id": 1, "at": 1 }, { "id": 2, "at": 2 }] }"#).unwrap();
+    assert!(left.eq_with(
+        &parse(r#"{ "items": [{ "id": 1.2 }, { "id": 2, "at": 7 }] }"#).unwrap(),
+        &options
+    ));
+    assert!(!left.eq_with(
+        &parse(r#"{ "items": [{ "id": 1 }, { "id": 3 }] }"#).unwrap(),
+        &options
+    ));
+}
+
+#[test]
+fn eq_ignoring() {
+    let left = parse(r#"{ "a": { "at": 1, "b": [{ "at": 2 }] }, "c": 3 }"#).unwrap();
+    let right = parse(r#"{ "a": { "at": 9, "b": [{}] }, "c": 3 }"#).unwrap();
+    assert_eq!(left.eq_ignoring(&right, &["**.at"]), Ok(true));
+    assert_eq!(left.eq_ignoring(&right, &["/a/at"]), Ok(false));
+    assert_eq!(left.eq_ignoring(&right, &["a.at", "a.b[0].at"]), Ok(true));
+    assert_eq!(
+        left.eq_ignoring(&parse(r#"{ "c": 4 }"#).unwrap(), &["/a", "/c"]),
+        Ok(true)
+    );
+    assert!(left.eq_ignoring(&right, &["a[0"]).is_err());
+}
+
+#[test]
+fn eq_ignoring_keeps_indexes() {
+    let left = parse(r#"{ "items": [1, 2, 3] }"#).unwrap();
+    let right = parse(r#"{ "items": [1, 9, 9] }"#).unwrap();
+    assert_eq!(left.eq_ignoring(&right, &["/items/0"]), Ok(false));
+    assert_eq!(
+        left.eq_ignoring(&right, &["/items/1", "items[2]"]),
+        Ok(true)
+    );
+    assert_eq!(
+        left.eq_ignoring(&parse(r#"{ "items": [1, 2] }"#).unwrap(), &["/items/2"]),
+        Ok(false)
+    );
+}
+
+#[test]
+fn eq_ignoring_pointers() {
+    let left = parse(r#"{ "headers": { "x.request-id": 1, "x": { "request-id": 2 } }, "a/b": 3 }"#)
+        .unwrap();
+    let right =
+        parse(r#"{ "headers": { "x.request-id": 9, "x": { "request-id": 2 } }, "a/b": 4 }"#)
+            .unwrap();
+    assert_eq!(
+        left.eq_ignoring(&right, &["/headers/x.request-id", "/a~1b"]),
+        Ok(true)
+    );
+    assert_eq!(
+        left.eq_ignoring(&right, &["/headers/x.request-id"]),
+        Ok(false)
+    );
+    assert_eq!(left.eq_ignoring(&right, &["/headers/*", "/a~1b"]), Ok(true));
+    assert!(left.eq_ignoring(&right, &["/a~2b"]).is_err());
+}
@@ -0,0 +1,440 @@
+#[cfg(test)]
+mod tests;
+
+use crate::convert::mismatch;
+use crate::pointer::AsPointer;
+use crate::{JsonObject, JsonPointer, Value};
+use std::convert::TryFrom;
+use std::fmt;
+use std::ops::RangeInclusive;
+
+/// A failed extraction, naming the location it happened at.
+#[derive(Debug, PartialEq, Clone)]
+pub struct ExtractError {
+    pub path: JsonPointer,
+    pub message: String,
+}
+
+impl fmt::Display for ExtractError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if self.path.is_root() {
+            write!(f, "/: {}", self.message)
+        } else {
+            write!(f, "{}: {}", self.path, self.message)
+        }
+    }
+}
+
+impl ExtractError {
+    /// Moves the error one level down, under `token`.
+    pub(crate) fn at(self, token: &str) -> Self {
+        ExtractError {
+            path: std::iter::once(token)
+                .chain(self.path.tokens().iter().map(String::as_str))
+                .collect(),
+            message: self.message,
+        }
+    }
+}
+
+/// An error without a location happened at the root.
+impl From<String> for ExtractError {
+    fn from(message: String) -> Self {
+        ExtractError {
+            path: JsonPointer::root(),
+            message,
+        }
+    }
+}
+
+impl From<ExtractError> for String {
+    fn from(error: ExtractError) -> Self {
+        error.to_string()
+    }
+}
+
+/// A value borrowed from a document together with its location in it, so
+/// that every extraction failure can report where it happened.
+///
+/// # Examples
+///
+/// ```
+///# fn main() -> Result<(),String> {
+///let config = json::parse(r#"{ "server": { "host": "localhost", "port": "80" } }"#)?;
+///
+///let server = config.require("server")?;
+///assert_eq!(server.require("host")?.as_str()?, "localhost");
+///assert_eq!(
+///    server.require("port")?.as_u16().unwrap_err().to_string(),
+///    "/server/port: expected integer in 0..=65535, found string"
+///);
+///# Ok(())
+///# }
+/// ```
+#[derive(Debug, PartialEq, Clone)]
+pub struct Field<'a> {
+    path: JsonPointer,
+    value: &'a Value,
+}
+
+macro_rules! as_integer {
+    ($($name:ident: $integer:ty),*) => {
+        $(
+            pub fn $name(&self) -> Result<$integer, ExtractError> {
+                self.as_integer_in(<$integer>::MIN as i128..=<$integer>::MAX as i128)
+                    .map(|value| value as $integer)
+            }
+        )*
+    };
+}
+
+impl<'a> Field<'a> {
+    pub fn new(value: &'a Value) -> Self {
+        Self {
+            path: JsonPointer::root(),
+            value,
+        }
+    }
+
+    pub fn path(&self) -> &JsonPointer {
+        &self.path
+    }
+
+    pub fn value(&self) -> &'a Value {
+        self.value
+    }
+
+    pub fn error<M: Into<String>>(&self, message: M) -> ExtractError {
+        ExtractError {
+            path: self.path.clone(),
+            message: message.into(),
+        }
+    }
+
+    fn child(&self, token: String, value: &'a Value) -> Field<'a> {
+        let mut path = self.path.clone();
+        path.push(token);
+        Field { path, value }
+    }
+
+    /// Looks up a key that must be present. `null` counts as present.
+    pub fn require(&self, key: &str) -> Result<Field<'a>, ExtractError> {
+        let mut path = self.path.clone();
+        path.push(key);
+        match self.as_object()?.get(key) {
+            Some(value) => Ok(Field { path, value }),
+            None => Err(ExtractError {
+                path,
+                message: "missing".to_string(),
+            }),
+        }
+    }
+
+    /// Looks up a key that may be missing or `null`.
+    pub fn optional(&self, key: &str) -> Result<Option<Field<'a>>, ExtractError> {
+        match self.as_object()?.get(key) {
+            None | Some(Value::Null) => Ok(None),
+            Some(value) => Ok(Some(self.child(key.to_string(), value))),
+        }
+    }
+
+    pub fn index(&self, index: usize) -> Result<Field<'a>, ExtractError> {
+        let values = match self.value {
+            Value::Array(values) => values,
+            value => return Err(self.error(mismatch("array", value))),
+        };
+        match values.get(index) {
+            Some(value) => Ok(self.child(index.to_string(), value)),
+            None => Err(self.error(format!(
+                "expected at least {} elements, found {}",
+                index + 1,
+                values.len()
+            ))),
+        }
+    }
+
+    pub fn as_object(&self) -> Result<&'a JsonObject, ExtractError> {
+        match self.value {
+            Value::Object(object) => Ok(object),
+            value => Err(self.error(mismatch("object", value))),
+        }
+    }
+
+    pub fn as_array(&self) -> Result<Vec<Field<'a>>, ExtractError> {
+        match self.value {
+            Value::Array(values) => Ok(values
+                .iter()
+                .enumerate()
+                .map(|(i, value)| self.child(i.to_string(), value))
+                .collect()),
+            value => Err(self.error(mismatch("array", value))),
+        }
+    }
+
+    /// The entries of an object, in order.
+    pub fn entries(&self) -> Result<Vec<(&'a str, Field<'a>)>, ExtractError> {
+        Ok(self
+            .as_object()?
+            .iter()
+            .map(|(key, value)| (key.as_str(), self.child(key.clone(), value)))
+            .collect())
+    }
+
+    pub fn as_str(&self) -> Result<&'a str, ExtractError> {
+        match self.value {
+            Value::String(value) => Ok(value),
+            value => Err(self.error(mismatch("string", value))),
+        }
+    }
+
+    pub fn as_bool(&self) -> Result<bool, ExtractError> {
+        match self.value {
+            Value::Bool(value) => Ok(*value),
+            value => Err(self.error(mismatch("bool", value))),
+        }
+    }
+
+    pub fn as_f64(&self) -> Result<f64, ExtractError> {
+        match self.value {
+            Value::Number(value) => Ok(*value),
+            value => Err(self.error(mismatch("number", value))),
+        }
+    }
+
+    /// Extracts an integer within `range`, rejecting fractional numbers.
+    pub fn as_integer_in(&self, range: RangeInclusive<i128>) -> Result<i128, ExtractError> {
+        let expected = format!("integer in {}..={}", range.start(), range.end());
+        let number = match self.value {
+            Value::Number(number) => *number,
+            value => return Err(self.error(mismatch(&expected, value))),
+        };
+        if number.fract() == 0.0
+            && number >= *range.start() as f64
+            && number < *range.end() as f64 + 1.0
+        {
+            Ok(number as i128)
+        } else {
+            Err(self.error(format!("expected {}, found {}", expected, number)))
+        }
+    }
+
+    as_integer!(
+        as_i8: i8,
+        as_i16: i16,
+        as_i32: i32,
+        as_i64: i64,
+        as_u8: u8,
+        as_u16: u16,
+        as_u32: u32,
+        as_u64: u64,
+        as_usize: usize
+    );
+
+    /// Converts with any [`TryFrom`] implementation, such as the ones for
+    /// collections, keeping the location of nested failures.
+    pub fn parse<T: TryFrom<&'a Value>>(&self) -> Result<T, ExtractError>
+    where
+        T::Error: Into<ExtractError>,
+    {
+        T::try_from(self.value).map_err(|error| {
+            let error = error.into();
+            let mut path = self.path.clone();
+            for token in error.path.tokens() {
+                path.push(token.as_str());
+            }
+            ExtractError {
+                path,
+                message: error.message,
+            }
+        })
+    }
+}
+
+impl Value {
+    /// Starts an extraction at the root of `self`, see [`Field`].
+    pub fn extract(&self) -> Field<'_> {
+        Field::new(self)
+    }
+
+    pub fn require(&self, key: &str) -> Result<Field<'_>, ExtractError> {
+        self.extract().require(key)
+    }
+}
+
+/// Records extraction failures instead of stopping at the first one, see
+/// [`collect`].
+#[derive(Debug, Default)]
+pub struct Collector {
+    errors: Vec<ExtractError>,
+}
+
+impl Collector {
+    /// Unwraps a successful extraction, or records its error and returns
+    /// `None`.
+    pub fn check<T>(&mut self, result: Result<T, ExtractError>) -> Option<T> {
+        match result {
+            Ok(value) => Some(value),
+            Err(error) => {
+                self.errors.push(error);
+                None
+            }
+        }
+    }
+
+    pub fn errors(&self) -> &[ExtractError] {
+        &self.errors
+    }
+}
+
+/// Runs `extract` with a [`Collector`] and returns either what it built or
+/// every error it recorded. Within `extract`, `?` on the `Option`s returned
+/// by [`Collector::check`] skips the parts that depend on a failed field
+/// while the other fields keep being checked.
+///
+/// # Examples
+///
+/// ```
+///use json::extract::{collect, ExtractError};
+///
+///struct Server {
+///    host: String,
+///    port: u16,
+///}
+///
+///let config = json::parse(r#"{ "server": { "host": 42, "port": "80" } }"#).unwrap();
+///
+///let server: Result<Server, Vec<ExtractError>> = collect(|c| {
+///    let server = c.check(config.require("server"))?;
+///    let host = c.check(server.require("host").and_then(|f| f.as_str()));
+///    let port = c.check(server.require("port").and_then(|f| f.as_u16()));
+///    Some(Server { host: host?.to_string(), port: port? })
+///});
+///
+///let errors: Vec<String> = server.err().unwrap().iter().map(|e| e.to_string()).collect();
+///assert_eq!(
+///    errors,
+///    vec![
+///        "/server/host: expected string, found number",
+///        "/server/port: expected integer in 0..=65535, found string",
+///    ]
+///);
+/// ```
+pub fn collect<T, F: FnOnce(&mut Collector) -> Option<T>>(
+    extract: F,
+) -> Result<T, Vec<ExtractError>> {
+    let mut collector = Collector::default();
+    match extract(&mut collector) {
+        Some(value) if collector.errors.is_empty() => Ok(value),
+        None if collector.errors.is_empty() => Err(vec![ExtractError {
+            path: JsonPointer::root(),
+            message: "extraction failed".to_string(),
+        }]),
+        _ => Err(collector.errors),
+    }
+}
+
+/// The value at `pointer` unless it is missing or `null`.
+fn present<'a>(value: &'a Value, pointer: &JsonPointer) -> Option<Field<'a>> {
+    match value.pointer(pointer)? {
+        Value::Null => None,
+        found => Some(Field {
+            path: pointer.clone(),
+            value: found,
+        }),
+    }
+}
+
+impl Value {
+    /// Converts the value at `pointer`, or returns `default` when it is
+    /// missing or `null`. A value of the wrong type is still an error.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    ///# fn main() -> Result<(),String> {
+    ///let config = json::parse(r#"{ "server": { "host": "example.com" } }"#)?;
+    ///
+    ///assert_eq!(config.get_or("/server/port", 8080u16)?, 8080);
+    ///assert_eq!(config.get_or("/server/host", "localhost".to_string())?, "example.com");
+    ///# Ok(())
+    ///# }
+    /// ```
+    pub fn get_or<'a, P, T>(&'a self, pointer: &P, default: T) -> Result<T, ExtractError>
+    where
+        P: AsPointer + ?Sized,
+        T: TryFrom<&'a Value>,
+        T::Error: Into<ExtractError>,
+    {
+        let pointer = match pointer.pointer_tokens() {
+            Ok(tokens) => tokens.iter().collect(),
+            Err(_) => return Ok(default),
+        };
+        match present(self, &pointer) {
+            Some(field) => field.parse(),
+            None => Ok(default),
+        }
+    }
+}
+
+/// Looks a setting up in several places in order, for example an
+/// environment override, then the user configuration, then the defaults.
+/// The first location holding a value other than `null` wins.
+///
+/// # Examples
+///
+/// ```
+///# fn main() -> Result<(),String> {
+///use json::extract::Fallback;
+///
+///let env = json::parse(r#"{}"#)?;
+///let user = json::parse(r#"{ "server": { "port": 9090 } }"#)?;
+///let defaults = json::parse(r#"{ "server": { "port": 8080, "host": "localhost" } }"#)?;
+///
+///let port: u16 = Fallback::new()
+///    .or(&env, "/PORT")
+///    .or(&user, "/server/port")
+///    .or(&defaults, "/server/port")
+///    .get_or(80)?;
+///assert_eq!(port, 9090);
+///# Ok(())
+///# }
+/// ```
+#[derive(Debug, Default, Clone)]
+pub struct Fallback<'a> {
+    candidates: Vec<(&'a Value, JsonPointer)>,
+}
+
+impl<'a> Fallback<'a> {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Adds a candidate location. Text that is no valid pointer never holds
+    /// a value.
+    pub fn or<P: AsPointer + ?Sized>(mut self, value: &'a Value, pointer: &P) -> Self {
+        if let Ok(tokens) = pointer.pointer_tokens() {
+            self.candidates.push((value, tokens.iter().collect()));
+        }
+        self
+    }
+
+    pub fn field(&self) -> Option<Field<'a>> {
+        self.candidates
+            .iter()
+            .find_map(|(value, pointer)| present(value, pointer))
+    }
+
+    pub fn get<T: TryFrom<&'a Value>>(&self) -> Result<Option<T>, ExtractError>
+    where
+        T::Error: Into<ExtractError>,
+    {
+        self.field().map(|field| field.parse()).transpose()
+    }
+
+    pub fn get_or<T: TryFrom<&'a Value>>(&self, default: T) -> Result<T, ExtractError>
+    where
+        T::Error: Into<ExtractError>,
+    {
+        Ok(self.get()?.unwrap_or(default))
+    }
+}
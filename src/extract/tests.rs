@@ -0,0 +1,213 @@
+use super::ExtractError;
+use crate::{parse, JsonPointer};
+use std::collections::HashMap;
+
+fn config() -> crate::Value {
+    parse(
+        r#"{
+            "server": { "host": "localhost", "port": 8080, "workers": 2.5 },
+            "users": [{ "name": "John" }, { "name": null }],
+            "limits": { "a": [1, 2], "b": [3, "4"] }
+        }"#,
+    )
+    .unwrap()
+}
+
+#[test]
+fn require() {
+    let config = config();
+    let server = config.require("server").unwrap();
+    assert_eq!(server.require("port").unwrap().as_u16(), Ok(8080));
+    assert_eq!(server.require("host").unwrap().as_str(), Ok("localhost"));
+    assert_eq!(
+        server.require("tls").unwrap_err().to_string(),
+        "/server/tls: missing"
+    );
+    assert_eq!(
+        server
+            .require("host")
+            .unwrap()
+            .require("x")
+            .unwrap_err()
+            .to_string(),
+        "/server/host: expected object, found string"
+    );
+}
+
+#[test]
+fn integers() {
+    let config = config();
+    let server = config.require("server").unwrap();
+    assert_eq!(
+        server
+            .require("workers")
+            .unwrap()
+            .as_u8()
+            .unwrap_err()
+            .to_string(),
+        "/server/workers: expected integer in 0..=255, found 2.5"
+    );
+    assert_eq!(
+        server
+            .require("port")
+            .unwrap()
+            .as_integer_in(1..=1024)
+            .unwrap_err()
+            .to_string(),
+        "/server/port: expected integer in 1..=1024, found 8080"
+    );
+    assert_eq!(
+        server
+            .require("host")
+            .unwrap()
+            .as_u16()
+            .unwrap_err()
+            .to_string(),
+        "/server/host: expected integer in 0..=65535, found string"
+    );
+}
+
+#[test]
+fn arrays_and_optional() {
+    let config = config();
+    let users = config.require("users").unwrap();
+    let second = users.index(1).unwrap();
+    assert_eq!(second.path().to_string(), "/users/1");
+    assert_eq!(second.optional("name"), Ok(None));
+    assert_eq!(
+        users
+            .index(0)
+            .unwrap()
+            .optional("name")
+            .unwrap()
+            .unwrap()
+            .as_str(),
+        Ok("John")
+    );
+    assert_eq!(
+        users.index(2).unwrap_err().to_string(),
+        "/users: expected at least 3 elements, found 2"
+    );
+}
+
+#[test]
+fn parse_keeps_nested_paths() {
+    let config = config();
+    let limits = config.require("limits").unwrap();
+    let error = limits.parse::<HashMap<String, Vec<u8>>>().unwrap_err();
+    let mut path = JsonPointer::root();
+    for token in &["limits", "b", "1"] {
+        path.push(*token);
+    }
+    assert_eq!(
+        error,
+        ExtractError {
+            path,
+            message: "expected u8, found string".to_string(),
+        }
+    );
+    assert_eq!(
+        limits.require("a").unwrap().parse::<Vec<u8>>(),
+        Ok(vec![1, 2])
+    );
+}
+
+#[test]
+fn parse_keeps_keys_with_separators() {
+    let value = parse(r#"{ "limits": { "a: b": [1, "x"] } }"#).unwrap();
+    let error = value
+        .require("limits")
+        .unwrap()
+        .parse::<HashMap<String, Vec<u8>>>()
+        .unwrap_err();
+    assert_eq!(error.path.tokens(), ["limits", "a: b", "1"]);
+    assert_eq!(error.message, "expected u8, found string");
+}
+
+#[test]
+fn collect_reports_every_error() {
+    let config = config();
+    let result = super::collect(|c| {
+        let server = c.check(config.require("server"))?;
+        let host = c.check(server.require("host").and_then(|f| f.as_str()));
+        let port = c.check(server.require("port").and_then(|f| f.as_u8()));
+        let workers = c.check(server.require("workers").and_then(|f| f.as_u8()));
+        let names: Vec<Option<&str>> = c
+            .check(config.require("users").and_then(|f| f.as_array()))?
+            .iter()
+            .map(|user| c.check(user.require("name").and_then(|f| f.as_str())))
+            .collect();
+        Some((host?, port?, workers?, names))
+    });
+    let errors: Vec<String> = result.unwrap_err().iter().map(|e| e.to_string()).collect();
+    assert_eq!(
+        errors,
+        vec![
+            "/server/port: expected integer in 0..=255, found 8080",
+            "/server/workers: expected integer in 0..=255, found 2.5",
+            "/users/1/name: expected string, found null",
+        ]
+    );
+}
+
+#[test]
+fn collect_success() {
+    let config = config();
+    let result = super::collect(|c| {
+        let server = c.check(config.require("server"))?;
+        c.check(server.require("port").and_then(|f| f.as_u16()))
+    });
+    assert_eq!(result, Ok(8080));
+    assert_eq!(
+        super::collect::<(), _>(|_| None).unwrap_err()[0].message,
+        "extraction failed"
+    );
+}
+
+#[test]
+fn get_or() {
+    let config = config();
+    assert_eq!(config.get_or("/server/port", 80u16), Ok(8080));
+    assert_eq!(config.get_or("/server/timeout", 30u8), Ok(30));
+    assert_eq!(
+        config.get_or("/users/1/name", "anonymous".to_string()),
+        Ok("anonymous".to_string())
+    );
+    assert_eq!(
+        config.get_or("/server/host", 0u8).unwrap_err().to_string(),
+        "/server/host: expected u8, found string"
+    );
+}
+
+#[test]
+fn json_pointer_arguments() {
+    let value = parse(r#"{ "a/b": { "port": 81 } }"#).unwrap();
+    let pointer: JsonPointer = vec!["a/b", "port"].into_iter().collect();
+    assert_eq!(value.get_or(&pointer, 80u16), Ok(81));
+    let port = super::Fallback::new().or(&value, &pointer);
+    assert_eq!(port.get::<u16>(), Ok(Some(81)));
+    assert_eq!(port.field().unwrap().path(), &pointer);
+    assert_eq!(value.get_or("no pointer", 80u16), Ok(80));
+}
+
+#[test]
+fn fallback() {
+    let config = config();
+    let overrides = parse(r#"{ "port": null, "host": "example.com" }"#).unwrap();
+    let port = super::Fallback::new()
+        .or(&overrides, "/port")
+        .or(&config, "/server/port");
+    assert_eq!(port.get::<u16>(), Ok(Some(8080)));
+    assert_eq!(port.field().unwrap().path().to_string(), "/server/port");
+    let host = super::Fallback::new()
+        .or(&overrides, "/host")
+        .or(&config, "/server/host");
+    assert_eq!(
+        host.get_or("localhost".to_string()),
+        Ok("example.com".to_string())
+    );
+    assert_eq!(
+        super::Fallback::new().or(&config, "/nope").get::<bool>(),
+        Ok(None)
+    );
+}
@@ -0,0 +1,194 @@
+#[cfg(test)]
+mod tests;
+
+use crate::{JsonObject, Value};
+use std::collections::HashMap;
+
+/// How many array elements [`Value::unflatten`] fills with `null` beyond those
+/// the keys account for.
+const MAX_NULL_PADDING: usize = 1024;
+
+enum Step {
+    Key(String),
+    Index(usize),
+}
+
+impl Value {
+    /// Flattens nested containers into a single object keyed by dotted paths
+    /// such as `a.b[0]`. Keys containing `.`, `[`, `]` or `\` have those
+    /// characters escaped with a `\`, so `{"a.b": 1}` becomes `a\.b`. Empty
+    /// arrays and objects are kept as values so that [`Value::unflatten`] can
+    /// restore them; a scalar root is stored under `""`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    ///# fn main() -> Result<(),String> {
+    ///let value = json::parse(r#"{ "a": { "b": [1] } }"#)?;
+    ///
+    ///let flat = value.flatten();
+    ///assert_eq!(flat.get("a.b[0]"), Some(&json::Value::Number(1.0)));
+    ///assert_eq!(json::Value::unflatten(flat)?, value);
+    ///
+    ///let value = json::parse(r#"{ "a.b": 1, "a": { "b": 2 } }"#)?;
+    ///let flat = value.flatten();
+    ///assert_eq!(flat.get(r"a\.b"), Some(&json::Value::Number(1.0)));
+    ///assert_eq!(flat.get("a.b"), Some(&json::Value::Number(2.0)));
+    ///assert_eq!(json::Value::unflatten(flat)?, value);
+    ///# Ok(())
+    ///# }
+    /// ```
+    pub fn flatten(&self) -> JsonObject {
+        let mut flat = JsonObject::new();
+        flatten_into(&mut String::new(), self, &mut flat);
+        flat
+    }
+
+    /// Rebuilds a nested value from the keys produced by [`Value::flatten`].
+    /// Array elements missing from the input are filled with `null`, up to
+    /// 1024 of them in total. Object members keep the order in which their
+    /// keys first appear. A `\` takes the character after it literally, as
+    /// part of a key.
+    pub fn unflatten(flat: JsonObject) -> Result<Value, String> {
+        // Every element of a flattened array has at least one key of its own.
+        let mut slots_left = flat.len() + MAX_NULL_PADDING;
+        let mut root = Node::Unset;
+        for (key, value) in flat {
+            let mut current = &mut root;
+            for step in parse_key(&key)? {
+                if let Node::Unset = current {
+                    *current = match step {
+                        Step::Key(_) => Node::Object(Vec::new(), HashMap::new()),
+                        Step::Index(_) => Node::Array(Vec::new()),
+                    };
+                }
+                current = match (current, step) {
+                    (Node::Object(entries, positions), Step::Key(name)) => {
+                        let position = *positions.entry(name.clone()).or_insert(entries.len());
+                        if position == entries.len() {
+                            entries.push((name, Node::Unset));
+                        }
+                        &mut entries[position].1
+                    }
+                    (Node::Array(items), Step::Index(index)) => {
+                        if items.len() <= index {
+                            let added = index + 1 - items.len();
+                            if added > slots_left {
+                                return Err(format!("array index out of range in key {}", key));
+                            }
+                            slots_left -= added;
+                            items.resize_with(index + 1, || Node::Unset);
+                        }
+                        &mut items[index]
+                    }
+                    _ => return Err(format!("conflicting key {}", key)),
+                };
+            }
+            match current {
+                Node::Unset => *current = Node::Leaf(value),
+                _ => return Err(format!("conflicting key {}", key)),
+            }
+        }
+        Ok(match root {
+            Node::Unset => Value::Object(JsonObject::new()),
+            root => root.into_value(),
+        })
+    }
+}
+
+/// A value being rebuilt by [`Value::unflatten`], where `Unset` tells
+/// locations nobody assigned apart from explicit `null`s. Object members are
+/// kept in insertion order, with their positions indexed by key.
+enum Node {
+    Unset,
+    Leaf(Value),
+    Object(Vec<(String, Node)>, HashMap<String, usize>),
+    Array(Vec<Node>),
+}
+
+impl Node {
+    fn into_value(self) -> Value {
+        match self {
+            Node::Unset => Value::Null,
+            Node::Leaf(value) => value,
+            Node::Object(entries, _) => Value::Object(
+                entries
+                    .into_iter()
+                    .map(|(key, node)| (key, node.into_value()))
+                    .collect(),
+            ),
+            Node::Array(items) => Value::Array(items.into_iter().map(Node::into_value).collect()),
+        }
+    }
+}
+
+fn flatten_into(path: &mut String, value: &Value, flat: &mut JsonObject) {
+    let length = path.len();
+    match value {
+        Value::Array(values) if !values.is_empty() => {
+            for (i, child) in values.iter().enumerate() {
+                path.push_str(&format!("[{}]", i));
+                flatten_into(path, child, flat);
+                path.truncate(length);
+            }
+        }
+        Value::Object(object) if !object.is_empty() => {
+            for (key, child) in object.iter() {
+                if !path.is_empty() {
+                    path.push('.');
+                }
+                for c in key.chars() {
+                    if let '.' | '[' | ']' | '\\' = c {
+                        path.push('\\');
+                    }
+                    path.push(c);
+                }
+                flatten_into(path, child, flat);
+                path.truncate(length);
+            }
+        }
+        value => {
+            flat.insert(path.clone(), value.clone());
+        }
+    }
+}
+
+fn parse_key(key: &str) -> Result<Vec<Step>, String> {
+    let mut steps = Vec::new();
+    let mut name = String::new();
+    // Whether the characters being read belong to a key name rather than
+    // following a `]`.
+    let mut in_name = true;
+    let mut chars = key.chars();
+    while let Some(c) = chars.next() {
+        match c {
+            '.' => {
+                if in_name {
+                    steps.push(Step::Key(std::mem::take(&mut name)));
+                }
+                in_name = true;
+            }
+            '[' => {
+                if in_name && !(steps.is_empty() && name.is_empty()) {
+                    steps.push(Step::Key(std::mem::take(&mut name)));
+                }
+                in_name = false;
+                let index: String = chars.by_ref().take_while(|c| *c != ']').collect();
+                let index = index
+                    .parse()
+                    .map_err(|_| format!("invalid array index in key {}", key))?;
+                steps.push(Step::Index(index));
+            }
+            _ if !in_name => return Err(format!("invalid key {}", key)),
+            '\\' => match chars.next() {
+                Some(c) => name.push(c),
+                None => return Err(format!("invalid key {}", key)),
+            },
+            c => name.push(c),
+        }
+    }
+    if in_name && !(steps.is_empty() && name.is_empty()) {
+        steps.push(Step::Key(name));
+    }
+    Ok(steps)
+}
@@ -0,0 +1,104 @@
+use crate::{parse, to_string, JsonObject, Value};
+
+fn flat(entries: &[(&str, Value)]) -> JsonObject {
+    entries
+        .iter()
+        .map(|(key, value)| (key.to_string(), value.clone()))
+        .collect()
+}
+
+#[test]
+fn flatten() {
+    let value = parse(r#"{ "a": { "b": [1, { "c": null }] }, "d": [], "e": {} }"#).unwrap();
+    assert_eq!(
+        value.flatten(),
+        flat(&[
+            ("a.b[0]", Value::Number(1.0)),
+            ("a.b[1].c", Value::Null),
+            ("d", Value::Array(vec![])),
+            ("e", Value::Object(JsonObject::new())),
+        ])
+    );
+    assert_eq!(
+        Value::Bool(true).flatten(),
+        flat(&[("", Value::Bool(true))])
+    );
+    assert_eq!(
+        parse("[[1]]").unwrap().flatten(),
+        flat(&[("[0][0]", Value::Number(1.0))])
+    );
+    assert_eq!(
+        parse(r#"{ "a.b": { "[c]": 1, "d\\": 2 } }"#)
+            .unwrap()
+            .flatten(),
+        flat(&[
+            (r"a\.b.\[c\]", Value::Number(1.0)),
+            (r"a\.b.d\\", Value::Number(2.0)),
+        ])
+    );
+}
+
+#[test]
+fn round_trip() {
+    for raw in &[
+        r#"{ "a": { "b": [1, { "c": null }] }, "d": [], "e": {} }"#,
+        r#"[[1, 2], { "a": [true] }]"#,
+        r#""scalar""#,
+        r#"{ "a.b": 1, "a": { "b": 2 } }"#,
+        r#"{ "x[0]": [{ "\\": null, "]": 3 }], "x": [4] }"#,
+    ] {
+        let value = parse(*raw).unwrap();
+        assert_eq!(Value::unflatten(value.flatten()), Ok(value));
+    }
+}
+
+#[test]
+fn unflatten_pads_arrays() {
+    assert_eq!(
+        Value::unflatten(flat(&[("a[2]", Value::Bool(true))])),
+        Ok(parse(r#"{ "a": [null, null, true] }"#).unwrap())
+    );
+}
+
+#[test]
+fn unflatten_errors() {
+    assert!(Value::unflatten(flat(&[("a", Value::Null), ("a.b", Value::Null)])).is_err());
+    assert_eq!(
+        Value::unflatten(flat(&[("a[x]", Value::Null)])),
+        Err("invalid array index in key a[x]".to_string())
+    );
+    assert_eq!(
+        Value::unflatten(flat(&[("a[0]b", Value::Null)])),
+        Err("invalid key a[0]b".to_string())
+    );
+    assert_eq!(
+        Value::unflatten(flat(&[("a\\", Value::Null)])),
+        Err("invalid key a\\".to_string())
+    );
+}
+
+#[test]
+fn unflatten_keeps_key_order() {
+    let value = Value::unflatten(flat(&[
+        ("z", Value::Null),
+        ("a.y", Value::Null),
+        ("m", Value::Null),
+        ("a.b", Value::Null),
+    ]))
+    .unwrap();
+    assert_eq!(
+        to_string(&value),
+        r#"{"z":null,"a":{"y":null,"b":null},"m":null}"#
+    );
+}
+
+#[test]
+fn unflatten_bounds_indexes() {
+    assert_eq!(
+        Value::unflatten(flat(&[("a[4294967295]", Value::Null)])),
+        Err("array index out of range in key a[4294967295]".to_string())
+    );
+    assert!(Value::unflatten(flat(&[("[1024]", Value::Null)])).is_ok());
+    assert!(Value::unflatten(flat(&[("[1025]", Value::Null)])).is_err());
+    assert!(Value::unflatten(flat(&[("a[1000]", Value::Null), ("b[1000]", Value::Null)])).is_err());
+}
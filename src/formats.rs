@@ -0,0 +1,206 @@
+#[cfg(test)]
+mod tests;
+
+use std::collections::HashMap;
+use std::fmt;
+use std::net::{Ipv4Addr, Ipv6Addr};
+use std::sync::Arc;
+
+/// Tells whether a string is in a given format.
+pub type FormatChecker = Arc<dyn Fn(&str) -> bool + Send + Sync>;
+
+/// The checkers used for the `format` keyword, by format name. Formats
+/// without a checker are annotations and accept any string.
+///
+/// [`Formats::new`] knows `date-time`, `date`, `email`, `uri`, `uuid`,
+/// `ipv4`, `ipv6` and `hostname`.
+///
+/// # Examples
+///
+/// ```
+///# fn main() -> Result<(),String> {
+///use json::schema::{Formats, Options, Schema};
+///
+///let mut formats = Formats::new();
+///formats.insert("even", |value: &str| value.len() % 2 == 0);
+///let options = Options { formats, ..Options::default() };
+///let schema = Schema::compile_with(&json::parse(r#"{ "format": "even" }"#)?, &options)?;
+///
+///assert!(schema.is_valid(&json::parse(r#""ab""#)?));
+///assert!(!schema.is_valid(&json::parse(r#""abc""#)?));
+///assert_eq!(Formats::new().check("ipv4", "127.0.0.1"), Some(true));
+///# Ok(())
+///# }
+/// ```
+#[derive(Clone)]
+pub struct Formats {
+    checkers: HashMap<String, FormatChecker>,
+}
+
+impl Formats {
+    /// The built-in formats.
+    pub fn new() -> Self {
+        let mut formats = Formats::empty();
+        formats.insert("date-time", is_date_time);
+        formats.insert("date", is_date);
+        formats.insert("email", is_email);
+        formats.insert("uri", is_uri);
+        formats.insert("uuid", is_uuid);
+        formats.insert("ipv4", |value: &str| value.parse::<Ipv4Addr>().is_ok());
+        formats.insert("ipv6", |value: &str| value.parse::<Ipv6Addr>().is_ok());
+        formats.insert("hostname", is_hostname);
+        formats
+    }
+
+    /// No formats at all, so that `format` is never checked.
+    pub fn empty() -> Self {
+        Formats {
+            checkers: HashMap::new(),
+        }
+    }
+
+    /// Adds or replaces the checker of a format.
+    pub fn insert<F>(&mut self, name: &str, checker: F)
+    where
+        F: Fn(&str) -> bool + Send + Sync + 'static,
+    {
+        self.checkers.insert(name.to_string(), Arc::new(checker));
+    }
+
+    pub fn get(&self, name: &str) -> Option<&FormatChecker> {
+        self.checkers.get(name)
+    }
+
+    /// Whether `value` is in the format, or `None` for unknown formats.
+    pub fn check(&self, name: &str, value: &str) -> Option<bool> {
+        self.get(name).map(|checker| checker(value))
+    }
+}
+
+impl Default for Formats {
+    fn default() -> Self {
+        Formats::new()
+    }
+}
+
+impl fmt::Debug for Formats {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let mut names: Vec<_> = self.checkers.keys().collect();
+        names.sort();
+        f.debug_set().entries(names).finish()
+    }
+}
+
+fn digits(value: &str, len: usize) -> Option<u32> {
+    if value.len() == len && value.bytes().all(|b| b.is_ascii_digit()) {
+        value.parse().ok()
+    } else {
+        None
+    }
+}
+
+/// A full-date of RFC 3339: `YYYY-MM-DD`.
+fn is_date(value: &str) -> bool {
+    let mut parts = value.splitn(3, '-');
+    let mut next = |len| parts.next().and_then(|part| digits(part, len));
+    let (year, month, day) = match (next(4), next(2), next(2)) {
+        (Some(year), Some(month), Some(day)) => (year, month, day),
+        _ => return false,
+    };
+    let leap = year % 4 == 0 && (year % 100 != 0 || year % 400 == 0);
+    let days = match month {
+        2 if leap => 29,
+        2 => 28,
+        4 | 6 | 9 | 11 => 30,
+        1..=12 => 31,
+        _ => return false,
+    };
+    (1..=days).contains(&day)
+}
+
+/// A date-time of RFC 3339, e.g. `2024-05-01T12:30:00.5+02:00`.
+fn is_date_time(value: &str) -> bool {
+    let separator = match value.find(['T', 't']) {
+        Some(i) => i,
+        None => return false,
+    };
+    let (date, time) = (&value[..separator], &value[separator + 1..]);
+    let (time, offset) = match time.find(['Z', 'z', '+', '-']) {
+        Some(i) => time.split_at(i),
+        None => return false,
+    };
+    let two_digits = |range: std::ops::Range<usize>| offset.get(range).and_then(|d| digits(d, 2));
+    let offset_ok = match offset {
+        "Z" | "z" => true,
+        offset => match (two_digits(1..3), offset.get(3..4), two_digits(4..6)) {
+            (Some(hours), Some(":"), Some(minutes)) => {
+                offset.len() == 6 && hours < 24 && minutes < 60
+            }
+            _ => false,
+        },
+    };
+    let (time, fraction) = match time.find('.') {
+        Some(i) => time.split_at(i),
+        None => (time, ".0"),
+    };
+    let fraction_ok = fraction.len() > 1 && fraction[1..].bytes().all(|b| b.is_ascii_digit());
+    let mut parts = time.split(':');
+    let mut next = || parts.next().and_then(|part| digits(part, 2));
+    let time_ok = match (next(), next(), next(), next()) {
+        (Some(hour), Some(minute), Some(second), None) => hour < 24 && minute < 60 && second <= 60,
+        _ => false,
+    };
+    is_date(date) && time_ok && fraction_ok && offset_ok
+}
+
+fn is_email(value: &str) -> bool {
+    match value.rfind('@') {
+        Some(i) => {
+            let (local, domain) = (&value[..i], &value[i + 1..]);
+            !local.is_empty()
+                && local.len() <= 64
+                && !local.contains(|c: char| c.is_whitespace() || c.is_control())
+                && is_hostname(domain)
+        }
+        None => false,
+    }
+}
+
+/// An absolute URI: a scheme followed by a colon and the rest, which must
+/// not hold whitespace.
+fn is_uri(value: &str) -> bool {
+    let colon = match value.find(':') {
+        Some(i) => i,
+        None => return false,
+    };
+    let scheme = &value[..colon];
+    scheme.starts_with(|c: char| c.is_ascii_alphabetic())
+        && scheme
+            .chars()
+            .all(|c| c.is_ascii_alphanumeric() || "+-.".contains(c))
+        && !value.contains(|c: char| c.is_whitespace() || c.is_control())
+}
+
+fn is_uuid(value: &str) -> bool {
+    let groups: Vec<&str> = value.split('-').collect();
+    groups.len() == 5
+        && groups
+            .iter()
+            .zip(&[8, 4, 4, 4, 12])
+            .all(|(group, len)| group.len() == *len && group.bytes().all(|b| b.is_ascii_hexdigit()))
+}
+
+fn is_hostname(value: &str) -> bool {
+    let value = value.strip_suffix('.').unwrap_or(value);
+    !value.is_empty()
+        && value.len() <= 253
+        && value.split('.').all(|label| {
+            !label.is_empty()
+                && label.len() <= 63
+                && !label.starts_with('-')
+                && !label.ends_with('-')
+                && label
+                    .bytes()
+                    .all(|b| b.is_ascii_alphanumeric() || b == b'-')
+        })
+}
@@ -0,0 +1,44 @@
+use super::Formats;
+
+fn check(format: &str, value: &str) -> bool {
+    Formats::new().check(format, value).unwrap()
+}
+
+#[test]
+fn dates() {
+    assert!(check("date", "2024-02-29"));
+    assert!(!check("date", "2023-02-29"));
+    assert!(!check("date", "2023-1-01"));
+    assert!(check("date-time", "2024-05-01T12:30:00Z"));
+    assert!(check("date-time", "2024-05-01t23:59:60.123-08:00"));
+    assert!(!check("date-time", "2024-05-01T24:00:00Z"));
+    assert!(!check("date-time", "2024-05-01T12:30:00"));
+    assert!(!check("date-time", "2024-05-01T12:30:00+0200"));
+    assert!(!check("date-time", "2024-05-01 12:30:00Z"));
+}
+
+#[test]
+fn network() {
+    assert!(check("email", "john.doe@example.com"));
+    assert!(!check("email", "john doe@example.com"));
+    assert!(!check("email", "john@-example.com"));
+    assert!(check("uri", "https://example.com/a?b#c"));
+    assert!(check("uri", "urn:isbn:0451450523"));
+    assert!(!check("uri", "/relative/path"));
+    assert!(check("ipv4", "192.168.0.1"));
+    assert!(!check("ipv4", "256.0.0.1"));
+    assert!(check("ipv6", "::1"));
+    assert!(!check("ipv6", "1::2::3"));
+    assert!(check("hostname", "www.example.com"));
+    assert!(!check("hostname", "under_score.com"));
+}
+
+#[test]
+fn registry() {
+    assert!(check("uuid", "67e55044-10b1-426f-9247-bb680e5fe0c8"));
+    assert!(!check("uuid", "67e55044-10b1-426f-9247"));
+    let mut formats = Formats::empty();
+    assert_eq!(formats.check("uuid", "x"), None);
+    formats.insert("uuid", |_: &str| true);
+    assert_eq!(formats.check("uuid", "x"), Some(true));
+}
@@ -0,0 +1,300 @@
+#[cfg(test)]
+mod tests;
+
+use crate::pointer::{array_index, AsPointer};
+use crate::{JsonObject, Value};
+use std::convert::TryFrom;
+
+/// An immutable [`Value`] laid out in three contiguous buffers: one for
+/// nodes, one for container slots and one for all string bytes. Freezing
+/// costs a single pass over the document; afterwards reading never
+/// allocates, which suits documents parsed once and queried many times.
+///
+/// Offsets are stored as `u32`, so freezing fails when the strings of a
+/// document take more than 4 GiB, or its nodes or container slots number more
+/// than `u32::MAX`.
+///
+/// # Examples
+///
+/// ```
+///# fn main() -> Result<(),String> {
+///use json::frozen::FrozenValue;
+///use std::convert::TryFrom;
+///
+///let value = json::parse(r#"{ "users": [{ "name": "John" }, { "name": "Jane" }] }"#)?;
+///let frozen = FrozenValue::try_from(&value)?;
+///
+///let users = frozen.root().get("users").unwrap();
+///assert_eq!(users.len(), 2);
+///assert_eq!(frozen.root().pointer("/users/1/name").and_then(|v| v.as_str()), Some("Jane"));
+///assert_eq!(frozen.to_value(), value);
+///# Ok(())
+///# }
+/// ```
+#[derive(Debug, Clone, PartialEq)]
+pub struct FrozenValue {
+    nodes: Vec<Node>,
+    slots: Vec<Slot>,
+    strings: String,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+struct Span {
+    start: u32,
+    len: u32,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum Node {
+    Null,
+    Bool(bool),
+    Number(f64),
+    String(Span),
+    Array(Span),
+    Object(Span),
+}
+
+/// A child of a container. Array slots have an empty key.
+#[derive(Debug, Clone, Copy, PartialEq)]
+struct Slot {
+    key: Span,
+    node: u32,
+}
+
+impl FrozenValue {
+    pub fn root(&self) -> FrozenRef<'_> {
+        FrozenRef {
+            frozen: self,
+            node: 0,
+        }
+    }
+
+    pub fn to_value(&self) -> Value {
+        self.root().to_value()
+    }
+
+    fn push_str(&mut self, value: &str) -> Result<Span, String> {
+        let span = span(self.strings.len(), value.len())?;
+        self.strings.push_str(value);
+        Ok(span)
+    }
+
+    fn str(&self, span: Span) -> &str {
+        let start = span.start as usize;
+        &self.strings[start..start + span.len as usize]
+    }
+
+    /// Appends `value` and its descendants, reserving the slots of each
+    /// container before visiting its children so that they stay contiguous.
+    fn push(&mut self, value: &Value) -> Result<u32, String> {
+        let index = offset(self.nodes.len())?;
+        self.nodes.push(Node::Null);
+        let node = match value {
+            Value::Null => Node::Null,
+            Value::Bool(value) => Node::Bool(*value),
+            Value::Number(value) => Node::Number(*value),
+            Value::String(value) => Node::String(self.push_str(value)?),
+            Value::Array(values) => {
+                let span = self.reserve(values.len())?;
+                for (i, value) in values.iter().enumerate() {
+                    self.slots[span.start as usize + i].node = self.push(value)?;
+                }
+                Node::Array(span)
+            }
+            Value::Object(object) => {
+                let span = self.reserve(object.len())?;
+                for (i, (key, value)) in object.iter().enumerate() {
+                    let slot = span.start as usize + i;
+                    self.slots[slot].key = self.push_str(key)?;
+                    self.slots[slot].node = self.push(value)?;
+                }
+                Node::Object(span)
+            }
+        };
+        self.nodes[index as usize] = node;
+        Ok(index)
+    }
+
+    fn reserve(&mut self, len: usize) -> Result<Span, String> {
+        let span = span(self.slots.len(), len)?;
+        let empty = Slot {
+            key: Span { start: 0, len: 0 },
+            node: 0,
+        };
+        self.slots.resize(self.slots.len() + len, empty);
+        Ok(span)
+    }
+}
+
+fn offset(value: usize) -> Result<u32, String> {
+    u32::try_from(value).map_err(|_| format!("offset {} is too large for a frozen value", value))
+}
+
+/// A span whose end also fits in a `u32`.
+fn span(start: usize, len: usize) -> Result<Span, String> {
+    offset(start.saturating_add(len))?;
+    Ok(Span {
+        start: offset(start)?,
+        len: offset(len)?,
+    })
+}
+
+impl TryFrom<&Value> for FrozenValue {
+    type Error = String;
+
+    fn try_from(value: &Value) -> Result<Self, Self::Error> {
+        let mut frozen = FrozenValue {
+            nodes: Vec::new(),
+            slots: Vec::new(),
+            strings: String::new(),
+        };
+        frozen.push(value)?;
+        frozen.nodes.shrink_to_fit();
+        frozen.slots.shrink_to_fit();
+        frozen.strings.shrink_to_fit();
+        Ok(frozen)
+    }
+}
+
+impl From<&FrozenValue> for Value {
+    fn from(frozen: &FrozenValue) -> Self {
+        frozen.to_value()
+    }
+}
+
+/// A borrowed node of a [`FrozenValue`].
+#[derive(Debug, Clone, Copy)]
+pub struct FrozenRef<'a> {
+    frozen: &'a FrozenValue,
+    node: u32,
+}
+
+impl<'a> FrozenRef<'a> {
+    fn node(&self) -> Node {
+        self.frozen.nodes[self.node as usize]
+    }
+
+    fn slots(&self) -> &'a [Slot] {
+        match self.node() {
+            Node::Array(span) | Node::Object(span) => {
+                let start = span.start as usize;
+                &self.frozen.slots[start..start + span.len as usize]
+            }
+            _ => &[],
+        }
+    }
+
+    fn at(&self, node: u32) -> FrozenRef<'a> {
+        FrozenRef {
+            frozen: self.frozen,
+            node,
+        }
+    }
+
+    pub fn type_name(&self) -> &'static str {
+        match self.node() {
+            Node::Null => "null",
+            Node::Bool(_) => "bool",
+            Node::Number(_) => "number",
+            Node::String(_) => "string",
+            Node::Array(_) => "array",
+            Node::Object(_) => "object",
+        }
+    }
+
+    pub fn is_null(&self) -> bool {
+        self.node() == Node::Null
+    }
+
+    pub fn as_bool(&self) -> Option<bool> {
+        match self.node() {
+            Node::Bool(value) => Some(value),
+            _ => None,
+        }
+    }
+
+    pub fn as_f64(&self) -> Option<f64> {
+        match self.node() {
+            Node::Number(value) => Some(value),
+            _ => None,
+        }
+    }
+
+    pub fn as_str(&self) -> Option<&'a str> {
+        match self.node() {
+            Node::String(span) => Some(self.frozen.str(span)),
+            _ => None,
+        }
+    }
+
+    /// Number of elements or entries; `0` for scalars.
+    pub fn len(&self) -> usize {
+        self.slots().len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Elements of an array, or values of an object.
+    pub fn values(&self) -> impl Iterator<Item = FrozenRef<'a>> + 'a {
+        let this = *self;
+        self.slots().iter().map(move |slot| this.at(slot.node))
+    }
+
+    pub fn entries(&self) -> impl Iterator<Item = (&'a str, FrozenRef<'a>)> + 'a {
+        let this = *self;
+        let slots = match self.node() {
+            Node::Object(_) => self.slots(),
+            _ => &[],
+        };
+        slots
+            .iter()
+            .map(move |slot| (this.frozen.str(slot.key), this.at(slot.node)))
+    }
+
+    pub fn get(&self, key: &str) -> Option<FrozenRef<'a>> {
+        self.entries().find(|(k, _)| *k == key).map(|(_, v)| v)
+    }
+
+    pub fn index(&self, index: usize) -> Option<FrozenRef<'a>> {
+        match self.node() {
+            Node::Array(_) => self.slots().get(index).map(|slot| self.at(slot.node)),
+            _ => None,
+        }
+    }
+
+    pub fn pointer<P: AsPointer + ?Sized>(&self, pointer: &P) -> Option<FrozenRef<'a>> {
+        let mut current = *self;
+        for token in pointer.pointer_tokens().ok()?.iter() {
+            current = match current.node() {
+                Node::Object(_) => current.get(token)?,
+                Node::Array(_) => current.index(array_index(token)?)?,
+                _ => return None,
+            };
+        }
+        Some(current)
+    }
+
+    pub fn to_value(&self) -> Value {
+        match self.node() {
+            Node::Null => Value::Null,
+            Node::Bool(value) => Value::Bool(value),
+            Node::Number(value) => Value::Number(value),
+            Node::String(span) => Value::String(self.frozen.str(span).to_string()),
+            Node::Array(_) => Value::Array(self.values().map(|v| v.to_value()).collect()),
+            Node::Object(_) => Value::Object(
+                self.entries()
+                    .map(|(k, v)| (k.to_string(), v.to_value()))
+                    .collect::<JsonObject>(),
+            ),
+        }
+    }
+}
+
+impl Value {
+    /// Shorthand for [`FrozenValue::try_from`].
+    pub fn freeze(&self) -> Result<FrozenValue, String> {
+        FrozenValue::try_from(self)
+    }
+}
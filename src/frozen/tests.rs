@@ -0,0 +1,57 @@
+use crate::parse;
+
+#[test]
+fn round_trip() {
+    let value =
+        parse(r#"{ "a": [1, "two", null, []], "b": { "c": true, "": {} }, "é": "ü" }"#).unwrap();
+    let frozen = value.freeze().unwrap();
+    assert_eq!(frozen.to_value(), value);
+    assert_eq!(
+        parse("42").unwrap().freeze().unwrap().to_value(),
+        parse("42").unwrap()
+    );
+}
+
+#[test]
+fn lookups() {
+    let frozen = parse(r#"{ "a": [1, "two", null], "b": { "c": true } }"#)
+        .unwrap()
+        .freeze()
+        .unwrap();
+    let root = frozen.root();
+    assert_eq!(root.type_name(), "object");
+    assert_eq!(root.len(), 2);
+    assert_eq!(root.pointer("/a/0").and_then(|v| v.as_f64()), Some(1.0));
+    assert_eq!(root.pointer("/a/1").and_then(|v| v.as_str()), Some("two"));
+    assert!(root.pointer("/a/2").unwrap().is_null());
+    assert_eq!(root.pointer("/b/c").and_then(|v| v.as_bool()), Some(true));
+    assert!(root.pointer("/a/3").is_none());
+    assert!(root.pointer("/b/c/d").is_none());
+    assert_eq!(
+        root.entries().map(|(k, _)| k).collect::<Vec<_>>(),
+        vec!["a", "b"]
+    );
+    assert!(root.get("a").unwrap().entries().next().is_none());
+    assert!(root.get("b").unwrap().index(0).is_none());
+}
+
+#[test]
+fn contiguous_storage() {
+    let frozen = parse(r#"[{ "key": "value" }, ["x", "y"]]"#)
+        .unwrap()
+        .freeze()
+        .unwrap();
+    assert_eq!(frozen.nodes.len(), 6);
+    assert_eq!(frozen.slots.len(), 5);
+    assert_eq!(frozen.strings, "keyvaluexy");
+}
+
+#[test]
+fn offsets_must_fit_u32() {
+    assert!(super::span(0, u32::MAX as usize).is_ok());
+    assert_eq!(
+        super::span(1, u32::MAX as usize),
+        Err("offset 4294967296 is too large for a frozen value".to_string())
+    );
+    assert!(super::span(u32::MAX as usize + 1, 0).is_err());
+}
@@ -0,0 +1,200 @@
+#[cfg(test)]
+mod tests;
+
+use crate::{JsonObject, Value};
+use std::ops::RangeInclusive;
+
+/// Relative likelihood of each type being generated. Containers are never
+/// generated beyond [`Options::max_depth`].
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub struct Weights {
+    pub null: u32,
+    pub bool: u32,
+    pub number: u32,
+    pub string: u32,
+    pub array: u32,
+    pub object: u32,
+}
+
+impl Default for Weights {
+    fn default() -> Self {
+        Self {
+            null: 1,
+            bool: 2,
+            number: 4,
+            string: 4,
+            array: 2,
+            object: 2,
+        }
+    }
+}
+
+/// Shape of the documents produced by a [`Generator`].
+#[derive(Debug, PartialEq, Clone)]
+pub struct Options {
+    /// Nesting depth of containers: `0` only yields scalars.
+    pub max_depth: usize,
+    pub array_len: RangeInclusive<usize>,
+    pub object_len: RangeInclusive<usize>,
+    pub string_len: RangeInclusive<usize>,
+    /// Object keys are drawn from this pool, or made up when it is empty.
+    pub keys: Vec<String>,
+    pub weights: Weights,
+    /// The root is always an object, as most payloads are.
+    pub object_root: bool,
+}
+
+impl Default for Options {
+    fn default() -> Self {
+        Self {
+            max_depth: 4,
+            array_len: 0..=5,
+            object_len: 0..=5,
+            string_len: 0..=12,
+            keys: Vec::new(),
+            weights: Weights::default(),
+            object_root: true,
+        }
+    }
+}
+
+/// Produces pseudo-random documents. The same options and seed always yield
+/// the same documents, so failures found with them can be replayed.
+///
+/// # Examples
+///
+/// ```
+///use json::generate::{Generator, Options};
+///
+///let options = Options {
+///    max_depth: 2,
+///    keys: vec!["id".to_string(), "name".to_string(), "tags".to_string()],
+///    ..Options::default()
+///};
+///let mut generator = Generator::new(options.clone(), 42);
+///let document = generator.generate();
+///
+///assert!(document.stats().max_depth <= 2);
+///assert!(document.keys().all(|key| options.keys.contains(key)));
+///assert_eq!(Generator::new(options, 42).generate(), document);
+/// ```
+#[derive(Debug, Clone)]
+pub struct Generator {
+    options: Options,
+    state: u64,
+}
+
+const ALPHABET: &[u8] = b"abcdefghijklmnopqrstuvwxyzABCDEFGHIJKLMNOPQRSTUVWXYZ0123456789 _-";
+
+impl Generator {
+    pub fn new(options: Options, seed: u64) -> Self {
+        Self {
+            options,
+            state: seed,
+        }
+    }
+
+    pub fn generate(&mut self) -> Value {
+        if self.options.object_root && self.options.max_depth > 0 {
+            self.object(1)
+        } else {
+            self.value(0)
+        }
+    }
+
+    /// SplitMix64, good enough for synthetic data and free of dependencies.
+    fn next_u64(&mut self) -> u64 {
+        self.state = self.state.wrapping_add(0x9e37_79b9_7f4a_7c15);
+        let mut z = self.state;
+        z = (z ^ (z >> 30)).wrapping_mul(0xbf58_476d_1ce4_e5b9);
+        z = (z ^ (z >> 27)).wrapping_mul(0x94d0_49bb_1331_11eb);
+        z ^ (z >> 31)
+    }
+
+    fn below(&mut self, bound: u64) -> u64 {
+        if bound == 0 {
+            0
+        } else {
+            self.next_u64() % bound
+        }
+    }
+
+    fn in_range(&mut self, range: &RangeInclusive<usize>) -> usize {
+        let span = range.end().saturating_sub(*range.start()) as u64 + 1;
+        range.start() + self.below(span) as usize
+    }
+
+    fn value(&mut self, depth: usize) -> Value {
+        let weights = self.options.weights;
+        let nested = depth < self.options.max_depth;
+        let choices = [
+            weights.null,
+            weights.bool,
+            weights.number,
+            weights.string,
+            if nested { weights.array } else { 0 },
+            if nested { weights.object } else { 0 },
+        ];
+        let mut pick = self.below(choices.iter().map(|w| *w as u64).sum());
+        let mut choice = 0;
+        for (i, weight) in choices.iter().enumerate() {
+            if pick < *weight as u64 {
+                choice = i;
+                break;
+            }
+            pick -= *weight as u64;
+        }
+        match choice {
+            1 => Value::Bool(self.below(2) == 1),
+            2 => self.number(),
+            3 => Value::String(self.string()),
+            4 => {
+                let len = self.in_range(&self.options.array_len.clone());
+                Value::Array((0..len).map(|_| self.value(depth + 1)).collect())
+            }
+            5 => self.object(depth + 1),
+            _ => Value::Null,
+        }
+    }
+
+    fn object(&mut self, depth: usize) -> Value {
+        let len = self.in_range(&self.options.object_len.clone());
+        let mut object = JsonObject::new();
+        for _ in 0..len {
+            let key = if self.options.keys.is_empty() {
+                self.string()
+            } else {
+                let i = self.below(self.options.keys.len() as u64) as usize;
+                self.options.keys[i].clone()
+            };
+            let value = self.value(depth);
+            object.insert(key, value);
+        }
+        Value::Object(object)
+    }
+
+    /// Half integers, half numbers with two decimals, within ±1000.
+    fn number(&mut self) -> Value {
+        let integer = self.below(2001) as f64 - 1000.0;
+        if self.below(2) == 0 {
+            Value::Number(integer)
+        } else {
+            Value::Number(integer + self.below(100) as f64 / 100.0)
+        }
+    }
+
+    fn string(&mut self) -> String {
+        let len = self.in_range(&self.options.string_len.clone());
+        (0..len)
+            .map(|_| ALPHABET[self.below(ALPHABET.len() as u64) as usize] as char)
+            .collect()
+    }
+}
+
+impl Iterator for Generator {
+    type Item = Value;
+
+    fn next(&mut self) -> Option<Value> {
+        Some(self.generate())
+    }
+}
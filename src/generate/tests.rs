@@ -0,0 +1,60 @@
+use super::{Generator, Options, Weights};
+use crate::Value;
+
+#[test]
+fn respects_constraints() {
+    let options = Options {
+        max_depth: 3,
+        array_len: 1..=2,
+        object_len: 2..=3,
+        string_len: 4..=4,
+        keys: vec!["a".to_string(), "b".to_string(), "c".to_string()],
+        ..Options::default()
+    };
+    for document in Generator::new(options, 7).take(200) {
+        let stats = document.stats();
+        assert!(stats.max_depth <= 3);
+        assert!(stats.largest_array <= 2);
+        assert!(stats.largest_object <= 3);
+        for (_, value) in document.walk() {
+            match value {
+                Value::String(s) => assert_eq!(s.len(), 4),
+                Value::Array(values) => assert!(!values.is_empty()),
+                Value::Object(object) => {
+                    assert!(object.keys().all(|k| ["a", "b", "c"].contains(&k.as_str())))
+                }
+                _ => {}
+            }
+        }
+    }
+}
+
+#[test]
+fn weights_select_types() {
+    let options = Options {
+        object_root: false,
+        weights: Weights {
+            null: 0,
+            bool: 0,
+            number: 0,
+            string: 0,
+            array: 1,
+            object: 0,
+        },
+        max_depth: 2,
+        array_len: 1..=1,
+        ..Options::default()
+    };
+    let value = Generator::new(options, 1).generate();
+    // Containers stop at the maximum depth, where no type is left to pick.
+    assert_eq!(value, crate::parse("[[null]]").unwrap());
+}
+
+#[test]
+fn seeds_are_reproducible() {
+    let first: Vec<_> = Generator::new(Options::default(), 3).take(5).collect();
+    let second: Vec<_> = Generator::new(Options::default(), 3).take(5).collect();
+    let other: Vec<_> = Generator::new(Options::default(), 4).take(5).collect();
+    assert_eq!(first, second);
+    assert_ne!(first, other);
+}
@@ -0,0 +1,120 @@
+#[cfg(test)]
+mod tests;
+
+use crate::JsonPointer;
+
+/// A path pattern such as `**/password`, `users[*].ssn` or `/items/*/etag`.
+///
+/// Segments are separated by `/` or `.`, and `[...]` introduces a segment of
+/// its own. `*` matches exactly one object key or array index and `**`
+/// matches any number of them, including none. A leading `/` is optional.
+///
+/// [`PathPattern::from_pointer`] reads a JSON Pointer instead, and a
+/// [`JsonPointer`] converts to the pattern matching just its location.
+///
+/// # Examples
+///
+/// ```
+///# fn main() -> Result<(),String> {
+///use json::PathPattern;
+///
+///let path = ["headers".to_string(), "x.id".to_string()];
+///assert!(PathPattern::from_pointer("/headers/x.id")?.matches(&path));
+///assert!(PathPattern::parse("headers.*")?.matches(&path));
+///assert!(!PathPattern::parse("/headers/x.id")?.matches(&path));
+///# Ok(())
+///# }
+/// ```
+#[derive(Debug, PartialEq, Clone)]
+pub struct PathPattern(Vec<Segment>);
+
+#[derive(Debug, PartialEq, Clone)]
+enum Segment {
+    Literal(String),
+    Any,
+    AnyDepth,
+}
+
+impl PathPattern {
+    pub fn parse(pattern: &str) -> Result<Self, String> {
+        let mut segments = Vec::new();
+        let mut current = String::new();
+        let mut chars = pattern.chars();
+        while let Some(c) = chars.next() {
+            match c {
+                '/' | '.' => push_segment(&mut segments, &mut current),
+                '[' => {
+                    push_segment(&mut segments, &mut current);
+                    loop {
+                        match chars.next() {
+                            Some(']') => break,
+                            Some(c) => current.push(c),
+                            None => return Err(format!("unclosed [ in pattern {}", pattern)),
+                        }
+                    }
+                    push_segment(&mut segments, &mut current);
+                }
+                c => current.push(c),
+            }
+        }
+        push_segment(&mut segments, &mut current);
+        Ok(PathPattern(segments))
+    }
+
+    /// Reads a JSON Pointer (RFC 6901) whose tokens are literal keys, except
+    /// for `*`, which matches any single key or index.
+    pub fn from_pointer(pointer: &str) -> Result<Self, String> {
+        let tokens = crate::pointer::tokens(pointer)?;
+        let segments = tokens
+            .into_iter()
+            .map(|token| match token.as_str() {
+                "*" => Segment::Any,
+                _ => Segment::Literal(token),
+            })
+            .collect();
+        Ok(PathPattern(segments))
+    }
+
+    /// Whether the location made of the unescaped `tokens` matches.
+    pub fn matches(&self, tokens: &[String]) -> bool {
+        matches_from(&self.0, tokens)
+    }
+}
+
+impl From<JsonPointer> for PathPattern {
+    fn from(pointer: JsonPointer) -> Self {
+        let segments = pointer.tokens().iter().cloned().map(Segment::Literal);
+        PathPattern(segments.collect())
+    }
+}
+
+fn push_segment(segments: &mut Vec<Segment>, current: &mut String) {
+    if current.is_empty() {
+        return;
+    }
+    segments.push(match current.as_str() {
+        "*" => Segment::Any,
+        "**" => Segment::AnyDepth,
+        _ => Segment::Literal(current.clone()),
+    });
+    current.clear();
+}
+
+fn matches_from(segments: &[Segment], tokens: &[String]) -> bool {
+    match segments.split_first() {
+        None => tokens.is_empty(),
+        Some((Segment::AnyDepth, rest)) => {
+            (0..=tokens.len()).any(|skipped| matches_from(rest, &tokens[skipped..]))
+        }
+        Some((segment, rest)) => match tokens.split_first() {
+            Some((token, tokens)) => {
+                let matched = match segment {
+                    Segment::Literal(literal) => literal == token,
+                    _ => true,
+                };
+                matched && matches_from(rest, tokens)
+            }
+            None => false,
+        },
+    }
+}
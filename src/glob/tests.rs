@@ -0,0 +1,49 @@
+use super::PathPattern;
+
+fn matches(pattern: &str, path: &[&str]) -> bool {
+    let tokens: Vec<String> = path.iter().map(|t| t.to_string()).collect();
+    PathPattern::parse(pattern).unwrap().matches(&tokens)
+}
+
+#[test]
+fn literal() {
+    assert!(matches("/a/b", &["a", "b"]));
+    assert!(matches("a.b", &["a", "b"]));
+    assert!(matches("users[0].ssn", &["users", "0", "ssn"]));
+    assert!(!matches("/a/b", &["a"]));
+    assert!(!matches("/a", &["a", "b"]));
+}
+
+#[test]
+fn wildcards() {
+    assert!(matches("users[*].ssn", &["users", "7", "ssn"]));
+    assert!(!matches("/*/b", &["b"]));
+    assert!(matches("**/password", &["password"]));
+    assert!(matches("**/password", &["a", "0", "password"]));
+    assert!(!matches("**/password", &["password", "hash"]));
+    assert!(matches("/a/**", &["a"]));
+    assert!(matches("**", &[]));
+}
+
+#[test]
+fn unclosed_bracket() {
+    assert_eq!(
+        PathPattern::parse("users[0"),
+        Err("unclosed [ in pattern users[0".to_string())
+    );
+}
+
+#[test]
+fn from_pointer() {
+    let pattern = PathPattern::from_pointer("/headers/x.request-id").unwrap();
+    assert!(pattern.matches(&["headers".to_string(), "x.request-id".to_string()]));
+    let pattern = PathPattern::from_pointer("/a~1b/*/~0c").unwrap();
+    assert!(pattern.matches(&["a/b".to_string(), "3".to_string(), "~c".to_string()]));
+    assert!(!pattern.matches(&[
+        "a".to_string(),
+        "b".to_string(),
+        "3".to_string(),
+        "~c".to_string()
+    ]));
+    assert!(PathPattern::from_pointer("/a~2").is_err());
+}
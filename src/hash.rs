@@ -0,0 +1,126 @@
+#[cfg(test)]
+mod tests;
+
+use crate::{JsonObject, Value};
+use std::hash::{Hash, Hasher};
+
+/// Hashing agrees with equality: `-0.0` and `0.0` hash alike, as do all NaNs.
+impl Hash for Value {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.rank().hash(state);
+        match self {
+            Value::Null => {}
+            Value::Bool(value) => value.hash(state),
+            Value::Number(value) => number_bits(*value).hash(state),
+            Value::String(value) => value.hash(state),
+            Value::Array(values) => values.hash(state),
+            Value::Object(object) => object.hash(state),
+        }
+    }
+}
+
+fn number_bits(value: f64) -> u64 {
+    if value == 0.0 {
+        0
+    } else if value.is_nan() {
+        f64::NAN.to_bits()
+    } else {
+        value.to_bits()
+    }
+}
+
+/// Entries are fed to the hasher in key order, so the result does not depend
+/// on iteration order.
+impl Hash for JsonObject {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        let mut entries: Vec<_> = self.iter().collect();
+        entries.sort_by_key(|(key, _)| *key);
+        self.len().hash(state);
+        for entry in entries {
+            entry.hash(state);
+        }
+    }
+}
+
+#[cfg(feature = "digest")]
+impl Value {
+    /// Digests the RFC 8785 canonical serialization, so equal documents hash
+    /// alike regardless of key order or number formatting.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    ///# fn main() -> Result<(),String> {
+    ///use sha2::Sha256;
+    ///
+    ///let a = json::parse(r#"{ "id": 1, "name": "John" }"#)?;
+    ///let b = json::parse(r#"{ "name": "John", "id": 1.0 }"#)?;
+    ///
+    ///assert_eq!(a.canonical_hash::<Sha256>(), b.canonical_hash::<Sha256>());
+    ///# Ok(())
+    ///# }
+    /// ```
+    pub fn canonical_hash<D: digest::Digest>(&self) -> digest::Output<D> {
+        D::digest(crate::to_string_canonical(self).as_bytes())
+    }
+}
+
+const FNV_OFFSET: u64 = 0xcbf2_9ce4_8422_2325;
+const FNV_PRIME: u64 = 0x0000_0100_0000_01b3;
+
+fn fnv(mut hash: u64, bytes: &[u8]) -> u64 {
+    for byte in bytes {
+        hash ^= u64::from(*byte);
+        hash = hash.wrapping_mul(FNV_PRIME);
+    }
+    hash
+}
+
+/// Spreads the bits of entry hashes before they are summed.
+fn mix(mut hash: u64) -> u64 {
+    hash ^= hash >> 33;
+    hash = hash.wrapping_mul(0xff51_afd7_ed55_8ccd);
+    hash ^= hash >> 33;
+    hash
+}
+
+fn structural_hash_of(value: &Value) -> u64 {
+    let hash = fnv(FNV_OFFSET, &[value.rank()]);
+    match value {
+        Value::Null => hash,
+        Value::Bool(value) => fnv(hash, &[*value as u8]),
+        Value::Number(value) => fnv(hash, &number_bits(*value).to_le_bytes()),
+        Value::String(value) => fnv(hash, value.as_bytes()),
+        Value::Array(values) => values.iter().fold(hash, |hash, value| {
+            fnv(hash, &structural_hash_of(value).to_le_bytes())
+        }),
+        Value::Object(object) => {
+            let entries = object.iter().fold(0u64, |sum, (key, value)| {
+                let entry = fnv(fnv(FNV_OFFSET, key.as_bytes()), &[0xff]);
+                sum.wrapping_add(mix(fnv(entry, &structural_hash_of(value).to_le_bytes())))
+            });
+            fnv(hash, &entries.to_le_bytes())
+        }
+    }
+}
+
+impl Value {
+    /// A fast, non-cryptographic 64-bit hash that is stable across runs and
+    /// platforms and ignores the order of object entries. Equal values always
+    /// hash alike; unequal values may collide.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    ///# fn main() -> Result<(),String> {
+    ///let a = json::parse(r#"{ "level": "warn", "msg": "disk full" }"#)?;
+    ///let b = json::parse(r#"{ "msg": "disk full", "level": "warn" }"#)?;
+    ///
+    ///assert_eq!(a.structural_hash(), b.structural_hash());
+    ///# Ok(())
+    ///# }
+    /// ```
+    pub fn structural_hash(&self) -> u64 {
+        structural_hash_of(self)
+    }
+}
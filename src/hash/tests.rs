@@ -0,0 +1,81 @@
+use crate::{parse, Value};
+use std::collections::HashSet;
+use std::hash::{Hash, Hasher};
+
+#[test]
+fn dedup_records() {
+    let records: HashSet<Value> = [
+        r#"{ "id": 1, "tags": ["a", "b"] }"#,
+        r#"{ "tags": ["a", "b"], "id": 1.0 }"#,
+        r#"{ "id": 1, "tags": ["b", "a"] }"#,
+        r#"{ "id": 2 }"#,
+    ]
+    .iter()
+    .map(|raw| parse(*raw).unwrap())
+    .collect();
+    assert_eq!(records.len(), 3);
+}
+
+#[test]
+fn consistent_with_eq() {
+    let set: HashSet<Value> = vec![
+        Value::Number(0.0),
+        Value::Number(-0.0),
+        Value::Number(f64::NAN),
+        Value::Number(-f64::NAN),
+    ]
+    .into_iter()
+    .collect();
+    assert_eq!(set.len(), 2);
+}
+
+/// Records everything written to it.
+#[derive(Default)]
+struct Recorder(Vec<u8>);
+
+impl Hasher for Recorder {
+    fn finish(&self) -> u64 {
+        0
+    }
+
+    fn write(&mut self, bytes: &[u8]) {
+        self.0.extend_from_slice(bytes);
+    }
+}
+
+#[test]
+fn objects_feed_the_given_hasher() {
+    let written = |raw: &str| {
+        let mut recorder = Recorder::default();
+        parse(raw).unwrap().hash(&mut recorder);
+        recorder.0
+    };
+    let bytes = written(r#"{ "a": 1, "bc": 2 }"#);
+    assert_eq!(bytes, written(r#"{ "bc": 2, "a": 1 }"#));
+    assert!(bytes.windows(2).any(|window| window == b"bc"));
+}
+
+#[cfg(feature = "digest")]
+#[test]
+fn canonical_hash() {
+    use sha2::{Digest, Sha256};
+
+    let value = parse(r#"{ "b": [1e2, "é"], "a": null }"#).unwrap();
+    assert_eq!(
+        value.canonical_hash::<Sha256>(),
+        Sha256::digest(r#"{"a":null,"b":[100,"é"]}"#.as_bytes())
+    );
+}
+
+#[test]
+fn structural_hash() {
+    let hash = |raw: &str| parse(raw).unwrap().structural_hash();
+    assert_eq!(
+        hash(r#"{ "a": [1, 2], "b": null }"#),
+        hash(r#"{ "b": null, "a": [1, 2] }"#)
+    );
+    assert_ne!(hash("[1, 2]"), hash("[2, 1]"));
+    assert_ne!(hash(r#"{ "a": 1, "b": 2 }"#), hash(r#"{ "a": 2, "b": 1 }"#));
+    assert_ne!(hash(r#""1""#), hash("1"));
+    assert_eq!(hash("-0"), hash("0"));
+}
@@ -0,0 +1,130 @@
+#[cfg(test)]
+mod tests;
+
+use crate::coerce::is_number_literal;
+use std::ops::Range;
+
+/// What a token of JSON text is, for coloring it.
+#[derive(Debug, PartialEq, Eq, Clone, Copy, Hash)]
+pub enum TokenClass {
+    /// A string used as an object key.
+    Key,
+    String,
+    Number,
+    /// `true`, `false` or `null`.
+    Literal,
+    /// One of `{ } [ ] , :`.
+    Punctuation,
+    /// A `// line` or `/* block */` comment.
+    Comment,
+    /// Text that can't start a valid token, or an unterminated one.
+    Error,
+}
+
+/// Splits `input` into classified tokens, given with their byte range.
+/// Whitespace is left out and nothing is ever rejected: anything that isn't
+/// a token is reported as [`TokenClass::Error`], so that text being edited
+/// can still be colored.
+///
+/// # Examples
+///
+/// ```
+///use json::TokenClass;
+///
+///let input = r#"{ "a": [1, tru] } // done"#;
+///let tokens: Vec<(&str, TokenClass)> = json::highlight(input)
+///    .into_iter()
+///    .map(|(span, class)| (&input[span], class))
+///    .collect();
+///
+///assert_eq!(
+///    tokens,
+///    vec![
+///        ("{", TokenClass::Punctuation),
+///        (r#""a""#, TokenClass::Key),
+///        (":", TokenClass::Punctuation),
+///        ("[", TokenClass::Punctuation),
+///        ("1", TokenClass::Number),
+///        (",", TokenClass::Punctuation),
+///        ("tru", TokenClass::Error),
+///        ("]", TokenClass::Punctuation),
+///        ("}", TokenClass::Punctuation),
+///        ("// done", TokenClass::Comment),
+///    ]
+///);
+/// ```
+pub fn highlight(input: &str) -> Vec<(Range<usize>, TokenClass)> {
+    let mut tokens = Vec::new();
+    let mut position = 0;
+    while let Some(c) = input[position..].chars().next() {
+        let rest = &input[position..];
+        let (length, class) = match c {
+            c if c.is_whitespace() => {
+                position += c.len_utf8();
+                continue;
+            }
+            '{' | '}' | '[' | ']' | ',' | ':' => (1, TokenClass::Punctuation),
+            '"' => string(rest),
+            '/' if rest.starts_with("//") => {
+                (rest.find('\n').unwrap_or(rest.len()), TokenClass::Comment)
+            }
+            '/' if rest.starts_with("/*") => match rest[2..].find("*/") {
+                Some(end) => (end + 4, TokenClass::Comment),
+                None => (rest.len(), TokenClass::Error),
+            },
+            c if c == '-' || c == '+' || c == '.' || c.is_ascii_digit() => {
+                let length = rest
+                    .find(|c: char| !(c.is_ascii_alphanumeric() || "+-.".contains(c)))
+                    .unwrap_or(rest.len());
+                let number = rest[..length].strip_prefix('+').unwrap_or(&rest[..length]);
+                if is_number_literal(number) {
+                    (length, TokenClass::Number)
+                } else {
+                    (length, TokenClass::Error)
+                }
+            }
+            c if c.is_alphanumeric() || c == '_' => {
+                let length = rest
+                    .find(|c: char| !(c.is_alphanumeric() || c == '_'))
+                    .unwrap_or(rest.len());
+                match &rest[..length] {
+                    "true" | "false" | "null" => (length, TokenClass::Literal),
+                    _ => (length, TokenClass::Error),
+                }
+            }
+            c => (c.len_utf8(), TokenClass::Error),
+        };
+        tokens.push((position..position + length, class));
+        position += length;
+    }
+    mark_keys(input, &mut tokens);
+    tokens
+}
+
+/// The length of the string `rest` starts with, quotes included.
+fn string(rest: &str) -> (usize, TokenClass) {
+    let mut escaped = false;
+    for (i, c) in rest.char_indices().skip(1) {
+        match c {
+            _ if escaped => escaped = false,
+            '\\' => escaped = true,
+            '"' => return (i + 1, TokenClass::String),
+            '\n' => return (i, TokenClass::Error),
+            _ => {}
+        }
+    }
+    (rest.len(), TokenClass::Error)
+}
+
+/// Reclassifies the strings followed by a colon, comments aside, as keys.
+fn mark_keys(input: &str, tokens: &mut [(Range<usize>, TokenClass)]) {
+    let mut next = None;
+    for (span, class) in tokens.iter_mut().rev() {
+        match class {
+            TokenClass::Comment => continue,
+            TokenClass::String if next == Some(":") => *class = TokenClass::Key,
+            _ => {}
+        }
+        next = Some(&input[span.clone()]);
+    }
+}
@@ -0,0 +1,47 @@
+use super::{highlight, TokenClass};
+
+fn classes(input: &str) -> Vec<(&str, TokenClass)> {
+    highlight(input)
+        .into_iter()
+        .map(|(span, class)| (&input[span], class))
+        .collect()
+}
+
+#[test]
+fn tokens() {
+    use TokenClass::*;
+    assert_eq!(
+        classes("{\"k\\\"\" /* c */ : -1.5e3, \"v\": \"é\"}"),
+        vec![
+            ("{", Punctuation),
+            ("\"k\\\"\"", Key),
+            ("/* c */", Comment),
+            (":", Punctuation),
+            ("-1.5e3", Number),
+            (",", Punctuation),
+            ("\"v\"", Key),
+            (":", Punctuation),
+            ("\"é\"", String),
+            ("}", Punctuation),
+        ]
+    );
+    assert_eq!(classes("[01, +2, nul]")[1], ("01", Error));
+    assert_eq!(classes("[01, +2, nul]")[3], ("+2", Number));
+    assert_eq!(classes("[01, +2, nul]")[5], ("nul", Error));
+}
+
+#[test]
+fn unterminated() {
+    use TokenClass::*;
+    assert_eq!(
+        classes("[\"ab\n1 /* x"),
+        vec![
+            ("[", Punctuation),
+            ("\"ab", Error),
+            ("1", Number),
+            ("/* x", Error)
+        ]
+    );
+    assert_eq!(classes("@ 'a'")[0], ("@", Error));
+    assert!(highlight("  \n ").is_empty());
+}
@@ -0,0 +1,404 @@
+//! JSON-RPC 2.0 messages, read from and written to [`Value`]s with
+//! [`FromJson`] and [`ToJson`].
+
+#[cfg(test)]
+mod tests;
+
+use crate::convert::{at, mismatch};
+use crate::{FromJson, JsonObject, ToJson, Value};
+use std::collections::HashMap;
+use std::fmt;
+
+/// The id pairing a response with its request. `Null` only appears in
+/// error responses to requests whose id could not be read.
+#[derive(Debug, PartialEq, Eq, Clone, Hash)]
+pub enum Id {
+    Number(i64),
+    String(String),
+    Null,
+}
+
+impl ToJson for Id {
+    fn to_json(&self) -> Value {
+        match self {
+            Id::Number(id) => Value::Number(*id as f64),
+            Id::String(id) => Value::String(id.clone()),
+            Id::Null => Value::Null,
+        }
+    }
+}
+
+impl FromJson for Id {
+    fn from_json(value: &Value) -> Result<Self, String> {
+        match value {
+            Value::Number(id) if id.fract() == 0.0 && id.abs() < i64::MAX as f64 => {
+                Ok(Id::Number(*id as i64))
+            }
+            Value::String(id) => Ok(Id::String(id.clone())),
+            Value::Null => Ok(Id::Null),
+            value => Err(mismatch("integer or string", value)),
+        }
+    }
+}
+
+/// A call expecting a [`Response`].
+#[derive(Debug, PartialEq, Clone)]
+pub struct Request {
+    pub id: Id,
+    pub method: String,
+    /// An array or object, if any.
+    pub params: Option<Value>,
+}
+
+impl Request {
+    /// The response answering this request with `result`.
+    pub fn reply(&self, result: Result<Value, Error>) -> Response {
+        Response {
+            id: self.id.clone(),
+            result,
+        }
+    }
+}
+
+/// A call without an id, which gets no response.
+#[derive(Debug, PartialEq, Clone)]
+pub struct Notification {
+    pub method: String,
+    pub params: Option<Value>,
+}
+
+/// The error object of a failed call.
+#[derive(Debug, PartialEq, Clone)]
+pub struct Error {
+    pub code: i64,
+    pub message: String,
+    pub data: Option<Value>,
+}
+
+impl Error {
+    pub const PARSE_ERROR: i64 = -32700;
+    pub const INVALID_REQUEST: i64 = -32600;
+    pub const METHOD_NOT_FOUND: i64 = -32601;
+    pub const INVALID_PARAMS: i64 = -32602;
+    pub const INTERNAL_ERROR: i64 = -32603;
+
+    pub fn new(code: i64, message: &str) -> Self {
+        Error {
+            code,
+            message: message.to_string(),
+            data: None,
+        }
+    }
+
+    pub fn method_not_found(method: &str) -> Self {
+        Error::new(
+            Error::METHOD_NOT_FOUND,
+            &format!("method not found: {}", method),
+        )
+    }
+
+    pub fn invalid_params(message: &str) -> Self {
+        Error::new(Error::INVALID_PARAMS, message)
+    }
+}
+
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{} ({})", self.message, self.code)
+    }
+}
+
+/// The outcome of a [`Request`], with either a result or an error.
+#[derive(Debug, PartialEq, Clone)]
+pub struct Response {
+    pub id: Id,
+    pub result: Result<Value, Error>,
+}
+
+/// Any message one side of a connection may send.
+#[derive(Debug, PartialEq, Clone)]
+pub enum Message {
+    Request(Request),
+    Notification(Notification),
+    Response(Response),
+}
+
+fn envelope() -> JsonObject {
+    let mut object = JsonObject::new();
+    object.insert("jsonrpc".to_string(), Value::String("2.0".to_string()));
+    object
+}
+
+fn insert_call(object: &mut JsonObject, method: &str, params: &Option<Value>) {
+    object.insert("method".to_string(), Value::String(method.to_string()));
+    if let Some(params) = params {
+        object.insert("params".to_string(), params.clone());
+    }
+}
+
+impl ToJson for Request {
+    fn to_json(&self) -> Value {
+        let mut object = envelope();
+        insert_call(&mut object, &self.method, &self.params);
+        object.insert("id".to_string(), self.id.to_json());
+        Value::Object(object)
+    }
+}
+
+impl ToJson for Notification {
+    fn to_json(&self) -> Value {
+        let mut object = envelope();
+        insert_call(&mut object, &self.method, &self.params);
+        Value::Object(object)
+    }
+}
+
+impl ToJson for Error {
+    fn to_json(&self) -> Value {
+        let mut object = JsonObject::new();
+        object.insert("code".to_string(), Value::Number(self.code as f64));
+        object.insert("message".to_string(), Value::String(self.message.clone()));
+        if let Some(data) = &self.data {
+            object.insert("data".to_string(), data.clone());
+        }
+        Value::Object(object)
+    }
+}
+
+impl ToJson for Response {
+    fn to_json(&self) -> Value {
+        let mut object = envelope();
+        match &self.result {
+            Ok(result) => object.insert("result".to_string(), result.clone()),
+            Err(error) => object.insert("error".to_string(), error.to_json()),
+        };
+        object.insert("id".to_string(), self.id.to_json());
+        Value::Object(object)
+    }
+}
+
+impl ToJson for Message {
+    fn to_json(&self) -> Value {
+        match self {
+            Message::Request(request) => request.to_json(),
+            Message::Notification(notification) => notification.to_json(),
+            Message::Response(response) => response.to_json(),
+        }
+    }
+}
+
+/// The members of a message, once its `"jsonrpc"` member is checked.
+fn members(value: &Value) -> Result<&JsonObject, String> {
+    let object = match value {
+        Value::Object(object) => object,
+        value => return Err(mismatch("object", value)),
+    };
+    match object.get("jsonrpc") {
+        Some(Value::String(version)) if version == "2.0" => Ok(object),
+        Some(_) => Err(at("jsonrpc", "expected \"2.0\"".to_string())),
+        None => Err(at("jsonrpc", "missing".to_string())),
+    }
+}
+
+fn call(object: &JsonObject) -> Result<(String, Option<Value>), String> {
+    let method = match object.get("method") {
+        Some(Value::String(method)) => method.clone(),
+        Some(value) => return Err(at("method", mismatch("string", value))),
+        None => return Err(at("method", "missing".to_string())),
+    };
+    let params = match object.get("params") {
+        Some(params @ Value::Array(_)) | Some(params @ Value::Object(_)) => Some(params.clone()),
+        Some(value) => return Err(at("params", mismatch("array or object", value))),
+        None => None,
+    };
+    Ok((method, params))
+}
+
+fn id(object: &JsonObject) -> Result<Id, String> {
+    match object.get("id") {
+        Some(id) => Id::from_json(id).map_err(|e| at("id", e)),
+        None => Err(at("id", "missing".to_string())),
+    }
+}
+
+impl FromJson for Request {
+    fn from_json(value: &Value) -> Result<Self, String> {
+        let object = members(value)?;
+        let (method, params) = call(object)?;
+        Ok(Request {
+            id: id(object)?,
+            method,
+            params,
+        })
+    }
+}
+
+impl FromJson for Notification {
+    fn from_json(value: &Value) -> Result<Self, String> {
+        let object = members(value)?;
+        if object.contains_key("id") {
+            return Err(at("id", "not allowed".to_string()));
+        }
+        let (method, params) = call(object)?;
+        Ok(Notification { method, params })
+    }
+}
+
+impl FromJson for Error {
+    fn from_json(value: &Value) -> Result<Self, String> {
+        let object = match value {
+            Value::Object(object) => object,
+            value => return Err(mismatch("object", value)),
+        };
+        let code = match object.get("code") {
+            Some(Value::Number(code)) if code.fract() == 0.0 => *code as i64,
+            Some(value) => return Err(at("code", mismatch("integer", value))),
+            None => return Err(at("code", "missing".to_string())),
+        };
+        let message = match object.get("message") {
+            Some(Value::String(message)) => message.clone(),
+            Some(value) => return Err(at("message", mismatch("string", value))),
+            None => return Err(at("message", "missing".to_string())),
+        };
+        Ok(Error {
+            code,
+            message,
+            data: object.get("data").cloned(),
+        })
+    }
+}
+
+impl FromJson for Response {
+    fn from_json(value: &Value) -> Result<Self, String> {
+        let object = members(value)?;
+        let result = match (object.get("result"), object.get("error")) {
+            (Some(result), None) => Ok(result.clone()),
+            (None, Some(error)) => Err(Error::from_json(error).map_err(|e| at("error", e))?),
+            (Some(_), Some(_)) => return Err("expected result or error, found both".to_string()),
+            (None, None) => return Err("expected result or error, found neither".to_string()),
+        };
+        Ok(Response {
+            id: id(object)?,
+            result,
+        })
+    }
+}
+
+impl FromJson for Message {
+    fn from_json(value: &Value) -> Result<Self, String> {
+        let object = members(value)?;
+        if !object.contains_key("method") {
+            Response::from_json(value).map(Message::Response)
+        } else if object.contains_key("id") {
+            Request::from_json(value).map(Message::Request)
+        } else {
+            Notification::from_json(value).map(Message::Notification)
+        }
+    }
+}
+
+/// Reads a message, or every message of a batch, pairing each element that
+/// isn't a valid message with the Invalid Request response a server should
+/// send back for it. An empty batch is itself invalid.
+///
+/// # Examples
+///
+/// ```
+///# fn main() -> Result<(),String> {
+///use json::jsonrpc::{read_batch, Error, Message};
+///
+///let batch = json::parse(r#"[
+///    { "jsonrpc": "2.0", "method": "sum", "params": [1, 2], "id": "1" },
+///    { "jsonrpc": "2.0", "method": "log" },
+///    { "foo": "boo" }
+///]"#)?;
+///let messages = read_batch(&batch).unwrap();
+///
+///assert!(matches!(&messages[0], Ok(Message::Request(request)) if request.method == "sum"));
+///assert!(matches!(&messages[1], Ok(Message::Notification(_))));
+///assert_eq!(messages[2].as_ref().unwrap_err().result.as_ref().unwrap_err().code, Error::INVALID_REQUEST);
+///# Ok(())
+///# }
+/// ```
+pub fn read_batch(value: &Value) -> Result<Vec<Result<Message, Response>>, Response> {
+    let invalid = |value: &Value, message: String| Response {
+        id: match value {
+            Value::Object(object) => object
+                .get("id")
+                .and_then(|id| Id::from_json(id).ok())
+                .unwrap_or(Id::Null),
+            _ => Id::Null,
+        },
+        result: Err(Error::new(Error::INVALID_REQUEST, &message)),
+    };
+    let read = |value: &Value| Message::from_json(value).map_err(|e| invalid(value, e));
+    match value {
+        Value::Array(values) if values.is_empty() => {
+            Err(invalid(value, "expected at least one message".to_string()))
+        }
+        Value::Array(values) => Ok(values.iter().map(read).collect()),
+        value => Ok(vec![read(value)]),
+    }
+}
+
+/// The requests a client sent and awaits a response for, numbered from 1.
+///
+/// # Examples
+///
+/// ```
+///# fn main() -> Result<(),String> {
+///use json::jsonrpc::{Pending, Response};
+///use json::FromJson;
+///
+///let mut pending = Pending::new();
+///let request = pending.request("initialize", None);
+///assert_eq!(json::to_string(&json::ToJson::to_json(&request)), r#"{"jsonrpc":"2.0","method":"initialize","id":1}"#);
+///
+///let response = Response::from_json(&json::parse(r#"{ "jsonrpc": "2.0", "result": {}, "id": 1 }"#)?)?;
+///assert_eq!(pending.resolve(&response)?, "initialize");
+///assert!(pending.is_empty());
+///assert_eq!(pending.resolve(&response), Err("no pending request with id 1".to_string()));
+///# Ok(())
+///# }
+/// ```
+#[derive(Debug, Clone, Default)]
+pub struct Pending {
+    next: i64,
+    methods: HashMap<Id, String>,
+}
+
+impl Pending {
+    pub fn new() -> Self {
+        Pending::default()
+    }
+
+    /// Builds a request with the next id and remembers it.
+    pub fn request(&mut self, method: &str, params: Option<Value>) -> Request {
+        self.next += 1;
+        let id = Id::Number(self.next);
+        self.methods.insert(id.clone(), method.to_string());
+        Request {
+            id,
+            method: method.to_string(),
+            params,
+        }
+    }
+
+    /// Forgets the request `response` answers and returns its method.
+    pub fn resolve(&mut self, response: &Response) -> Result<String, String> {
+        self.methods.remove(&response.id).ok_or_else(|| {
+            format!(
+                "no pending request with id {}",
+                crate::to_string(&response.id.to_json())
+            )
+        })
+    }
+
+    pub fn len(&self) -> usize {
+        self.methods.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.methods.is_empty()
+    }
+}
@@ -0,0 +1,64 @@
+use super::{read_batch, Error, Id, Message, Notification, Request, Response};
+use crate::{parse, FromJson, ToJson};
+
+#[test]
+fn round_trip() {
+    let messages = [
+        Message::Request(Request {
+            id: Id::String("a".to_string()),
+            method: "sum".to_string(),
+            params: Some(parse("[1, 2]").unwrap()),
+        }),
+        Message::Notification(Notification {
+            method: "exit".to_string(),
+            params: None,
+        }),
+        Message::Response(Response {
+            id: Id::Number(3),
+            result: Ok(parse("null").unwrap()),
+        }),
+        Message::Response(Response {
+            id: Id::Null,
+            result: Err(Error {
+                data: Some(parse(r#"{ "line": 1 }"#).unwrap()),
+                ..Error::new(Error::PARSE_ERROR, "parse error")
+            }),
+        }),
+    ];
+    for message in &messages {
+        assert_eq!(Message::from_json(&message.to_json()).as_ref(), Ok(message));
+    }
+    let request = Request::from_json(&messages[0].to_json()).unwrap();
+    assert_eq!(
+        request.reply(Err(Error::method_not_found("sum"))).to_json(),
+        parse(
+            r#"{ "jsonrpc": "2.0", "error": { "code": -32601, "message": "method not found: sum" }, "id": "a" }"#
+        )
+        .unwrap()
+    );
+}
+
+#[test]
+fn errors() {
+    let read = |json: &str| Message::from_json(&parse(json).unwrap());
+    assert_eq!(
+        read(r#"{ "jsonrpc": "1.0", "method": "a" }"#),
+        Err("/jsonrpc: expected \"2.0\"".to_string())
+    );
+    assert_eq!(
+        read(r#"{ "jsonrpc": "2.0", "method": "a", "params": 1, "id": 1 }"#),
+        Err("/params: expected array or object, found number".to_string())
+    );
+    assert_eq!(
+        read(r#"{ "jsonrpc": "2.0", "result": 1, "error": {}, "id": 1 }"#),
+        Err("expected result or error, found both".to_string())
+    );
+    assert_eq!(
+        read(r#"{ "jsonrpc": "2.0", "method": "a", "id": 1.5 }"#),
+        Err("/id: expected integer or string, found number".to_string())
+    );
+    let empty = read_batch(&parse("[]").unwrap()).unwrap_err();
+    assert_eq!(empty.id, Id::Null);
+    let single = read_batch(&parse(r#"{ "jsonrpc": "2.0", "id": 7 }"#).unwrap()).unwrap();
+    assert_eq!(single[0].as_ref().unwrap_err().id, Id::Number(7));
+}
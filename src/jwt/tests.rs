@@ -0,0 +1,53 @@
+use super::Jwt;
+use crate::parse;
+use base64::engine::general_purpose::URL_SAFE_NO_PAD;
+use base64::Engine;
+
+fn token(header: &str, payload: &str) -> String {
+    format!(
+        "{}.{}.",
+        URL_SAFE_NO_PAD.encode(header),
+        URL_SAFE_NO_PAD.encode(payload)
+    )
+}
+
+#[test]
+fn claims() {
+    let jwt = Jwt::decode(&token(
+        r#"{"alg":"none"}"#,
+        r#"{"sub":"a","iat":1000.5,"exp":2000}"#,
+    ))
+    .unwrap();
+    assert_eq!(jwt.header, parse(r#"{"alg":"none"}"#).unwrap());
+    assert_eq!(jwt.issued_at(), Ok(Some(1000)));
+    assert!(jwt.signature.is_empty());
+    assert_eq!(jwt.is_expired(1999), Ok(false));
+    assert_eq!(jwt.is_expired(2000), Ok(true));
+
+    let jwt = Jwt::decode(&token("{}", r#"{"sub":1,"exp":"soon"}"#)).unwrap();
+    assert_eq!(
+        jwt.subject(),
+        Err("/sub: expected string, found number".to_string())
+    );
+    assert_eq!(
+        jwt.is_expired(0),
+        Err("/exp: expected number, found string".to_string())
+    );
+    assert_eq!(jwt.algorithm(), Ok(None));
+}
+
+#[test]
+fn malformed() {
+    assert_eq!(
+        Jwt::decode("a.b"),
+        Err("expected 3 segments, found 2".to_string())
+    );
+    assert_eq!(
+        Jwt::decode(&token("{}", "[]")),
+        Err("payload: expected object, found array".to_string())
+    );
+    assert!(Jwt::decode("e30.e30.*")
+        .unwrap_err()
+        .starts_with("signature: invalid base64url"));
+    assert_eq!(Jwt::decode("e30=.e30.").map(|jwt| jwt.payload), parse("{}"));
+}
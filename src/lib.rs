@@ -1,77 +1,415 @@
 #[cfg(test)]
 mod tests;
 
+#[cfg(feature = "arbitrary")]
+mod arbitrary;
+#[cfg(feature = "arrow")]
+mod arrow_impl;
+#[cfg(feature = "base64")]
+mod base64_impl;
+#[cfg(feature = "bson")]
+mod bson;
+#[cfg(feature = "cbor")]
+mod cbor;
+pub mod codegen;
+mod coerce;
+mod color;
+mod columns;
+mod combinators;
+pub mod config;
+mod convert;
+#[cfg(feature = "csv")]
+mod csv;
+#[cfg(any(feature = "chrono", feature = "time"))]
+mod datetime;
+#[cfg(feature = "decimal")]
+mod decimal;
+mod detect;
+pub mod diff;
+pub mod eq;
+pub mod extract;
+mod flatten;
+mod formats;
+pub mod frozen;
+pub mod generate;
+mod glob;
+mod hash;
+mod highlight;
+pub mod jsonrpc;
+#[cfg(feature = "base64")]
+pub mod jwt;
+pub mod merge;
+#[cfg(feature = "msgpack")]
+mod msgpack;
+mod number;
+mod ord;
+pub mod patch;
+mod pointer;
+mod presence;
+mod prune;
+mod query;
+#[cfg(feature = "rayon")]
+mod rayon_impl;
+mod redact;
+pub mod reference;
+mod rename;
+pub mod rules;
+pub mod schema;
+mod ser;
+#[cfg(feature = "serde")]
+mod serde_de;
+#[cfg(feature = "serde")]
+mod serde_impl;
+#[cfg(feature = "serde_json")]
+mod serde_json_impl;
+#[cfg(feature = "serde")]
+mod serde_ser;
+pub mod shape;
+pub mod shared;
+mod sort;
+mod stats;
+#[cfg(feature = "proptest")]
+pub mod strategy;
+mod tagged;
+pub mod testing;
+#[cfg(feature = "toml")]
+mod toml_impl;
+mod typed;
+#[cfg(feature = "uuid")]
+mod uuid_impl;
+mod walk;
+#[cfg(feature = "xml")]
+mod xml;
+#[cfg(feature = "yaml")]
+mod yaml;
+
+#[cfg(feature = "bson")]
+pub use bson::{from_bson, to_bson};
+#[cfg(feature = "cbor")]
+pub use cbor::{from_cbor, to_cbor};
+pub use coerce::CoercionRules;
+pub use color::Theme;
+pub use columns::{Column, Columns};
+#[cfg(feature = "csv")]
+pub use csv::{from_csv, to_csv, CsvOptions};
+pub use detect::{detect, ContentKind, Dialect};
+pub use glob::PathPattern;
+pub use highlight::{highlight, TokenClass};
+#[cfg(feature = "msgpack")]
+pub use msgpack::{from_msgpack, to_msgpack};
+pub use number::{normalize_number, ExponentPolicy};
+pub use pointer::{AsPointer, JsonPointer, SetOptions};
+pub use presence::Presence;
+pub use prune::PruneOptions;
+pub use query::{
+    from_query_string, from_query_string_with, to_query_string, to_query_string_with, ArrayFormat,
+    QueryOptions,
+};
+#[cfg(feature = "rayon")]
+pub use rayon_impl::{parse_all_parallel, parse_stream};
+pub use redact::Redaction;
+pub use rename::{CaseStyle, Renamed};
+pub use ser::{
+    escape_str, to_string, to_string_canonical, to_string_into, to_string_pretty, to_writer,
+    to_writer_with, KeyComparator, KeyOrder, Serializer,
+};
+#[cfg(feature = "serde")]
+pub use serde_de::from_value;
+#[cfg(feature = "serde")]
+pub use serde_impl::Error as SerdeError;
+#[cfg(feature = "serde")]
+pub use serde_ser::to_value;
+pub use sort::sort_keys_textual;
+pub use stats::JsonStats;
+pub use tagged::Decoder;
+#[cfg(feature = "toml")]
+pub use toml_impl::{from_toml_str, to_toml_string};
+pub use typed::{FromJson, ToJson};
+
+/// Derives [`ToJson`] and [`FromJson`]: structs with named fields map to
+/// objects, tuple structs to arrays (or to their only field), unit structs
+/// to `null`, and enums to `"Variant"` or `{ "Variant": content }`.
+#[cfg(feature = "derive")]
+pub use json_derive::{FromJson, ToJson};
+#[doc(hidden)]
+pub use typed::private as __private;
+
+// Lets the derived code, which names this crate `json`, compile inside it.
+extern crate self as json;
+pub use walk::{Visit, VisitorMut, Walk};
+#[cfg(feature = "xml")]
+pub use xml::{from_xml_str, to_xml_string, XmlOptions};
+#[cfg(feature = "yaml")]
+pub use yaml::{from_yaml_str, to_yaml_string};
+
 use core::f64;
-use std::{collections::HashMap, iter::Peekable, str::Chars};
+use std::{borrow::Cow, cmp::Ordering, collections::HashMap, fmt, iter::FromIterator, str::Chars};
 
+/// Scans the input by byte offset, so that tokens are sliced out of it
+/// rather than built up one character at a time.
 struct Reader<'a> {
-    chars: Peekable<Chars<'a>>,
+    raw: &'a str,
+    position: usize,
 }
 
 impl<'a> Reader<'a> {
     fn new(raw: &'a str) -> Self {
-        Self {
-            chars: raw.chars().peekable(),
-        }
+        Self { raw, position: 0 }
     }
 
-    fn peek(&mut self) -> Option<&char> {
-        self.chars.peek()
+    fn rest(&self) -> &'a str {
+        &self.raw[self.position..]
+    }
+
+    fn peek(&self) -> Option<char> {
+        self.rest().chars().next()
     }
 
     fn next(&mut self) -> Option<char> {
-        self.chars.next()
+        let c = self.peek()?;
+        self.position += c.len_utf8();
+        Some(c)
+    }
+
+    /// The line and column, both counted from 1, of the next character.
+    fn location(&self) -> (usize, usize) {
+        let read = &self.raw[..self.position];
+        let line_start = read.rfind('\n').map_or(0, |i| i + 1);
+        (
+            read.matches('\n').count() + 1,
+            read[line_start..].chars().count() + 1,
+        )
     }
 
     fn skip_whitespaces(&mut self) -> bool {
-        loop {
-            match self.peek() {
-                Some(c) if c.is_whitespace() => {
-                    self.next();
-                }
-                None => return false,
-                _ => return true,
-            }
-        }
+        let rest = self.rest();
+        let trimmed = rest.trim_start();
+        self.position += rest.len() - trimmed.len();
+        !trimmed.is_empty()
     }
 
-    fn read_until(&mut self, delimiters: &[char]) -> Option<(String, char)> {
+    fn read_until(&mut self, delimiters: &[char]) -> Option<(&'a str, char)> {
         let (value, matched) = self.read_until_or_end(delimiters);
         self.next();
         matched.map(|c| (value, c))
     }
 
-    fn read_until_or_end(&mut self, delimiters: &[char]) -> (String, Option<char>) {
-        let mut result = String::new();
-        while let Some(c) = self.peek() {
-            if delimiters.contains(c) {
-                return (result, Some(c.to_owned()));
+    fn read_until_or_end(&mut self, delimiters: &[char]) -> (&'a str, Option<char>) {
+        let rest = self.rest();
+        match rest.find(delimiters) {
+            Some(i) => {
+                self.position += i;
+                (&rest[..i], rest[i..].chars().next())
+            }
+            None => {
+                self.position = self.raw.len();
+                (rest, None)
             }
-            result.push(self.next().unwrap())
         }
-        (result, None)
     }
 
-    fn skip_until(&mut self, delimiters: &Vec<char>) -> Option<char> {
+    fn skip_until(&mut self, delimiters: &[char]) -> Option<char> {
         self.read_until(delimiters).map(|(_, c)| c)
     }
 
     fn read_token(&mut self, token: &str) -> bool {
         for c in token.chars() {
-            if self.next() != Some(c) {
+            if self.peek() != Some(c) {
                 return false;
             }
+            self.next();
         }
         true
     }
+
+    /// Reads the rest of a string whose opening quote was consumed, up to
+    /// and including its closing quote, returning the text within.
+    fn read_string(&mut self) -> Result<&'a str, String> {
+        let rest = self.rest();
+        let mut escaped = false;
+        for (i, byte) in rest.bytes().enumerate() {
+            if escaped {
+                escaped = false;
+            } else if byte == b'\\' {
+                escaped = true;
+            } else if byte == b'"' {
+                self.position += i + 1;
+                return Ok(&rest[..i]);
+            }
+        }
+        self.position = self.raw.len();
+        if escaped {
+            Err("no char to escape".to_string())
+        } else {
+            Err("invalid json string".to_string())
+        }
+    }
 }
 
-#[derive(Debug, PartialEq)]
-pub struct JsonObject(HashMap<String, Value>);
+/// Objects with more entries than this are indexed by a hash map, smaller
+/// ones are searched linearly.
+const INDEX_THRESHOLD: usize = 16;
+
+/// A JSON object. Entries keep their insertion order, which is also the
+/// order they are serialized in; equality ignores it.
+#[derive(Clone, Default)]
+pub struct JsonObject {
+    entries: Vec<(String, Value)>,
+    // Boxed so that the index only costs a pointer in every `Value`.
+    #[allow(clippy::box_collection)]
+    index: Option<Box<HashMap<String, usize>>>,
+}
 
 impl JsonObject {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    fn position(&self, key: &str) -> Option<usize> {
+        match &self.index {
+            Some(index) => index.get(key).copied(),
+            None => self.entries.iter().position(|(k, _)| k == key),
+        }
+    }
+
+    fn reindex(&mut self) {
+        self.index = if self.entries.len() > INDEX_THRESHOLD {
+            let index = self.entries.iter().enumerate();
+            Some(Box::new(index.map(|(i, (k, _))| (k.clone(), i)).collect()))
+        } else {
+            None
+        };
+    }
+
+    pub fn get(&self, key: &str) -> Option<&Value> {
+        self.position(key).map(|i| &self.entries[i].1)
+    }
+
+    pub fn get_mut(&mut self, key: &str) -> Option<&mut Value> {
+        let i = self.position(key)?;
+        Some(&mut self.entries[i].1)
+    }
+
+    pub fn contains_key(&self, key: &str) -> bool {
+        self.position(key).is_some()
+    }
+
+    /// Inserts a new key at the end, or replaces the value of an existing key
+    /// in place.
+    pub fn insert(&mut self, key: String, value: Value) -> Option<Value> {
+        if let Some(i) = self.position(&key) {
+            return Some(std::mem::replace(&mut self.entries[i].1, value));
+        }
+        match &mut self.index {
+            Some(index) => {
+                index.insert(key.clone(), self.entries.len());
+                self.entries.push((key, value));
+            }
+            None => {
+                self.entries.push((key, value));
+                if self.entries.len() > INDEX_THRESHOLD {
+                    self.reindex();
+                }
+            }
+        }
+        None
+    }
+
+    /// Removes a key, shifting the following entries to keep their order.
+    pub fn remove(&mut self, key: &str) -> Option<Value> {
+        let position = self.position(key)?;
+        let (_, value) = self.entries.remove(position);
+        if let Some(index) = &mut self.index {
+            index.remove(key);
+            for (key, _) in &self.entries[position..] {
+                *index.get_mut(key).unwrap() -= 1;
+            }
+        }
+        Some(value)
+    }
+
+    pub fn len(&self) -> usize {
+        self.entries.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.entries.is_empty()
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = (&String, &Value)> {
+        self.entries.iter().map(|(key, value)| (key, value))
+    }
+
+    pub fn iter_mut(&mut self) -> impl Iterator<Item = (&String, &mut Value)> {
+        self.entries.iter_mut().map(|(key, value)| (&*key, value))
+    }
+
+    pub fn keys(&self) -> impl Iterator<Item = &String> {
+        self.entries.iter().map(|(key, _)| key)
+    }
+
+    pub fn values(&self) -> impl Iterator<Item = &Value> {
+        self.entries.iter().map(|(_, value)| value)
+    }
+
+    /// Keeps only the entries for which `f` returns `true`, in their order,
+    /// letting `f` modify the values it sees.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    ///# fn main() -> Result<(),String> {
+    ///let mut object = json::parse(r#"{ "a": 1, "b": "2", "c": 3 }"#)?.to_object()?;
+    ///object.retain(|key, value| key != "a" && value.type_name() == "number");
+    ///
+    ///assert_eq!(json::Value::Object(object), json::parse(r#"{ "c": 3 }"#)?);
+    ///# Ok(())
+    ///# }
+    /// ```
+    pub fn retain<F: FnMut(&String, &mut Value) -> bool>(&mut self, mut f: F) {
+        let length = self.entries.len();
+        self.entries.retain_mut(|(key, value)| f(key, value));
+        if self.index.is_some() && self.entries.len() < length {
+            self.reindex();
+        }
+    }
+
+    /// Reorders the entries with `compare`, keeping equal keys in place.
+    pub fn sort_by<F: FnMut(&str, &str) -> Ordering>(&mut self, mut compare: F) {
+        self.entries.sort_by(|(a, _), (b, _)| compare(a, b));
+        if self.index.is_some() {
+            self.reindex();
+        }
+    }
+
+    /// Builds an object from entries in order. A repeated key keeps its
+    /// first position and its last value.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    ///# fn main() -> Result<(),String> {
+    ///use json::{JsonObject, Value};
+    ///
+    ///let pairs = vec![("b".to_string(), Value::Null), ("a".to_string(), Value::Bool(true))];
+    ///let object = JsonObject::from_pairs(pairs.clone());
+    ///
+    ///assert_eq!(json::to_string(&Value::Object(object.clone())), r#"{"b":null,"a":true}"#);
+    ///assert_eq!(object.into_pairs(), pairs);
+    ///# Ok(())
+    ///# }
+    /// ```
+    pub fn from_pairs(pairs: Vec<(String, Value)>) -> Self {
+        pairs.into_iter().collect()
+    }
+
+    /// The entries in order.
+    pub fn into_pairs(self) -> Vec<(String, Value)> {
+        self.entries
+    }
+
     pub fn take(&mut self, key: &str) -> Result<Value, String> {
-        match self.0.remove(key) {
+        match self.remove(key) {
             Some(value) => Ok(value),
             None => Err(format!("key {} not defined", key)),
         }
@@ -90,7 +428,61 @@ impl JsonObject {
     }
 }
 
-#[derive(Debug, PartialEq)]
+impl fmt::Debug for JsonObject {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_map().entries(self.iter()).finish()
+    }
+}
+
+impl PartialEq for JsonObject {
+    fn eq(&self, other: &Self) -> bool {
+        self.len() == other.len()
+            && self
+                .iter()
+                .all(|(key, value)| other.get(key) == Some(value))
+    }
+}
+
+impl IntoIterator for JsonObject {
+    type Item = (String, Value);
+    type IntoIter = std::vec::IntoIter<(String, Value)>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.entries.into_iter()
+    }
+}
+
+impl FromIterator<(String, Value)> for JsonObject {
+    fn from_iter<T: IntoIterator<Item = (String, Value)>>(iter: T) -> Self {
+        let mut object = JsonObject::new();
+        for (key, value) in iter {
+            object.insert(key, value);
+        }
+        object
+    }
+}
+
+impl From<Vec<(String, Value)>> for JsonObject {
+    fn from(pairs: Vec<(String, Value)>) -> Self {
+        JsonObject::from_pairs(pairs)
+    }
+}
+
+impl From<JsonObject> for Vec<(String, Value)> {
+    fn from(object: JsonObject) -> Self {
+        object.into_pairs()
+    }
+}
+
+impl From<HashMap<String, Value>> for JsonObject {
+    fn from(map: HashMap<String, Value>) -> Self {
+        map.into_iter().collect()
+    }
+}
+
+/// Values compare by the total order documented on their [`Ord`]
+/// implementation.
+#[derive(Debug, Clone)]
 pub enum Value {
     Null,
     Bool(bool),
@@ -101,6 +493,17 @@ pub enum Value {
 }
 
 impl Value {
+    pub fn type_name(&self) -> &'static str {
+        match self {
+            Value::Null => "null",
+            Value::Bool(_) => "bool",
+            Value::Number(_) => "number",
+            Value::String(_) => "string",
+            Value::Array(_) => "array",
+            Value::Object(_) => "object",
+        }
+    }
+
     pub fn to_object(self) -> Result<JsonObject, String> {
         if let Value::Object(object) = self {
             Ok(object)
@@ -111,7 +514,7 @@ impl Value {
 
     pub fn to_string(self) -> Result<String, String> {
         if let Value::String(value) = self {
-            return Ok(value);
+            Ok(value)
         } else {
             Err("not a json string".to_string())
         }
@@ -119,11 +522,80 @@ impl Value {
 
     pub fn to_number(self) -> Result<f64, String> {
         if let Value::Number(value) = self {
-            return Ok(value);
+            Ok(value)
         } else {
             Err("not a json number".to_string())
         }
     }
+
+    fn as_object_option(&self) -> Option<&JsonObject> {
+        match self {
+            Value::Object(object) => Some(object),
+            _ => None,
+        }
+    }
+
+    /// The keys of an object; nothing for any other value.
+    pub fn keys(&self) -> impl Iterator<Item = &String> {
+        self.as_object_option()
+            .into_iter()
+            .flat_map(JsonObject::keys)
+    }
+
+    /// The values of an object or the elements of an array; nothing for any
+    /// other value.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    ///# fn main() -> Result<(),String> {
+    ///let sum = |value: &json::Value| -> f64 {
+    ///    value.values().filter_map(|v| v.clone().to_number().ok()).sum()
+    ///};
+    ///
+    ///assert_eq!(sum(&json::parse("[1, 2]")?), 3.0);
+    ///assert_eq!(sum(&json::parse(r#"{ "a": 3, "b": 4 }"#)?), 7.0);
+    ///assert_eq!(sum(&json::parse("5")?), 0.0);
+    ///# Ok(())
+    ///# }
+    /// ```
+    pub fn values(&self) -> impl Iterator<Item = &Value> {
+        let elements = match self {
+            Value::Array(values) => values.as_slice(),
+            _ => &[],
+        };
+        self.as_object_option()
+            .into_iter()
+            .flat_map(JsonObject::values)
+            .chain(elements)
+    }
+
+    /// The entries of an object; nothing for any other value.
+    pub fn entries(&self) -> impl Iterator<Item = (&String, &Value)> {
+        self.as_object_option()
+            .into_iter()
+            .flat_map(JsonObject::iter)
+    }
+
+    /// Keeps only the elements of an array for which `f` returns `true`, in
+    /// their order; any other value is left alone.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    ///# fn main() -> Result<(),String> {
+    ///let mut value = json::parse("[1, null, 2, null]")?;
+    ///value.retain_array(|element| *element != json::Value::Null);
+    ///
+    ///assert_eq!(value, json::parse("[1, 2]")?);
+    ///# Ok(())
+    ///# }
+    /// ```
+    pub fn retain_array<F: FnMut(&mut Value) -> bool>(&mut self, f: F) {
+        if let Value::Array(values) = self {
+            values.retain_mut(f);
+        }
+    }
 }
 
 fn parse_array(reader: &mut Reader) -> Result<Vec<Value>, String> {
@@ -131,13 +603,14 @@ fn parse_array(reader: &mut Reader) -> Result<Vec<Value>, String> {
     if !reader.skip_whitespaces() {
         return Err("unable to parse array".to_string());
     }
-    if reader.peek() == Some(&']') {
+    if reader.peek() == Some(']') {
+        reader.next();
         return Ok(Vec::new());
     }
     let mut values = Vec::new();
     loop {
         values.push(parse_value(reader)?);
-        if let Some(c) = reader.skip_until(&vec![',', ']']) {
+        if let Some(c) = reader.skip_until(&[',', ']']) {
             if c == ']' {
                 return Ok(values);
             }
@@ -148,21 +621,73 @@ fn parse_array(reader: &mut Reader) -> Result<Vec<Value>, String> {
 }
 
 fn parse_string(reader: &mut Reader) -> Result<String, String> {
-    let mut result = String::new();
-    loop {
-        match reader.read_until(&vec!['"', '\\']) {
-            Some((value, '"')) => {
-                result.push_str(&value);
-                return Ok(result);
-            }
-            Some((value, '\\')) => {
-                result.push_str(&value);
-                let escaped = reader.next().ok_or_else(|| "no char to escape".to_string())?;
-                result.push(escaped)
-            },
-            Some((_, _)) => panic!(),
-            None => return Err("invalid json string".to_string()),
+    unescape_str(reader.read_string()?).map(Cow::into_owned)
+}
+
+/// Decodes the escape sequences of the text within a JSON string, as the
+/// parser does, only allocating when there are any. The reverse of
+/// [`escape_str`].
+///
+/// # Examples
+///
+/// ```
+///# fn main() -> Result<(),String> {
+///assert_eq!(json::unescape_str(r#"say \"hi\"\u0021"#)?, r#"say "hi"!"#);
+///assert_eq!(json::unescape_str(r"\ud83d\ude00")?, "😀");
+///assert_eq!(json::unescape_str(r"\x"), Err(r"invalid escape \x".to_string()));
+///# Ok(())
+///# }
+/// ```
+pub fn unescape_str(text: &str) -> Result<Cow<'_, str>, String> {
+    if !text.contains('\\') {
+        return Ok(Cow::Borrowed(text));
+    }
+    let mut result = String::with_capacity(text.len());
+    let mut chars = text.chars();
+    while let Some(c) = chars.next() {
+        if c != '\\' {
+            result.push(c);
+            continue;
         }
+        let escaped = chars
+            .next()
+            .ok_or_else(|| "no char to escape".to_string())?;
+        result.push(match escaped {
+            '"' | '\\' | '/' => escaped,
+            'b' => '\u{8}',
+            'f' => '\u{c}',
+            'n' => '\n',
+            'r' => '\r',
+            't' => '\t',
+            'u' => {
+                let high = read_hex(&mut chars)?;
+                let code = if (0xd800..0xdc00).contains(&high) {
+                    let low = match (chars.next(), chars.next()) {
+                        (Some('\\'), Some('u')) => read_hex(&mut chars)?,
+                        _ => return Err(format!("unpaired surrogate \\u{:04x}", high)),
+                    };
+                    if !(0xdc00..0xe000).contains(&low) {
+                        return Err(format!("unpaired surrogate \\u{:04x}", high));
+                    }
+                    0x10000 + ((high - 0xd800) << 10) + (low - 0xdc00)
+                } else {
+                    high
+                };
+                std::char::from_u32(code)
+                    .ok_or_else(|| format!("unpaired surrogate \\u{:04x}", code))?
+            }
+            c => return Err(format!("invalid escape \\{}", c)),
+        });
+    }
+    Ok(Cow::Owned(result))
+}
+
+/// Reads the four hexadecimal digits of a `\u` escape.
+fn read_hex(chars: &mut Chars) -> Result<u32, String> {
+    let digits: String = chars.take(4).collect();
+    match u32::from_str_radix(&digits, 16) {
+        Ok(code) if digits.len() == 4 && digits.chars().all(|c| c.is_ascii_hexdigit()) => Ok(code),
+        _ => Err(format!("invalid unicode escape \\u{}", digits)),
     }
 }
 
@@ -190,21 +715,21 @@ fn parse_false(reader: &mut Reader) -> Result<Value, String> {
     }
 }
 
-fn parse_object(reader: &mut Reader) -> Result<HashMap<String, Value>, String> {
+fn parse_object(reader: &mut Reader) -> Result<JsonObject, String> {
     reader.next().unwrap();
-    let mut value = HashMap::new();
+    let mut value = JsonObject::new();
 
-    while let Some(delimiter) = reader.skip_until(&vec!['"', '}']) {
+    while let Some(delimiter) = reader.skip_until(&['"', '}']) {
         if delimiter == '}' {
             return Ok(value);
         }
         let name = parse_string(reader)?;
-        if reader.skip_until(&vec![':']).is_none() {
+        if reader.skip_until(&[':']).is_none() {
             return Err("missing property value".to_string());
         }
         value.insert(name, parse_value(reader)?);
 
-        if let Some(delimiter) = reader.skip_until(&vec![',', '}']) {
+        if let Some(delimiter) = reader.skip_until(&[',', '}']) {
             if delimiter == '}' {
                 return Ok(value);
             }
@@ -217,8 +742,9 @@ fn parse_object(reader: &mut Reader) -> Result<HashMap<String, Value>, String> {
 }
 
 fn parse_number(reader: &mut Reader) -> Result<f64, String> {
-    let (raw, _) = reader.read_until_or_end(&vec![',', ']', '}']);
-    raw.trim().parse()
+    let (raw, _) = reader.read_until_or_end(&[',', ']', '}']);
+    raw.trim()
+        .parse()
         .map_err(|_| format!("{} is not a valid number", raw))
 }
 
@@ -226,7 +752,7 @@ fn parse_value(reader: &mut Reader) -> Result<Value, String> {
     if !reader.skip_whitespaces() {
         return Err("empty string".to_string());
     }
-    return match reader.peek() {
+    match reader.peek() {
         Some('n') => parse_null(reader),
         Some('t') => parse_true(reader),
         Some('f') => parse_false(reader),
@@ -235,12 +761,12 @@ fn parse_value(reader: &mut Reader) -> Result<Value, String> {
             reader.next().unwrap();
             parse_string(reader).map(Value::String)
         }
-        Some('{') => parse_object(reader).map(JsonObject).map(Value::Object),
-        Some(c) if *c == '+' || *c == '-' || c.is_digit(10) => {
+        Some('{') => parse_object(reader).map(Value::Object),
+        Some(c) if c == '+' || c == '-' || c.is_ascii_digit() => {
             parse_number(reader).map(Value::Number)
         }
         _ => Err("malformed json".to_string()),
-    };
+    }
 }
 
 /// # Examples
@@ -259,7 +785,29 @@ fn parse_value(reader: &mut Reader) -> Result<Value, String> {
 ///# }
 /// ```
 pub fn parse<'a, S: Into<&'a str>>(raw: S) -> Result<Value, String> {
+    parse_reader(&mut Reader::new(raw.into()))
+}
+
+/// Parses like [`parse`], prefixing errors with the line and column, both
+/// counted from 1, at which the parser gave up.
+///
+/// # Examples
+///
+/// ```
+///assert_eq!(
+///    json::parse_located("{\n  \"a\": tru\n}"),
+///    Err("line 2, column 11: expected true".to_string())
+///);
+/// ```
+pub fn parse_located<'a, S: Into<&'a str>>(raw: S) -> Result<Value, String> {
     let reader = &mut Reader::new(raw.into());
+    parse_reader(reader).map_err(|e| {
+        let (line, column) = reader.location();
+        format!("line {}, column {}: {}", line, column, e)
+    })
+}
+
+fn parse_reader(reader: &mut Reader) -> Result<Value, String> {
     let value = parse_value(reader)?;
     if reader.skip_whitespaces() {
         return Err("unexpected text after value".to_string());
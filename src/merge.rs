@@ -0,0 +1,147 @@
+#[cfg(test)]
+mod tests;
+
+use crate::pointer::AsPointer;
+use crate::{JsonPointer, Value};
+use std::collections::HashMap;
+
+/// How two arrays found at the same location are combined.
+#[derive(Debug, PartialEq, Clone, Default)]
+pub enum MergeStrategy {
+    /// The incoming array replaces the existing one.
+    #[default]
+    Replace,
+    /// The incoming elements are appended to the existing ones.
+    Concat,
+    /// Objects sharing the same value for the given key are merged together,
+    /// every other incoming element is appended.
+    ById(String),
+}
+
+/// Array strategies used by [`Value::merge_with`]. Strategies in `paths` are
+/// keyed by the JSON Pointer of the array in the merged document, in which a
+/// `*` token matches any key or index, and take precedence over `default`.
+/// When several keys match, the one with the fewest `*` wins, then the
+/// smallest.
+#[derive(Debug, PartialEq, Clone, Default)]
+pub struct MergeOptions {
+    pub default: MergeStrategy,
+    pub paths: HashMap<JsonPointer, MergeStrategy>,
+}
+
+impl MergeOptions {
+    pub fn new(default: MergeStrategy) -> Self {
+        Self {
+            default,
+            paths: HashMap::new(),
+        }
+    }
+
+    /// Sets the strategy for the arrays at `path`. Text that is no valid
+    /// pointer never matches and is skipped.
+    pub fn with_path<P: AsPointer + ?Sized>(mut self, path: &P, strategy: MergeStrategy) -> Self {
+        if let Ok(tokens) = path.pointer_tokens() {
+            self.paths.insert(tokens.iter().collect(), strategy);
+        }
+        self
+    }
+
+    fn strategy(&self, path: &[String]) -> &MergeStrategy {
+        self.paths
+            .iter()
+            .filter_map(|(pattern, strategy)| {
+                let pattern = pattern.tokens();
+                let matched = pattern.len() == path.len()
+                    && pattern
+                        .iter()
+                        .zip(path)
+                        .all(|(expected, token)| expected == "*" || expected == token);
+                if !matched {
+                    return None;
+                }
+                let wildcards = pattern.iter().filter(|token| *token == "*").count();
+                Some((wildcards, pattern, strategy))
+            })
+            .min_by(|(a, a_pattern, _), (b, b_pattern, _)| (a, a_pattern).cmp(&(b, b_pattern)))
+            .map_or(&self.default, |(_, _, strategy)| strategy)
+    }
+}
+
+impl Value {
+    /// Deep merges `other` into `self`: objects are merged key by key, any
+    /// other value (arrays included) is replaced.
+    pub fn merge(&mut self, other: Value) {
+        self.merge_with(other, &MergeOptions::default())
+    }
+
+    /// # Examples
+    ///
+    /// ```
+    ///# fn main() -> Result<(),String> {
+    ///use json::merge::{MergeOptions, MergeStrategy};
+    ///
+    ///let mut config = json::parse(r#"{ "users": [{ "id": 1, "name": "John" }] }"#)?;
+    ///let overrides = json::parse(r#"{ "users": [{ "id": 1, "admin": true }] }"#)?;
+    ///
+    ///config.merge_with(
+    ///    overrides,
+    ///    &MergeOptions::default().with_path("/users", MergeStrategy::ById("id".to_string())),
+    ///);
+    ///assert_eq!(
+    ///    config,
+    ///    json::parse(r#"{ "users": [{ "id": 1, "name": "John", "admin": true }] }"#)?
+    ///);
+    ///# Ok(())
+    ///# }
+    /// ```
+    pub fn merge_with(&mut self, other: Value, options: &MergeOptions) {
+        merge_at(self, other, options, &mut Vec::new())
+    }
+}
+
+fn merge_at(target: &mut Value, other: Value, options: &MergeOptions, path: &mut Vec<String>) {
+    match (target, other) {
+        (Value::Object(target), Value::Object(other)) => {
+            for (key, value) in other {
+                path.push(key.clone());
+                match target.get_mut(&key) {
+                    Some(existing) => merge_at(existing, value, options, path),
+                    None => {
+                        target.insert(key, value);
+                    }
+                }
+                path.pop();
+            }
+        }
+        (Value::Array(target), Value::Array(other)) => match options.strategy(path) {
+            MergeStrategy::Replace => *target = other,
+            MergeStrategy::Concat => target.extend(other),
+            MergeStrategy::ById(key) => {
+                for value in other {
+                    let id = field(&value, key).cloned();
+                    let position = id.and_then(|id| {
+                        target
+                            .iter()
+                            .position(|existing| field(existing, key) == Some(&id))
+                    });
+                    match position {
+                        Some(index) => {
+                            path.push(index.to_string());
+                            merge_at(&mut target[index], value, options, path);
+                            path.pop();
+                        }
+                        None => target.push(value),
+                    }
+                }
+            }
+        },
+        (target, other) => *target = other,
+    }
+}
+
+fn field<'a>(value: &'a Value, key: &str) -> Option<&'a Value> {
+    match value {
+        Value::Object(object) => object.get(key),
+        _ => None,
+    }
+}
@@ -0,0 +1,105 @@
+use super::{MergeOptions, MergeStrategy};
+use crate::{parse, JsonPointer};
+
+#[test]
+fn merge_objects() {
+    let mut value = parse(r#"{ "a": { "b": 1, "c": [1] }, "d": true }"#).unwrap();
+    value.merge(parse(r#"{ "a": { "c": [2], "e": null }, "d": false }"#).unwrap());
+    assert_eq!(
+        value,
+        parse(r#"{ "a": { "b": 1, "c": [2], "e": null }, "d": false }"#).unwrap()
+    );
+}
+
+#[test]
+fn merge_concat() {
+    let mut value = parse(r#"{ "a": [1], "b": { "c": [1] } }"#).unwrap();
+    value.merge_with(
+        parse(r#"{ "a": [2], "b": { "c": [2] } }"#).unwrap(),
+        &MergeOptions::new(MergeStrategy::Concat),
+    );
+    assert_eq!(
+        value,
+        parse(r#"{ "a": [1, 2], "b": { "c": [1, 2] } }"#).unwrap()
+    );
+}
+
+#[test]
+fn merge_by_id() {
+    let mut value = parse(
+        r#"{ "servers": [
+            { "name": "a", "port": 80, "tags": ["x"] },
+            { "name": "b", "port": 81 }
+        ] }"#,
+    )
+    .unwrap();
+    value.merge_with(
+        parse(
+            r#"{ "servers": [
+                { "name": "b", "port": 8081 },
+                { "name": "a", "tags": ["y"] },
+                { "name": "c" },
+                42
+            ] }"#,
+        )
+        .unwrap(),
+        &MergeOptions::new(MergeStrategy::ById("name".to_string()))
+            .with_path("/servers/0/tags", MergeStrategy::Concat),
+    );
+    assert_eq!(
+        value,
+        parse(
+            r#"{ "servers": [
+                { "name": "a", "port": 80, "tags": ["x", "y"] },
+                { "name": "b", "port": 8081 },
+                { "name": "c" },
+                42
+            ] }"#
+        )
+        .unwrap()
+    );
+}
+
+#[test]
+fn merge_path_overrides_default() {
+    let mut value = parse(r#"{ "a": [1], "b": [1] }"#).unwrap();
+    value.merge_with(
+        parse(r#"{ "a": [2], "b": [2] }"#).unwrap(),
+        &MergeOptions::default().with_path("/b", MergeStrategy::Concat),
+    );
+    assert_eq!(value, parse(r#"{ "a": [2], "b": [1, 2] }"#).unwrap());
+}
+
+#[test]
+fn merge_path_wildcards() {
+    let mut value =
+        parse(r#"{ "a/b": [1], "servers": [{ "name": "a", "tags": ["x"], "ports": [80] }] }"#)
+            .unwrap();
+    value.merge_with(
+        parse(r#"{ "a/b": [2], "servers": [{ "name": "a", "tags": ["y"], "ports": [81] }] }"#)
+            .unwrap(),
+        &MergeOptions::default()
+            .with_path("/a~1b", MergeStrategy::Concat)
+            .with_path("/servers", MergeStrategy::ById("name".to_string()))
+            .with_path("/servers/*/*", MergeStrategy::Concat)
+            .with_path("/servers/*/ports", MergeStrategy::Replace),
+    );
+    assert_eq!(
+        value,
+        parse(
+            r#"{ "a/b": [1, 2], "servers": [{ "name": "a", "tags": ["x", "y"], "ports": [81] }] }"#
+        )
+        .unwrap()
+    );
+}
+
+#[test]
+fn merge_json_pointer_paths() {
+    let mut value = parse(r#"{ "a/b": [1] }"#).unwrap();
+    let path: JsonPointer = vec!["a/b"].into_iter().collect();
+    value.merge_with(
+        parse(r#"{ "a/b": [2] }"#).unwrap(),
+        &MergeOptions::default().with_path(&path, MergeStrategy::Concat),
+    );
+    assert_eq!(value, parse(r#"{ "a/b": [1, 2] }"#).unwrap());
+}
@@ -0,0 +1,267 @@
+#[cfg(test)]
+mod tests;
+
+use crate::{to_string, JsonObject, Value};
+use base64::engine::general_purpose::URL_SAFE_NO_PAD;
+use base64::Engine;
+use std::convert::TryInto;
+
+/// Nesting beyond which decoding gives up rather than overflow the stack.
+const MAX_DEPTH: usize = 128;
+
+/// Encodes a value as MessagePack. Integral numbers use the smallest
+/// integer format holding them and others a float32 when it holds them
+/// exactly, a float64 otherwise.
+///
+/// # Examples
+///
+/// ```
+///# fn main() -> Result<(),String> {
+///let value = json::parse(r#"{ "id": 300, "tags": ["a"], "ok": true }"#)?;
+///let bytes = json::to_msgpack(&value);
+///
+///assert_eq!(&bytes[..7], [0x83, 0xa2, b'i', b'd', 0xcd, 0x01, 0x2c]);
+///assert_eq!(json::from_msgpack(&bytes)?, value);
+///# Ok(())
+///# }
+/// ```
+pub fn to_msgpack(value: &Value) -> Vec<u8> {
+    let mut output = Vec::new();
+    encode(&mut output, value);
+    output
+}
+
+/// Writes a length in the smallest of the fix, 8, 16 or 32 bits forms whose
+/// markers are given, a zero marker standing for a form the type lacks.
+fn length(output: &mut Vec<u8>, length: usize, fix: Option<(u8, usize)>, markers: [u8; 3]) {
+    match fix {
+        Some((marker, max)) if length <= max => output.push(marker | length as u8),
+        _ if length <= 0xff && markers[0] != 0 => output.extend([markers[0], length as u8]),
+        _ if length <= 0xffff => {
+            output.push(markers[1]);
+            output.extend((length as u16).to_be_bytes());
+        }
+        _ => {
+            output.push(markers[2]);
+            output.extend((length as u32).to_be_bytes());
+        }
+    }
+}
+
+fn encode(output: &mut Vec<u8>, value: &Value) {
+    match value {
+        Value::Null => output.push(0xc0),
+        Value::Bool(false) => output.push(0xc2),
+        Value::Bool(true) => output.push(0xc3),
+        Value::Number(n) if n.fract() == 0.0 && *n >= 0.0 && *n < u64::MAX as f64 => {
+            match *n as u64 {
+                n @ 0..=0x7f => output.push(n as u8),
+                n @ 0x80..=0xff => output.extend([0xcc, n as u8]),
+                n @ 0x100..=0xffff => {
+                    output.push(0xcd);
+                    output.extend((n as u16).to_be_bytes());
+                }
+                n @ 0x1_0000..=0xffff_ffff => {
+                    output.push(0xce);
+                    output.extend((n as u32).to_be_bytes());
+                }
+                n => {
+                    output.push(0xcf);
+                    output.extend(n.to_be_bytes());
+                }
+            }
+        }
+        Value::Number(n) if n.fract() == 0.0 && *n < 0.0 && *n >= i64::MIN as f64 => {
+            match *n as i64 {
+                n @ -32..=-1 => output.push(n as u8),
+                n @ -0x80..=-33 => output.extend([0xd0, n as u8]),
+                n @ -0x8000..=-0x81 => {
+                    output.push(0xd1);
+                    output.extend((n as i16).to_be_bytes());
+                }
+                n @ -0x8000_0000..=-0x8001 => {
+                    output.push(0xd2);
+                    output.extend((n as i32).to_be_bytes());
+                }
+                n => {
+                    output.push(0xd3);
+                    output.extend(n.to_be_bytes());
+                }
+            }
+        }
+        Value::Number(n) if (*n as f32) as f64 == *n || n.is_nan() => {
+            output.push(0xca);
+            output.extend((*n as f32).to_be_bytes());
+        }
+        Value::Number(n) => {
+            output.push(0xcb);
+            output.extend(n.to_be_bytes());
+        }
+        Value::String(value) => encode_str(output, value),
+        Value::Array(values) => {
+            length(output, values.len(), Some((0x90, 15)), [0, 0xdc, 0xdd]);
+            for value in values {
+                encode(output, value);
+            }
+        }
+        Value::Object(object) => {
+            length(output, object.len(), Some((0x80, 15)), [0, 0xde, 0xdf]);
+            for (key, value) in object.iter() {
+                encode_str(output, key);
+                encode(output, value);
+            }
+        }
+    }
+}
+
+fn encode_str(output: &mut Vec<u8>, value: &str) {
+    length(output, value.len(), Some((0xa0, 31)), [0xd9, 0xda, 0xdb]);
+    output.extend(value.as_bytes());
+}
+
+/// Decodes a single MessagePack object. Binary data becomes a base64url
+/// string without padding, map keys that aren't strings become the JSON
+/// text of the key and non-finite floats become `null`. Extension types
+/// are rejected, as JSON has no counterpart for them.
+///
+/// # Examples
+///
+/// ```
+///# fn main() -> Result<(),String> {
+///// {1: bin8 [1, 2]}
+///let bytes = [0x81, 0x01, 0xc4, 0x02, 0x01, 0x02];
+///
+///assert_eq!(json::from_msgpack(&bytes)?, json::parse(r#"{ "1": "AQI" }"#)?);
+///# Ok(())
+///# }
+/// ```
+pub fn from_msgpack(bytes: &[u8]) -> Result<Value, String> {
+    let mut decoder = Decoder { bytes, offset: 0 };
+    let value = decoder.value(0)?;
+    if decoder.offset != bytes.len() {
+        return Err(format!(
+            "unexpected bytes after value at offset {}",
+            decoder.offset
+        ));
+    }
+    Ok(value)
+}
+
+struct Decoder<'a> {
+    bytes: &'a [u8],
+    offset: usize,
+}
+
+impl<'a> Decoder<'a> {
+    fn take(&mut self, length: usize) -> Result<&'a [u8], String> {
+        let end = self
+            .offset
+            .checked_add(length)
+            .filter(|end| *end <= self.bytes.len());
+        match end {
+            Some(end) => {
+                let taken = &self.bytes[self.offset..end];
+                self.offset = end;
+                Ok(taken)
+            }
+            None => Err("unexpected end of input".to_string()),
+        }
+    }
+
+    fn array<const N: usize>(&mut self) -> Result<[u8; N], String> {
+        Ok(self.take(N)?.try_into().unwrap())
+    }
+
+    /// Reads a big-endian unsigned length of `size` bytes.
+    fn length(&mut self, size: usize) -> Result<usize, String> {
+        Ok(match size {
+            1 => self.take(1)?[0] as usize,
+            2 => u16::from_be_bytes(self.array()?) as usize,
+            _ => u32::from_be_bytes(self.array()?) as usize,
+        })
+    }
+
+    fn str(&mut self, length: usize) -> Result<Value, String> {
+        let bytes = self.take(length)?;
+        let value = std::str::from_utf8(bytes).map_err(|_| "invalid utf-8 in string")?;
+        Ok(Value::String(value.to_string()))
+    }
+
+    fn values(&mut self, length: usize, depth: usize) -> Result<Value, String> {
+        let mut values = Vec::new();
+        for _ in 0..length {
+            values.push(self.value(depth + 1)?);
+        }
+        Ok(Value::Array(values))
+    }
+
+    fn entries(&mut self, length: usize, depth: usize) -> Result<Value, String> {
+        let mut object = JsonObject::new();
+        for _ in 0..length {
+            let key = match self.value(depth + 1)? {
+                Value::String(key) => key,
+                key => to_string(&key),
+            };
+            let value = self.value(depth + 1)?;
+            object.insert(key, value);
+        }
+        Ok(Value::Object(object))
+    }
+
+    fn value(&mut self, depth: usize) -> Result<Value, String> {
+        if depth > MAX_DEPTH {
+            return Err("too deeply nested".to_string());
+        }
+        let marker = self.take(1)?[0];
+        let number = |n: f64| Ok(Value::Number(n));
+        match marker {
+            0x00..=0x7f => number(marker as f64),
+            0x80..=0x8f => self.entries((marker & 0x0f) as usize, depth),
+            0x90..=0x9f => self.values((marker & 0x0f) as usize, depth),
+            0xa0..=0xbf => self.str((marker & 0x1f) as usize),
+            0xc0 => Ok(Value::Null),
+            0xc2 => Ok(Value::Bool(false)),
+            0xc3 => Ok(Value::Bool(true)),
+            0xc4..=0xc6 => {
+                let length = self.length(1 << (marker - 0xc4))?;
+                Ok(Value::String(URL_SAFE_NO_PAD.encode(self.take(length)?)))
+            }
+            0xca => Ok(float(f32::from_be_bytes(self.array()?) as f64)),
+            0xcb => Ok(float(f64::from_be_bytes(self.array()?))),
+            0xcc => number(self.take(1)?[0] as f64),
+            0xcd => number(u16::from_be_bytes(self.array()?) as f64),
+            0xce => number(u32::from_be_bytes(self.array()?) as f64),
+            0xcf => number(u64::from_be_bytes(self.array()?) as f64),
+            0xd0 => number(self.take(1)?[0] as i8 as f64),
+            0xd1 => number(i16::from_be_bytes(self.array()?) as f64),
+            0xd2 => number(i32::from_be_bytes(self.array()?) as f64),
+            0xd3 => number(i64::from_be_bytes(self.array()?) as f64),
+            0xd9..=0xdb => {
+                let length = self.length(1 << (marker - 0xd9))?;
+                self.str(length)
+            }
+            0xdc | 0xdd => {
+                let length = self.length(2 << (marker - 0xdc))?;
+                self.values(length, depth)
+            }
+            0xde | 0xdf => {
+                let length = self.length(2 << (marker - 0xde))?;
+                self.entries(length, depth)
+            }
+            0xe0..=0xff => number(marker as i8 as f64),
+            0xc7..=0xc9 | 0xd4..=0xd8 => Err(format!(
+                "unsupported extension type at offset {}",
+                self.offset - 1
+            )),
+            0xc1 => Err(format!("invalid marker 0xc1 at offset {}", self.offset - 1)),
+        }
+    }
+}
+
+fn float(value: f64) -> Value {
+    if value.is_finite() {
+        Value::Number(value)
+    } else {
+        Value::Null
+    }
+}
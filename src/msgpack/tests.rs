@@ -0,0 +1,43 @@
+use super::{from_msgpack, to_msgpack};
+use crate::parse;
+
+#[test]
+fn round_trip() {
+    let value = parse(
+        r#"{ "ints": [0, 127, 128, 65536, 4294967296, -1, -32, -33, -129, -40000, -3000000000],
+             "floats": [0.5, 0.1, 1e300], "s": "héllo", "o": {}, "b": [true, false, null] }"#,
+    )
+    .unwrap();
+    assert_eq!(from_msgpack(&to_msgpack(&value)), Ok(value));
+    assert_eq!(to_msgpack(&parse("-33").unwrap()), [0xd0, 0xdf]);
+    assert_eq!(to_msgpack(&parse("0.1").unwrap())[0], 0xcb);
+}
+
+#[test]
+fn long_containers() {
+    let value = crate::Value::Array((0..70000).map(|i| crate::Value::Number(i as f64)).collect());
+    let bytes = to_msgpack(&value);
+    assert_eq!(bytes[0], 0xdd);
+    assert_eq!(from_msgpack(&bytes), Ok(value));
+    let key = "k".repeat(300);
+    let value = parse(format!(r#"{{ "{}": "" }}"#, key).as_str()).unwrap();
+    assert_eq!(&to_msgpack(&value)[..4], [0x81, 0xda, 0x01, 0x2c]);
+    assert_eq!(from_msgpack(&to_msgpack(&value)), Ok(value));
+}
+
+#[test]
+fn errors() {
+    assert_eq!(
+        from_msgpack(&[0x92, 0x01]),
+        Err("unexpected end of input".to_string())
+    );
+    assert_eq!(
+        from_msgpack(&[0xd4, 0x01, 0x00]),
+        Err("unsupported extension type at offset 0".to_string())
+    );
+    assert_eq!(
+        from_msgpack(&[0xc0, 0xc0]),
+        Err("unexpected bytes after value at offset 1".to_string())
+    );
+    assert!(from_msgpack(&[0x91; 1000]).is_err());
+}
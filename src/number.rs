@@ -0,0 +1,147 @@
+#[cfg(test)]
+mod tests;
+
+use crate::coerce::is_number_literal;
+use crate::Value;
+use std::fmt::Write;
+
+/// Exponents past this are refused, far beyond those `f64` can hold, so that
+/// a short literal can't expand into an enormous one.
+const MAX_EXPONENT: i64 = 10_000;
+
+/// When [`normalize_number`] and [`Serializer::with_exponent_policy`] write
+/// numbers with an exponent.
+///
+/// [`Serializer::with_exponent_policy`]: crate::Serializer::with_exponent_policy
+#[derive(Debug, PartialEq, Eq, Clone, Copy, Hash)]
+pub enum ExponentPolicy {
+    /// Never, e.g. `1500` and `0.0000001`.
+    Never,
+    /// Always, with one digit before the point, e.g. `1.5e+3` and `1e-7`.
+    Always,
+    /// Below `1e-6` and from `1e21` on, as ECMAScript and RFC 8785 do, e.g.
+    /// `1500` and `1e-7`.
+    Ecmascript,
+}
+
+/// Rewrites a JSON number literal in a canonical form without going through
+/// `f64`, so no digit is lost: the sign of zero, leading and trailing zeros
+/// and the `+` of exponents are dropped, and the exponent is placed as
+/// `policy` says.
+///
+/// # Examples
+///
+/// ```
+///# fn main() -> Result<(),String> {
+///use json::{normalize_number, ExponentPolicy};
+///
+///assert_eq!(normalize_number("-0.0", ExponentPolicy::Ecmascript)?, "0");
+///assert_eq!(normalize_number("1.50E+3", ExponentPolicy::Ecmascript)?, "1500");
+///assert_eq!(normalize_number("1500", ExponentPolicy::Always)?, "1.5e+3");
+///assert_eq!(normalize_number("12.5e-9", ExponentPolicy::Never)?, "0.0000000125");
+///assert_eq!(normalize_number("12345678901234567890123", ExponentPolicy::Ecmascript)?, "1.2345678901234567890123e+22");
+///assert!(normalize_number("01", ExponentPolicy::Never).is_err());
+///# Ok(())
+///# }
+/// ```
+pub fn normalize_number(literal: &str, policy: ExponentPolicy) -> Result<String, String> {
+    if !is_number_literal(literal) {
+        return Err(format!("expected a number, found {:?}", literal));
+    }
+    let (negative, unsigned) = match literal.strip_prefix('-') {
+        Some(unsigned) => (true, unsigned),
+        None => (false, literal),
+    };
+    let (mantissa, exponent) = match unsigned.find(['e', 'E']) {
+        Some(e) => (&unsigned[..e], &unsigned[e + 1..]),
+        None => (unsigned, "0"),
+    };
+    let exponent = exponent
+        .trim_start_matches('+')
+        .parse::<i64>()
+        .ok()
+        .filter(|exponent| exponent.abs() <= MAX_EXPONENT)
+        .ok_or_else(|| format!("exponent of {} is out of range", literal))?;
+    let (integer, fraction) = mantissa.split_once('.').unwrap_or((mantissa, ""));
+    let digits = format!("{}{}", integer, fraction);
+    let significant = digits.trim_start_matches('0');
+    let n = integer.len() as i64 - (digits.len() - significant.len()) as i64 + exponent;
+    let significant = significant.trim_end_matches('0');
+    let mut output = String::new();
+    if significant.is_empty() {
+        output.push('0');
+        return Ok(output);
+    }
+    if negative {
+        output.push('-');
+    }
+    write_decimal(&mut output, significant, n, policy);
+    Ok(output)
+}
+
+/// Writes the number `0.<digits> × 10^n`, `digits` having neither leading
+/// nor trailing zeros.
+pub(crate) fn write_decimal(output: &mut String, digits: &str, n: i64, policy: ExponentPolicy) {
+    let k = digits.len() as i64;
+    let plain = match policy {
+        ExponentPolicy::Never => true,
+        ExponentPolicy::Always => false,
+        ExponentPolicy::Ecmascript => -6 < n && n <= 21,
+    };
+    if plain && k <= n {
+        output.push_str(digits);
+        output.extend(std::iter::repeat('0').take((n - k) as usize));
+    } else if plain && 0 < n {
+        output.push_str(&digits[..n as usize]);
+        output.push('.');
+        output.push_str(&digits[n as usize..]);
+    } else if plain {
+        output.push_str("0.");
+        output.extend(std::iter::repeat('0').take(-n as usize));
+        output.push_str(digits);
+    } else {
+        output.push_str(&digits[..1]);
+        if k > 1 {
+            output.push('.');
+            output.push_str(&digits[1..]);
+        }
+        write!(
+            output,
+            "e{}{}",
+            if n > 0 { "+" } else { "-" },
+            (n - 1).abs()
+        )
+        .unwrap();
+    }
+}
+
+impl Value {
+    /// Replaces every `-0` in the tree with `0`, so that both serialize
+    /// alike. Other numbers already have a single form, `1.0` being the
+    /// same `f64` as `1`. Where the exponent goes is up to the writer; see
+    /// [`Serializer::with_exponent_policy`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    ///# fn main() -> Result<(),String> {
+    ///let mut value = json::parse(r#"{ "a": [-0.0, 1.0] }"#)?;
+    ///value.normalize_numbers();
+    ///
+    ///assert_eq!(json::to_string(&value), r#"{"a":[0,1]}"#);
+    ///# Ok(())
+    ///# }
+    /// ```
+    ///
+    /// [`Serializer::with_exponent_policy`]: crate::Serializer::with_exponent_policy
+    pub fn normalize_numbers(&mut self) {
+        match self {
+            Value::Number(number) if *number == 0.0 => *number = 0.0,
+            Value::Array(values) => values.iter_mut().for_each(Value::normalize_numbers),
+            Value::Object(object) => object
+                .iter_mut()
+                .for_each(|(_, value)| value.normalize_numbers()),
+            _ => {}
+        }
+    }
+}
@@ -0,0 +1,54 @@
+use super::{normalize_number, ExponentPolicy};
+use crate::{parse, to_string};
+
+#[test]
+fn normalize_number_policies() {
+    let cases = [
+        ("0", "0", "0", "0"),
+        ("-0e5", "0", "0", "0"),
+        ("10", "10", "1e+1", "10"),
+        ("-1.250", "-1.25", "-1.25e+0", "-1.25"),
+        ("0.00012", "0.00012", "1.2e-4", "0.00012"),
+        ("1E-7", "0.0000001", "1e-7", "1e-7"),
+        ("1e21", "1000000000000000000000", "1e+21", "1e+21"),
+        ("100e-2", "1", "1e+0", "1"),
+    ];
+    for (literal, never, always, ecmascript) in cases.iter() {
+        assert_eq!(
+            normalize_number(literal, ExponentPolicy::Never).unwrap(),
+            *never
+        );
+        assert_eq!(
+            normalize_number(literal, ExponentPolicy::Always).unwrap(),
+            *always
+        );
+        assert_eq!(
+            normalize_number(literal, ExponentPolicy::Ecmascript).unwrap(),
+            *ecmascript
+        );
+    }
+}
+
+#[test]
+fn normalize_number_errors() {
+    assert_eq!(
+        normalize_number("+1", ExponentPolicy::Never),
+        Err("expected a number, found \"+1\"".to_string())
+    );
+    assert!(normalize_number("1.", ExponentPolicy::Never).is_err());
+    assert_eq!(
+        normalize_number("1e10001", ExponentPolicy::Never),
+        Err("exponent of 1e10001 is out of range".to_string())
+    );
+    assert_eq!(
+        normalize_number("1e-10000", ExponentPolicy::Always).unwrap(),
+        "1e-10000"
+    );
+}
+
+#[test]
+fn normalize_numbers() {
+    let mut value = parse(r#"[-0, { "a": -0.0, "b": -1 }, "-0"]"#).unwrap();
+    value.normalize_numbers();
+    assert_eq!(to_string(&value), r#"[0,{"a":0,"b":-1},"-0"]"#);
+}
@@ -0,0 +1,87 @@
+#[cfg(test)]
+mod tests;
+
+use crate::{JsonObject, Value};
+use std::cmp::Ordering;
+
+impl Value {
+    pub(crate) fn rank(&self) -> u8 {
+        match self {
+            Value::Null => 0,
+            Value::Bool(_) => 1,
+            Value::Number(_) => 2,
+            Value::String(_) => 3,
+            Value::Array(_) => 4,
+            Value::Object(_) => 5,
+        }
+    }
+}
+
+/// Numbers compare numerically, `-0.0` equals `0.0` and NaN is equal to
+/// itself and greater than every other number.
+fn compare_numbers(left: f64, right: f64) -> Ordering {
+    match (left.is_nan(), right.is_nan()) {
+        (true, true) => Ordering::Equal,
+        (true, false) => Ordering::Greater,
+        (false, true) => Ordering::Less,
+        (false, false) => left.partial_cmp(&right).unwrap(),
+    }
+}
+
+impl PartialEq for Value {
+    fn eq(&self, other: &Self) -> bool {
+        match (self, other) {
+            (Value::Null, Value::Null) => true,
+            (Value::Bool(left), Value::Bool(right)) => left == right,
+            (Value::Number(left), Value::Number(right)) => {
+                compare_numbers(*left, *right) == Ordering::Equal
+            }
+            (Value::String(left), Value::String(right)) => left == right,
+            (Value::Array(left), Value::Array(right)) => left == right,
+            (Value::Object(left), Value::Object(right)) => left == right,
+            _ => false,
+        }
+    }
+}
+
+impl Eq for Value {}
+
+impl PartialOrd for Value {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+/// Values of different types are ordered `null < bool < number < string <
+/// array < object`. Arrays compare lexicographically, objects compare their
+/// entries sorted by key lexicographically.
+impl Ord for Value {
+    fn cmp(&self, other: &Self) -> Ordering {
+        match (self, other) {
+            (Value::Bool(left), Value::Bool(right)) => left.cmp(right),
+            (Value::Number(left), Value::Number(right)) => compare_numbers(*left, *right),
+            (Value::String(left), Value::String(right)) => left.cmp(right),
+            (Value::Array(left), Value::Array(right)) => left.cmp(right),
+            (Value::Object(left), Value::Object(right)) => left.cmp(right),
+            _ => self.rank().cmp(&other.rank()),
+        }
+    }
+}
+
+impl Eq for JsonObject {}
+
+impl PartialOrd for JsonObject {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for JsonObject {
+    fn cmp(&self, other: &Self) -> Ordering {
+        let mut left: Vec<_> = self.iter().collect();
+        let mut right: Vec<_> = other.iter().collect();
+        left.sort();
+        right.sort();
+        left.cmp(&right)
+    }
+}
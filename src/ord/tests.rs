@@ -0,0 +1,52 @@
+use crate::{parse, Value};
+use std::collections::BTreeSet;
+
+#[test]
+fn order_across_types() {
+    let mut values: Vec<Value> = ["{}", "[]", r#""a""#, "1", "true", "null"]
+        .iter()
+        .map(|raw| parse(*raw).unwrap())
+        .collect();
+    values.sort();
+    assert_eq!(
+        values,
+        vec![
+            Value::Null,
+            Value::Bool(true),
+            Value::Number(1.0),
+            Value::String("a".to_string()),
+            Value::Array(vec![]),
+            parse("{}").unwrap(),
+        ]
+    );
+}
+
+#[test]
+fn numbers() {
+    assert!(Value::Number(-1.0) < Value::Number(0.5));
+    assert_eq!(Value::Number(-0.0), Value::Number(0.0));
+    assert_eq!(Value::Number(f64::NAN), Value::Number(f64::NAN));
+    assert!(Value::Number(f64::INFINITY) < Value::Number(f64::NAN));
+}
+
+#[test]
+fn containers() {
+    assert!(parse("[1, 2]").unwrap() < parse("[1, 3]").unwrap());
+    assert!(parse("[1]").unwrap() < parse("[1, 0]").unwrap());
+    assert!(parse(r#"{ "a": 2 }"#).unwrap() < parse(r#"{ "b": 1 }"#).unwrap());
+    assert!(parse(r#"{ "a": 1, "b": 2 }"#).unwrap() < parse(r#"{ "a": 1, "c": 0 }"#).unwrap());
+}
+
+#[test]
+fn btree_keys() {
+    let set: BTreeSet<Value> = [
+        "[1]",
+        "[1.0]",
+        r#"{ "a": 1, "b": 2 }"#,
+        r#"{ "b": 2, "a": 1 }"#,
+    ]
+    .iter()
+    .map(|raw| parse(*raw).unwrap())
+    .collect();
+    assert_eq!(set.len(), 2);
+}
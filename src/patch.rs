@@ -0,0 +1,502 @@
+#[cfg(test)]
+mod tests;
+
+use crate::pointer::{array_index, AsPointer};
+use crate::{JsonObject, JsonPointer, Value};
+use std::fmt;
+use std::iter::FromIterator;
+
+/// A single RFC 6902 operation.
+#[derive(Debug, PartialEq, Clone)]
+pub enum Operation {
+    Add {
+        path: JsonPointer,
+        value: Value,
+    },
+    Remove {
+        path: JsonPointer,
+    },
+    Replace {
+        path: JsonPointer,
+        value: Value,
+    },
+    Move {
+        from: JsonPointer,
+        path: JsonPointer,
+    },
+    Copy {
+        from: JsonPointer,
+        path: JsonPointer,
+    },
+    Test {
+        path: JsonPointer,
+        value: Value,
+    },
+}
+
+impl Operation {
+    pub fn path(&self) -> &JsonPointer {
+        match self {
+            Operation::Add { path, .. }
+            | Operation::Remove { path }
+            | Operation::Replace { path, .. }
+            | Operation::Move { path, .. }
+            | Operation::Copy { path, .. }
+            | Operation::Test { path, .. } => path,
+        }
+    }
+
+    fn from_value(value: Value) -> Result<Self, String> {
+        let mut object = value.to_object()?;
+        let op = object.take_string("op")?;
+        let path = JsonPointer::parse(&object.take_string("path")?)?;
+        match op.as_str() {
+            "add" => Ok(Operation::Add {
+                path,
+                value: object.take("value")?,
+            }),
+            "remove" => Ok(Operation::Remove { path }),
+            "replace" => Ok(Operation::Replace {
+                path,
+                value: object.take("value")?,
+            }),
+            "move" => Ok(Operation::Move {
+                from: JsonPointer::parse(&object.take_string("from")?)?,
+                path,
+            }),
+            "copy" => Ok(Operation::Copy {
+                from: JsonPointer::parse(&object.take_string("from")?)?,
+                path,
+            }),
+            "test" => Ok(Operation::Test {
+                path,
+                value: object.take("value")?,
+            }),
+            _ => Err(format!("unknown patch operation {}", op)),
+        }
+    }
+
+    fn to_value(&self) -> Value {
+        let (op, path, from, value) = match self {
+            Operation::Add { path, value } => ("add", path, None, Some(value)),
+            Operation::Remove { path } => ("remove", path, None, None),
+            Operation::Replace { path, value } => ("replace", path, None, Some(value)),
+            Operation::Move { from, path } => ("move", path, Some(from), None),
+            Operation::Copy { from, path } => ("copy", path, Some(from), None),
+            Operation::Test { path, value } => ("test", path, None, Some(value)),
+        };
+        let mut object = JsonObject::new();
+        object.insert("op".to_string(), Value::String(op.to_string()));
+        if let Some(from) = from {
+            object.insert("from".to_string(), Value::String(from.to_string()));
+        }
+        object.insert("path".to_string(), Value::String(path.to_string()));
+        if let Some(value) = value {
+            object.insert("value".to_string(), value.clone());
+        }
+        Value::Object(object)
+    }
+
+    fn apply(&self, target: &mut Value) -> Result<(), ConflictKind> {
+        match self {
+            Operation::Add { path, value } => add(target, path, value.clone()),
+            Operation::Remove { path } => remove(target, path).map(|_| ()),
+            Operation::Replace { path, value } => {
+                *lookup_mut(target, path)? = value.clone();
+                Ok(())
+            }
+            Operation::Move { from, path } => {
+                if from == path {
+                    return lookup_mut(target, from).map(|_| ());
+                }
+                if path.tokens().starts_with(from.tokens()) {
+                    return Err(ConflictKind::MoveIntoChild);
+                }
+                let value = remove(target, from).map_err(|kind| match kind {
+                    ConflictKind::PathNotFound => ConflictKind::FromNotFound,
+                    kind => kind,
+                })?;
+                add(target, path, value)
+            }
+            Operation::Copy { from, path } => {
+                let value = match target.pointer(from) {
+                    Some(value) => value.clone(),
+                    None => return Err(ConflictKind::FromNotFound),
+                };
+                add(target, path, value)
+            }
+            Operation::Test { path, value } => {
+                let actual = lookup_mut(target, path)?;
+                if actual == value {
+                    Ok(())
+                } else {
+                    Err(ConflictKind::TestFailed {
+                        expected: value.clone(),
+                        actual: actual.clone(),
+                    })
+                }
+            }
+        }
+    }
+}
+
+/// An RFC 6902 JSON Patch document.
+#[derive(Debug, PartialEq, Clone, Default)]
+pub struct Patch(pub Vec<Operation>);
+
+impl Patch {
+    pub fn from_value(value: Value) -> Result<Self, String> {
+        match value {
+            Value::Array(operations) => operations
+                .into_iter()
+                .map(Operation::from_value)
+                .collect::<Result<_, _>>()
+                .map(Patch),
+            _ => Err("not a json array".to_string()),
+        }
+    }
+
+    pub fn to_value(&self) -> Value {
+        Value::Array(self.0.iter().map(Operation::to_value).collect())
+    }
+
+    /// The patch turning `from` into `to`: objects and arrays are compared
+    /// member by member, anything else that differs is replaced. Array
+    /// elements are matched by index, so an insertion at the front replaces
+    /// every following element.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    ///# fn main() -> Result<(),String> {
+    ///use json::patch::Patch;
+    ///
+    ///let mut from = json::parse(r#"{ "name": "John", "tags": ["a", "b", "c"], "age": 42 }"#)?;
+    ///let to = json::parse(r#"{ "name": "Jane", "tags": ["a"], "admin": true }"#)?;
+    ///
+    ///let patch = Patch::diff(&from, &to);
+    ///assert_eq!(
+    ///    patch.to_value(),
+    ///    json::parse(r#"[
+    ///        { "op": "replace", "path": "/name", "value": "Jane" },
+    ///        { "op": "remove", "path": "/tags/2" },
+    ///        { "op": "remove", "path": "/tags/1" },
+    ///        { "op": "remove", "path": "/age" },
+    ///        { "op": "add", "path": "/admin", "value": true }
+    ///    ]"#)?
+    ///);
+    ///patch.apply(&mut from)?;
+    ///assert_eq!(from, to);
+    ///# Ok(())
+    ///# }
+    /// ```
+    pub fn diff(from: &Value, to: &Value) -> Patch {
+        let mut operations = Vec::new();
+        diff_at(&mut JsonPointer::root(), from, to, &mut operations);
+        Patch(operations)
+    }
+
+    /// Applies every operation in order. The target is left untouched if any
+    /// operation fails.
+    pub fn apply(&self, target: &mut Value) -> Result<(), String> {
+        let mut result = target.clone();
+        for (index, operation) in self.0.iter().enumerate() {
+            operation.apply(&mut result).map_err(|kind| {
+                Conflict {
+                    index,
+                    path: operation.path().clone(),
+                    kind,
+                }
+                .to_string()
+            })?;
+        }
+        *target = result;
+        Ok(())
+    }
+
+    /// Reports every operation that would fail against `target` without
+    /// mutating it. Operations are simulated in order, so later operations see
+    /// the effect of earlier successful ones; failing operations are skipped.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    ///# fn main() -> Result<(),String> {
+    ///use json::patch::{ConflictKind, Patch};
+    ///
+    ///let document = json::parse(r#"{ "name": "John Smith" }"#)?;
+    ///let patch = Patch::from_value(json::parse(r#"[
+    ///    { "op": "test", "path": "/name", "value": "John Smith" },
+    ///    { "op": "remove", "path": "/age" }
+    ///]"#)?)?;
+    ///
+    ///let conflicts = patch.validate_against(&document);
+    ///assert_eq!(conflicts.len(), 1);
+    ///assert_eq!(conflicts[0].index, 1);
+    ///assert_eq!(conflicts[0].kind, ConflictKind::PathNotFound);
+    ///# Ok(())
+    ///# }
+    /// ```
+    pub fn validate_against(&self, target: &Value) -> Vec<Conflict> {
+        let mut simulated = target.clone();
+        let mut conflicts = Vec::new();
+        for (index, operation) in self.0.iter().enumerate() {
+            if let Err(kind) = operation.apply(&mut simulated) {
+                conflicts.push(Conflict {
+                    index,
+                    path: operation.path().clone(),
+                    kind,
+                });
+            }
+        }
+        conflicts
+    }
+}
+
+impl Value {
+    /// Moves the value at `from` to `to` as the RFC 6902 `move` operation
+    /// does: `to` may name a new object member, an array index to insert at
+    /// or `-` to append, but not a child of `from`. On error, the value is
+    /// put back at `from`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    ///# fn main() -> Result<(),String> {
+    ///let mut value = json::parse(r#"{ "name": "John", "tags": ["a"] }"#)?;
+    ///value.move_pointer("/name", "/tags/0")?;
+    ///
+    ///assert_eq!(value, json::parse(r#"{ "tags": ["John", "a"] }"#)?);
+    ///assert_eq!(
+    ///    value.move_pointer("/tags", "/tags/0"),
+    ///    Err(r#"move "/tags" to "/tags/0": cannot move a value into one of its children"#.to_string())
+    ///);
+    ///# Ok(())
+    ///# }
+    /// ```
+    pub fn move_pointer<F, T>(&mut self, from: &F, to: &T) -> Result<(), String>
+    where
+        F: AsPointer + ?Sized,
+        T: AsPointer + ?Sized,
+    {
+        let from = &JsonPointer::from_iter(from.pointer_tokens()?.iter());
+        let to = &JsonPointer::from_iter(to.pointer_tokens()?.iter());
+        let error = |kind: ConflictKind| {
+            format!(
+                "move {:?} to {:?}: {}",
+                from.to_string(),
+                to.to_string(),
+                kind
+            )
+        };
+        if from == to {
+            return lookup_mut(self, from).map(|_| ()).map_err(error);
+        }
+        if to.tokens().starts_with(from.tokens()) {
+            return Err(error(ConflictKind::MoveIntoChild));
+        }
+        let value = remove(self, from).map_err(|kind| match kind {
+            ConflictKind::PathNotFound => error(ConflictKind::FromNotFound),
+            kind => error(kind),
+        })?;
+        if let Err(kind) = add(self, to, value.clone()) {
+            add(self, from, value).unwrap();
+            return Err(error(kind));
+        }
+        Ok(())
+    }
+
+    /// Copies the value at `from` to `to` as the RFC 6902 `copy` operation
+    /// does.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    ///# fn main() -> Result<(),String> {
+    ///let mut value = json::parse(r#"{ "a": { "b": 1 } }"#)?;
+    ///value.copy_pointer("/a", "/a/c")?;
+    ///
+    ///assert_eq!(value, json::parse(r#"{ "a": { "b": 1, "c": { "b": 1 } } }"#)?);
+    ///assert!(value.copy_pointer("/x", "/y").is_err());
+    ///# Ok(())
+    ///# }
+    /// ```
+    pub fn copy_pointer<F, T>(&mut self, from: &F, to: &T) -> Result<(), String>
+    where
+        F: AsPointer + ?Sized,
+        T: AsPointer + ?Sized,
+    {
+        let from = JsonPointer::from_iter(from.pointer_tokens()?.iter());
+        let to = JsonPointer::from_iter(to.pointer_tokens()?.iter());
+        let error = format!("copy {:?} to {:?}", from.to_string(), to.to_string());
+        Operation::Copy { from, path: to }
+            .apply(self)
+            .map_err(|kind| format!("{}: {}", error, kind))
+    }
+}
+
+fn diff_at(path: &mut JsonPointer, from: &Value, to: &Value, operations: &mut Vec<Operation>) {
+    match (from, to) {
+        (Value::Object(from), Value::Object(to)) => {
+            for (key, value) in from.iter() {
+                path.push(key.as_str());
+                match to.get(key) {
+                    Some(to) => diff_at(path, value, to, operations),
+                    None => operations.push(Operation::Remove { path: path.clone() }),
+                }
+                path.pop();
+            }
+            for (key, value) in to.iter().filter(|(key, _)| !from.contains_key(key)) {
+                path.push(key.as_str());
+                operations.push(Operation::Add {
+                    path: path.clone(),
+                    value: value.clone(),
+                });
+                path.pop();
+            }
+        }
+        (Value::Array(from), Value::Array(to)) => {
+            for (i, (from, to)) in from.iter().zip(to).enumerate() {
+                path.push(i.to_string());
+                diff_at(path, from, to, operations);
+                path.pop();
+            }
+            for i in (to.len()..from.len()).rev() {
+                path.push(i.to_string());
+                operations.push(Operation::Remove { path: path.clone() });
+                path.pop();
+            }
+            for (i, value) in to.iter().enumerate().skip(from.len()) {
+                path.push(i.to_string());
+                operations.push(Operation::Add {
+                    path: path.clone(),
+                    value: value.clone(),
+                });
+                path.pop();
+            }
+        }
+        (from, to) if from == to => {}
+        (_, to) => operations.push(Operation::Replace {
+            path: path.clone(),
+            value: to.clone(),
+        }),
+    }
+}
+
+/// An operation of a [`Patch`] that cannot be applied.
+#[derive(Debug, PartialEq, Clone)]
+pub struct Conflict {
+    pub index: usize,
+    pub path: JsonPointer,
+    pub kind: ConflictKind,
+}
+
+impl fmt::Display for Conflict {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "operation {} at {:?}: {}",
+            self.index,
+            self.path.to_string(),
+            self.kind
+        )
+    }
+}
+
+#[derive(Debug, PartialEq, Clone)]
+pub enum ConflictKind {
+    PathNotFound,
+    FromNotFound,
+    InvalidArrayIndex,
+    NotAContainer,
+    MoveIntoChild,
+    TestFailed { expected: Value, actual: Value },
+}
+
+impl fmt::Display for ConflictKind {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ConflictKind::PathNotFound => write!(f, "path not found"),
+            ConflictKind::FromNotFound => write!(f, "from path not found"),
+            ConflictKind::InvalidArrayIndex => write!(f, "invalid array index"),
+            ConflictKind::NotAContainer => write!(f, "parent is not an object or array"),
+            ConflictKind::MoveIntoChild => {
+                write!(f, "cannot move a value into one of its children")
+            }
+            ConflictKind::TestFailed { expected, actual } => {
+                write!(f, "expected {:?}, found {:?}", expected, actual)
+            }
+        }
+    }
+}
+
+fn lookup_mut<'a>(
+    target: &'a mut Value,
+    path: &JsonPointer,
+) -> Result<&'a mut Value, ConflictKind> {
+    target.pointer_mut(path).ok_or(ConflictKind::PathNotFound)
+}
+
+/// Resolves the container holding the last token of `path`.
+fn parent_mut<'a>(
+    target: &'a mut Value,
+    path: &JsonPointer,
+) -> Result<Option<(&'a mut Value, String)>, ConflictKind> {
+    let (last, tokens) = match path.tokens().split_last() {
+        Some(split) => split,
+        None => return Ok(None),
+    };
+    let mut parent = target;
+    for token in tokens {
+        parent = match parent {
+            Value::Object(object) => object.get_mut(token),
+            Value::Array(values) => array_index(token).and_then(move |i| values.get_mut(i)),
+            _ => None,
+        }
+        .ok_or(ConflictKind::PathNotFound)?;
+    }
+    Ok(Some((parent, last.clone())))
+}
+
+fn add(target: &mut Value, path: &JsonPointer, value: Value) -> Result<(), ConflictKind> {
+    let (parent, last) = match parent_mut(target, path)? {
+        Some(parent) => parent,
+        None => {
+            *target = value;
+            return Ok(());
+        }
+    };
+    match parent {
+        Value::Object(object) => {
+            object.insert(last, value);
+            Ok(())
+        }
+        Value::Array(values) if last == "-" => {
+            values.push(value);
+            Ok(())
+        }
+        Value::Array(values) => match array_index(&last) {
+            Some(index) if index <= values.len() => {
+                values.insert(index, value);
+                Ok(())
+            }
+            _ => Err(ConflictKind::InvalidArrayIndex),
+        },
+        _ => Err(ConflictKind::NotAContainer),
+    }
+}
+
+fn remove(target: &mut Value, path: &JsonPointer) -> Result<Value, ConflictKind> {
+    let (parent, last) = match parent_mut(target, path)? {
+        Some(parent) => parent,
+        None => return Ok(std::mem::replace(target, Value::Null)),
+    };
+    match parent {
+        Value::Object(object) => object.remove(&last).ok_or(ConflictKind::PathNotFound),
+        Value::Array(values) => match array_index(&last) {
+            Some(index) if index < values.len() => Ok(values.remove(index)),
+            _ => Err(ConflictKind::InvalidArrayIndex),
+        },
+        _ => Err(ConflictKind::NotAContainer),
+    }
+}
@@ -0,0 +1,182 @@
+use super::{Conflict, ConflictKind, Patch};
+use crate::{parse, JsonPointer, Value};
+
+fn patch(raw: &str) -> Patch {
+    Patch::from_value(parse(raw).unwrap()).unwrap()
+}
+
+#[test]
+fn apply() {
+    let mut document = parse(r#"{ "a": [1, 2], "b": { "c": true } }"#).unwrap();
+    patch(
+        r#"[
+            { "op": "add", "path": "/a/-", "value": 3 },
+            { "op": "remove", "path": "/a/0" },
+            { "op": "replace", "path": "/b/c", "value": false },
+            { "op": "move", "from": "/b/c", "path": "/d" },
+            { "op": "copy", "from": "/a", "path": "/b/a" },
+            { "op": "test", "path": "/d", "value": false }
+        ]"#,
+    )
+    .apply(&mut document)
+    .unwrap();
+    assert_eq!(
+        document,
+        parse(r#"{ "a": [2, 3], "b": { "a": [2, 3] }, "d": false }"#).unwrap()
+    );
+}
+
+#[test]
+fn apply_is_atomic() {
+    let mut document = parse(r#"{ "a": 1 }"#).unwrap();
+    let result = patch(
+        r#"[
+            { "op": "add", "path": "/b", "value": 2 },
+            { "op": "remove", "path": "/c" }
+        ]"#,
+    )
+    .apply(&mut document);
+    assert_eq!(
+        result,
+        Err(r#"operation 1 at "/c": path not found"#.to_string())
+    );
+    assert_eq!(document, parse(r#"{ "a": 1 }"#).unwrap());
+}
+
+#[test]
+fn unknown_operation() {
+    assert_eq!(
+        Patch::from_value(parse(r#"[{ "op": "merge", "path": "" }]"#).unwrap()),
+        Err("unknown patch operation merge".to_string())
+    );
+}
+
+#[test]
+fn invalid_pointers() {
+    assert_eq!(
+        Patch::from_value(parse(r#"[{ "op": "remove", "path": "c" }]"#).unwrap()),
+        Err("c is not a valid json pointer".to_string())
+    );
+    assert!(Patch::from_value(
+        parse(r#"[{ "op": "copy", "from": "/a~2", "path": "/b" }]"#).unwrap()
+    )
+    .is_err());
+}
+
+#[test]
+fn validate_against_reports_all_conflicts() {
+    let document = parse(r#"{ "a": [1], "b": "text" }"#).unwrap();
+    let conflicts = patch(
+        r#"[
+            { "op": "test", "path": "/b", "value": "other" },
+            { "op": "add", "path": "/a/5", "value": 1 },
+            { "op": "add", "path": "/b/c", "value": 1 },
+            { "op": "move", "from": "/a", "path": "/a/0" },
+            { "op": "copy", "from": "/missing", "path": "/c" }
+        ]"#,
+    )
+    .validate_against(&document);
+    let kinds: Vec<ConflictKind> = conflicts.into_iter().map(|c| c.kind).collect();
+    assert_eq!(
+        kinds,
+        vec![
+            ConflictKind::TestFailed {
+                expected: Value::String("other".to_string()),
+                actual: Value::String("text".to_string()),
+            },
+            ConflictKind::InvalidArrayIndex,
+            ConflictKind::NotAContainer,
+            ConflictKind::MoveIntoChild,
+            ConflictKind::FromNotFound,
+        ]
+    );
+}
+
+#[test]
+fn validate_against_sees_earlier_operations() {
+    let document = parse("{}").unwrap();
+    let conflicts = patch(
+        r#"[
+            { "op": "add", "path": "/a", "value": {} },
+            { "op": "add", "path": "/a/b", "value": 1 },
+            { "op": "remove", "path": "/x/y" }
+        ]"#,
+    )
+    .validate_against(&document);
+    assert_eq!(
+        conflicts,
+        vec![Conflict {
+            index: 2,
+            path: JsonPointer::parse("/x/y").unwrap(),
+            kind: ConflictKind::PathNotFound,
+        }]
+    );
+    assert_eq!(document, parse("{}").unwrap());
+}
+
+#[test]
+fn diff_round_trip() {
+    let pairs = [
+        ("1", r#""1""#),
+        (r#"{ "a": [1, 2] }"#, r#"{ "a": [0, 2, { "b~/c": null }] }"#),
+        (r#"[[1, 2, 3], { "x": 1 }]"#, r#"[[], { "y": 1 }]"#),
+        (r#"{ "a": 1 }"#, r#"{ "a": 1 }"#),
+    ];
+    for &(from, to) in pairs.iter() {
+        let (mut from, to) = (parse(from).unwrap(), parse(to).unwrap());
+        let patch = Patch::diff(&from, &to);
+        assert_eq!(Patch::from_value(patch.to_value()), Ok(patch.clone()));
+        patch.apply(&mut from).unwrap();
+        assert_eq!(from, to);
+    }
+    assert_eq!(
+        Patch::diff(&parse("[1]").unwrap(), &parse("[2]").unwrap()).to_value(),
+        parse(r#"[{ "op": "replace", "path": "/0", "value": 2 }]"#).unwrap()
+    );
+    assert!(Patch::diff(&parse("{}").unwrap(), &parse("{}").unwrap())
+        .0
+        .is_empty());
+}
+
+#[test]
+fn move_pointer() {
+    let mut document = parse(r#"{ "a": [1, 2, 3], "b": {} }"#).unwrap();
+    document.move_pointer("/a/0", "/a/-").unwrap();
+    document.move_pointer("/a", "/b/a").unwrap();
+    document.move_pointer("/b", "/b").unwrap();
+    assert_eq!(document, parse(r#"{ "b": { "a": [2, 3, 1] } }"#).unwrap());
+
+    assert_eq!(
+        document.move_pointer("/x", "/y"),
+        Err(r#"move "/x" to "/y": from path not found"#.to_string())
+    );
+    assert_eq!(
+        document.move_pointer("/b/a/1", "/b/a/5"),
+        Err(r#"move "/b/a/1" to "/b/a/5": invalid array index"#.to_string())
+    );
+    assert_eq!(document, parse(r#"{ "b": { "a": [2, 3, 1] } }"#).unwrap());
+    assert!(document.move_pointer("/b", "/b/a/0").is_err());
+    assert!(document.move_pointer("/b/a", "/b/ab").is_ok());
+}
+
+#[test]
+fn copy_pointer() {
+    let mut document = parse(r#"{ "a": [1] }"#).unwrap();
+    document.copy_pointer("/a/0", "/a/0").unwrap();
+    document.copy_pointer("/a", "").unwrap();
+    assert_eq!(document, parse("[1, 1]").unwrap());
+    assert_eq!(
+        document.copy_pointer("/0", "/a"),
+        Err(r#"copy "/0" to "/a": invalid array index"#.to_string())
+    );
+}
+
+#[test]
+fn pointers_accept_keys_with_slashes() {
+    let mut document = parse(r#"{ "a/b": 1 }"#).unwrap();
+    let from: JsonPointer = vec!["a/b"].into_iter().collect();
+    document.move_pointer(&from, "/c~1d").unwrap();
+    assert_eq!(document, parse(r#"{ "c/d": 1 }"#).unwrap());
+    let diff = Patch::diff(&parse("{}").unwrap(), &document);
+    assert_eq!(diff.0[0].path().tokens(), ["c/d"]);
+}
@@ -0,0 +1,421 @@
+#[cfg(test)]
+mod tests;
+
+use crate::convert::mismatch;
+use crate::{JsonObject, Value};
+use std::borrow::Cow;
+use std::fmt;
+use std::iter::FromIterator;
+use std::str::FromStr;
+
+/// A JSON Pointer (RFC 6901) held as its unescaped reference tokens, so that
+/// keys holding `/` or `~` need no escaping by hand.
+///
+/// # Examples
+///
+/// ```
+///# fn main() -> Result<(),String> {
+///use json::JsonPointer;
+///
+///let mut pointer = JsonPointer::parse("/paths/~1users")?;
+///pointer.push("get");
+///assert_eq!(pointer.tokens(), ["paths", "/users", "get"]);
+///assert_eq!(pointer.to_string(), "/paths/~1users/get");
+///
+///let value = json::parse(r#"{ "paths": { "/users": { "get": true } } }"#)?;
+///assert_eq!(value.pointer(&pointer), Some(&json::Value::Bool(true)));
+///# Ok(())
+///# }
+/// ```
+#[derive(Debug, PartialEq, Eq, Clone, Default, Hash, PartialOrd, Ord)]
+pub struct JsonPointer(Vec<String>);
+
+impl JsonPointer {
+    pub fn root() -> Self {
+        Self::default()
+    }
+
+    /// Parses the text of a pointer, such as `/a~1b/0`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    ///use json::JsonPointer;
+    ///
+    ///assert_eq!(JsonPointer::parse("").map(|p| p.is_root()), Ok(true));
+    ///assert_eq!(
+    ///    JsonPointer::parse("a/b"),
+    ///    Err("a/b is not a valid json pointer".to_string())
+    ///);
+    ///assert!(JsonPointer::parse("/a~2").is_err());
+    /// ```
+    pub fn parse(pointer: &str) -> Result<Self, String> {
+        tokens(pointer).map(JsonPointer)
+    }
+
+    pub fn is_root(&self) -> bool {
+        self.0.is_empty()
+    }
+
+    pub fn tokens(&self) -> &[String] {
+        &self.0
+    }
+
+    pub fn push<T: Into<String>>(&mut self, token: T) {
+        self.0.push(token.into())
+    }
+
+    pub fn pop(&mut self) -> Option<String> {
+        self.0.pop()
+    }
+
+    /// The last token, naming the value within its parent.
+    pub fn last(&self) -> Option<&str> {
+        self.0.last().map(String::as_str)
+    }
+
+    /// The pointer to the parent, if any.
+    pub fn parent(&self) -> Option<JsonPointer> {
+        let (_, parent) = self.0.split_last()?;
+        Some(JsonPointer(parent.to_vec()))
+    }
+
+    /// Escapes `~` and `/` to place `token` in the text of a pointer.
+    pub fn escape_token(token: &str) -> String {
+        escape_token(token)
+    }
+
+    /// Decodes `~1` and `~0` in a token taken from the text of a pointer.
+    pub fn unescape_token(token: &str) -> String {
+        unescape_token(token)
+    }
+}
+
+impl fmt::Display for JsonPointer {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for token in &self.0 {
+            write!(f, "/{}", escape_token(token))?;
+        }
+        Ok(())
+    }
+}
+
+impl FromStr for JsonPointer {
+    type Err = String;
+
+    fn from_str(pointer: &str) -> Result<Self, Self::Err> {
+        JsonPointer::parse(pointer)
+    }
+}
+
+impl<T: Into<String>> FromIterator<T> for JsonPointer {
+    fn from_iter<I: IntoIterator<Item = T>>(tokens: I) -> Self {
+        JsonPointer(tokens.into_iter().map(Into::into).collect())
+    }
+}
+
+/// A pointer given either as text, such as `"/users/0"`, or as a
+/// [`JsonPointer`]. Methods locating values by pointer accept both.
+pub trait AsPointer {
+    /// The unescaped reference tokens, or why the text is no valid pointer.
+    fn pointer_tokens(&self) -> Result<Cow<'_, [String]>, String>;
+
+    /// Like [`AsPointer::pointer_tokens`], but text not starting with `/`
+    /// names a single key, as methods taking a key or a pointer expect.
+    fn key_or_pointer_tokens(&self) -> Result<Cow<'_, [String]>, String> {
+        self.pointer_tokens()
+    }
+}
+
+impl AsPointer for str {
+    fn pointer_tokens(&self) -> Result<Cow<'_, [String]>, String> {
+        tokens(self).map(Cow::Owned)
+    }
+
+    fn key_or_pointer_tokens(&self) -> Result<Cow<'_, [String]>, String> {
+        if self.starts_with('/') {
+            self.pointer_tokens()
+        } else {
+            Ok(Cow::Owned(vec![self.to_string()]))
+        }
+    }
+}
+
+impl AsPointer for String {
+    fn pointer_tokens(&self) -> Result<Cow<'_, [String]>, String> {
+        self.as_str().pointer_tokens()
+    }
+
+    fn key_or_pointer_tokens(&self) -> Result<Cow<'_, [String]>, String> {
+        self.as_str().key_or_pointer_tokens()
+    }
+}
+
+impl AsPointer for JsonPointer {
+    fn pointer_tokens(&self) -> Result<Cow<'_, [String]>, String> {
+        Ok(Cow::Borrowed(&self.0))
+    }
+}
+
+impl<P: AsPointer + ?Sized> AsPointer for &P {
+    fn pointer_tokens(&self) -> Result<Cow<'_, [String]>, String> {
+        (**self).pointer_tokens()
+    }
+
+    fn key_or_pointer_tokens(&self) -> Result<Cow<'_, [String]>, String> {
+        (**self).key_or_pointer_tokens()
+    }
+}
+
+/// The text of a pointer given either way.
+pub(crate) fn pointer_text<P: AsPointer + ?Sized>(pointer: &P) -> Result<String, String> {
+    let tokens = pointer.pointer_tokens()?;
+    Ok(JsonPointer(tokens.into_owned()).to_string())
+}
+
+/// Splits a JSON Pointer (RFC 6901) into its unescaped reference tokens.
+pub(crate) fn tokens(pointer: &str) -> Result<Vec<String>, String> {
+    if pointer.is_empty() {
+        return Ok(Vec::new());
+    }
+    let invalid_escape = pointer
+        .match_indices('~')
+        .any(|(i, _)| !matches!(pointer.as_bytes().get(i + 1), Some(b'0') | Some(b'1')));
+    if !pointer.starts_with('/') || invalid_escape {
+        return Err(format!("{} is not a valid json pointer", pointer));
+    }
+    Ok(pointer[1..].split('/').map(unescape_token).collect())
+}
+
+pub(crate) fn unescape_token(token: &str) -> String {
+    token.replace("~1", "/").replace("~0", "~")
+}
+
+pub(crate) fn escape_token(token: &str) -> String {
+    token.replace('~', "~0").replace('/', "~1")
+}
+
+/// Parses an array index token, rejecting leading zeros and signs.
+pub(crate) fn array_index(token: &str) -> Option<usize> {
+    if token.is_empty() || (token.len() > 1 && token.starts_with('0')) {
+        return None;
+    }
+    if !token.bytes().all(|b| b.is_ascii_digit()) {
+        return None;
+    }
+    token.parse().ok()
+}
+
+impl Value {
+    /// # Examples
+    ///
+    /// ```
+    ///# fn main() -> Result<(),String> {
+    ///let value = json::parse(r#"{ "users": [{ "name": "John Smith" }] }"#)?;
+    ///
+    ///assert_eq!(
+    ///    value.pointer("/users/0/name"),
+    ///    Some(&json::Value::String("John Smith".to_string()))
+    ///);
+    ///assert_eq!(value.pointer("/users/1"), None);
+    ///# Ok(())
+    ///# }
+    /// ```
+    pub fn pointer<P: AsPointer + ?Sized>(&self, pointer: &P) -> Option<&Value> {
+        self.lookup(&pointer.pointer_tokens().ok()?)
+    }
+
+    pub(crate) fn lookup(&self, tokens: &[String]) -> Option<&Value> {
+        let mut current = self;
+        for token in tokens {
+            current = match current {
+                Value::Object(object) => object.get(token)?,
+                Value::Array(values) => values.get(array_index(token)?)?,
+                _ => return None,
+            };
+        }
+        Some(current)
+    }
+
+    pub fn pointer_mut<P: AsPointer + ?Sized>(&mut self, pointer: &P) -> Option<&mut Value> {
+        let mut current = self;
+        for token in pointer.pointer_tokens().ok()?.iter() {
+            current = match current {
+                Value::Object(object) => object.get_mut(token)?,
+                Value::Array(values) => values.get_mut(array_index(token)?)?,
+                _ => return None,
+            };
+        }
+        Some(current)
+    }
+}
+
+/// How [`Value::set_pointer_with`] treats array indexes past the end.
+#[derive(Debug, PartialEq, Eq, Clone, Copy, Default)]
+pub struct SetOptions {
+    /// Pads arrays with `null` up to the index instead of failing, adding at
+    /// most 1024 elements at a time.
+    pub pad_arrays: bool,
+}
+
+/// How many `null`s [`SetOptions::pad_arrays`] adds to an array at most.
+const MAX_PADDING: usize = 1024;
+
+impl Value {
+    /// Stores `value` at `pointer`, creating the missing containers along the
+    /// way: an array when the token that follows is an index or `-`, an
+    /// object otherwise. `null` values found on the way are replaced
+    /// likewise, and `-` appends to an array as in RFC 6902. On error, `self`
+    /// is left as it was.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    ///# fn main() -> Result<(),String> {
+    ///let mut value = json::Value::Null;
+    ///value.set_pointer_mut("/a/b/0/c", json::Value::Bool(true))?;
+    ///value.set_pointer_mut("/a/b/-", json::Value::Null)?;
+    ///value.set_pointer_mut("/a/d", json::Value::Number(1.0))?;
+    ///
+    ///assert_eq!(value, json::parse(r#"{ "a": { "b": [{ "c": true }, null], "d": 1 } }"#)?);
+    ///assert_eq!(
+    ///    value.set_pointer_mut("/a/d/e", json::Value::Null),
+    ///    Err("/a/d: expected object or array, found number".to_string())
+    ///);
+    ///# Ok(())
+    ///# }
+    /// ```
+    pub fn set_pointer_mut<P: AsPointer + ?Sized>(
+        &mut self,
+        pointer: &P,
+        value: Value,
+    ) -> Result<(), String> {
+        self.set_pointer_with(pointer, value, &SetOptions::default())
+    }
+
+    /// Like [`Value::set_pointer_mut`], but indexes past the end of an array
+    /// can pad it with up to 1024 `null`s.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    ///# fn main() -> Result<(),String> {
+    ///let mut value = json::parse(r#"{ "items": [1, 2, 3] }"#)?;
+    ///assert_eq!(
+    ///    value.set_pointer_mut("/items/5", json::Value::Bool(true)),
+    ///    Err("/items: index 5 is out of bounds for array of length 3".to_string())
+    ///);
+    ///
+    ///let options = json::SetOptions { pad_arrays: true };
+    ///value.set_pointer_with("/items/5", json::Value::Bool(true), &options)?;
+    ///assert_eq!(value, json::parse(r#"{ "items": [1, 2, 3, null, null, true] }"#)?);
+    ///# Ok(())
+    ///# }
+    /// ```
+    pub fn set_pointer_with<P: AsPointer + ?Sized>(
+        &mut self,
+        pointer: &P,
+        value: Value,
+        options: &SetOptions,
+    ) -> Result<(), String> {
+        let tokens = pointer.pointer_tokens()?;
+        check_set(self, &tokens, options)?;
+        let mut current = self;
+        for (i, token) in tokens.iter().enumerate() {
+            if *current == Value::Null {
+                *current = if token == "-" || array_index(token).is_some() {
+                    Value::Array(Vec::new())
+                } else {
+                    Value::Object(JsonObject::new())
+                };
+            }
+            current = match current {
+                Value::Object(object) => {
+                    if !object.contains_key(token) {
+                        object.insert(token.clone(), Value::Null);
+                    }
+                    object.get_mut(token).unwrap()
+                }
+                Value::Array(values) => {
+                    let index = set_index(values.len(), token, options)
+                        .map_err(|error| at(&tokens[..i], error))?;
+                    if index >= values.len() {
+                        values.resize(index + 1, Value::Null);
+                    }
+                    &mut values[index]
+                }
+                other => return Err(at(&tokens[..i], mismatch("object or array", other))),
+            };
+        }
+        *current = value;
+        Ok(())
+    }
+}
+
+/// Checks that [`Value::set_pointer_with`] can store a value at `tokens`
+/// before it changes anything, so that a failed call leaves `value` as it
+/// was.
+fn check_set(value: &Value, tokens: &[String], options: &SetOptions) -> Result<(), String> {
+    let mut current = Some(value);
+    for (i, token) in tokens.iter().enumerate() {
+        current = match current {
+            Some(Value::Object(object)) => object.get(token),
+            Some(Value::Array(values)) => {
+                let index = set_index(values.len(), token, options)
+                    .map_err(|error| at(&tokens[..i], error))?;
+                values.get(index)
+            }
+            None | Some(Value::Null) if token == "-" || array_index(token).is_some() => {
+                set_index(0, token, options).map_err(|error| at(&tokens[..i], error))?;
+                None
+            }
+            None | Some(Value::Null) => None,
+            Some(other) => return Err(at(&tokens[..i], mismatch("object or array", other))),
+        };
+    }
+    Ok(())
+}
+
+/// The index `token` stands for in an array of length `len` that a value is
+/// stored into.
+fn set_index(len: usize, token: &str, options: &SetOptions) -> Result<usize, String> {
+    let index = if token == "-" {
+        len
+    } else {
+        array_index(token).ok_or_else(|| format!("expected an array index, found {}", token))?
+    };
+    if index > len && !options.pad_arrays {
+        return Err(format!(
+            "index {} is out of bounds for array of length {}",
+            index, len
+        ));
+    }
+    if index > len + MAX_PADDING {
+        return Err(format!(
+            "index {} exceeds the padding limit of {} for array of length {}",
+            index, MAX_PADDING, len
+        ));
+    }
+    Ok(index)
+}
+
+/// Prefixes `error` with the pointer to where it happened.
+fn at(tokens: &[String], error: String) -> String {
+    if tokens.is_empty() {
+        error
+    } else {
+        let path: String = tokens
+            .iter()
+            .map(|token| format!("/{}", escape_token(token)))
+            .collect();
+        format!("{}: {}", path, error)
+    }
+}
+
+impl Value {
+    /// Looks up `path` as a JSON Pointer, or as a key of `self` when given as
+    /// text not starting with `/`.
+    pub(crate) fn field<P: AsPointer + ?Sized>(&self, path: &P) -> Option<&Value> {
+        self.lookup(&path.key_or_pointer_tokens().ok()?)
+    }
+}
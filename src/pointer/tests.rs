@@ -0,0 +1,156 @@
+use super::SetOptions;
+use crate::{parse, JsonPointer, Value};
+
+#[test]
+fn set_pointer_mut_creates_containers() {
+    let mut value = parse(r#"{ "a": null, "b": [] }"#).unwrap();
+    value.set_pointer_mut("/a/0/0", Value::Bool(true)).unwrap();
+    value.set_pointer_mut("/b/0/x~1y", Value::Null).unwrap();
+    value.set_pointer_mut("/b/1", Value::Number(2.0)).unwrap();
+    value.set_pointer_mut("/c/01", Value::Number(3.0)).unwrap();
+    assert_eq!(
+        value,
+        parse(r#"{ "a": [[true]], "b": [{ "x/y": null }, 2], "c": { "01": 3 } }"#).unwrap()
+    );
+
+    value.set_pointer_mut("", Value::Null).unwrap();
+    assert_eq!(value, Value::Null);
+}
+
+#[test]
+fn set_pointer_mut_errors() {
+    let mut value = parse(r#"{ "a": [1, 2, 3], "b": "c" }"#).unwrap();
+    let mut set = |pointer: &str| value.set_pointer_mut(pointer, Value::Null);
+    assert_eq!(
+        set("/a/10"),
+        Err("/a: index 10 is out of bounds for array of length 3".to_string())
+    );
+    assert_eq!(
+        set("/a/x"),
+        Err("/a: expected an array index, found x".to_string())
+    );
+    assert_eq!(
+        set("/b/c"),
+        Err("/b: expected object or array, found string".to_string())
+    );
+    assert_eq!(set("a"), Err("a is not a valid json pointer".to_string()));
+    assert_eq!(
+        Value::Bool(true).set_pointer_mut("/a", Value::Null),
+        Err("expected object or array, found bool".to_string())
+    );
+}
+
+#[test]
+fn set_pointer_with_padding_and_append() {
+    let mut value = parse(r#"{ "items": [1] }"#).unwrap();
+    value
+        .set_pointer_mut("/items/-", Value::Number(2.0))
+        .unwrap();
+    value
+        .set_pointer_mut("/new/-/-", Value::Number(3.0))
+        .unwrap();
+    assert_eq!(
+        value,
+        parse(r#"{ "items": [1, 2], "new": [[3]] }"#).unwrap()
+    );
+
+    let options = SetOptions { pad_arrays: true };
+    value
+        .set_pointer_with("/items/3", Value::Bool(false), &options)
+        .unwrap();
+    value
+        .set_pointer_with("/grid/1/2", Value::Bool(true), &options)
+        .unwrap();
+    assert_eq!(
+        value.pointer("/items"),
+        Some(&parse("[1, 2, null, false]").unwrap())
+    );
+    assert_eq!(
+        value.pointer("/grid"),
+        Some(&parse("[null, [null, null, true]]").unwrap())
+    );
+    assert!(value
+        .set_pointer_with("/items/x", Value::Null, &options)
+        .is_err());
+}
+
+#[test]
+fn set_pointer_with_bounded_padding() {
+    let options = SetOptions { pad_arrays: true };
+    let mut value = parse(r#"{ "a": [] }"#).unwrap();
+    assert_eq!(
+        value.set_pointer_with("/a/4294967295", Value::Null, &options),
+        Err(
+            "/a: index 4294967295 exceeds the padding limit of 1024 for array of length 0"
+                .to_string()
+        )
+    );
+    value
+        .set_pointer_with("/a/1024", Value::Null, &options)
+        .unwrap();
+    assert_eq!(value.pointer("/a/1024"), Some(&Value::Null));
+    assert_eq!(value.pointer("/a/1025"), None);
+    assert_eq!(
+        value.set_pointer_with("/a/2050", Value::Null, &options),
+        Err(
+            "/a: index 2050 exceeds the padding limit of 1024 for array of length 1025".to_string()
+        )
+    );
+}
+
+#[test]
+fn json_pointer_parsing() {
+    let pointer: JsonPointer = "/a~1b/~0/0".parse().unwrap();
+    assert_eq!(pointer.tokens(), ["a/b", "~", "0"]);
+    assert_eq!(pointer.last(), Some("0"));
+    assert_eq!(pointer.parent().unwrap().to_string(), "/a~1b/~0");
+    assert_eq!(JsonPointer::root().parent(), None);
+    assert_eq!(JsonPointer::parse("/").unwrap().tokens(), [""]);
+    for invalid in ["a", "/~", "/a~x"].iter() {
+        assert_eq!(
+            JsonPointer::parse(invalid),
+            Err(format!("{} is not a valid json pointer", invalid))
+        );
+    }
+    let built: JsonPointer = vec!["x/y", "z"].into_iter().collect();
+    assert_eq!(built.to_string(), "/x~1y/z");
+    assert_eq!(JsonPointer::escape_token("~/"), "~0~1");
+    assert_eq!(JsonPointer::unescape_token("~01"), "~1");
+}
+
+#[test]
+fn pointer_apis_accept_json_pointers() {
+    let mut value = parse(r#"{ "a/b": [1] }"#).unwrap();
+    let pointer: JsonPointer = vec!["a/b", "0"].into_iter().collect();
+    assert_eq!(value.pointer(&pointer), Some(&Value::Number(1.0)));
+    *value.pointer_mut(&pointer).unwrap() = Value::Number(2.0);
+    value
+        .set_pointer_mut(&pointer.parent().unwrap(), Value::Null)
+        .unwrap();
+    let text = "/c".to_string();
+    value.copy_pointer("/a~1b", &text).unwrap();
+    value
+        .move_pointer(&text, &JsonPointer::parse("/d").unwrap())
+        .unwrap();
+    assert_eq!(value, parse(r#"{ "a/b": null, "d": null }"#).unwrap());
+    assert_eq!(value.pointer("/a~2b"), None);
+}
+
+#[test]
+fn failed_set_leaves_value_unchanged() {
+    let original = parse(r#"{ "a": null, "b": [1], "c": 2 }"#).unwrap();
+    let padded = SetOptions { pad_arrays: true };
+    let cases = [
+        ("/a/x/0/y/3", SetOptions::default()),
+        ("/new/-/k/2000", padded),
+        ("/b/-/x/1", SetOptions::default()),
+        ("/c/d", padded),
+    ];
+    for (pointer, options) in cases.iter() {
+        let mut value = original.clone();
+        assert!(value
+            .set_pointer_with(*pointer, Value::Null, options)
+            .is_err());
+        assert_eq!(value, original, "{}", pointer);
+    }
+}
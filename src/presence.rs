@@ -0,0 +1,74 @@
+#[cfg(test)]
+mod tests;
+
+use crate::pointer::AsPointer;
+use crate::{JsonObject, Value};
+
+/// Whether a field was omitted, explicitly set to `null`, or set to a value.
+#[derive(Debug, PartialEq, Clone, Copy)]
+pub enum Presence<'a> {
+    Missing,
+    Null,
+    Present(&'a Value),
+}
+
+impl<'a> Presence<'a> {
+    fn of(value: Option<&'a Value>) -> Self {
+        match value {
+            None => Presence::Missing,
+            Some(Value::Null) => Presence::Null,
+            Some(value) => Presence::Present(value),
+        }
+    }
+
+    pub fn is_missing(&self) -> bool {
+        matches!(self, Presence::Missing)
+    }
+
+    pub fn is_null(&self) -> bool {
+        matches!(self, Presence::Null)
+    }
+
+    /// `None` when missing, `Some(None)` when `null`.
+    pub fn into_option(self) -> Option<Option<&'a Value>> {
+        match self {
+            Presence::Missing => None,
+            Presence::Null => Some(None),
+            Presence::Present(value) => Some(Some(value)),
+        }
+    }
+}
+
+impl JsonObject {
+    /// # Examples
+    ///
+    /// ```
+    ///# fn main() -> Result<(),String> {
+    ///use json::Presence;
+    ///
+    ///let patch = json::parse(r#"{ "nickname": null, "age": 42 }"#)?.to_object()?;
+    ///
+    ///assert_eq!(patch.presence("nickname"), Presence::Null);
+    ///assert_eq!(patch.presence("email"), Presence::Missing);
+    ///assert_eq!(patch.presence("age"), Presence::Present(&json::Value::Number(42.0)));
+    ///# Ok(())
+    ///# }
+    /// ```
+    pub fn presence(&self, key: &str) -> Presence<'_> {
+        Presence::of(self.get(key))
+    }
+}
+
+impl Value {
+    /// The presence of `key` in an object; any other value has no keys.
+    pub fn presence(&self, key: &str) -> Presence<'_> {
+        match self {
+            Value::Object(object) => object.presence(key),
+            _ => Presence::Missing,
+        }
+    }
+
+    pub fn pointer_presence<P: AsPointer + ?Sized>(&self, pointer: &P) -> Presence<'_> {
+        Presence::of(self.pointer(pointer))
+    }
+}
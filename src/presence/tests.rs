@@ -0,0 +1,29 @@
+use super::Presence;
+use crate::{parse, Value};
+
+#[test]
+fn presence() {
+    let value = parse(r#"{ "a": null, "b": { "c": false, "d": null } }"#).unwrap();
+    assert_eq!(value.presence("a"), Presence::Null);
+    assert_eq!(value.presence("z"), Presence::Missing);
+    assert_eq!(
+        value.pointer_presence("/b/c"),
+        Presence::Present(&Value::Bool(false))
+    );
+    assert_eq!(value.pointer_presence("/b/d"), Presence::Null);
+    assert_eq!(value.pointer_presence("/b/e"), Presence::Missing);
+    assert_eq!(Value::Null.presence("a"), Presence::Missing);
+}
+
+#[test]
+fn into_option() {
+    let value = parse(r#"{ "a": null, "b": 1 }"#).unwrap();
+    assert_eq!(value.presence("a").into_option(), Some(None));
+    assert_eq!(
+        value.presence("b").into_option(),
+        Some(Some(&Value::Number(1.0)))
+    );
+    assert_eq!(value.presence("c").into_option(), None);
+    assert!(value.presence("a").is_null());
+    assert!(value.presence("c").is_missing());
+}
@@ -0,0 +1,77 @@
+#[cfg(test)]
+mod tests;
+
+use crate::Value;
+
+/// Which object members [`Value::prune`] removes. All of them by default.
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub struct PruneOptions {
+    pub nulls: bool,
+    pub empty_objects: bool,
+    pub empty_arrays: bool,
+}
+
+impl Default for PruneOptions {
+    fn default() -> Self {
+        PruneOptions {
+            nulls: true,
+            empty_objects: true,
+            empty_arrays: true,
+        }
+    }
+}
+
+impl PruneOptions {
+    fn removes(&self, value: &Value) -> bool {
+        match value {
+            Value::Null => self.nulls,
+            Value::Object(object) => self.empty_objects && object.is_empty(),
+            Value::Array(values) => self.empty_arrays && values.is_empty(),
+            _ => false,
+        }
+    }
+}
+
+impl Value {
+    /// Removes, throughout the tree, the object members `options` selects and
+    /// returns how many were removed. Members are pruned after their own
+    /// members, so an object left empty goes too. Array elements are kept to
+    /// preserve the positions of the others.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    ///# fn main() -> Result<(),String> {
+    ///let mut value = json::parse(r#"{ "a": null, "b": { "c": [] }, "d": [null, {}], "e": 0 }"#)?;
+    ///
+    ///assert_eq!(value.prune(&json::PruneOptions::default()), 3);
+    ///assert_eq!(value, json::parse(r#"{ "d": [null, {}], "e": 0 }"#)?);
+    ///
+    ///let mut value = json::parse(r#"{ "a": null, "b": [] }"#)?;
+    ///let options = json::PruneOptions { empty_arrays: false, ..Default::default() };
+    ///value.prune(&options);
+    ///assert_eq!(value, json::parse(r#"{ "b": [] }"#)?);
+    ///# Ok(())
+    ///# }
+    /// ```
+    pub fn prune(&mut self, options: &PruneOptions) -> usize {
+        match self {
+            Value::Array(values) => values.iter_mut().map(|v| v.prune(options)).sum(),
+            Value::Object(object) => {
+                let mut removed = 0;
+                let mut kept = Vec::new();
+                for (key, mut value) in std::mem::take(object) {
+                    removed += value.prune(options);
+                    if options.removes(&value) {
+                        removed += 1;
+                    } else {
+                        kept.push((key, value));
+                    }
+                }
+                *object = kept.into_iter().collect();
+                removed
+            }
+            _ => 0,
+        }
+    }
+}
@@ -0,0 +1,47 @@
+use super::PruneOptions;
+use crate::parse;
+
+#[test]
+fn prune_cascades() {
+    let mut value = parse(r#"[{ "a": { "b": { "c": null } } }, { "d": [[]] }]"#).unwrap();
+    assert_eq!(value.prune(&PruneOptions::default()), 3);
+    assert_eq!(value, parse(r#"[{}, { "d": [[]] }]"#).unwrap());
+}
+
+#[test]
+fn prune_toggles() {
+    let document = r#"{ "a": null, "b": {}, "c": [], "d": { "e": null } }"#;
+    let prune = |options: PruneOptions| {
+        let mut value = parse(document).unwrap();
+        value.prune(&options);
+        value
+    };
+    let none = PruneOptions {
+        nulls: false,
+        empty_objects: false,
+        empty_arrays: false,
+    };
+    assert_eq!(prune(none), parse(document).unwrap());
+    assert_eq!(
+        prune(PruneOptions {
+            nulls: true,
+            ..none
+        }),
+        parse(r#"{ "b": {}, "c": [], "d": {} }"#).unwrap()
+    );
+    assert_eq!(
+        prune(PruneOptions {
+            empty_objects: true,
+            ..none
+        }),
+        parse(r#"{ "a": null, "c": [], "d": { "e": null } }"#).unwrap()
+    );
+    assert_eq!(
+        prune(PruneOptions {
+            nulls: true,
+            empty_objects: true,
+            ..none
+        }),
+        parse(r#"{ "c": [] }"#).unwrap()
+    );
+}
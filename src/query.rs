@@ -0,0 +1,275 @@
+#[cfg(test)]
+mod tests;
+
+use crate::convert::mismatch;
+use crate::reference::percent_decode;
+use crate::{to_string, JsonObject, Value};
+
+/// How arrays are spelled in a query string.
+#[derive(Debug, PartialEq, Clone, Copy)]
+pub enum ArrayFormat {
+    /// `a[]=1&a[]=2`, the default.
+    Brackets,
+    /// `a[0]=1&a[1]=2`.
+    Indices,
+    /// `a=1&a=2`.
+    Repeat,
+    /// `a=1,2`. Reading splits every value holding a comma.
+    Comma,
+}
+
+/// How [`from_query_string_with`] and [`to_query_string_with`] map query
+/// strings onto JSON.
+#[derive(Debug, PartialEq, Clone, Copy)]
+pub struct QueryOptions {
+    /// How arrays are written, and whether values are split on commas when
+    /// read. Brackets, indices and repeated keys are all understood when
+    /// reading.
+    pub arrays: ArrayFormat,
+}
+
+impl Default for QueryOptions {
+    fn default() -> Self {
+        QueryOptions {
+            arrays: ArrayFormat::Brackets,
+        }
+    }
+}
+
+/// Parses an `application/x-www-form-urlencoded` query string, such as a
+/// form submission, with the default [`QueryOptions`].
+///
+/// # Examples
+///
+/// ```
+///# fn main() -> Result<(),String> {
+///let value = json::from_query_string("a=1&b[]=2&b[]=3&c[d]=x+y%21")?;
+///
+///assert_eq!(value, json::parse(r#"{ "a": "1", "b": ["2", "3"], "c": { "d": "x y!" } }"#)?);
+///# Ok(())
+///# }
+/// ```
+pub fn from_query_string(text: &str) -> Result<Value, String> {
+    from_query_string_with(text, &QueryOptions::default())
+}
+
+/// Parses a query string into an object, nesting values under the keys
+/// their brackets name: `c[d]=x` sets `d` in the object under `c`, `b[]=x`
+/// appends to the array under `b` and `b[0]=x` sets or appends its first
+/// item. A key repeated without brackets gathers its values in an array.
+/// `+` stands for a space and every value stays a string; see
+/// [`Value::coerce_with`] to convert them.
+///
+/// # Examples
+///
+/// ```
+///# fn main() -> Result<(),String> {
+///let options = json::QueryOptions { arrays: json::ArrayFormat::Comma };
+///
+///assert_eq!(json::from_query_string_with("tags=a,b&q", &options)?, json::parse(r#"{ "tags": ["a", "b"], "q": "" }"#)?);
+///assert_eq!(
+///    json::from_query_string("a=1&a[b]=2"),
+///    Err("conflicting values for \"a[b]\"".to_string())
+///);
+///# Ok(())
+///# }
+/// ```
+pub fn from_query_string_with(text: &str, options: &QueryOptions) -> Result<Value, String> {
+    let mut root = Value::Object(JsonObject::new());
+    for pair in text.split('&').filter(|pair| !pair.is_empty()) {
+        let (key, value) = pair.split_once('=').unwrap_or((pair, ""));
+        let value = if options.arrays == ArrayFormat::Comma && value.contains(',') {
+            Value::Array(
+                value
+                    .split(',')
+                    .map(|item| decode(item).map(Value::String))
+                    .collect::<Result<_, _>>()?,
+            )
+        } else {
+            Value::String(decode(value)?)
+        };
+        let segments = segments(key)
+            .into_iter()
+            .map(decode)
+            .collect::<Result<Vec<_>, _>>()?;
+        let conflict = || {
+            format!(
+                "conflicting values for {:?}",
+                decode(key).unwrap_or_default()
+            )
+        };
+        insert(&mut root, &segments, value).ok_or_else(conflict)?;
+    }
+    Ok(root)
+}
+
+fn decode(text: &str) -> Result<String, String> {
+    percent_decode(&text.replace('+', " "))
+}
+
+/// Splits `a[b][]` into `a`, `b` and an empty segment. Keys whose brackets
+/// don't pair up are kept whole.
+fn segments(key: &str) -> Vec<&str> {
+    let (name, mut rest) = match key.find('[') {
+        Some(i) if i > 0 => key.split_at(i),
+        _ => return vec![key],
+    };
+    let mut segments = vec![name];
+    while !rest.is_empty() {
+        match rest.strip_prefix('[').and_then(|rest| rest.split_once(']')) {
+            Some((segment, tail)) if !segment.contains('[') => {
+                segments.push(segment);
+                rest = tail;
+            }
+            _ => return vec![key],
+        }
+    }
+    segments
+}
+
+/// Sets the value at the path `segments` name, creating the containers on
+/// the way, or returns `None` if the path runs into a value of another
+/// kind.
+fn insert(target: &mut Value, segments: &[String], value: Value) -> Option<()> {
+    let (segment, rest) = match segments.split_first() {
+        Some(split) => split,
+        None => {
+            match target {
+                Value::Null => *target = value,
+                Value::String(_) => *target = Value::Array(vec![target.clone(), value]),
+                Value::Array(values) if !values.iter().any(is_container) => values.push(value),
+                _ => return None,
+            }
+            return Some(());
+        }
+    };
+    let container = || match rest.first() {
+        Some(next) if next.is_empty() || next.parse::<usize>().is_ok() => Value::Array(Vec::new()),
+        Some(_) => Value::Object(JsonObject::new()),
+        None => Value::Null,
+    };
+    match target {
+        Value::Object(object) => {
+            if !object.contains_key(segment) {
+                object.insert(segment.clone(), container());
+            }
+            insert(object.get_mut(segment).unwrap(), rest, value)
+        }
+        Value::Array(values) if segment.is_empty() => {
+            values.push(container());
+            insert(values.last_mut().unwrap(), rest, value)
+        }
+        Value::Array(values) => match segment.parse::<usize>() {
+            Ok(index) if index < values.len() => insert(&mut values[index], rest, value),
+            Ok(_) => {
+                values.push(container());
+                insert(values.last_mut().unwrap(), rest, value)
+            }
+            Err(_) => None,
+        },
+        _ => None,
+    }
+}
+
+fn is_container(value: &Value) -> bool {
+    matches!(value, Value::Array(_) | Value::Object(_))
+}
+
+/// Writes an object as a query string with the default [`QueryOptions`].
+///
+/// # Examples
+///
+/// ```
+///# fn main() -> Result<(),String> {
+///let value = json::parse(r#"{ "q": "rust & json", "page": 2, "tags": ["a", "b"], "f": { "lang": "en" } }"#)?;
+///
+///assert_eq!(json::to_query_string(&value)?, "q=rust+%26+json&page=2&tags[]=a&tags[]=b&f[lang]=en");
+///# Ok(())
+///# }
+/// ```
+pub fn to_query_string(value: &Value) -> Result<String, String> {
+    to_query_string_with(value, &QueryOptions::default())
+}
+
+/// Writes an object as a query string, reversing [`from_query_string_with`].
+/// Nested objects become bracketed keys and arrays are spelled as
+/// `options.arrays` says, except that arrays of containers always use
+/// indices to keep each item's keys together. Numbers and booleans are
+/// written as their JSON text, `null` as an empty value, and empty arrays
+/// and objects are left out.
+///
+/// # Examples
+///
+/// ```
+///# fn main() -> Result<(),String> {
+///let value = json::parse(r#"{ "ids": [1, 2], "sort": null }"#)?;
+///let options = json::QueryOptions { arrays: json::ArrayFormat::Repeat };
+///
+///assert_eq!(json::to_query_string_with(&value, &options)?, "ids=1&ids=2&sort=");
+///# Ok(())
+///# }
+/// ```
+pub fn to_query_string_with(value: &Value, options: &QueryOptions) -> Result<String, String> {
+    let object = match value {
+        Value::Object(object) => object,
+        value => return Err(mismatch("object", value)),
+    };
+    let mut pairs = Vec::new();
+    for (key, value) in object.iter() {
+        write_pairs(&mut pairs, &encode(key), value, options);
+    }
+    Ok(pairs.join("&"))
+}
+
+fn write_pairs(pairs: &mut Vec<String>, key: &str, value: &Value, options: &QueryOptions) {
+    match value {
+        Value::Object(object) => {
+            for (name, value) in object.iter() {
+                write_pairs(pairs, &format!("{}[{}]", key, encode(name)), value, options);
+            }
+        }
+        Value::Array(values) if values.iter().any(is_container) => {
+            for (i, value) in values.iter().enumerate() {
+                write_pairs(pairs, &format!("{}[{}]", key, i), value, options);
+            }
+        }
+        Value::Array(values) if options.arrays == ArrayFormat::Comma && !values.is_empty() => {
+            let items: Vec<String> = values.iter().map(|value| encode(&text(value))).collect();
+            pairs.push(format!("{}={}", key, items.join(",")));
+        }
+        Value::Array(values) => {
+            for (i, value) in values.iter().enumerate() {
+                let key = match options.arrays {
+                    ArrayFormat::Brackets => format!("{}[]", key),
+                    ArrayFormat::Indices => format!("{}[{}]", key, i),
+                    ArrayFormat::Repeat | ArrayFormat::Comma => key.to_string(),
+                };
+                pairs.push(format!("{}={}", key, encode(&text(value))));
+            }
+        }
+        value => pairs.push(format!("{}={}", key, encode(&text(value)))),
+    }
+}
+
+fn text(value: &Value) -> String {
+    match value {
+        Value::String(value) => value.clone(),
+        Value::Null => String::new(),
+        value => to_string(value),
+    }
+}
+
+/// Percent-encodes all but the unreserved characters, with `+` for spaces.
+fn encode(text: &str) -> String {
+    let mut output = String::with_capacity(text.len());
+    for byte in text.bytes() {
+        match byte {
+            b'A'..=b'Z' | b'a'..=b'z' | b'0'..=b'9' | b'-' | b'.' | b'_' | b'~' => {
+                output.push(byte as char)
+            }
+            b' ' => output.push('+'),
+            byte => output.push_str(&format!("%{:02X}", byte)),
+        }
+    }
+    output
+}
@@ -0,0 +1,57 @@
+use super::{
+    from_query_string, from_query_string_with, to_query_string_with, ArrayFormat, QueryOptions,
+};
+use crate::parse;
+
+#[test]
+fn nesting() {
+    assert_eq!(
+        from_query_string(
+            "u[0][name]=a&u[0][role]=x&u[1][name]=b&ids=1&ids=2&e=&&k%5Bx%5D=1&m[x=2"
+        ),
+        parse(
+            r#"{ "u": [{ "name": "a", "role": "x" }, { "name": "b" }], "ids": ["1", "2"], "e": "",
+                 "k[x]": "1", "m[x": "2" }"#
+        )
+    );
+    assert_eq!(
+        from_query_string("a[5]=x&a[9]=y"),
+        parse(r#"{ "a": ["x", "y"] }"#)
+    );
+}
+
+#[test]
+fn round_trip() {
+    let value = parse(
+        r#"{ "a b": "c&d=e", "n": [1, 2], "o": { "p": [{ "q": "r" }, { "q": "s" }] }, "t": true }"#,
+    )
+    .unwrap();
+    for arrays in [
+        ArrayFormat::Brackets,
+        ArrayFormat::Indices,
+        ArrayFormat::Repeat,
+        ArrayFormat::Comma,
+    ] {
+        let options = QueryOptions { arrays };
+        let text = to_query_string_with(&value, &options).unwrap();
+        let mut back = from_query_string_with(&text, &options).unwrap();
+        back.coerce_with(&crate::CoercionRules::default());
+        assert_eq!(back, value, "{}", text);
+    }
+}
+
+#[test]
+fn errors() {
+    assert_eq!(
+        from_query_string("a[]=1&a[x]=2"),
+        Err("conflicting values for \"a[x]\"".to_string())
+    );
+    assert_eq!(
+        from_query_string("a[b]=1&a=2"),
+        Err("conflicting values for \"a\"".to_string())
+    );
+    assert_eq!(
+        from_query_string("a=%zz"),
+        Err("invalid percent-encoding in \"%zz\"".to_string())
+    );
+}
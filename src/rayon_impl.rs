@@ -0,0 +1,167 @@
+#[cfg(test)]
+mod tests;
+
+use crate::{parse, JsonObject, Value};
+use rayon::iter::{
+    Either, IntoParallelIterator, IntoParallelRefIterator, IntoParallelRefMutIterator, Map,
+    ParallelBridge, ParallelIterator,
+};
+use std::sync::mpsc::{Receiver, Sender};
+
+/// Parses every document on the rayon thread pool, returning the results in
+/// the order of `docs`.
+///
+/// # Examples
+///
+/// ```
+///let results = json::parse_all_parallel(&["[1, 2]", "{", "null"]);
+///
+///assert_eq!(results[0], json::parse("[1, 2]"));
+///assert!(results[1].is_err());
+///assert_eq!(results[2], Ok(json::Value::Null));
+/// ```
+pub fn parse_all_parallel(docs: &[&str]) -> Vec<Result<Value, String>> {
+    docs.par_iter().map(|doc| parse(*doc)).collect()
+}
+
+/// Parses the documents arriving on `input` on the rayon thread pool and
+/// sends each result on `output` along with the tag it came with, in no
+/// particular order. Returns once `input` is disconnected, which drops the
+/// senders so that `output`'s receiver ends too.
+///
+/// # Examples
+///
+/// ```
+///use std::sync::mpsc::channel;
+///use std::thread;
+///
+///let (documents, input) = channel();
+///let (output, results) = channel();
+///let worker = thread::spawn(move || json::parse_stream(input, output));
+///
+///for (line, text) in ["1", "[", "\"a\""].iter().enumerate() {
+///    documents.send((line, text.to_string())).unwrap();
+///}
+///drop(documents);
+///
+///let mut results: Vec<_> = results.iter().collect();
+///results.sort_by_key(|(line, _)| *line);
+///assert_eq!(results[0], (0, Ok(json::Value::Number(1.0))));
+///assert!(results[1].1.is_err());
+///worker.join().unwrap();
+/// ```
+pub fn parse_stream<T: Send>(
+    input: Receiver<(T, String)>,
+    output: Sender<(T, Result<Value, String>)>,
+) {
+    input
+        .into_iter()
+        .par_bridge()
+        .for_each_with(output, |output, (tag, doc)| {
+            // A disconnected receiver only means nobody wants the results.
+            let _ = output.send((tag, parse(doc.as_str())));
+        });
+}
+
+type Entry = (String, Value);
+
+impl IntoParallelIterator for JsonObject {
+    type Item = Entry;
+    type Iter = rayon::vec::IntoIter<Entry>;
+
+    fn into_par_iter(self) -> Self::Iter {
+        self.entries.into_par_iter()
+    }
+}
+
+impl<'a> IntoParallelIterator for &'a JsonObject {
+    type Item = (&'a String, &'a Value);
+    type Iter = Map<rayon::slice::Iter<'a, Entry>, fn(&'a Entry) -> Self::Item>;
+
+    fn into_par_iter(self) -> Self::Iter {
+        self.entries.par_iter().map(|(key, value)| (key, value))
+    }
+}
+
+/// Keys can't be changed, as that would invalidate the index.
+impl<'a> IntoParallelIterator for &'a mut JsonObject {
+    type Item = (&'a String, &'a mut Value);
+    type Iter = Map<rayon::slice::IterMut<'a, Entry>, fn(&'a mut Entry) -> Self::Item>;
+
+    fn into_par_iter(self) -> Self::Iter {
+        self.entries
+            .par_iter_mut()
+            .map(|(key, value)| (&*key, value))
+    }
+}
+
+/// Like [`Value::values`], iterates over the elements of an array or the
+/// values of an object, and over nothing for any other value.
+///
+/// # Examples
+///
+/// ```
+///# fn main() -> Result<(),String> {
+///use rayon::prelude::*;
+///
+///let rows = json::parse(r#"[{ "price": 2 }, { "price": 3 }, { "price": 5 }]"#)?;
+///
+///let total: f64 = rows
+///    .par_iter()
+///    .filter_map(|row| row.pointer("/price")?.clone().to_number().ok())
+///    .sum();
+///assert_eq!(total, 10.0);
+///# Ok(())
+///# }
+/// ```
+impl IntoParallelIterator for Value {
+    type Item = Value;
+    type Iter =
+        Either<rayon::vec::IntoIter<Value>, Map<rayon::vec::IntoIter<Entry>, fn(Entry) -> Value>>;
+
+    fn into_par_iter(self) -> Self::Iter {
+        match self {
+            Value::Object(object) => {
+                Either::Right(object.entries.into_par_iter().map(|(_, value)| value))
+            }
+            Value::Array(values) => Either::Left(values.into_par_iter()),
+            _ => Either::Left(Vec::new().into_par_iter()),
+        }
+    }
+}
+
+impl<'a> IntoParallelIterator for &'a Value {
+    type Item = &'a Value;
+    type Iter = Either<
+        rayon::slice::Iter<'a, Value>,
+        Map<rayon::slice::Iter<'a, Entry>, fn(&'a Entry) -> &'a Value>,
+    >;
+
+    fn into_par_iter(self) -> Self::Iter {
+        match self {
+            Value::Object(object) => {
+                Either::Right(object.entries.par_iter().map(|(_, value)| value))
+            }
+            Value::Array(values) => Either::Left(values.par_iter()),
+            _ => Either::Left([].par_iter()),
+        }
+    }
+}
+
+impl<'a> IntoParallelIterator for &'a mut Value {
+    type Item = &'a mut Value;
+    type Iter = Either<
+        rayon::slice::IterMut<'a, Value>,
+        Map<rayon::slice::IterMut<'a, Entry>, fn(&'a mut Entry) -> &'a mut Value>,
+    >;
+
+    fn into_par_iter(self) -> Self::Iter {
+        match self {
+            Value::Object(object) => {
+                Either::Right(object.entries.par_iter_mut().map(|(_, value)| value))
+            }
+            Value::Array(values) => Either::Left(values.par_iter_mut()),
+            _ => Either::Left([].par_iter_mut()),
+        }
+    }
+}
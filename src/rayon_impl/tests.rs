@@ -0,0 +1,62 @@
+use super::{parse_all_parallel, parse_stream};
+use crate::{parse, Value};
+use rayon::iter::IntoParallelRefIterator;
+use std::sync::mpsc::channel;
+
+#[test]
+fn keeps_order() {
+    let docs: Vec<String> = (0..1000).map(|i| format!("[{}]", i)).collect();
+    let docs: Vec<&str> = docs.iter().map(String::as_str).collect();
+    let results = parse_all_parallel(&docs);
+    assert_eq!(results.len(), 1000);
+    for (doc, result) in docs.iter().zip(results) {
+        assert_eq!(result, parse(*doc));
+    }
+    assert!(parse_all_parallel(&[]).is_empty());
+}
+
+#[test]
+fn stream() {
+    let (documents, input) = channel();
+    let (output, results) = channel();
+    for i in 0..100 {
+        documents.send((i, i.to_string())).unwrap();
+    }
+    drop(documents);
+    parse_stream(input, output);
+    let mut results: Vec<_> = results.iter().collect();
+    results.sort_by_key(|(i, _)| *i);
+    assert_eq!(results.len(), 100);
+    assert!(results
+        .into_iter()
+        .all(|(i, result)| result == Ok(Value::Number(i as f64))));
+}
+
+#[test]
+fn parallel_iterators() {
+    use rayon::iter::{IntoParallelIterator, IntoParallelRefMutIterator, ParallelIterator};
+
+    let mut value = parse(r#"{ "a": 1, "b": [2, 3], "c": "x" }"#).unwrap();
+    value
+        .par_iter_mut()
+        .for_each(|value| *value = Value::Bool(true));
+    assert_eq!(
+        value,
+        parse(r#"{ "a": true, "b": true, "c": true }"#).unwrap()
+    );
+    assert_eq!(parse("[1, 2, 3]").unwrap().into_par_iter().count(), 3);
+    assert_eq!(parse("1").unwrap().par_iter().count(), 0);
+
+    let mut object = value.to_object().unwrap();
+    let keys: Vec<&String> = (&object).into_par_iter().map(|(key, _)| key).collect();
+    assert_eq!(keys, vec!["a", "b", "c"]);
+    object
+        .par_iter_mut()
+        .for_each(|(key, value)| *value = Value::String(key.clone()));
+    assert_eq!(object.get("b"), Some(&Value::String("b".to_string())));
+    let entries: Vec<(String, Value)> = object.into_par_iter().collect();
+    assert_eq!(
+        entries[2],
+        ("c".to_string(), Value::String("c".to_string()))
+    );
+}
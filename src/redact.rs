@@ -0,0 +1,66 @@
+#[cfg(test)]
+mod tests;
+
+use crate::glob::PathPattern;
+use crate::{JsonPointer, Value, Visit};
+
+/// What happens to a value matched by [`Value::redact`].
+#[derive(Debug, PartialEq, Clone)]
+pub enum Redaction {
+    Replace(Value),
+    Remove,
+}
+
+impl Value {
+    /// Masks or removes, in place, every value whose path matches one of the
+    /// glob-style `patterns` (see the `**/password` and `users[*].ssn` forms
+    /// below) and returns how many values were redacted.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    ///# fn main() -> Result<(),String> {
+    ///use json::{Redaction, Value};
+    ///
+    ///let mut value = json::parse(r#"{
+    ///    "db": { "password": "hunter2" },
+    ///    "users": [{ "name": "John", "ssn": "078-05-1120" }]
+    ///}"#)?;
+    ///
+    ///let redacted = value.redact(
+    ///    &["**/password", "users[*].ssn"],
+    ///    Redaction::Replace(Value::String("***".to_string())),
+    ///)?;
+    ///assert_eq!(redacted, 2);
+    ///assert_eq!(value, json::parse(r#"{
+    ///    "db": { "password": "***" },
+    ///    "users": [{ "name": "John", "ssn": "***" }]
+    ///}"#)?);
+    ///# Ok(())
+    ///# }
+    /// ```
+    pub fn redact(&mut self, patterns: &[&str], redaction: Redaction) -> Result<usize, String> {
+        let patterns = patterns
+            .iter()
+            .map(|pattern| PathPattern::parse(pattern))
+            .collect::<Result<Vec<_>, _>>()?;
+        let mut redacted = 0;
+        self.visit_mut(&mut |path: &JsonPointer, value: &mut Value| {
+            if !patterns
+                .iter()
+                .any(|pattern| pattern.matches(path.tokens()))
+            {
+                return Visit::Continue;
+            }
+            redacted += 1;
+            match &redaction {
+                Redaction::Replace(replacement) => {
+                    *value = replacement.clone();
+                    Visit::Skip
+                }
+                Redaction::Remove => Visit::Remove,
+            }
+        });
+        Ok(redacted)
+    }
+}
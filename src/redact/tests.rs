@@ -0,0 +1,46 @@
+use super::Redaction;
+use crate::{parse, Value};
+
+#[test]
+fn replace() {
+    let mut value = parse(r#"{ "token": "a", "nested": [{ "token": { "x": 1 } }] }"#).unwrap();
+    assert_eq!(
+        value.redact(&["**/token"], Redaction::Replace(Value::Null)),
+        Ok(2)
+    );
+    assert_eq!(
+        value,
+        parse(r#"{ "token": null, "nested": [{ "token": null }] }"#).unwrap()
+    );
+}
+
+#[test]
+fn remove() {
+    let mut value = parse(r#"{ "users": [{ "id": 1, "ssn": "x" }, { "id": 2 }] }"#).unwrap();
+    assert_eq!(value.redact(&["users[*].ssn"], Redaction::Remove), Ok(1));
+    assert_eq!(
+        value,
+        parse(r#"{ "users": [{ "id": 1 }, { "id": 2 }] }"#).unwrap()
+    );
+}
+
+#[test]
+fn remove_indexed_element() {
+    let mut value = parse(r#"{ "items": [1, 2, 3, 4] }"#).unwrap();
+    assert_eq!(value.redact(&["items[0]"], Redaction::Remove), Ok(1));
+    assert_eq!(value, parse(r#"{ "items": [2, 3, 4] }"#).unwrap());
+    assert_eq!(
+        value.redact(&["items[0]", "items[2]"], Redaction::Remove),
+        Ok(2)
+    );
+    assert_eq!(value, parse(r#"{ "items": [3] }"#).unwrap());
+}
+
+#[test]
+fn invalid_pattern() {
+    let mut value = parse("{}").unwrap();
+    assert_eq!(
+        value.redact(&["a[0"], Redaction::Remove),
+        Err("unclosed [ in pattern a[0".to_string())
+    );
+}
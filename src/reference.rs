@@ -0,0 +1,286 @@
+#[cfg(test)]
+mod tests;
+
+use crate::convert::at;
+use crate::Value;
+use std::collections::HashMap;
+
+/// Documents that `$ref`s may point into, keyed by their URI.
+///
+/// References are resolved against the URI of the document they appear in:
+/// `#/a` points within the same document, `other.json#/a` into a sibling of
+/// it and `https://example.com/x.json` to that exact URI. Fragments must be
+/// JSON Pointers, percent-encoded as in any URI.
+///
+/// # Examples
+///
+/// ```
+///# fn main() -> Result<(),String> {
+///use json::reference::DocumentStore;
+///
+///let mut store = DocumentStore::new();
+///store.insert("https://example.com/api.json", json::parse(r#"{
+///    "user": { "$ref": "common.json#/$defs/user" }
+///}"#)?);
+///store.insert("https://example.com/common.json", json::parse(r##"{
+///    "$defs": { "user": { "name": { "$ref": "#/$defs/name" } }, "name": "string" }
+///}"##)?);
+///
+///assert_eq!(
+///    store.expand("https://example.com/api.json")?,
+///    json::parse(r#"{ "user": { "name": "string" } }"#)?
+///);
+///# Ok(())
+///# }
+/// ```
+#[derive(Debug, Clone, Default)]
+pub struct DocumentStore {
+    documents: HashMap<String, Value>,
+}
+
+impl DocumentStore {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Adds a document, returning the one previously stored at `uri`.
+    pub fn insert<S: Into<String>>(&mut self, uri: S, document: Value) -> Option<Value> {
+        self.documents.insert(uri.into(), document)
+    }
+
+    pub fn get(&self, uri: &str) -> Option<&Value> {
+        self.documents.get(uri)
+    }
+
+    /// Returns the value `reference` points to when found in the document
+    /// at `base`, along with the URI of the document holding it.
+    pub fn resolve(&self, base: &str, reference: &str) -> Result<(String, &Value), String> {
+        Resolver::new(Some(self), None)
+            .resolve(base, reference)
+            .map(|target| (target.uri, target.value))
+    }
+
+    /// Follows `value` while it is a `$ref`, returning the first value that
+    /// isn't one along with the URI of the document holding it.
+    pub fn follow<'a>(
+        &'a self,
+        base: &str,
+        value: &'a Value,
+    ) -> Result<(String, &'a Value), String> {
+        let resolver = Resolver::new(Some(self), None);
+        let (mut uri, mut value) = (base.to_string(), value);
+        let mut seen = Vec::new();
+        while let Some(reference) = reference(value) {
+            let target = resolver.resolve(&uri, reference)?;
+            if seen.contains(&target.key()) {
+                return Err(format!("circular $ref {:?}", reference));
+            }
+            seen.push(target.key());
+            uri = target.uri;
+            value = target.value;
+        }
+        Ok((uri, value))
+    }
+
+    /// Copies the document at `uri`, replacing every `$ref` by a copy of
+    /// what it points to. Fails on references that can't be resolved and on
+    /// cycles, which can't be expanded.
+    pub fn expand(&self, uri: &str) -> Result<Value, String> {
+        let document = self
+            .get(uri)
+            .ok_or_else(|| format!("no document at {:?}", uri))?;
+        Resolver::new(Some(self), None).expand(uri, document, &mut Vec::new())
+    }
+}
+
+impl Value {
+    /// Resolves a reference to a location within this document, such as
+    /// `#/$defs/user`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    ///# fn main() -> Result<(),String> {
+    ///let spec = json::parse(r##"{
+    ///    "paths": { "/users": { "$ref": "#/components/users" } },
+    ///    "components": { "users": { "get": "list" } }
+    ///}"##)?;
+    ///
+    ///assert_eq!(spec.resolve_ref("#/components/users/get")?, &json::parse(r#""list""#)?);
+    ///assert_eq!(
+    ///    spec.expand_refs()?.pointer("/paths/~1users/get"),
+    ///    Some(&json::parse(r#""list""#)?)
+    ///);
+    ///# Ok(())
+    ///# }
+    /// ```
+    pub fn resolve_ref(&self, reference: &str) -> Result<&Value, String> {
+        let resolver = Resolver::new(None, Some(("", self)));
+        resolver.resolve("", reference).map(|target| target.value)
+    }
+
+    /// Copies this document, replacing every `$ref` to a location within it
+    /// by a copy of what it points to.
+    pub fn expand_refs(&self) -> Result<Value, String> {
+        Resolver::new(None, Some(("", self))).expand("", self, &mut Vec::new())
+    }
+}
+
+/// The `$ref` of an object holding one.
+pub(crate) fn reference(value: &Value) -> Option<&str> {
+    match value {
+        Value::Object(object) => match object.get("$ref") {
+            Some(Value::String(reference)) => Some(reference),
+            _ => None,
+        },
+        _ => None,
+    }
+}
+
+/// Finds the targets of references among the documents of a store and,
+/// optionally, a document held outside of it along with its URI.
+pub(crate) struct Resolver<'a> {
+    store: Option<&'a DocumentStore>,
+    local: Option<(&'a str, &'a Value)>,
+}
+
+pub(crate) struct Target<'a> {
+    pub(crate) uri: String,
+    pub(crate) pointer: String,
+    pub(crate) value: &'a Value,
+}
+
+impl Target<'_> {
+    /// Identifies the target regardless of the reference that led to it.
+    pub(crate) fn key(&self) -> (String, String) {
+        (self.uri.clone(), self.pointer.clone())
+    }
+}
+
+impl<'a> Resolver<'a> {
+    pub(crate) fn new(
+        store: Option<&'a DocumentStore>,
+        local: Option<(&'a str, &'a Value)>,
+    ) -> Self {
+        Resolver { store, local }
+    }
+
+    pub(crate) fn resolve(&self, base: &str, reference: &str) -> Result<Target<'a>, String> {
+        let (uri, fragment) = join(base, reference);
+        let stored = self.store.and_then(|store| store.get(&uri));
+        let document = match (self.local, stored) {
+            (Some((local, document)), _) if local == uri => document,
+            (_, Some(document)) => document,
+            _ => return Err(format!("no document at {:?}", uri)),
+        };
+        let pointer = percent_decode(fragment)?;
+        if !pointer.is_empty() && !pointer.starts_with('/') {
+            return Err(format!(
+                "unsupported fragment {:?}, expected a json pointer",
+                fragment
+            ));
+        }
+        let value = document
+            .pointer(&pointer)
+            .ok_or_else(|| format!("unresolvable $ref {:?}", reference))?;
+        Ok(Target {
+            uri,
+            pointer,
+            value,
+        })
+    }
+
+    /// Copies `value`, found in the document at `base`, with its references
+    /// expanded. `expanding` holds the targets being expanded, whose
+    /// appearance again would mean a cycle.
+    pub(crate) fn expand(
+        &self,
+        base: &str,
+        value: &Value,
+        expanding: &mut Vec<(String, String)>,
+    ) -> Result<Value, String> {
+        if let Some(reference) = reference(value) {
+            let target = self.resolve(base, reference)?;
+            if expanding.contains(&target.key()) {
+                return Err(format!("circular $ref {:?}", reference));
+            }
+            expanding.push(target.key());
+            let expanded = self.expand(&target.uri, target.value, expanding);
+            expanding.pop();
+            return expanded;
+        }
+        match value {
+            Value::Object(object) => object
+                .iter()
+                .map(|(key, value)| {
+                    let value = self
+                        .expand(base, value, expanding)
+                        .map_err(|e| at(key, e))?;
+                    Ok((key.clone(), value))
+                })
+                .collect::<Result<_, String>>()
+                .map(Value::Object),
+            Value::Array(values) => values
+                .iter()
+                .enumerate()
+                .map(|(i, value)| {
+                    self.expand(base, value, expanding)
+                        .map_err(|e| at(&i.to_string(), e))
+                })
+                .collect::<Result<_, _>>()
+                .map(Value::Array),
+            value => Ok(value.clone()),
+        }
+    }
+}
+
+/// Resolves a reference against the URI of the document it appears in,
+/// returning the URI of the target document and the fragment.
+pub(crate) fn join<'r>(base: &str, reference: &'r str) -> (String, &'r str) {
+    let (path, fragment) = match reference.find('#') {
+        Some(i) => (&reference[..i], &reference[i + 1..]),
+        None => (reference, ""),
+    };
+    let base = base.split('#').next().unwrap_or_default();
+    let uri = if path.is_empty() {
+        base.to_string()
+    } else if is_absolute(path) {
+        path.to_string()
+    } else {
+        match base.rfind('/') {
+            Some(i) => format!("{}{}", &base[..=i], path),
+            None => path.to_string(),
+        }
+    };
+    (uri, fragment)
+}
+
+/// Whether the reference starts with a scheme such as `https:` or `urn:`.
+fn is_absolute(reference: &str) -> bool {
+    match reference.find(':') {
+        Some(i) => reference[..i]
+            .chars()
+            .all(|c| c.is_ascii_alphanumeric() || "+-.".contains(c)),
+        None => false,
+    }
+}
+
+pub(crate) fn percent_decode(fragment: &str) -> Result<String, String> {
+    let mut bytes = Vec::with_capacity(fragment.len());
+    let mut rest = fragment.as_bytes();
+    while let Some((&byte, tail)) = rest.split_first() {
+        if byte == b'%' {
+            let hex = tail
+                .get(..2)
+                .and_then(|hex| std::str::from_utf8(hex).ok())
+                .and_then(|hex| u8::from_str_radix(hex, 16).ok())
+                .ok_or_else(|| format!("invalid percent-encoding in {:?}", fragment))?;
+            bytes.push(hex);
+            rest = &tail[2..];
+        } else {
+            bytes.push(byte);
+            rest = tail;
+        }
+    }
+    String::from_utf8(bytes).map_err(|_| format!("invalid percent-encoding in {:?}", fragment))
+}
@@ -0,0 +1,74 @@
+use super::{join, DocumentStore};
+use crate::parse;
+
+#[test]
+fn joining() {
+    assert_eq!(join("", "#/a"), ("".to_string(), "/a"));
+    assert_eq!(
+        join("https://x.org/a/b.json#/c", "c.json#/d"),
+        ("https://x.org/a/c.json".to_string(), "/d")
+    );
+    assert_eq!(
+        join("https://x.org/b.json", "urn:example:c"),
+        ("urn:example:c".to_string(), "")
+    );
+    assert_eq!(join("b.json", "c.json"), ("c.json".to_string(), ""));
+}
+
+#[test]
+fn local_references() {
+    let document =
+        parse(r##"{ "a": { "$ref": "#/b%20c" }, "b c": [1, { "$ref": "#/d" }], "d": 2 }"##)
+            .unwrap();
+    assert_eq!(document.resolve_ref("#/b%20c/0"), Ok(&parse("1").unwrap()));
+    assert_eq!(
+        document.resolve_ref("#/e"),
+        Err(r##"unresolvable $ref "#/e""##.to_string())
+    );
+    assert_eq!(
+        document.resolve_ref("#e"),
+        Err(r#"unsupported fragment "e", expected a json pointer"#.to_string())
+    );
+    assert_eq!(
+        document.expand_refs().unwrap().pointer("/a"),
+        Some(&parse("[1, 2]").unwrap())
+    );
+}
+
+#[test]
+fn cycles() {
+    let document = parse(r##"{ "a": { "next": { "$ref": "#/a" } } }"##).unwrap();
+    assert_eq!(
+        document.expand_refs(),
+        Err(r##"/a/next/next: circular $ref "#/a""##.to_string())
+    );
+    let mut store = DocumentStore::new();
+    store.insert(
+        "x",
+        parse(r##"{ "a": { "$ref": "#/b" }, "b": { "$ref": "#/a" } }"##).unwrap(),
+    );
+    let a = store.get("x").unwrap().pointer("/a").unwrap();
+    assert_eq!(
+        store.follow("x", a),
+        Err(r##"circular $ref "#/b""##.to_string())
+    );
+}
+
+#[test]
+fn following() {
+    let mut store = DocumentStore::new();
+    store.insert("dir/x", parse(r#"{ "a": { "$ref": "y#/b" } }"#).unwrap());
+    store.insert(
+        "dir/y",
+        parse(r##"{ "b": { "$ref": "#/c" }, "c": true }"##).unwrap(),
+    );
+    let a = store.get("dir/x").unwrap().pointer("/a").unwrap();
+    assert_eq!(
+        store.follow("dir/x", a),
+        Ok(("dir/y".to_string(), &parse("true").unwrap()))
+    );
+    assert_eq!(
+        store.resolve("dir/x", "z"),
+        Err(r#"no document at "dir/z""#.to_string())
+    );
+}
@@ -0,0 +1,246 @@
+#[cfg(test)]
+mod tests;
+
+use crate::codegen::words;
+use crate::glob::PathPattern;
+use crate::{JsonObject, JsonPointer, Value};
+use std::collections::{HashMap, HashSet};
+
+/// The naming conventions [`Value::rename_keys`] converts keys to.
+#[derive(Debug, PartialEq, Eq, Clone, Copy, Hash)]
+pub enum CaseStyle {
+    /// `firstName`
+    CamelCase,
+    /// `first_name`
+    SnakeCase,
+    /// `first-name`
+    KebabCase,
+    /// `FirstName`
+    PascalCase,
+}
+
+impl CaseStyle {
+    /// Converts `name` to this style, splitting it into words at separators
+    /// and at lowercase to uppercase transitions. Leading separators, as in
+    /// `_id` or `$ref`, are kept.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    ///use json::CaseStyle;
+    ///
+    ///assert_eq!(CaseStyle::SnakeCase.convert("userId"), "user_id");
+    ///assert_eq!(CaseStyle::CamelCase.convert("created-at"), "createdAt");
+    ///assert_eq!(CaseStyle::KebabCase.convert("_HomePage"), "_home-page");
+    /// ```
+    pub fn convert(&self, name: &str) -> String {
+        let start = name
+            .find(|c: char| c.is_alphanumeric())
+            .unwrap_or(name.len());
+        let words = words(name);
+        if words.is_empty() {
+            return name.to_string();
+        }
+        let mut converted = name[..start].to_string();
+        match self {
+            CaseStyle::SnakeCase => converted.push_str(&words.join("_")),
+            CaseStyle::KebabCase => converted.push_str(&words.join("-")),
+            CaseStyle::CamelCase | CaseStyle::PascalCase => {
+                for (i, word) in words.iter().enumerate() {
+                    let mut chars = word.chars();
+                    if let Some(first) = chars.next() {
+                        if i == 0 && *self == CaseStyle::CamelCase {
+                            converted.push(first);
+                        } else {
+                            converted.extend(first.to_uppercase());
+                        }
+                        converted.extend(chars);
+                    }
+                }
+            }
+        }
+        converted
+    }
+}
+
+impl Value {
+    /// Converts the keys of every object in the tree to `style`. When two
+    /// keys of an object convert to the same name, the later entry wins.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    ///# fn main() -> Result<(),String> {
+    ///let mut value = json::parse(r#"{ "userId": 1, "homeAddress": { "zipCode": "1000" } }"#)?;
+    ///value.rename_keys(json::CaseStyle::SnakeCase);
+    ///
+    ///assert_eq!(value, json::parse(r#"{ "user_id": 1, "home_address": { "zip_code": "1000" } }"#)?);
+    ///# Ok(())
+    ///# }
+    /// ```
+    pub fn rename_keys(&mut self, style: CaseStyle) {
+        match self {
+            Value::Array(values) => values.iter_mut().for_each(|v| v.rename_keys(style)),
+            Value::Object(object) => {
+                *object = std::mem::take(object)
+                    .into_iter()
+                    .map(|(key, mut value)| {
+                        value.rename_keys(style);
+                        (style.convert(&key), value)
+                    })
+                    .collect();
+            }
+            _ => {}
+        }
+    }
+}
+
+/// A key [`Value::rename_keys_map`] renamed, located before and after the
+/// renaming.
+#[derive(Debug, PartialEq, Eq, Clone, Hash)]
+pub struct Renamed {
+    pub from: JsonPointer,
+    pub to: JsonPointer,
+}
+
+impl Value {
+    /// Renames the keys found in `renames` in every object of the tree and
+    /// reports where it did. A key keeps its name, and is not reported, when
+    /// its new name is already used by another member.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    ///# fn main() -> Result<(),String> {
+    ///use std::collections::HashMap;
+    ///
+    ///let mut value = json::parse(r#"{ "user": { "mail": "a@b.c", "tel": null }, "mail": 1, "email": 2 }"#)?;
+    ///let renames: HashMap<&str, &str> = vec![("mail", "email"), ("phone", "tel")].into_iter().collect();
+    ///
+    ///let report = value.rename_keys_map(&renames);
+    ///assert_eq!(value, json::parse(r#"{ "user": { "email": "a@b.c", "tel": null }, "mail": 1, "email": 2 }"#)?);
+    ///assert_eq!(report.len(), 1);
+    ///assert_eq!(report[0].from.to_string(), "/user/mail");
+    ///assert_eq!(report[0].to.to_string(), "/user/email");
+    ///# Ok(())
+    ///# }
+    /// ```
+    pub fn rename_keys_map(&mut self, renames: &HashMap<&str, &str>) -> Vec<Renamed> {
+        let mut report = Vec::new();
+        let mut paths = (JsonPointer::root(), JsonPointer::root());
+        rename_at(self, renames, None, &mut paths, &mut report);
+        report
+    }
+
+    /// Like [`Value::rename_keys_map`], but only renames the keys of the
+    /// objects whose original path matches one of `scopes`, path patterns
+    /// such as `/users/*` or `**.address`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    ///# fn main() -> Result<(),String> {
+    ///use std::collections::HashMap;
+    ///
+    ///let mut value = json::parse(r#"{ "id": 1, "items": [{ "id": 2 }] }"#)?;
+    ///let renames: HashMap<&str, &str> = vec![("id", "sku")].into_iter().collect();
+    ///
+    ///value.rename_keys_map_at(&renames, &["/items/*"])?;
+    ///assert_eq!(value, json::parse(r#"{ "id": 1, "items": [{ "sku": 2 }] }"#)?);
+    ///# Ok(())
+    ///# }
+    /// ```
+    pub fn rename_keys_map_at(
+        &mut self,
+        renames: &HashMap<&str, &str>,
+        scopes: &[&str],
+    ) -> Result<Vec<Renamed>, String> {
+        let scopes = scopes
+            .iter()
+            .map(|scope| PathPattern::parse(scope))
+            .collect::<Result<Vec<_>, String>>()?;
+        let mut report = Vec::new();
+        let mut paths = (JsonPointer::root(), JsonPointer::root());
+        rename_at(self, renames, Some(&scopes), &mut paths, &mut report);
+        Ok(report)
+    }
+}
+
+/// `paths` holds the location of `value` before and after the renaming.
+fn rename_at(
+    value: &mut Value,
+    renames: &HashMap<&str, &str>,
+    scopes: Option<&[PathPattern]>,
+    paths: &mut (JsonPointer, JsonPointer),
+    report: &mut Vec<Renamed>,
+) {
+    match value {
+        Value::Array(values) => {
+            for (i, child) in values.iter_mut().enumerate() {
+                paths.0.push(i.to_string());
+                paths.1.push(i.to_string());
+                rename_at(child, renames, scopes, paths, report);
+                paths.0.pop();
+                paths.1.pop();
+            }
+        }
+        Value::Object(object) => {
+            let in_scope = scopes.map_or(true, |scopes| {
+                scopes.iter().any(|s| s.matches(paths.0.tokens()))
+            });
+            let targets = if in_scope {
+                plan(object, renames)
+            } else {
+                vec![None; object.len()]
+            };
+            let entries = std::mem::take(object).into_iter().zip(targets);
+            let mut renamed = JsonObject::new();
+            for ((key, mut child), target) in entries {
+                let name = target.unwrap_or(&key).to_string();
+                paths.0.push(key.as_str());
+                paths.1.push(name.as_str());
+                if target.is_some() {
+                    report.push(Renamed {
+                        from: paths.0.clone(),
+                        to: paths.1.clone(),
+                    });
+                }
+                rename_at(&mut child, renames, scopes, paths, report);
+                paths.0.pop();
+                paths.1.pop();
+                renamed.insert(name, child);
+            }
+            *object = renamed;
+        }
+        _ => {}
+    }
+}
+
+/// Picks the new name of each member of `object`, if any. Renames that would
+/// collide with another member are dropped until none do.
+fn plan<'a>(object: &JsonObject, renames: &HashMap<&str, &'a str>) -> Vec<Option<&'a str>> {
+    let mut targets: Vec<Option<&str>> = object
+        .keys()
+        .map(|key| renames.get(key.as_str()).copied().filter(|to| to != key))
+        .collect();
+    loop {
+        let mut taken: HashSet<&str> = object
+            .keys()
+            .zip(&targets)
+            .filter(|(_, target)| target.is_none())
+            .map(|(key, _)| key.as_str())
+            .collect();
+        let mut blocked = false;
+        for target in targets.iter_mut() {
+            if let Some(to) = *target {
+                if !taken.insert(to) {
+                    *target = None;
+                    blocked = true;
+                }
+            }
+        }
+        if !blocked {
+            return targets;
+        }
+    }
+}
@@ -0,0 +1,89 @@
+use super::CaseStyle;
+use crate::parse;
+use std::collections::HashMap;
+
+#[test]
+fn convert() {
+    let names = ["firstName", "first_name", "first-name", "FirstName"];
+    let styles = [
+        CaseStyle::CamelCase,
+        CaseStyle::SnakeCase,
+        CaseStyle::KebabCase,
+        CaseStyle::PascalCase,
+    ];
+    for (expected, style) in names.iter().zip(styles.iter()) {
+        for name in names.iter() {
+            assert_eq!(style.convert(name), *expected);
+        }
+    }
+    assert_eq!(CaseStyle::PascalCase.convert("$ref"), "$Ref");
+    assert_eq!(CaseStyle::SnakeCase.convert("item2Id"), "item2_id");
+    assert_eq!(CaseStyle::CamelCase.convert("--"), "--");
+}
+
+#[test]
+fn rename_keys() {
+    let mut value = parse(r#"[{ "a_b": [{ "c_d": { "EF": null } }], "aB": 1 }, "x_y"]"#).unwrap();
+    value.rename_keys(CaseStyle::CamelCase);
+    assert_eq!(value, parse(r#"[{ "aB": 1 }, "x_y"]"#).unwrap());
+
+    let mut value = parse(r#"{ "a_b": { "c_d": { "e": null } } }"#).unwrap();
+    value.rename_keys(CaseStyle::KebabCase);
+    assert_eq!(
+        value,
+        parse(r#"{ "a-b": { "c-d": { "e": null } } }"#).unwrap()
+    );
+}
+
+fn renames<'a>(pairs: &[(&'a str, &'a str)]) -> HashMap<&'a str, &'a str> {
+    pairs.iter().copied().collect()
+}
+
+#[test]
+fn rename_keys_map_resolves_collisions() {
+    let mut value = parse(r#"{ "a": 1, "b": 2, "c": 3 }"#).unwrap();
+    let report = value.rename_keys_map(&renames(&[("a", "b"), ("b", "c")]));
+    assert_eq!(value, parse(r#"{ "a": 1, "b": 2, "c": 3 }"#).unwrap());
+    assert!(report.is_empty());
+
+    let mut value = parse(r#"{ "a": 1, "b": 2 }"#).unwrap();
+    let report = value.rename_keys_map(&renames(&[("a", "b"), ("b", "a")]));
+    assert_eq!(value, parse(r#"{ "b": 1, "a": 2 }"#).unwrap());
+    assert_eq!(report.len(), 2);
+
+    let mut value = parse(r#"{ "a": 1, "b": 2 }"#).unwrap();
+    let report = value.rename_keys_map(&renames(&[("a", "x"), ("b", "x")]));
+    assert_eq!(value, parse(r#"{ "x": 1, "b": 2 }"#).unwrap());
+    assert_eq!(report.len(), 1);
+}
+
+#[test]
+fn rename_keys_map_reports_paths() {
+    let mut value = parse(r#"[{ "a/b": { "a/b": null } }]"#).unwrap();
+    let report = value.rename_keys_map(&renames(&[("a/b", "c")]));
+    let report: Vec<(String, String)> = report
+        .iter()
+        .map(|r| (r.from.to_string(), r.to.to_string()))
+        .collect();
+    assert_eq!(
+        report,
+        vec![
+            ("/0/a~1b".to_string(), "/0/c".to_string()),
+            ("/0/a~1b/a~1b".to_string(), "/0/c/c".to_string()),
+        ]
+    );
+}
+
+#[test]
+fn rename_keys_map_at() {
+    let mut value = parse(r#"{ "a": { "x": 1 }, "b": { "x": 2, "c": { "x": 3 } } }"#).unwrap();
+    let report = value
+        .rename_keys_map_at(&renames(&[("x", "y")]), &["b", "**.c"])
+        .unwrap();
+    assert_eq!(
+        value,
+        parse(r#"{ "a": { "x": 1 }, "b": { "y": 2, "c": { "y": 3 } } }"#).unwrap()
+    );
+    assert_eq!(report.len(), 2);
+    assert!(value.rename_keys_map_at(&renames(&[]), &["a["]).is_err());
+}
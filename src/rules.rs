@@ -0,0 +1,385 @@
+#[cfg(test)]
+mod tests;
+
+use crate::convert::mismatch;
+use crate::extract::{ExtractError, Field};
+use crate::{JsonPointer, Value};
+use std::fmt::Display;
+use std::ops::{Bound, RangeBounds};
+
+/// A constraint on values, built from the functions of this module as a
+/// lighter alternative to JSON Schema.
+///
+/// # Examples
+///
+/// ```
+///# fn main() -> Result<(),String> {
+///use json::rules::{array, integer, object, string, Rule};
+///
+///let rule = object()
+///    .key("port", integer().range(1..65536))
+///    .key("hosts", array(string().non_empty()).non_empty())
+///    .optional("name", string());
+///
+///assert!(rule.validate(&json::parse(r#"{ "port": 80, "hosts": ["a"] }"#)?).is_ok());
+///let errors = rule.validate(&json::parse(r#"{ "port": 0, "hosts": ["a", ""] }"#)?).unwrap_err();
+///let errors: Vec<String> = errors.iter().map(|e| e.to_string()).collect();
+///assert_eq!(
+///    errors,
+///    vec![
+///        "/port: expected integer in 1..65536, found 0",
+///        "/hosts/1: expected length in 1.., found 0",
+///    ]
+///);
+///# Ok(())
+///# }
+/// ```
+pub trait Rule {
+    /// Reports every violation found in `value`, which is found at `path`.
+    fn check(&self, value: &Value, path: &mut JsonPointer, errors: &mut Vec<ExtractError>);
+
+    fn validate(&self, value: &Value) -> Result<(), Vec<ExtractError>> {
+        let mut errors = Vec::new();
+        self.check(value, &mut JsonPointer::root(), &mut errors);
+        if errors.is_empty() {
+            Ok(())
+        } else {
+            Err(errors)
+        }
+    }
+
+    /// Also accepts `null`.
+    fn nullable(self) -> Nullable<Self>
+    where
+        Self: Sized,
+    {
+        Nullable(self)
+    }
+}
+
+impl Field<'_> {
+    /// Validates this field, reporting errors at their location in the
+    /// whole document.
+    pub fn validate(&self, rule: &dyn Rule) -> Result<(), Vec<ExtractError>> {
+        let mut errors = Vec::new();
+        rule.check(self.value(), &mut self.path().clone(), &mut errors);
+        if errors.is_empty() {
+            Ok(())
+        } else {
+            Err(errors)
+        }
+    }
+}
+
+fn report(path: &JsonPointer, message: String, errors: &mut Vec<ExtractError>) {
+    errors.push(ExtractError {
+        path: path.clone(),
+        message,
+    });
+}
+
+fn bounds<T: Copy + Into<U>, U, R: RangeBounds<T>>(range: R) -> (Bound<U>, Bound<U>) {
+    let copy = |bound: Bound<&T>| match bound {
+        Bound::Included(value) => Bound::Included((*value).into()),
+        Bound::Excluded(value) => Bound::Excluded((*value).into()),
+        Bound::Unbounded => Bound::Unbounded,
+    };
+    (copy(range.start_bound()), copy(range.end_bound()))
+}
+
+/// Writes bounds the way Rust writes ranges, e.g. `1..65536` or `0..=1`.
+fn describe<T: Display>((start, end): &(Bound<T>, Bound<T>)) -> String {
+    let start = match start {
+        Bound::Included(value) | Bound::Excluded(value) => value.to_string(),
+        Bound::Unbounded => String::new(),
+    };
+    match end {
+        Bound::Included(value) => format!("{}..={}", start, value),
+        Bound::Excluded(value) => format!("{}..{}", start, value),
+        Bound::Unbounded => format!("{}..", start),
+    }
+}
+
+fn contains<T: PartialOrd>(bounds: &(Bound<T>, Bound<T>), value: &T) -> bool {
+    bounds.contains(value)
+}
+
+fn check_length(
+    bounds: &(Bound<usize>, Bound<usize>),
+    length: usize,
+    path: &JsonPointer,
+    errors: &mut Vec<ExtractError>,
+) {
+    if !contains(bounds, &length) {
+        let message = format!("expected length in {}, found {}", describe(bounds), length);
+        report(path, message, errors);
+    }
+}
+
+/// Accepts anything.
+pub fn any() -> Any {
+    Any
+}
+
+#[derive(Debug, Clone, Copy)]
+pub struct Any;
+
+impl Rule for Any {
+    fn check(&self, _: &Value, _: &mut JsonPointer, _: &mut Vec<ExtractError>) {}
+}
+
+/// Accepts `null` besides what the wrapped rule accepts.
+#[derive(Debug, Clone)]
+pub struct Nullable<R>(R);
+
+impl<R: Rule> Rule for Nullable<R> {
+    fn check(&self, value: &Value, path: &mut JsonPointer, errors: &mut Vec<ExtractError>) {
+        if !matches!(value, Value::Null) {
+            self.0.check(value, path, errors);
+        }
+    }
+}
+
+pub fn boolean() -> Boolean {
+    Boolean
+}
+
+#[derive(Debug, Clone, Copy)]
+pub struct Boolean;
+
+impl Rule for Boolean {
+    fn check(&self, value: &Value, path: &mut JsonPointer, errors: &mut Vec<ExtractError>) {
+        if !matches!(value, Value::Bool(_)) {
+            report(path, mismatch("bool", value), errors);
+        }
+    }
+}
+
+/// Accepts numbers without a fractional part.
+pub fn integer() -> Number {
+    Number {
+        integer: true,
+        bounds: (Bound::Unbounded, Bound::Unbounded),
+    }
+}
+
+pub fn number() -> Number {
+    Number {
+        integer: false,
+        bounds: (Bound::Unbounded, Bound::Unbounded),
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct Number {
+    integer: bool,
+    bounds: (Bound<f64>, Bound<f64>),
+}
+
+impl Number {
+    pub fn range<T: Into<f64> + Copy, R: RangeBounds<T>>(mut self, range: R) -> Self {
+        self.bounds = bounds(range);
+        self
+    }
+}
+
+impl Rule for Number {
+    fn check(&self, value: &Value, path: &mut JsonPointer, errors: &mut Vec<ExtractError>) {
+        let expected = if self.integer { "integer" } else { "number" };
+        let number = match value {
+            Value::Number(number) if !self.integer || number.fract() == 0.0 => *number,
+            Value::Number(number) => {
+                return report(path, format!("expected integer, found {}", number), errors)
+            }
+            value => return report(path, mismatch(expected, value), errors),
+        };
+        if !contains(&self.bounds, &number) {
+            let range = describe(&self.bounds);
+            let message = format!("expected {} in {}, found {}", expected, range, number);
+            report(path, message, errors);
+        }
+    }
+}
+
+pub fn string() -> Text {
+    Text {
+        length: (Bound::Unbounded, Bound::Unbounded),
+        values: None,
+    }
+}
+
+/// Strings, whose length is counted in characters.
+#[derive(Debug, Clone)]
+pub struct Text {
+    length: (Bound<usize>, Bound<usize>),
+    values: Option<Vec<String>>,
+}
+
+impl Text {
+    pub fn non_empty(self) -> Self {
+        self.len(1..)
+    }
+
+    pub fn len<R: RangeBounds<usize>>(mut self, range: R) -> Self {
+        self.length = bounds(range);
+        self
+    }
+
+    /// Only accepts the given values.
+    pub fn one_of(mut self, values: &[&str]) -> Self {
+        self.values = Some(values.iter().map(|value| value.to_string()).collect());
+        self
+    }
+}
+
+impl Rule for Text {
+    fn check(&self, value: &Value, path: &mut JsonPointer, errors: &mut Vec<ExtractError>) {
+        let value = match value {
+            Value::String(value) => value,
+            value => return report(path, mismatch("string", value), errors),
+        };
+        check_length(&self.length, value.chars().count(), path, errors);
+        if let Some(values) = self
+            .values
+            .as_ref()
+            .filter(|values| !values.contains(value))
+        {
+            let values: Vec<_> = values.iter().map(|value| format!("{:?}", value)).collect();
+            let message = format!("expected one of {}, found {:?}", values.join(", "), value);
+            report(path, message, errors);
+        }
+    }
+}
+
+/// Accepts arrays whose every element satisfies `item`.
+pub fn array<R: Rule + 'static>(item: R) -> Array {
+    Array {
+        item: Box::new(item),
+        length: (Bound::Unbounded, Bound::Unbounded),
+    }
+}
+
+pub struct Array {
+    item: Box<dyn Rule>,
+    length: (Bound<usize>, Bound<usize>),
+}
+
+impl Array {
+    pub fn non_empty(self) -> Self {
+        self.len(1..)
+    }
+
+    pub fn len<R: RangeBounds<usize>>(mut self, range: R) -> Self {
+        self.length = bounds(range);
+        self
+    }
+}
+
+impl Rule for Array {
+    fn check(&self, value: &Value, path: &mut JsonPointer, errors: &mut Vec<ExtractError>) {
+        let values = match value {
+            Value::Array(values) => values,
+            value => return report(path, mismatch("array", value), errors),
+        };
+        check_length(&self.length, values.len(), path, errors);
+        for (i, value) in values.iter().enumerate() {
+            path.push(i.to_string());
+            self.item.check(value, path, errors);
+            path.pop();
+        }
+    }
+}
+
+/// Accepts objects, any keys of which are allowed unless
+/// [`deny_unknown`](Object::deny_unknown) is called.
+pub fn object() -> Object {
+    Object {
+        keys: Vec::new(),
+        deny_unknown: false,
+    }
+}
+
+pub struct Object {
+    /// Rules by key, with whether the key is required.
+    keys: Vec<(String, Box<dyn Rule>, bool)>,
+    deny_unknown: bool,
+}
+
+impl Object {
+    /// A key that must be present. `null` counts as present.
+    pub fn key<R: Rule + 'static>(mut self, key: &str, rule: R) -> Self {
+        self.keys.push((key.to_string(), Box::new(rule), true));
+        self
+    }
+
+    /// A key that may be missing or `null`.
+    pub fn optional<R: Rule + 'static>(mut self, key: &str, rule: R) -> Self {
+        self.keys
+            .push((key.to_string(), Box::new(rule.nullable()), false));
+        self
+    }
+
+    /// Rejects the keys no rule was given for.
+    pub fn deny_unknown(mut self) -> Self {
+        self.deny_unknown = true;
+        self
+    }
+}
+
+impl Rule for Object {
+    fn check(&self, value: &Value, path: &mut JsonPointer, errors: &mut Vec<ExtractError>) {
+        let object = match value {
+            Value::Object(object) => object,
+            value => return report(path, mismatch("object", value), errors),
+        };
+        for (key, rule, required) in &self.keys {
+            path.push(key.as_str());
+            match object.get(key) {
+                Some(value) => rule.check(value, path, errors),
+                None if *required => report(path, "missing".to_string(), errors),
+                None => {}
+            }
+            path.pop();
+        }
+        if self.deny_unknown {
+            for (key, _) in object.iter() {
+                if !self.keys.iter().any(|(k, _, _)| k == key) {
+                    path.push(key.as_str());
+                    report(path, "unexpected key".to_string(), errors);
+                    path.pop();
+                }
+            }
+        }
+    }
+}
+
+/// Accepts the values `f` returns `Ok` for, reporting its errors.
+///
+/// # Examples
+///
+/// ```
+///# fn main() -> Result<(),String> {
+///use json::rules::{custom, Rule};
+///
+///let even = custom(|value| match value {
+///    json::Value::Number(n) if n % 2.0 == 0.0 => Ok(()),
+///    _ => Err("expected an even number".to_string()),
+///});
+///assert!(even.validate(&json::parse("4")?).is_ok());
+///assert!(even.validate(&json::parse("5")?).is_err());
+///# Ok(())
+///# }
+/// ```
+pub fn custom<F: Fn(&Value) -> Result<(), String>>(f: F) -> Custom<F> {
+    Custom(f)
+}
+
+pub struct Custom<F>(F);
+
+impl<F: Fn(&Value) -> Result<(), String>> Rule for Custom<F> {
+    fn check(&self, value: &Value, path: &mut JsonPointer, errors: &mut Vec<ExtractError>) {
+        if let Err(message) = (self.0)(value) {
+            report(path, message, errors);
+        }
+    }
+}
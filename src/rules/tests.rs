@@ -0,0 +1,71 @@
+use super::{any, array, boolean, integer, number, object, string, Rule};
+use crate::parse;
+
+fn errors(rule: &dyn Rule, value: &str) -> Vec<String> {
+    match rule.validate(&parse(value).unwrap()) {
+        Ok(()) => Vec::new(),
+        Err(errors) => errors.iter().map(|e| e.to_string()).collect(),
+    }
+}
+
+#[test]
+fn scalars() {
+    assert_eq!(
+        errors(&integer(), "1.5"),
+        vec!["/: expected integer, found 1.5"]
+    );
+    assert_eq!(
+        errors(&number().range(0..=1), "2"),
+        vec!["/: expected number in 0..=1, found 2"]
+    );
+    assert_eq!(
+        errors(&boolean(), "null"),
+        vec!["/: expected bool, found null"]
+    );
+    assert!(errors(&boolean().nullable(), "null").is_empty());
+    assert_eq!(
+        errors(&string().one_of(&["a", "b"]).len(..=1), r#""cd""#),
+        vec![
+            "/: expected length in ..=1, found 2",
+            r#"/: expected one of "a", "b", found "cd""#
+        ]
+    );
+    assert!(errors(&any(), "[]").is_empty());
+}
+
+#[test]
+fn containers() {
+    let rule = object()
+        .key("tags", array(string()).len(..2))
+        .optional("id", integer())
+        .deny_unknown();
+    assert!(errors(&rule, r#"{ "tags": [], "id": null }"#).is_empty());
+    assert_eq!(
+        errors(&rule, r#"{ "id": "1", "other": 1 }"#),
+        vec![
+            "/tags: missing",
+            "/id: expected integer, found string",
+            "/other: unexpected key"
+        ]
+    );
+    assert_eq!(
+        errors(&rule, r#"{ "tags": ["a", 1] }"#),
+        vec![
+            "/tags: expected length in ..2, found 2",
+            "/tags/1: expected string, found number"
+        ]
+    );
+}
+
+#[test]
+fn fields() {
+    let config = parse(r#"{ "server": { "port": 0 } }"#).unwrap();
+    let server = config.require("server").unwrap();
+    let errors = server
+        .validate(&object().key("port", integer().range(1..)))
+        .unwrap_err();
+    assert_eq!(
+        errors[0].to_string(),
+        "/server/port: expected integer in 1.., found 0"
+    );
+}
@@ -0,0 +1,946 @@
+#[cfg(test)]
+mod tests;
+
+use crate::convert::{at, mismatch};
+pub use crate::formats::{FormatChecker, Formats};
+use crate::reference::{join, DocumentStore, Resolver};
+use crate::{to_string, JsonObject, JsonPointer, Value};
+use std::fmt;
+
+/// Dialect written to the `$schema` keyword of generated schemas.
+pub const DRAFT_2020_12: &str = "https://json-schema.org/draft/2020-12/schema";
+
+/// Strings become an `enum` when they take at most this many distinct values
+/// and each value was seen at least twice.
+const ENUM_CANDIDATES: usize = 8;
+
+/// Produces a JSON Schema (draft 2020-12) that every sample satisfies.
+/// Numbers are `integer` when every sample was integral, object properties
+/// present in all samples are `required`, and strings drawn from a small set
+/// of repeated values become an `enum`.
+///
+/// # Examples
+///
+/// ```
+///# fn main() -> Result<(),String> {
+///let samples = vec![
+///    json::parse(r#"{ "id": 1, "status": "open", "tags": ["a"] }"#)?,
+///    json::parse(r#"{ "id": 2, "status": "closed" }"#)?,
+///    json::parse(r#"{ "id": 3, "status": "open", "tags": [] }"#)?,
+///    json::parse(r#"{ "id": 4, "status": "closed", "tags": ["b", "c"] }"#)?,
+///];
+///
+///let schema = json::schema::infer_schema(samples.iter());
+///assert_eq!(schema.pointer("/properties/id/type"), Some(&json::parse(r#""integer""#)?));
+///assert_eq!(schema.pointer("/properties/status/enum"), Some(&json::parse(r#"["open", "closed"]"#)?));
+///assert_eq!(schema.pointer("/properties/tags/items/type"), Some(&json::parse(r#""string""#)?));
+///assert_eq!(schema.pointer("/required"), Some(&json::parse(r#"["id", "status"]"#)?));
+///# Ok(())
+///# }
+/// ```
+pub fn infer_schema<'a>(values: impl Iterator<Item = &'a Value>) -> Value {
+    let mut inferred = Inferred::default();
+    for value in values {
+        inferred.observe(value);
+    }
+    let mut schema = JsonObject::new();
+    schema.insert(
+        "$schema".to_string(),
+        Value::String(DRAFT_2020_12.to_string()),
+    );
+    if let Value::Object(object) = inferred.to_schema() {
+        for (key, value) in object {
+            schema.insert(key, value);
+        }
+    }
+    Value::Object(schema)
+}
+
+/// What has been learned about the samples found at one location.
+#[derive(Debug, Default)]
+struct Inferred {
+    null: bool,
+    bool: bool,
+    integers: usize,
+    fractions: usize,
+    /// Distinct strings with their number of occurrences, or `None` once
+    /// there are too many of them to form an enum.
+    strings: Option<Vec<(String, usize)>>,
+    string_count: usize,
+    /// Elements of every array seen, if any.
+    items: Option<Box<Inferred>>,
+    arrays: usize,
+    /// Properties in order of first appearance, with how many objects had
+    /// each of them.
+    properties: Vec<(String, usize, Inferred)>,
+    objects: usize,
+}
+
+impl Inferred {
+    fn observe(&mut self, value: &Value) {
+        match value {
+            Value::Null => self.null = true,
+            Value::Bool(_) => self.bool = true,
+            Value::Number(value) if value.fract() == 0.0 => self.integers += 1,
+            Value::Number(_) => self.fractions += 1,
+            Value::String(value) => {
+                if self.string_count == 0 {
+                    self.strings = Some(Vec::new());
+                }
+                self.string_count += 1;
+                if let Some(strings) = &mut self.strings {
+                    match strings.iter().position(|(s, _)| s == value) {
+                        Some(i) => strings[i].1 += 1,
+                        None if strings.len() < ENUM_CANDIDATES => strings.push((value.clone(), 1)),
+                        None => self.strings = None,
+                    }
+                }
+            }
+            Value::Array(values) => {
+                self.arrays += 1;
+                let items = self.items.get_or_insert_with(Box::default);
+                for value in values {
+                    items.observe(value);
+                }
+            }
+            Value::Object(object) => {
+                self.objects += 1;
+                for (key, value) in object.iter() {
+                    let i = match self.properties.iter().position(|(k, _, _)| k == key) {
+                        Some(i) => i,
+                        None => {
+                            self.properties.push((key.clone(), 0, Inferred::default()));
+                            self.properties.len() - 1
+                        }
+                    };
+                    let (_, count, inferred) = &mut self.properties[i];
+                    *count += 1;
+                    inferred.observe(value);
+                }
+            }
+        }
+    }
+
+    fn types(&self) -> Vec<&'static str> {
+        let mut types = Vec::new();
+        let mut add = |present: bool, name| {
+            if present {
+                types.push(name)
+            }
+        };
+        add(self.null, "null");
+        add(self.bool, "boolean");
+        add(self.fractions > 0, "number");
+        add(self.integers > 0 && self.fractions == 0, "integer");
+        add(self.string_count > 0, "string");
+        add(self.arrays > 0, "array");
+        add(self.objects > 0, "object");
+        types
+    }
+
+    fn to_schema(&self) -> Value {
+        let mut schema = JsonObject::new();
+        let types = self.types();
+        match types.as_slice() {
+            [] => {}
+            [single] => {
+                schema.insert("type".to_string(), string(single));
+            }
+            types => {
+                let types = types.iter().map(|name| string(name)).collect();
+                schema.insert("type".to_string(), Value::Array(types));
+            }
+        }
+        if let (["string"], Some(strings)) = (types.as_slice(), &self.strings) {
+            if strings.iter().all(|(_, count)| *count >= 2) {
+                let values = strings.iter().map(|(s, _)| string(s)).collect();
+                schema.insert("enum".to_string(), Value::Array(values));
+            }
+        }
+        // When every array was empty nothing is known about the elements.
+        if let Some(items) = self.items.as_ref().filter(|i| !i.types().is_empty()) {
+            schema.insert("items".to_string(), items.to_schema());
+        }
+        if self.objects > 0 {
+            let properties = self
+                .properties
+                .iter()
+                .map(|(key, _, inferred)| (key.clone(), inferred.to_schema()))
+                .collect();
+            schema.insert("properties".to_string(), Value::Object(properties));
+            let required: Vec<_> = self
+                .properties
+                .iter()
+                .filter(|(_, count, _)| *count == self.objects)
+                .map(|(key, _, _)| string(key))
+                .collect();
+            if !required.is_empty() {
+                schema.insert("required".to_string(), Value::Array(required));
+            }
+        }
+        Value::Object(schema)
+    }
+}
+
+fn string(value: &str) -> Value {
+    Value::String(value.to_string())
+}
+
+/// A JSON Schema (draft 2020-12) compiled for validating instances.
+///
+/// Supports `type`, `enum` and `const`, the string (`minLength`,
+/// `maxLength`, `pattern`), number (`minimum`, `maximum`, their exclusive
+/// forms, `multipleOf`), array (`items`, `prefixItems`, `minItems`,
+/// `maxItems`, `uniqueItems`) and object (`properties`,
+/// `patternProperties`, `additionalProperties`, `required`,
+/// `minProperties`, `maxProperties`) constraints, and the `allOf`, `anyOf`,
+/// `oneOf` and `not` applicators, as well as `$ref` and `format`. Other
+/// keywords are annotations and are ignored. Patterns need the `regex`
+/// feature.
+///
+/// # Examples
+///
+/// ```
+///# fn main() -> Result<(),String> {
+///use json::schema::Schema;
+///
+///let schema = Schema::compile(&json::parse(r#"{
+///    "type": "object",
+///    "properties": {
+///        "port": { "type": "integer", "minimum": 1, "maximum": 65535 },
+///        "hosts": { "type": "array", "items": { "type": "string", "minLength": 1 } }
+///    },
+///    "required": ["port"],
+///    "additionalProperties": false
+///}"#)?)?;
+///
+///assert!(schema.is_valid(&json::parse(r#"{ "port": 80, "hosts": ["a"] }"#)?));
+///let errors = schema.validate(&json::parse(r#"{ "port": 0, "hosts": [""], "tls": true }"#)?).unwrap_err();
+///let errors: Vec<String> = errors.iter().map(|e| e.to_string()).collect();
+///assert_eq!(
+///    errors,
+///    vec![
+///        "/port: expected at least 1, found 0",
+///        "/hosts/0: expected at least 1 characters, found 0",
+///        "/tls: not allowed",
+///    ]
+///);
+///# Ok(())
+///# }
+/// ```
+#[derive(Debug, Clone)]
+pub struct Schema {
+    root: Node,
+    /// The schemas `$ref`s point to, indexed by [`Keyword::Ref`].
+    refs: Vec<Node>,
+}
+
+#[derive(Debug, Clone)]
+enum Node {
+    /// The `true` and `false` schemas.
+    Always(bool),
+    /// Keywords with their names.
+    Keywords(Vec<(String, Keyword)>),
+}
+
+#[derive(Debug, Clone)]
+enum Keyword {
+    Ref(usize),
+    Type(Vec<String>),
+    Enum(Vec<Value>),
+    Const(Value),
+    MinLength(usize),
+    MaxLength(usize),
+    Pattern(Pattern),
+    Format(Format),
+    Minimum(f64),
+    Maximum(f64),
+    ExclusiveMinimum(f64),
+    ExclusiveMaximum(f64),
+    MultipleOf(f64),
+    PrefixItems(Vec<Node>),
+    /// Applies to the elements following the `prefixItems`.
+    Items(usize, Box<Node>),
+    MinItems(usize),
+    MaxItems(usize),
+    UniqueItems,
+    Properties(Vec<(String, Node)>),
+    PatternProperties(Vec<(Pattern, Node)>),
+    /// Applies to the properties matched by neither `properties` nor
+    /// `patternProperties`.
+    AdditionalProperties(Vec<String>, Vec<Pattern>, Box<Node>),
+    Required(Vec<String>),
+    MinProperties(usize),
+    MaxProperties(usize),
+    AllOf(Vec<Node>),
+    AnyOf(Vec<Node>),
+    OneOf(Vec<Node>),
+    Not(Box<Node>),
+}
+
+/// A format with a checker.
+#[derive(Clone)]
+struct Format {
+    name: String,
+    checker: FormatChecker,
+}
+
+impl fmt::Debug for Format {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_tuple("Format").field(&self.name).finish()
+    }
+}
+
+/// A violation of a schema.
+#[derive(Debug, PartialEq, Clone)]
+pub struct ValidationError {
+    /// Location of the offending value within the instance.
+    pub instance_path: JsonPointer,
+    /// Location of the violated keyword within the schema, through the
+    /// `$ref`s followed to reach it.
+    pub schema_path: JsonPointer,
+    pub message: String,
+}
+
+/// Written as the instance path followed by the message, or just the
+/// message for the root.
+impl fmt::Display for ValidationError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if self.instance_path.is_root() {
+            write!(f, "{}", self.message)
+        } else {
+            write!(f, "{}: {}", self.instance_path, self.message)
+        }
+    }
+}
+
+/// Renders errors grouped by instance path, in order of first appearance,
+/// each followed by the keyword it violates.
+///
+/// # Examples
+///
+/// ```
+///# fn main() -> Result<(),String> {
+///use json::schema::{render_errors, Schema};
+///
+///let schema = Schema::compile(&json::parse(r#"{
+///    "properties": { "port": { "type": "integer", "minimum": 1 } },
+///    "required": ["host"]
+///}"#)?)?;
+///let errors = schema.validate(&json::parse(r#"{ "port": -0.5 }"#)?).unwrap_err();
+///
+///assert_eq!(errors[1].schema_path.to_string(), "/properties/port/minimum");
+///assert_eq!(
+///    render_errors(&errors),
+///    "/port:\n  expected integer, found number (/properties/port/type)\n  \
+///     expected at least 1, found -0.5 (/properties/port/minimum)\n\
+///     /host:\n  missing (/required)\n"
+///);
+///# Ok(())
+///# }
+/// ```
+pub fn render_errors(errors: &[ValidationError]) -> String {
+    let mut paths: Vec<&JsonPointer> = Vec::new();
+    for error in errors {
+        if !paths.contains(&&error.instance_path) {
+            paths.push(&error.instance_path);
+        }
+    }
+    let mut output = String::new();
+    for path in paths {
+        if path.is_root() {
+            output.push('/');
+        } else {
+            output.push_str(&path.to_string());
+        }
+        output.push_str(":\n");
+        for error in errors.iter().filter(|e| &e.instance_path == path) {
+            output.push_str(&format!("  {} ({})\n", error.message, error.schema_path));
+        }
+    }
+    output
+}
+
+/// How to compile a schema.
+#[derive(Debug, Clone, Default)]
+pub struct Options {
+    /// Documents `$ref`s may point into, besides the schema itself.
+    pub store: DocumentStore,
+    /// Checkers for the `format` keyword.
+    pub formats: Formats,
+}
+
+const TYPES: [&str; 7] = [
+    "null", "boolean", "number", "integer", "string", "array", "object",
+];
+
+#[cfg(feature = "regex")]
+type Pattern = regex::Regex;
+
+/// Stands in for regular expressions, which can't be compiled without the
+/// `regex` feature.
+#[cfg(not(feature = "regex"))]
+#[derive(Debug, Clone)]
+enum Pattern {}
+
+#[cfg(feature = "regex")]
+fn pattern(source: &Value) -> Result<Pattern, String> {
+    match source {
+        Value::String(source) => regex::Regex::new(source).map_err(|e| e.to_string()),
+        source => Err(mismatch("string", source)),
+    }
+}
+
+#[cfg(not(feature = "regex"))]
+fn pattern(_: &Value) -> Result<Pattern, String> {
+    Err("patterns require the regex feature".to_string())
+}
+
+#[cfg(feature = "regex")]
+fn is_match(pattern: &Pattern, value: &str) -> bool {
+    pattern.is_match(value)
+}
+
+#[cfg(not(feature = "regex"))]
+fn is_match(pattern: &Pattern, _: &str) -> bool {
+    match *pattern {}
+}
+
+#[cfg(feature = "regex")]
+fn source(pattern: &Pattern) -> &str {
+    pattern.as_str()
+}
+
+#[cfg(not(feature = "regex"))]
+fn source(pattern: &Pattern) -> &str {
+    match *pattern {}
+}
+
+impl Schema {
+    /// Compiles a schema, failing with the location of the first keyword
+    /// that isn't well-formed. Its `$ref`s may point within it and the
+    /// built-in formats are checked.
+    pub fn compile(schema: &Value) -> Result<Schema, String> {
+        Schema::compile_with(schema, &Options::default())
+    }
+
+    /// Compiles a schema whose `$ref`s may also point into the documents of
+    /// the store, and whose formats are checked by the given checkers. The
+    /// schema's URI is its `$id`, if any.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    ///# fn main() -> Result<(),String> {
+    ///use json::schema::{Options, Schema};
+    ///
+    ///let mut options = Options::default();
+    ///options.store.insert("https://example.com/node.json", json::parse(r##"{
+    ///    "type": "object",
+    ///    "properties": { "children": { "type": "array", "items": { "$ref": "#" } } },
+    ///    "required": ["name"]
+    ///}"##)?);
+    ///let schema = Schema::compile_with(
+    ///    &json::parse(r#"{ "$id": "https://example.com/tree.json", "$ref": "node.json" }"#)?,
+    ///    &options,
+    ///)?;
+    ///
+    ///let errors = schema
+    ///    .validate(&json::parse(r#"{ "name": "a", "children": [{ "children": [] }] }"#)?)
+    ///    .unwrap_err();
+    ///assert_eq!(errors[0].to_string(), "/children/0/name: missing");
+    ///assert_eq!(
+    ///    errors[0].schema_path.to_string(),
+    ///    "/$ref/properties/children/items/$ref/required"
+    ///);
+    ///# Ok(())
+    ///# }
+    /// ```
+    pub fn compile_with(schema: &Value, options: &Options) -> Result<Schema, String> {
+        let uri = match schema.pointer("/$id") {
+            Some(Value::String(id)) => id.as_str(),
+            _ => "",
+        };
+        let mut compiler = Compiler {
+            resolver: Resolver::new(Some(&options.store), Some((uri, schema))),
+            formats: &options.formats,
+            targets: Vec::new(),
+        };
+        let root = compiler.compile(uri, schema)?;
+        let refs = compiler
+            .targets
+            .into_iter()
+            .map(|(_, node)| node.expect("compiled target"))
+            .collect();
+        Ok(Schema { root, refs })
+    }
+
+    /// Lists every violation found in `instance`, in schema order.
+    pub fn validate(&self, instance: &Value) -> Result<(), Vec<ValidationError>> {
+        let mut validator = Validator {
+            refs: &self.refs,
+            path: JsonPointer::root(),
+            schema_path: JsonPointer::root(),
+            errors: Vec::new(),
+            following: Vec::new(),
+        };
+        validator.node(&self.root, instance);
+        if validator.errors.is_empty() {
+            Ok(())
+        } else {
+            Err(validator.errors)
+        }
+    }
+
+    pub fn is_valid(&self, instance: &Value) -> bool {
+        self.validate(instance).is_ok()
+    }
+}
+
+/// Compiles the schemas of a document and those its `$ref`s point to.
+struct Compiler<'a> {
+    resolver: Resolver<'a>,
+    formats: &'a Formats,
+    /// Targets of the references met so far, each compiled once and then
+    /// shared, which also lets recursive schemas refer to themselves.
+    targets: Vec<((String, String), Option<Node>)>,
+}
+
+impl Compiler<'_> {
+    /// Compiles a schema found in the document at `base`.
+    fn compile(&mut self, base: &str, schema: &Value) -> Result<Node, String> {
+        let object = match schema {
+            Value::Bool(value) => return Ok(Node::Always(*value)),
+            Value::Object(object) => object,
+            schema => return Err(mismatch("schema", schema)),
+        };
+        let base = match object.get("$id") {
+            Some(Value::String(id)) => join(base, id).0,
+            _ => base.to_string(),
+        };
+        let base = base.as_str();
+        let mut keywords = Vec::new();
+        for (name, value) in object.iter() {
+            let keyword = match name.as_str() {
+                "$ref" => match value {
+                    Value::String(reference) => self.reference(base, reference).map(Keyword::Ref),
+                    value => Err(mismatch("string", value)),
+                },
+                "type" => types(value).map(Keyword::Type),
+                "enum" => match value {
+                    Value::Array(values) => Ok(Keyword::Enum(values.clone())),
+                    value => Err(mismatch("array", value)),
+                },
+                "const" => Ok(Keyword::Const(value.clone())),
+                "minLength" => count(value).map(Keyword::MinLength),
+                "maxLength" => count(value).map(Keyword::MaxLength),
+                "pattern" => pattern(value).map(Keyword::Pattern),
+                "format" => match value {
+                    Value::String(name) => match self.formats.get(name) {
+                        Some(checker) => Ok(Keyword::Format(Format {
+                            name: name.clone(),
+                            checker: checker.clone(),
+                        })),
+                        None => continue,
+                    },
+                    value => Err(mismatch("string", value)),
+                },
+                "minimum" => number(value).map(Keyword::Minimum),
+                "maximum" => number(value).map(Keyword::Maximum),
+                "exclusiveMinimum" => number(value).map(Keyword::ExclusiveMinimum),
+                "exclusiveMaximum" => number(value).map(Keyword::ExclusiveMaximum),
+                "multipleOf" => match number(value)? {
+                    divisor if divisor > 0.0 => Ok(Keyword::MultipleOf(divisor)),
+                    _ => Err("expected a number greater than 0".to_string()),
+                },
+                "prefixItems" => self.schemas(base, value).map(Keyword::PrefixItems),
+                "items" => {
+                    let skip = match object.get("prefixItems") {
+                        Some(Value::Array(prefix)) => prefix.len(),
+                        _ => 0,
+                    };
+                    self.compile(base, value)
+                        .map(|node| Keyword::Items(skip, Box::new(node)))
+                }
+                "minItems" => count(value).map(Keyword::MinItems),
+                "maxItems" => count(value).map(Keyword::MaxItems),
+                "uniqueItems" => match value {
+                    Value::Bool(true) => Ok(Keyword::UniqueItems),
+                    Value::Bool(false) => continue,
+                    value => Err(mismatch("bool", value)),
+                },
+                "properties" => self
+                    .named(base, value, |name| Ok(name.to_string()))
+                    .map(Keyword::Properties),
+                "patternProperties" => self
+                    .named(base, value, |name| {
+                        pattern(&Value::String(name.to_string()))
+                    })
+                    .map(Keyword::PatternProperties),
+                "additionalProperties" => {
+                    let known = match object.get("properties") {
+                        Some(Value::Object(properties)) => {
+                            properties.iter().map(|(name, _)| name.clone()).collect()
+                        }
+                        _ => Vec::new(),
+                    };
+                    let patterns = match object.get("patternProperties") {
+                        Some(Value::Object(properties)) => properties
+                            .iter()
+                            .map(|(name, _)| pattern(&Value::String(name.clone())))
+                            .collect::<Result<_, _>>()
+                            .map_err(|e| at("patternProperties", e))?,
+                        _ => Vec::new(),
+                    };
+                    self.compile(base, value)
+                        .map(|node| Keyword::AdditionalProperties(known, patterns, Box::new(node)))
+                }
+                "required" => strings(value).map(Keyword::Required),
+                "minProperties" => count(value).map(Keyword::MinProperties),
+                "maxProperties" => count(value).map(Keyword::MaxProperties),
+                "allOf" => self.schemas(base, value).map(Keyword::AllOf),
+                "anyOf" => self.schemas(base, value).map(Keyword::AnyOf),
+                "oneOf" => self.schemas(base, value).map(Keyword::OneOf),
+                "not" => self
+                    .compile(base, value)
+                    .map(|node| Keyword::Not(Box::new(node))),
+                _ => continue,
+            };
+            keywords.push((name.clone(), keyword.map_err(|e| at(name, e))?));
+        }
+        Ok(Node::Keywords(keywords))
+    }
+
+    fn reference(&mut self, base: &str, reference: &str) -> Result<usize, String> {
+        let target = self.resolver.resolve(base, reference)?;
+        let key = target.key();
+        if let Some(i) = self.targets.iter().position(|(k, _)| *k == key) {
+            return Ok(i);
+        }
+        let i = self.targets.len();
+        self.targets.push((key, None));
+        self.targets[i].1 = Some(self.compile(&target.uri, target.value)?);
+        Ok(i)
+    }
+
+    fn schemas(&mut self, base: &str, value: &Value) -> Result<Vec<Node>, String> {
+        match value {
+            Value::Array(values) if !values.is_empty() => values
+                .iter()
+                .enumerate()
+                .map(|(i, value)| self.compile(base, value).map_err(|e| at(&i.to_string(), e)))
+                .collect(),
+            Value::Array(_) => Err("expected at least one schema".to_string()),
+            value => Err(mismatch("array", value)),
+        }
+    }
+
+    fn named<T>(
+        &mut self,
+        base: &str,
+        value: &Value,
+        name: impl Fn(&str) -> Result<T, String>,
+    ) -> Result<Vec<(T, Node)>, String> {
+        match value {
+            Value::Object(object) => object
+                .iter()
+                .map(|(key, value)| {
+                    let entry = name(key).and_then(|name| Ok((name, self.compile(base, value)?)));
+                    entry.map_err(|e| at(key, e))
+                })
+                .collect(),
+            value => Err(mismatch("object", value)),
+        }
+    }
+}
+
+fn types(value: &Value) -> Result<Vec<String>, String> {
+    let names = match value {
+        Value::String(name) => vec![name.clone()],
+        value => strings(value)?,
+    };
+    match names.iter().find(|name| !TYPES.contains(&name.as_str())) {
+        Some(name) => Err(format!("unknown type {:?}", name)),
+        None => Ok(names),
+    }
+}
+
+fn count(value: &Value) -> Result<usize, String> {
+    match value {
+        Value::Number(n) if *n >= 0.0 && n.fract() == 0.0 => Ok(*n as usize),
+        value => Err(mismatch("non-negative integer", value)),
+    }
+}
+
+fn number(value: &Value) -> Result<f64, String> {
+    match value {
+        Value::Number(n) => Ok(*n),
+        value => Err(mismatch("number", value)),
+    }
+}
+
+fn strings(value: &Value) -> Result<Vec<String>, String> {
+    match value {
+        Value::Array(values) => values
+            .iter()
+            .enumerate()
+            .map(|(i, value)| match value {
+                Value::String(value) => Ok(value.clone()),
+                value => Err(at(&i.to_string(), mismatch("string", value))),
+            })
+            .collect(),
+        value => Err(mismatch("array", value)),
+    }
+}
+
+fn has_type(value: &Value, name: &str) -> bool {
+    match (name, value) {
+        ("null", Value::Null) | ("boolean", Value::Bool(_)) | ("number", Value::Number(_)) => true,
+        ("integer", Value::Number(n)) => n.is_finite() && n.fract() == 0.0,
+        ("string", Value::String(_)) | ("array", Value::Array(_)) => true,
+        ("object", Value::Object(_)) => true,
+        _ => false,
+    }
+}
+
+/// Walks an instance alongside the schema, collecting violations.
+struct Validator<'s> {
+    refs: &'s [Node],
+    path: JsonPointer,
+    schema_path: JsonPointer,
+    errors: Vec<ValidationError>,
+    /// The references being followed, with the depth of the instance at
+    /// which they were met: meeting one again at the same depth would
+    /// recurse forever.
+    following: Vec<(usize, usize)>,
+}
+
+impl Validator<'_> {
+    fn report(&mut self, message: String) {
+        self.errors.push(ValidationError {
+            instance_path: self.path.clone(),
+            schema_path: self.schema_path.clone(),
+            message,
+        });
+    }
+
+    fn node(&mut self, node: &Node, instance: &Value) {
+        match node {
+            Node::Always(true) => {}
+            Node::Always(false) => self.report("not allowed".to_string()),
+            Node::Keywords(keywords) => {
+                for (name, keyword) in keywords {
+                    self.schema_path.push(name.as_str());
+                    self.keyword(keyword, instance);
+                    self.schema_path.pop();
+                }
+            }
+        }
+    }
+
+    /// Validates against the `i`th schema of an applicator.
+    fn nth(&mut self, i: usize, node: &Node, instance: &Value) {
+        self.schema_path.push(i.to_string());
+        self.node(node, instance);
+        self.schema_path.pop();
+    }
+
+    /// Whether `instance` is valid against the `i`th schema of an
+    /// applicator, without reporting why it isn't.
+    fn passes(&mut self, i: usize, node: &Node, instance: &Value) -> bool {
+        let errors = std::mem::take(&mut self.errors);
+        self.nth(i, node, instance);
+        std::mem::replace(&mut self.errors, errors).is_empty()
+    }
+
+    /// Validates the element or property `token` of the instance against a
+    /// subschema, found under `schema_token` if the keyword holds several.
+    fn child(&mut self, schema_token: Option<&str>, token: &str, node: &Node, instance: &Value) {
+        if let Some(schema_token) = schema_token {
+            self.schema_path.push(schema_token);
+        }
+        self.path.push(token);
+        self.node(node, instance);
+        self.path.pop();
+        if schema_token.is_some() {
+            self.schema_path.pop();
+        }
+    }
+
+    fn keyword(&mut self, keyword: &Keyword, instance: &Value) {
+        match (keyword, instance) {
+            (Keyword::Ref(i), value) => {
+                let depth = self.path.tokens().len();
+                if self.following.contains(&(*i, depth)) {
+                    return self.report("circular $ref".to_string());
+                }
+                self.following.push((*i, depth));
+                let refs = self.refs;
+                self.node(&refs[*i], value);
+                self.following.pop();
+            }
+            (Keyword::Required(names), Value::Object(object)) => {
+                for name in names.iter().filter(|name| !object.contains_key(name)) {
+                    self.path.push(name.as_str());
+                    self.report("missing".to_string());
+                    self.path.pop();
+                }
+            }
+            (Keyword::PrefixItems(nodes), Value::Array(values)) => {
+                for (i, (node, value)) in nodes.iter().zip(values).enumerate() {
+                    let i = i.to_string();
+                    self.child(Some(&i), &i, node, value);
+                }
+            }
+            (Keyword::Items(skip, node), Value::Array(values)) => {
+                for (i, value) in values.iter().enumerate().skip(*skip) {
+                    self.child(None, &i.to_string(), node, value);
+                }
+            }
+            (Keyword::Properties(nodes), Value::Object(object)) => {
+                for (name, node) in nodes {
+                    if let Some(value) = object.get(name) {
+                        self.child(Some(name), name, node, value);
+                    }
+                }
+            }
+            (Keyword::PatternProperties(nodes), Value::Object(object)) => {
+                for (name, value) in object.iter() {
+                    for (pattern, node) in nodes.iter().filter(|(p, _)| is_match(p, name)) {
+                        self.child(Some(source(pattern)), name, node, value);
+                    }
+                }
+            }
+            (Keyword::AdditionalProperties(known, patterns, node), Value::Object(object)) => {
+                let additional = object.iter().filter(|(name, _)| {
+                    !known.contains(name) && !patterns.iter().any(|p| is_match(p, name))
+                });
+                for (name, value) in additional {
+                    self.child(None, name, node, value);
+                }
+            }
+            (Keyword::AllOf(nodes), value) => {
+                for (i, node) in nodes.iter().enumerate() {
+                    self.nth(i, node, value);
+                }
+            }
+            (Keyword::AnyOf(nodes), value) => {
+                if !(0..nodes.len()).any(|i| self.passes(i, &nodes[i], value)) {
+                    self.report("expected to match a schema of anyOf".to_string());
+                }
+            }
+            (Keyword::OneOf(nodes), value) => {
+                let matched: Vec<_> = (0..nodes.len())
+                    .filter(|&i| self.passes(i, &nodes[i], value))
+                    .map(|i| i.to_string())
+                    .collect();
+                let found = match matched.len() {
+                    1 => return,
+                    0 => "none".to_string(),
+                    _ => format!("schemas {}", matched.join(", ")),
+                };
+                self.report(format!(
+                    "expected to match one schema of oneOf, matched {}",
+                    found
+                ));
+            }
+            (Keyword::Not(node), value) => {
+                let errors = std::mem::take(&mut self.errors);
+                self.node(node, value);
+                if std::mem::replace(&mut self.errors, errors).is_empty() {
+                    self.report("expected not to match the schema of not".to_string());
+                }
+            }
+            (keyword, value) => {
+                if let Some(message) = keyword.check(value) {
+                    self.report(message);
+                }
+            }
+        }
+    }
+}
+
+impl Keyword {
+    /// Checks the keywords that only look at the instance itself.
+    fn check(&self, instance: &Value) -> Option<String> {
+        let length = |value: &str| value.chars().count();
+        Some(match (self, instance) {
+            (Keyword::Type(names), value) if !names.iter().any(|name| has_type(value, name)) => {
+                mismatch(&names.join(" or "), value)
+            }
+            (Keyword::Enum(values), value) if !values.contains(value) => format!(
+                "expected one of {}, found {}",
+                to_string(&Value::Array(values.clone())),
+                to_string(value)
+            ),
+            (Keyword::Const(expected), value) if expected != value => {
+                format!(
+                    "expected {}, found {}",
+                    to_string(expected),
+                    to_string(value)
+                )
+            }
+            (Keyword::MinLength(min), Value::String(value)) if length(value) < *min => format!(
+                "expected at least {} characters, found {}",
+                min,
+                length(value)
+            ),
+            (Keyword::MaxLength(max), Value::String(value)) if length(value) > *max => format!(
+                "expected at most {} characters, found {}",
+                max,
+                length(value)
+            ),
+            (Keyword::Pattern(pattern), Value::String(value)) if !is_match(pattern, value) => {
+                format!("expected to match {:?}, found {:?}", source(pattern), value)
+            }
+            (Keyword::Format(format), Value::String(value)) if !(format.checker)(value) => {
+                format!("expected {}, found {:?}", format.name, value)
+            }
+            (Keyword::Minimum(min), Value::Number(n)) if n < min => {
+                format!("expected at least {}, found {}", min, n)
+            }
+            (Keyword::Maximum(max), Value::Number(n)) if n > max => {
+                format!("expected at most {}, found {}", max, n)
+            }
+            (Keyword::ExclusiveMinimum(min), Value::Number(n)) if n <= min => {
+                format!("expected greater than {}, found {}", min, n)
+            }
+            (Keyword::ExclusiveMaximum(max), Value::Number(n)) if n >= max => {
+                format!("expected less than {}, found {}", max, n)
+            }
+            (Keyword::MultipleOf(divisor), Value::Number(n)) => {
+                let quotient = n / divisor;
+                if (quotient - quotient.round()).abs() <= 1e-9 * quotient.abs().max(1.0) {
+                    return None;
+                }
+                format!("expected a multiple of {}, found {}", divisor, n)
+            }
+            (Keyword::MinItems(min), Value::Array(values)) if values.len() < *min => {
+                format!("expected at least {} items, found {}", min, values.len())
+            }
+            (Keyword::MaxItems(max), Value::Array(values)) if values.len() > *max => {
+                format!("expected at most {} items, found {}", max, values.len())
+            }
+            (Keyword::UniqueItems, Value::Array(values)) => {
+                let (i, j) = (1..values.len())
+                    .find_map(|j| (0..j).find(|&i| values[i] == values[j]).map(|i| (i, j)))?;
+                format!("expected unique items, found /{} equal to /{}", j, i)
+            }
+            (Keyword::MinProperties(min), Value::Object(object)) if object.len() < *min => {
+                format!(
+                    "expected at least {} properties, found {}",
+                    min,
+                    object.len()
+                )
+            }
+            (Keyword::MaxProperties(max), Value::Object(object)) if object.len() > *max => {
+                format!(
+                    "expected at most {} properties, found {}",
+                    max,
+                    object.len()
+                )
+            }
+            _ => return None,
+        })
+    }
+}
@@ -0,0 +1,262 @@
+use super::infer_schema;
+use crate::parse;
+
+#[test]
+fn mixed_types() {
+    let samples = [
+        parse(r#"{ "a": 1, "b": null, "c": [1, 2.5] }"#).unwrap(),
+        parse(r#"{ "a": "x", "b": true, "c": [] }"#).unwrap(),
+    ];
+    assert_eq!(
+        infer_schema(samples.iter()),
+        parse(
+            r#"{
+                "$schema": "https://json-schema.org/draft/2020-12/schema",
+                "type": "object",
+                "properties": {
+                    "a": { "type": ["integer", "string"] },
+                    "b": { "type": ["null", "boolean"] },
+                    "c": { "type": "array", "items": { "type": "number" } }
+                },
+                "required": ["a", "b", "c"]
+            }"#
+        )
+        .unwrap()
+    );
+}
+
+#[test]
+fn enum_candidates() {
+    let repeated = [parse(r#""a""#).unwrap(), parse(r#""a""#).unwrap()];
+    assert_eq!(
+        infer_schema(repeated.iter()).pointer("/enum"),
+        Some(&parse(r#"["a"]"#).unwrap())
+    );
+    let distinct: Vec<_> = (0..20)
+        .map(|i| crate::Value::String((i % 10).to_string()))
+        .collect();
+    assert_eq!(infer_schema(distinct.iter()).pointer("/enum"), None);
+    let once = [parse(r#""a""#).unwrap(), parse(r#""b""#).unwrap()];
+    assert_eq!(infer_schema(once.iter()).pointer("/enum"), None);
+}
+
+#[test]
+fn no_samples() {
+    assert_eq!(
+        infer_schema(std::iter::empty()),
+        parse(r#"{ "$schema": "https://json-schema.org/draft/2020-12/schema" }"#).unwrap()
+    );
+}
+
+mod validation {
+    use crate::parse;
+    use crate::schema::Schema;
+
+    fn errors(schema: &str, instance: &str) -> Vec<String> {
+        let schema = Schema::compile(&parse(schema).unwrap()).unwrap();
+        match schema.validate(&parse(instance).unwrap()) {
+            Ok(()) => Vec::new(),
+            Err(errors) => errors.iter().map(|e| e.to_string()).collect(),
+        }
+    }
+
+    #[test]
+    fn scalars() {
+        assert_eq!(
+            errors(r#"{ "type": ["integer", "null"] }"#, "1.5"),
+            vec!["expected integer or null, found number"]
+        );
+        assert_eq!(
+            errors(r#"{ "enum": ["a", 1] }"#, r#""b""#),
+            vec![r#"expected one of ["a",1], found "b""#]
+        );
+        assert!(errors(r#"{ "const": { "a": [1] } }"#, r#"{ "a": [1.0] }"#).is_empty());
+        assert_eq!(
+            errors(r#"{ "maxLength": 2, "minimum": 3 }"#, r#""héé""#),
+            vec!["expected at most 2 characters, found 3"]
+        );
+        assert_eq!(
+            errors(r#"{ "exclusiveMaximum": 3, "multipleOf": 0.1 }"#, "3"),
+            vec!["expected less than 3, found 3"]
+        );
+        assert!(errors(r#"{ "multipleOf": 0.1 }"#, "0.3").is_empty());
+        assert_eq!(
+            errors(r#"{ "multipleOf": 2 }"#, "3"),
+            vec!["expected a multiple of 2, found 3"]
+        );
+    }
+
+    #[test]
+    fn arrays() {
+        let schema = r#"{
+            "prefixItems": [{ "type": "string" }],
+            "items": { "type": "integer" },
+            "maxItems": 3,
+            "uniqueItems": true
+        }"#;
+        assert!(errors(schema, r#"["a", 1, 2]"#).is_empty());
+        assert_eq!(
+            errors(schema, r#"[1, "b", 1, 1]"#),
+            vec![
+                "/0: expected string, found number",
+                "/1: expected integer, found string",
+                "expected at most 3 items, found 4",
+                "expected unique items, found /2 equal to /0",
+            ]
+        );
+    }
+
+    #[test]
+    fn objects() {
+        let schema = r#"{
+            "properties": { "a": { "type": "string" } },
+            "additionalProperties": { "type": "integer" },
+            "required": ["a", "b"],
+            "minProperties": 2
+        }"#;
+        assert!(errors(schema, r#"{ "a": "x", "b": 1 }"#).is_empty());
+        assert_eq!(
+            errors(schema, r#"{ "c": "y" }"#),
+            vec![
+                "/c: expected integer, found string",
+                "/a: missing",
+                "/b: missing",
+                "expected at least 2 properties, found 1",
+            ]
+        );
+    }
+
+    #[test]
+    fn applicators() {
+        let schema = r#"{
+            "anyOf": [{ "type": "string" }, { "type": "integer" }],
+            "oneOf": [{ "minimum": 0 }, { "multipleOf": 2 }],
+            "not": { "const": 3 }
+        }"#;
+        assert!(errors(schema, "1").is_empty());
+        assert_eq!(
+            errors(schema, r#""a""#),
+            vec!["expected to match one schema of oneOf, matched schemas 0, 1"]
+        );
+        assert_eq!(
+            errors(r#"{ "oneOf": [{ "type": "string" }, false] }"#, "4"),
+            vec!["expected to match one schema of oneOf, matched none"]
+        );
+        assert_eq!(
+            errors(schema, "4"),
+            vec!["expected to match one schema of oneOf, matched schemas 0, 1"]
+        );
+        assert_eq!(
+            errors(schema, "3"),
+            vec!["expected not to match the schema of not"]
+        );
+        assert_eq!(
+            errors(schema, "null"),
+            vec![
+                "expected to match a schema of anyOf",
+                "expected to match one schema of oneOf, matched schemas 0, 1"
+            ]
+        );
+        assert!(errors("true", "1").is_empty());
+        assert_eq!(errors("false", "1"), vec!["not allowed"]);
+    }
+
+    #[test]
+    fn invalid_schemas() {
+        let compile = |schema| Schema::compile(&parse(schema).unwrap()).map(|_| ());
+        assert_eq!(
+            compile(r#"{ "properties": { "a": { "minLength": -1 } } }"#),
+            Err("/properties/a/minLength: expected non-negative integer, found number".to_string())
+        );
+        assert_eq!(
+            compile(r#"{ "type": "int" }"#),
+            Err(r#"/type: unknown type "int""#.to_string())
+        );
+        assert_eq!(
+            compile(r#"{ "allOf": [true, 1] }"#),
+            Err("/allOf/1: expected schema, found number".to_string())
+        );
+    }
+
+    #[test]
+    fn references() {
+        let schema = r##"{
+            "$defs": {
+                "list": { "type": ["object", "null"], "properties": { "next": { "$ref": "#/$defs/list" } } },
+                "loop": { "$ref": "#/$defs/loop" }
+            },
+            "properties": {
+                "list": { "$ref": "#/$defs/list" },
+                "loop": { "$ref": "#/$defs/loop" }
+            }
+        }"##;
+        assert!(errors(schema, r#"{ "list": { "next": { "next": null } } }"#).is_empty());
+        assert_eq!(
+            errors(
+                schema,
+                r#"{ "list": { "next": { "next": 1 } }, "loop": 1 }"#
+            ),
+            vec![
+                "/list/next/next: expected object or null, found number",
+                "/loop: circular $ref",
+            ]
+        );
+        assert_eq!(
+            Schema::compile(&parse(r##"{ "items": { "$ref": "#/$defs/a" } }"##).unwrap())
+                .map(|_| ()),
+            Err(r##"/items/$ref: unresolvable $ref "#/$defs/a""##.to_string())
+        );
+    }
+
+    #[test]
+    fn schema_paths() {
+        let schema = r##"{
+            "$defs": { "name": { "type": "string" } },
+            "prefixItems": [{ "$ref": "#/$defs/name" }],
+            "items": { "allOf": [true, { "not": { "type": "integer" } }] }
+        }"##;
+        let schema = Schema::compile(&parse(schema).unwrap()).unwrap();
+        let paths: Vec<_> = schema
+            .validate(&parse("[1, 2]").unwrap())
+            .unwrap_err()
+            .iter()
+            .map(|e| (e.instance_path.to_string(), e.schema_path.to_string()))
+            .collect();
+        assert_eq!(
+            paths,
+            vec![
+                ("/0".to_string(), "/prefixItems/0/$ref/type".to_string()),
+                ("/1".to_string(), "/items/allOf/1/not".to_string()),
+            ]
+        );
+    }
+
+    #[test]
+    fn formats() {
+        let schema =
+            r#"{ "properties": { "at": { "format": "date-time" }, "x": { "format": "other" } } }"#;
+        assert!(errors(schema, r#"{ "at": "2024-01-01T00:00:00Z", "x": "y" }"#).is_empty());
+        assert_eq!(
+            errors(schema, r#"{ "at": "2024-01-01" }"#),
+            vec![r#"/at: expected date-time, found "2024-01-01""#]
+        );
+    }
+
+    #[cfg(feature = "regex")]
+    #[test]
+    fn patterns() {
+        let schema = r#"{
+            "pattern": "^[a-z]+$",
+            "patternProperties": { "^x-": { "type": "string" } },
+            "additionalProperties": false
+        }"#;
+        assert_eq!(
+            errors(schema, r#""A1""#),
+            vec![r#"expected to match "^[a-z]+$", found "A1""#]
+        );
+        assert_eq!(
+            errors(schema, r#"{ "x-a": "b", "x-c": 1, "d": 2 }"#),
+            vec!["/x-c: expected string, found number", "/d: not allowed"]
+        );
+    }
+}
@@ -0,0 +1,507 @@
+#[cfg(test)]
+mod tests;
+
+use crate::color::{Theme, RESET};
+use crate::number::{write_decimal, ExponentPolicy};
+use crate::{JsonObject, Value};
+use std::borrow::Cow;
+use std::cmp::Ordering;
+use std::fmt::{self, Write};
+use std::io;
+use std::sync::Arc;
+
+/// Serializes a value to compact JSON text.
+///
+/// # Examples
+///
+/// ```
+///# fn main() -> Result<(),String> {
+///let value = json::parse(r#"[ 1, "two", null ]"#)?;
+///
+///assert_eq!(json::to_string(&value), r#"[1,"two",null]"#);
+///# Ok(())
+///# }
+/// ```
+pub fn to_string(value: &Value) -> String {
+    let mut output = String::new();
+    write_value(&mut output, value, &KeyOrder::Insertion, None);
+    output
+}
+
+/// Appends the compact JSON text of a value to `output`, so that a buffer
+/// can be reused across calls.
+///
+/// # Examples
+///
+/// ```
+///# fn main() -> Result<(),String> {
+///let mut buffer = String::new();
+///json::to_string_into(&json::parse("[1, 2]")?, &mut buffer);
+///assert_eq!(buffer, "[1,2]");
+///
+///buffer.clear();
+///json::to_string_into(&json::parse(r#"{ "a": null }"#)?, &mut buffer);
+///assert_eq!(buffer, r#"{"a":null}"#);
+///# Ok(())
+///# }
+/// ```
+pub fn to_string_into(value: &Value, output: &mut String) {
+    write_value(output, value, &KeyOrder::Insertion, None);
+}
+
+/// Writes the compact JSON text of a value to `writer`.
+pub fn to_writer<W: io::Write>(writer: &mut W, value: &Value) -> Result<(), String> {
+    to_writer_with(writer, value, &mut Serializer::new())
+}
+
+/// Writes a value to `writer` as `serializer` formats it, reusing its
+/// buffer.
+///
+/// # Examples
+///
+/// ```
+///# fn main() -> Result<(),String> {
+///let mut serializer = json::Serializer::new();
+///let mut output = Vec::new();
+///for i in 0..3 {
+///    json::to_writer_with(&mut output, &json::Value::Number(i as f64), &mut serializer)?;
+///    output.push(b' ');
+///}
+///assert_eq!(output, b"0 1 2 ");
+///# Ok(())
+///# }
+/// ```
+pub fn to_writer_with<W: io::Write>(
+    writer: &mut W,
+    value: &Value,
+    serializer: &mut Serializer,
+) -> Result<(), String> {
+    writer
+        .write_all(serializer.serialize(value).as_bytes())
+        .map_err(|e| e.to_string())
+}
+
+/// A comparator of object keys, see [`KeyOrder::custom`].
+pub type KeyComparator = dyn Fn(&str, &str) -> Ordering + Send + Sync;
+
+/// How [`Serializer`] orders the members of objects.
+#[derive(Clone, Default)]
+pub enum KeyOrder {
+    /// The order of the object itself.
+    #[default]
+    Insertion,
+    /// Sorted by key.
+    Sorted,
+    /// The listed keys first, in the order given, then the others sorted.
+    Priority(Vec<String>),
+    /// Sorted by a comparator of keys, see [`KeyOrder::custom`].
+    Custom(Arc<KeyComparator>),
+}
+
+impl KeyOrder {
+    /// Lists `keys` first, then the others sorted.
+    pub fn priority(keys: &[&str]) -> Self {
+        KeyOrder::Priority(keys.iter().map(|key| key.to_string()).collect())
+    }
+
+    /// Sorts keys with `compare`. Keys comparing equal keep their order.
+    pub fn custom<F: Fn(&str, &str) -> Ordering + Send + Sync + 'static>(compare: F) -> Self {
+        KeyOrder::Custom(Arc::new(compare))
+    }
+
+    pub fn compare(&self, a: &str, b: &str) -> Ordering {
+        match self {
+            KeyOrder::Insertion => Ordering::Equal,
+            KeyOrder::Sorted => a.cmp(b),
+            KeyOrder::Priority(keys) => {
+                let rank = |key| keys.iter().position(|k| k == key);
+                match (rank(a), rank(b)) {
+                    (Some(a), Some(b)) => a.cmp(&b),
+                    (Some(_), None) => Ordering::Less,
+                    (None, Some(_)) => Ordering::Greater,
+                    (None, None) => a.cmp(b),
+                }
+            }
+            KeyOrder::Custom(compare) => compare(a, b),
+        }
+    }
+}
+
+impl fmt::Debug for KeyOrder {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            KeyOrder::Insertion => write!(f, "Insertion"),
+            KeyOrder::Sorted => write!(f, "Sorted"),
+            KeyOrder::Priority(keys) => f.debug_tuple("Priority").field(keys).finish(),
+            KeyOrder::Custom(_) => write!(f, "Custom(..)"),
+        }
+    }
+}
+
+/// Calls `f` with the index, key and value of every entry, in `order`.
+fn for_each_entry<'a, F: FnMut(usize, &'a String, &'a Value)>(
+    object: &'a JsonObject,
+    order: &KeyOrder,
+    mut f: F,
+) {
+    if let KeyOrder::Insertion = order {
+        for (i, (key, value)) in object.iter().enumerate() {
+            f(i, key, value);
+        }
+    } else {
+        let mut entries: Vec<_> = object.iter().collect();
+        entries.sort_by(|(a, _), (b, _)| order.compare(a, b));
+        for (i, (key, value)) in entries.into_iter().enumerate() {
+            f(i, key, value);
+        }
+    }
+}
+
+/// Serializes values into a buffer it keeps, so that serializing many of
+/// them only allocates while the buffer grows.
+#[derive(Debug, Clone, Default)]
+pub struct Serializer {
+    buffer: String,
+    pretty: bool,
+    key_order: KeyOrder,
+    exponent_policy: Option<ExponentPolicy>,
+}
+
+impl Serializer {
+    /// A serializer writing compact JSON, as [`to_string`] does.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// A serializer writing indented JSON, as [`to_string_pretty`] does.
+    pub fn pretty() -> Self {
+        Serializer {
+            pretty: true,
+            ..Self::default()
+        }
+    }
+
+    /// Writes the members of objects in `order` rather than their own.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    ///# fn main() -> Result<(),String> {
+    ///use json::{KeyOrder, Serializer};
+    ///
+    ///let value = json::parse(r#"{ "version": 1, "name": "app", "id": 7, "deps": {} }"#)?;
+    ///
+    ///let mut serializer = Serializer::new().with_key_order(KeyOrder::priority(&["id", "name"]));
+    ///assert_eq!(serializer.serialize(&value), r#"{"id":7,"name":"app","deps":{},"version":1}"#);
+    ///
+    ///let by_length = KeyOrder::custom(|a, b| a.len().cmp(&b.len()));
+    ///let mut serializer = Serializer::new().with_key_order(by_length);
+    ///assert_eq!(serializer.serialize(&value), r#"{"id":7,"name":"app","deps":{},"version":1}"#);
+    ///# Ok(())
+    ///# }
+    /// ```
+    pub fn with_key_order(mut self, order: KeyOrder) -> Self {
+        self.key_order = order;
+        self
+    }
+
+    /// Writes numbers from their shortest round-tripping digits, placing
+    /// the exponent as `policy` says, as [`normalize_number`] does with
+    /// literals. `-0` is written `0`, so equal numbers are written alike.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    ///# fn main() -> Result<(),String> {
+    ///use json::{ExponentPolicy, Serializer};
+    ///
+    ///let value = json::parse("[1500, -0.0, 1e-7, 1e21]")?;
+    ///
+    ///let mut serializer = Serializer::new().with_exponent_policy(ExponentPolicy::Always);
+    ///assert_eq!(serializer.serialize(&value), "[1.5e+3,0,1e-7,1e+21]");
+    ///let mut serializer = Serializer::new().with_exponent_policy(ExponentPolicy::Never);
+    ///assert_eq!(serializer.serialize(&value), "[1500,0,0.0000001,1000000000000000000000]");
+    ///# Ok(())
+    ///# }
+    /// ```
+    ///
+    /// [`normalize_number`]: crate::normalize_number
+    pub fn with_exponent_policy(mut self, policy: ExponentPolicy) -> Self {
+        self.exponent_policy = Some(policy);
+        self
+    }
+
+    /// Returns the JSON text of `value`, valid until the next call.
+    pub fn serialize(&mut self, value: &Value) -> &str {
+        self.buffer.clear();
+        let (order, numbers) = (&self.key_order, self.exponent_policy);
+        if self.pretty {
+            write_pretty(&mut self.buffer, value, 0, &Theme::plain(), order, numbers);
+        } else {
+            write_value(&mut self.buffer, value, order, numbers);
+        }
+        &self.buffer
+    }
+}
+
+/// Numbers are written as [`write_number`] does unless `numbers` names an
+/// exponent policy.
+fn write_value(
+    output: &mut String,
+    value: &Value,
+    order: &KeyOrder,
+    numbers: Option<ExponentPolicy>,
+) {
+    match value {
+        Value::Null => output.push_str("null"),
+        Value::Bool(value) => output.push_str(if *value { "true" } else { "false" }),
+        Value::Number(value) => match numbers {
+            Some(policy) => write_number_with(output, *value, policy),
+            None => write_number(output, *value),
+        },
+        Value::String(value) => write_string(output, value),
+        Value::Array(values) => {
+            output.push('[');
+            for (i, value) in values.iter().enumerate() {
+                if i > 0 {
+                    output.push(',');
+                }
+                write_value(output, value, order, numbers);
+            }
+            output.push(']');
+        }
+        Value::Object(object) => {
+            output.push('{');
+            for_each_entry(object, order, |i, key, value| {
+                if i > 0 {
+                    output.push(',');
+                }
+                write_string(output, key);
+                output.push(':');
+                write_value(output, value, order, numbers);
+            });
+            output.push('}');
+        }
+    }
+}
+
+/// Serializes a value to JSON text indented by two spaces, one element or
+/// entry per line.
+///
+/// # Examples
+///
+/// ```
+///# fn main() -> Result<(),String> {
+///let value = json::parse(r#"{ "a": [1, {}], "b": "c" }"#)?;
+///
+///assert_eq!(json::to_string_pretty(&value), "{\n  \"a\": [\n    1,\n    {}\n  ],\n  \"b\": \"c\"\n}");
+///# Ok(())
+///# }
+/// ```
+pub fn to_string_pretty(value: &Value) -> String {
+    let mut output = String::new();
+    write_pretty(
+        &mut output,
+        value,
+        0,
+        &Theme::plain(),
+        &KeyOrder::Insertion,
+        None,
+    );
+    output
+}
+
+pub(crate) fn write_pretty(
+    output: &mut String,
+    value: &Value,
+    depth: usize,
+    theme: &Theme,
+    order: &KeyOrder,
+    numbers: Option<ExponentPolicy>,
+) {
+    let indent = |output: &mut String, depth| output.extend(std::iter::repeat("  ").take(depth));
+    let punctuation = |output: &mut String, text| paint(output, &theme.punctuation, text);
+    match value {
+        Value::Array(values) if !values.is_empty() => {
+            punctuation(output, "[");
+            for (i, value) in values.iter().enumerate() {
+                if i > 0 {
+                    punctuation(output, ",");
+                }
+                output.push('\n');
+                indent(output, depth + 1);
+                write_pretty(output, value, depth + 1, theme, order, numbers);
+            }
+            output.push('\n');
+            indent(output, depth);
+            punctuation(output, "]");
+        }
+        Value::Object(object) if !object.is_empty() => {
+            punctuation(output, "{");
+            for_each_entry(object, order, |i, key, value| {
+                if i > 0 {
+                    punctuation(output, ",");
+                }
+                output.push('\n');
+                indent(output, depth + 1);
+                let mut quoted = String::new();
+                write_string(&mut quoted, key);
+                paint(output, &theme.key, &quoted);
+                punctuation(output, ":");
+                output.push(' ');
+                write_pretty(output, value, depth + 1, theme, order, numbers);
+            });
+            output.push('\n');
+            indent(output, depth);
+            punctuation(output, "}");
+        }
+        value => {
+            let color = match value {
+                Value::String(_) => &theme.string,
+                Value::Number(_) => &theme.number,
+                Value::Array(_) | Value::Object(_) => &theme.punctuation,
+                _ => &theme.literal,
+            };
+            let mut text = String::new();
+            write_value(&mut text, value, order, numbers);
+            paint(output, color, &text);
+        }
+    }
+}
+
+/// Wraps `text` in the given ANSI escape sequence, if any.
+fn paint(output: &mut String, color: &str, text: &str) {
+    if color.is_empty() {
+        output.push_str(text);
+    } else {
+        output.push_str(color);
+        output.push_str(text);
+        output.push_str(RESET);
+    }
+}
+
+/// Non-finite numbers have no JSON representation and are written as `null`.
+pub(crate) fn write_number(output: &mut String, value: f64) {
+    if value.is_finite() {
+        write!(output, "{}", value).unwrap();
+    } else {
+        output.push_str("null");
+    }
+}
+
+/// Escapes text to be placed within a JSON string, as the serializer does,
+/// only allocating when some character needs it. Quotes, backslashes and
+/// control characters are escaped; everything else, `/` and non-ASCII
+/// characters included, is kept.
+///
+/// # Examples
+///
+/// ```
+///assert_eq!(json::escape_str("plain text"), "plain text");
+///assert_eq!(json::escape_str("say \"hi\"\n"), r#"say \"hi\"\n"#);
+///assert_eq!(json::escape_str("\u{1}é"), r"\u0001é");
+/// ```
+pub fn escape_str(value: &str) -> Cow<'_, str> {
+    if !value.chars().any(|c| c == '"' || c == '\\' || c < ' ') {
+        return Cow::Borrowed(value);
+    }
+    let mut output = String::with_capacity(value.len() + 2);
+    write_escaped(&mut output, value);
+    Cow::Owned(output)
+}
+
+pub(crate) fn write_string(output: &mut String, value: &str) {
+    output.push('"');
+    write_escaped(output, value);
+    output.push('"');
+}
+
+fn write_escaped(output: &mut String, value: &str) {
+    for c in value.chars() {
+        match c {
+            '"' => output.push_str("\\\""),
+            '\\' => output.push_str("\\\\"),
+            '\n' => output.push_str("\\n"),
+            '\r' => output.push_str("\\r"),
+            '\t' => output.push_str("\\t"),
+            '\u{8}' => output.push_str("\\b"),
+            '\u{c}' => output.push_str("\\f"),
+            c if c < ' ' => write!(output, "\\u{:04x}", c as u32).unwrap(),
+            c => output.push(c),
+        }
+    }
+}
+
+/// Serializes a value in the RFC 8785 (JCS) canonical form: no whitespace,
+/// object keys sorted by their UTF-16 code units and numbers formatted as
+/// ECMAScript does.
+///
+/// # Examples
+///
+/// ```
+///# fn main() -> Result<(),String> {
+///let value = json::parse(r#"{ "b": [1.0, 1e21], "a": "é" }"#)?;
+///
+///assert_eq!(json::to_string_canonical(&value), r#"{"a":"é","b":[1,1e+21]}"#);
+///# Ok(())
+///# }
+/// ```
+pub fn to_string_canonical(value: &Value) -> String {
+    let mut output = String::new();
+    write_canonical(&mut output, value);
+    output
+}
+
+fn write_canonical(output: &mut String, value: &Value) {
+    match value {
+        Value::Number(value) => write_number_with(output, *value, ExponentPolicy::Ecmascript),
+        Value::Array(values) => {
+            output.push('[');
+            for (i, value) in values.iter().enumerate() {
+                if i > 0 {
+                    output.push(',');
+                }
+                write_canonical(output, value);
+            }
+            output.push(']');
+        }
+        Value::Object(object) => {
+            let mut entries: Vec<_> = object.iter().collect();
+            entries.sort_by(|(a, _), (b, _)| a.encode_utf16().cmp(b.encode_utf16()));
+            output.push('{');
+            for (i, (key, value)) in entries.into_iter().enumerate() {
+                if i > 0 {
+                    output.push(',');
+                }
+                write_string(output, key);
+                output.push(':');
+                write_canonical(output, value);
+            }
+            output.push('}');
+        }
+        value => write_value(output, value, &KeyOrder::Insertion, None),
+    }
+}
+
+/// Formats a number from its shortest round-tripping digits. With
+/// [`ExponentPolicy::Ecmascript`], this is ECMAScript's
+/// `Number.prototype.toString`, which is what RFC 8785 mandates.
+fn write_number_with(output: &mut String, value: f64, policy: ExponentPolicy) {
+    if !value.is_finite() {
+        output.push_str("null");
+        return;
+    }
+    if value == 0.0 {
+        output.push('0');
+        return;
+    }
+    if value < 0.0 {
+        output.push('-');
+    }
+    // `{:e}` yields the shortest round-tripping digits, e.g. `1.2345e-7`.
+    let scientific = format!("{:e}", value.abs());
+    let (mantissa, exponent) = scientific.split_at(scientific.find('e').unwrap());
+    let digits: String = mantissa.chars().filter(|c| *c != '.').collect();
+    let n = exponent[1..].parse::<i64>().unwrap() + 1;
+    write_decimal(output, &digits, n, policy);
+}
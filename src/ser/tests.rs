@@ -0,0 +1,146 @@
+use super::{
+    escape_str, to_string, to_string_canonical, to_string_into, to_string_pretty, to_writer,
+    ExponentPolicy, KeyOrder, Serializer,
+};
+use crate::{parse, unescape_str, Value};
+use std::borrow::Cow;
+
+#[test]
+fn compact() {
+    assert_eq!(
+        to_string(&parse(r#"[null, true, 4.5, "a\"b", []]"#).unwrap()),
+        r#"[null,true,4.5,"a\"b",[]]"#
+    );
+    assert_eq!(
+        to_string(&Value::String("\n\u{1}".to_string())),
+        r#""\n\u0001""#
+    );
+    assert_eq!(to_string(&Value::Number(f64::NAN)), "null");
+}
+
+#[test]
+fn canonical_numbers() {
+    let cases = [
+        (0.0, "0"),
+        (-0.0, "0"),
+        (1.0, "1"),
+        (-1.5, "-1.5"),
+        (100.0, "100"),
+        (0.000001, "0.000001"),
+        (0.0000001, "1e-7"),
+        (123e18, "123000000000000000000"),
+        (1e21, "1e+21"),
+        (1.2345e25, "1.2345e+25"),
+        (5e-324, "5e-324"),
+    ];
+    for (number, expected) in cases.iter() {
+        assert_eq!(to_string_canonical(&Value::Number(*number)), *expected);
+    }
+}
+
+#[test]
+fn canonical_key_order() {
+    assert_eq!(
+        to_string_canonical(&parse(r#"{ "b": 1, "a": { "d": 2, "c": 3 } }"#).unwrap()),
+        r#"{"a":{"c":3,"d":2},"b":1}"#
+    );
+    let mut object = crate::JsonObject::new();
+    object.insert("\u{fb01}".to_string(), Value::Null);
+    object.insert("\u{1f600}".to_string(), Value::Null);
+    object.insert("b".to_string(), Value::Null);
+    // U+1F600 encodes as a surrogate pair, which sorts before U+FB01 in UTF-16.
+    assert_eq!(
+        to_string_canonical(&Value::Object(object)),
+        "{\"b\":null,\"\u{1f600}\":null,\"\u{fb01}\":null}"
+    );
+}
+
+#[test]
+fn pretty() {
+    assert_eq!(to_string_pretty(&parse("[]").unwrap()), "[]");
+    assert_eq!(to_string_pretty(&parse("1").unwrap()), "1");
+    assert_eq!(
+        to_string_pretty(&parse(r#"{ "a": { "b": [true, null] }, "c": {} }"#).unwrap()),
+        "{\n  \"a\": {\n    \"b\": [\n      true,\n      null\n    ]\n  },\n  \"c\": {}\n}"
+    );
+}
+
+#[test]
+fn reused_buffers() {
+    let value = parse(r#"{ "a": [1, "b"] }"#).unwrap();
+    let mut output = "x".to_string();
+    to_string_into(&value, &mut output);
+    assert_eq!(output, r#"x{"a":[1,"b"]}"#);
+
+    let mut serializer = Serializer::pretty();
+    assert_eq!(serializer.serialize(&value), to_string_pretty(&value));
+    assert_eq!(serializer.serialize(&Value::Null), "null");
+
+    let mut written = Vec::new();
+    to_writer(&mut written, &value).unwrap();
+    assert_eq!(written, to_string(&value).into_bytes());
+}
+
+#[test]
+fn escape_str_round_trips() {
+    assert!(matches!(escape_str("a/b é"), Cow::Borrowed("a/b é")));
+    let text: String = (0..0x80u8).map(char::from).chain("é😀".chars()).collect();
+    let escaped = escape_str(&text);
+    assert!(!escaped.chars().any(|c| c < ' '));
+    assert_eq!(unescape_str(&escaped).unwrap(), text);
+    assert_eq!(
+        parse(format!("\"{}\"", escaped).as_str()),
+        Ok(Value::String(text))
+    );
+    assert!(matches!(unescape_str("plain"), Ok(Cow::Borrowed("plain"))));
+}
+
+#[test]
+fn key_order() {
+    let value =
+        parse(r#"{ "c": { "z": 1, "id": 2 }, "id": [{ "b": 3, "a": 4 }], "a": null }"#).unwrap();
+    let mut sorted = Serializer::new().with_key_order(KeyOrder::Sorted);
+    assert_eq!(
+        sorted.serialize(&value),
+        r#"{"a":null,"c":{"id":2,"z":1},"id":[{"a":4,"b":3}]}"#
+    );
+    let mut priority = Serializer::pretty().with_key_order(KeyOrder::priority(&["id", "c"]));
+    assert_eq!(
+        priority.serialize(&value),
+        "{\n  \"id\": [\n    {\n      \"a\": 4,\n      \"b\": 3\n    }\n  ],\n  \"c\": {\n    \"id\": 2,\n    \"z\": 1\n  },\n  \"a\": null\n}"
+    );
+    let mut reversed = Serializer::new().with_key_order(KeyOrder::custom(|a, b| b.cmp(a)));
+    assert_eq!(
+        reversed.serialize(&value),
+        r#"{"id":[{"b":3,"a":4}],"c":{"z":1,"id":2},"a":null}"#
+    );
+    assert_eq!(
+        format!("{:?}", KeyOrder::custom(|a, b| a.cmp(b))),
+        "Custom(..)"
+    );
+}
+
+#[test]
+fn exponent_policy() {
+    let value = parse("[0, -0.0, 1500, 0.25, 1e-7, 1e21, -12.5e30]").unwrap();
+    let cases = [
+        (
+            ExponentPolicy::Never,
+            "[0,0,1500,0.25,0.0000001,1000000000000000000000,-12500000000000000000000000000000]",
+        ),
+        (
+            ExponentPolicy::Always,
+            "[0,0,1.5e+3,2.5e-1,1e-7,1e+21,-1.25e+31]",
+        ),
+        (
+            ExponentPolicy::Ecmascript,
+            "[0,0,1500,0.25,1e-7,1e+21,-1.25e+31]",
+        ),
+    ];
+    for (policy, expected) in cases.iter() {
+        let mut serializer = Serializer::new().with_exponent_policy(*policy);
+        assert_eq!(serializer.serialize(&value), *expected);
+    }
+    let mut pretty = Serializer::pretty().with_exponent_policy(ExponentPolicy::Always);
+    assert_eq!(pretty.serialize(&parse("[100]").unwrap()), "[\n  1e+2\n]");
+}
@@ -0,0 +1,70 @@
+use super::from_value;
+use crate::{parse, Value};
+use serde::Deserialize;
+use std::collections::BTreeMap;
+
+#[derive(Debug, PartialEq, Deserialize)]
+struct Server {
+    name: String,
+    port: u16,
+    weight: f32,
+    tags: Vec<String>,
+    limits: Option<BTreeMap<String, u32>>,
+    kind: Kind,
+    shapes: Vec<Shape>,
+}
+
+#[derive(Debug, PartialEq, Deserialize)]
+#[serde(rename_all = "lowercase")]
+enum Kind {
+    Primary,
+    Replica,
+}
+
+#[derive(Debug, PartialEq, Deserialize)]
+enum Shape {
+    Circle(f64),
+    Rect { w: u8, h: u8 },
+    Line(u8, u8),
+}
+
+#[test]
+fn structs() {
+    let value = parse(
+        r#"{ "name": "a", "port": 80, "weight": 0.5, "tags": [], "kind": "replica",
+             "shapes": [{ "Circle": 1.5 }, { "Rect": { "w": 1, "h": 2 } }, { "Line": [3, 4] }] }"#,
+    )
+    .unwrap();
+    assert_eq!(
+        from_value::<Server>(value),
+        Ok(Server {
+            name: "a".to_string(),
+            port: 80,
+            weight: 0.5,
+            tags: Vec::new(),
+            limits: None,
+            kind: Kind::Replica,
+            shapes: vec![
+                Shape::Circle(1.5),
+                Shape::Rect { w: 1, h: 2 },
+                Shape::Line(3, 4)
+            ],
+        })
+    );
+}
+
+#[test]
+fn errors() {
+    let error = from_value::<u8>(Value::Number(300.0)).unwrap_err();
+    assert_eq!(
+        error.to_string(),
+        "invalid value: integer `300`, expected u8"
+    );
+    let error = from_value::<Kind>(parse(r#""backup""#).unwrap()).unwrap_err();
+    assert_eq!(
+        String::from(error),
+        "unknown variant `backup`, expected `primary` or `replica`"
+    );
+    assert!(from_value::<(u8, u8)>(parse("[1, 2, 3]").unwrap()).is_err());
+    assert!(from_value::<Shape>(parse(r#"{ "Circle": "x" }"#).unwrap()).is_err());
+}
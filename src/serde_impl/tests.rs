@@ -0,0 +1,42 @@
+use crate::{parse, JsonObject, Value};
+use serde_test::{assert_de_tokens, assert_tokens, Token};
+
+#[test]
+fn round_trip() {
+    let value = parse(r#"{ "b": [1, 2.5, null], "a": true, "c": "x" }"#).unwrap();
+    assert_tokens(
+        &value,
+        &[
+            Token::Map { len: Some(3) },
+            Token::Str("b"),
+            Token::Seq { len: Some(3) },
+            Token::I64(1),
+            Token::F64(2.5),
+            Token::Unit,
+            Token::SeqEnd,
+            Token::Str("a"),
+            Token::Bool(true),
+            Token::Str("c"),
+            Token::Str("x"),
+            Token::MapEnd,
+        ],
+    );
+}
+
+#[test]
+fn deserialize_other_types() {
+    assert_de_tokens(&Value::Number(7.0), &[Token::U8(7)]);
+    assert_de_tokens(&Value::Null, &[Token::None]);
+    assert_de_tokens(&Value::Bool(true), &[Token::Some, Token::Bool(true)]);
+    assert_de_tokens(&Value::Number(1e300), &[Token::F64(1e300)]);
+    let object: JsonObject = vec![("k".to_string(), Value::Null)].into_iter().collect();
+    assert_de_tokens(
+        &object,
+        &[
+            Token::Map { len: None },
+            Token::String("k"),
+            Token::Unit,
+            Token::MapEnd,
+        ],
+    );
+}
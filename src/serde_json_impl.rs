@@ -0,0 +1,62 @@
+#[cfg(test)]
+mod tests;
+
+use crate::serde_impl::integer;
+use crate::{JsonObject, Value};
+
+/// Numbers become `f64`, so integers beyond 2^53 lose precision. Key order
+/// is kept when serde_json's `preserve_order` feature is enabled, and
+/// sorted otherwise.
+///
+/// # Examples
+///
+/// ```
+///let value = json::Value::from(serde_json::json!({ "id": 7, "tags": ["a", null] }));
+///
+///assert_eq!(value, json::parse(r#"{ "id": 7, "tags": ["a", null] }"#).unwrap());
+///assert_eq!(serde_json::Value::from(value), serde_json::json!({ "id": 7, "tags": ["a", null] }));
+/// ```
+impl From<serde_json::Value> for Value {
+    fn from(value: serde_json::Value) -> Self {
+        match value {
+            serde_json::Value::Null => Value::Null,
+            serde_json::Value::Bool(value) => Value::Bool(value),
+            serde_json::Value::Number(value) => Value::Number(value.as_f64().unwrap_or(f64::NAN)),
+            serde_json::Value::String(value) => Value::String(value),
+            serde_json::Value::Array(values) => {
+                Value::Array(values.into_iter().map(Value::from).collect())
+            }
+            serde_json::Value::Object(map) => Value::Object(
+                map.into_iter()
+                    .map(|(key, value)| (key, Value::from(value)))
+                    .collect::<JsonObject>(),
+            ),
+        }
+    }
+}
+
+/// Integral numbers that fit in an `i64` become serde_json integers, other
+/// finite ones floats and the non-finite ones `null`.
+impl From<Value> for serde_json::Value {
+    fn from(value: Value) -> Self {
+        match value {
+            Value::Null => serde_json::Value::Null,
+            Value::Bool(value) => serde_json::Value::Bool(value),
+            Value::Number(value) => match integer(value) {
+                Some(value) => serde_json::Value::Number(value.into()),
+                None => serde_json::Number::from_f64(value)
+                    .map_or(serde_json::Value::Null, serde_json::Value::Number),
+            },
+            Value::String(value) => serde_json::Value::String(value),
+            Value::Array(values) => {
+                serde_json::Value::Array(values.into_iter().map(serde_json::Value::from).collect())
+            }
+            Value::Object(object) => serde_json::Value::Object(
+                object
+                    .into_iter()
+                    .map(|(key, value)| (key, serde_json::Value::from(value)))
+                    .collect(),
+            ),
+        }
+    }
+}
@@ -0,0 +1,21 @@
+use crate::{parse, Value};
+use serde_json::json;
+
+#[test]
+fn numbers() {
+    assert_eq!(Value::from(json!(u64::MAX)), Value::Number(u64::MAX as f64));
+    assert_eq!(Value::from(json!(-1.5)), Value::Number(-1.5));
+    assert_eq!(serde_json::Value::from(Value::Number(3.0)), json!(3));
+    assert!(serde_json::Value::from(Value::Number(3.0)).is_i64());
+    assert_eq!(serde_json::Value::from(Value::Number(0.5)), json!(0.5));
+    assert_eq!(
+        serde_json::Value::from(Value::Number(f64::NAN)),
+        json!(null)
+    );
+}
+
+#[test]
+fn round_trip() {
+    let value = parse(r#"{ "a": [true, null, "x", { "b": 1e300 }], "c": {} }"#).unwrap();
+    assert_eq!(Value::from(serde_json::Value::from(value.clone())), value);
+}
@@ -0,0 +1,59 @@
+use super::to_value;
+use crate::{from_value, parse};
+use serde::{Deserialize, Serialize};
+use std::collections::{BTreeMap, HashMap};
+
+#[derive(Debug, PartialEq, Serialize, Deserialize)]
+struct Order {
+    id: u64,
+    note: Option<String>,
+    lines: Vec<(String, f32)>,
+    status: Status,
+    totals: BTreeMap<u8, bool>,
+}
+
+#[derive(Debug, PartialEq, Serialize, Deserialize)]
+enum Status {
+    Open,
+    Held(String),
+    Split(u8, u8),
+    Shipped { carrier: char },
+}
+
+#[test]
+fn round_trip() {
+    for status in [
+        Status::Open,
+        Status::Held("x".to_string()),
+        Status::Split(1, 2),
+        Status::Shipped { carrier: 'u' },
+    ] {
+        let order = Order {
+            id: 1,
+            note: None,
+            lines: vec![("a".to_string(), 1.5)],
+            status,
+            totals: vec![(1, true)].into_iter().collect(),
+        };
+        let value = to_value(&order).unwrap();
+        assert_eq!(from_value::<Order>(value), Ok(order));
+    }
+    assert_eq!(
+        to_value(&Status::Shipped { carrier: 'u' }).unwrap(),
+        parse(r#"{ "Shipped": { "carrier": "u" } }"#).unwrap()
+    );
+    assert_eq!(
+        to_value(&Status::Split(1, 2)).unwrap(),
+        parse(r#"{ "Split": [1, 2] }"#).unwrap()
+    );
+}
+
+#[test]
+fn invalid_keys() {
+    let mut map = HashMap::new();
+    map.insert(vec![1], 1);
+    assert_eq!(
+        to_value(&map).unwrap_err().to_string(),
+        "map key must be a string, a number or a boolean"
+    );
+}
@@ -0,0 +1,178 @@
+#[cfg(test)]
+mod tests;
+
+use crate::Value;
+use std::collections::BTreeMap;
+use std::fmt;
+
+/// The types observed at one path across a set of records. Paths use `.`
+/// between keys and `[]` for the elements of an array, e.g. `users[].name`.
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub struct FieldSummary {
+    pub path: String,
+    /// Occurrences of each type, keyed by [`Value::type_name`].
+    pub types: BTreeMap<&'static str, usize>,
+    /// How many of the enclosing objects lacked the field.
+    pub missing: usize,
+}
+
+impl FieldSummary {
+    /// Number of enclosing values the field could have appeared in.
+    pub fn total(&self) -> usize {
+        self.types.values().sum::<usize>() + self.missing
+    }
+
+    pub fn is_optional(&self) -> bool {
+        self.missing > 0
+    }
+}
+
+impl fmt::Display for FieldSummary {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let mut counts: Vec<_> = self.types.iter().map(|(t, n)| (*t, *n)).collect();
+        if self.missing > 0 {
+            counts.push(("missing", self.missing));
+        }
+        counts.sort_by_key(|(_, count)| std::cmp::Reverse(*count));
+        let total = self.total() as f64;
+        write!(f, "{}:", self.path)?;
+        for (i, (name, count)) in counts.into_iter().enumerate() {
+            let separator = if i == 0 { " " } else { ", " };
+            let percent = (count as f64 * 100.0 / total).round();
+            write!(f, "{}{} ({}%)", separator, name, percent)?;
+        }
+        Ok(())
+    }
+}
+
+/// The union of the fields found in a set of records, sorted by path.
+#[derive(Debug, PartialEq, Eq, Clone, Default)]
+pub struct ShapeSummary {
+    pub records: usize,
+    pub fields: Vec<FieldSummary>,
+}
+
+impl ShapeSummary {
+    pub fn field(&self, path: &str) -> Option<&FieldSummary> {
+        self.fields.iter().find(|field| field.path == path)
+    }
+}
+
+impl fmt::Display for ShapeSummary {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for field in &self.fields {
+            writeln!(f, "{}", field)?;
+        }
+        Ok(())
+    }
+}
+
+/// Reports every field seen in `records` with the share of each type.
+///
+/// # Examples
+///
+/// ```
+///# fn main() -> Result<(),String> {
+///let records = json::parse(r#"[
+///    { "user": { "name": "John", "age": 42 } },
+///    { "user": { "name": "Jane", "age": "unknown" } },
+///    { "user": { "name": "Joan" } },
+///    { "user": null }
+///]"#)?;
+///
+///let summary = records.summarize_shape();
+///assert_eq!(summary.records, 4);
+///assert_eq!(
+///    summary.field("user.age").unwrap().to_string(),
+///    "user.age: number (33%), string (33%), missing (33%)"
+///);
+///assert_eq!(
+///    summary.to_string(),
+///    "user: object (75%), null (25%)\nuser.age: number (33%), string (33%), missing (33%)\nuser.name: string (100%)\n"
+///);
+///# Ok(())
+///# }
+/// ```
+pub fn summarize<'a>(records: impl IntoIterator<Item = &'a Value>) -> ShapeSummary {
+    let mut fields = BTreeMap::new();
+    let mut count = 0;
+    for record in records {
+        count += 1;
+        observe(&mut fields, "", None, record);
+    }
+    let objects_at = |fields: &BTreeMap<String, Observed>, path: &str| {
+        fields
+            .get(path)
+            .and_then(|observed| observed.types.get("object"))
+            .copied()
+            .unwrap_or(0)
+    };
+    let summaries = fields
+        .iter()
+        .filter(|(path, _)| !path.is_empty())
+        .map(|(path, observed)| {
+            let seen = observed.types.values().sum::<usize>();
+            let missing = match &observed.parent {
+                Some(parent) => objects_at(&fields, parent) - seen,
+                None => 0,
+            };
+            FieldSummary {
+                path: path.clone(),
+                types: observed.types.clone(),
+                missing,
+            }
+        })
+        .collect();
+    ShapeSummary {
+        records: count,
+        fields: summaries,
+    }
+}
+
+#[derive(Debug, Default)]
+struct Observed {
+    types: BTreeMap<&'static str, usize>,
+    /// Path of the object holding this field; `None` for array elements,
+    /// which can't be missing.
+    parent: Option<String>,
+}
+
+fn observe(
+    fields: &mut BTreeMap<String, Observed>,
+    path: &str,
+    parent: Option<&str>,
+    value: &Value,
+) {
+    let observed = fields.entry(path.to_string()).or_default();
+    observed.parent = parent.map(str::to_string);
+    *observed.types.entry(value.type_name()).or_insert(0) += 1;
+    match value {
+        Value::Array(values) => {
+            let item = format!("{}[]", path);
+            for value in values {
+                observe(fields, &item, None, value);
+            }
+        }
+        Value::Object(object) => {
+            for (key, value) in object.iter() {
+                let child = if path.is_empty() {
+                    key.clone()
+                } else {
+                    format!("{}.{}", path, key)
+                };
+                observe(fields, &child, Some(path), value);
+            }
+        }
+        _ => {}
+    }
+}
+
+impl Value {
+    /// Summarizes the elements of an array, or this value alone otherwise.
+    pub fn summarize_shape(&self) -> ShapeSummary {
+        match self {
+            Value::Array(values) => summarize(values),
+            value => summarize(std::iter::once(value)),
+        }
+    }
+}
@@ -0,0 +1,33 @@
+use super::summarize;
+use crate::parse;
+
+#[test]
+fn optional_fields() {
+    let records = vec![
+        parse(r#"{ "id": 1, "tags": ["a", 2] }"#).unwrap(),
+        parse(r#"{ "id": 2, "tags": [] }"#).unwrap(),
+        parse(r#"{ "id": 3 }"#).unwrap(),
+    ];
+    let summary = summarize(&records);
+    assert_eq!(summary.records, 3);
+    assert_eq!(
+        summary.to_string(),
+        "id: number (100%)\ntags: array (67%), missing (33%)\ntags[]: number (50%), string (50%)\n"
+    );
+    let tags = summary.field("tags").unwrap();
+    assert!(tags.is_optional());
+    assert_eq!(tags.total(), 3);
+    assert!(!summary.field("tags[]").unwrap().is_optional());
+}
+
+#[test]
+fn nested_objects_in_arrays() {
+    let summary = parse(r#"{ "users": [{ "name": "a" }, { "name": "b", "admin": true }] }"#)
+        .unwrap()
+        .summarize_shape();
+    assert_eq!(summary.records, 1);
+    let admin = summary.field("users[].admin").unwrap();
+    assert_eq!(admin.missing, 1);
+    assert_eq!(admin.types.get("bool"), Some(&1));
+    assert_eq!(summary.field("users[].name").unwrap().missing, 0);
+}
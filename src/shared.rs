@@ -0,0 +1,368 @@
+#[cfg(test)]
+mod tests;
+
+use crate::pointer::{array_index, AsPointer};
+use crate::{JsonObject, Value};
+use std::collections::{HashMap, HashSet};
+use std::sync::Arc;
+
+/// A [`Value`] whose strings and containers are reference counted, so that
+/// cloning it takes constant time however large the document is. Clones
+/// share their subtrees and can be handed to other threads.
+///
+/// # Examples
+///
+/// ```
+///# fn main() -> Result<(),String> {
+///use json::shared::ArcValue;
+///
+///let document = ArcValue::from(json::parse(r#"{ "users": [{ "name": "John" }] }"#)?);
+///let copy = document.clone();
+///
+///assert!(document.ptr_eq(&copy));
+///assert_eq!(copy.pointer("/users/0/name").and_then(ArcValue::as_str), Some("John"));
+///# Ok(())
+///# }
+/// ```
+#[derive(Debug, Clone)]
+pub enum ArcValue {
+    Null,
+    Bool(bool),
+    Number(f64),
+    String(Arc<str>),
+    Array(Arc<Vec<ArcValue>>),
+    Object(Arc<Vec<(Arc<str>, ArcValue)>>),
+}
+
+impl ArcValue {
+    pub fn type_name(&self) -> &'static str {
+        match self {
+            ArcValue::Null => "null",
+            ArcValue::Bool(_) => "bool",
+            ArcValue::Number(_) => "number",
+            ArcValue::String(_) => "string",
+            ArcValue::Array(_) => "array",
+            ArcValue::Object(_) => "object",
+        }
+    }
+
+    /// Whether both values share the same allocation. Scalars without one
+    /// never do.
+    pub fn ptr_eq(&self, other: &ArcValue) -> bool {
+        match (self, other) {
+            (ArcValue::String(a), ArcValue::String(b)) => Arc::ptr_eq(a, b),
+            (ArcValue::Array(a), ArcValue::Array(b)) => Arc::ptr_eq(a, b),
+            (ArcValue::Object(a), ArcValue::Object(b)) => Arc::ptr_eq(a, b),
+            _ => false,
+        }
+    }
+
+    pub fn as_bool(&self) -> Option<bool> {
+        match self {
+            ArcValue::Bool(value) => Some(*value),
+            _ => None,
+        }
+    }
+
+    pub fn as_f64(&self) -> Option<f64> {
+        match self {
+            ArcValue::Number(value) => Some(*value),
+            _ => None,
+        }
+    }
+
+    pub fn as_str(&self) -> Option<&str> {
+        match self {
+            ArcValue::String(value) => Some(value),
+            _ => None,
+        }
+    }
+
+    pub fn as_array(&self) -> Option<&[ArcValue]> {
+        match self {
+            ArcValue::Array(values) => Some(values),
+            _ => None,
+        }
+    }
+
+    pub fn get(&self, key: &str) -> Option<&ArcValue> {
+        match self {
+            ArcValue::Object(entries) => entries
+                .iter()
+                .find(|(k, _)| &**k == key)
+                .map(|(_, value)| value),
+            _ => None,
+        }
+    }
+
+    pub fn entries(&self) -> impl Iterator<Item = (&str, &ArcValue)> {
+        let entries = match self {
+            ArcValue::Object(entries) => entries.as_slice(),
+            _ => &[],
+        };
+        entries.iter().map(|(key, value)| (&**key, value))
+    }
+
+    pub fn pointer<P: AsPointer + ?Sized>(&self, pointer: &P) -> Option<&ArcValue> {
+        let mut current = self;
+        for token in pointer.pointer_tokens().ok()?.iter() {
+            current = match current {
+                ArcValue::Object(_) => current.get(token)?,
+                ArcValue::Array(values) => values.get(array_index(token)?)?,
+                _ => return None,
+            };
+        }
+        Some(current)
+    }
+}
+
+/// Copy-on-write mutation: each accessor below copies the container it
+/// returns if, and only if, another clone still shares it. Children are
+/// shared by the copy, so editing a deep path only duplicates the
+/// containers along that path.
+impl ArcValue {
+    pub fn as_array_mut(&mut self) -> Option<&mut Vec<ArcValue>> {
+        match self {
+            ArcValue::Array(values) => Some(Arc::make_mut(values)),
+            _ => None,
+        }
+    }
+
+    pub fn get_mut(&mut self, key: &str) -> Option<&mut ArcValue> {
+        match self {
+            ArcValue::Object(entries) if entries.iter().any(|(k, _)| &**k == key) => {
+                Arc::make_mut(entries)
+                    .iter_mut()
+                    .find(|(k, _)| &**k == key)
+                    .map(|(_, value)| value)
+            }
+            _ => None,
+        }
+    }
+
+    /// Inserts or replaces an entry of an object.
+    pub fn insert(&mut self, key: &str, value: ArcValue) -> Result<Option<ArcValue>, String> {
+        if let Some(existing) = self.get_mut(key) {
+            return Ok(Some(std::mem::replace(existing, value)));
+        }
+        match self {
+            ArcValue::Object(entries) => {
+                Arc::make_mut(entries).push((key.into(), value));
+                Ok(None)
+            }
+            _ => Err("not a json object".to_string()),
+        }
+    }
+
+    /// # Examples
+    ///
+    /// ```
+    ///# fn main() -> Result<(),String> {
+    ///use json::shared::ArcValue;
+    ///
+    ///let base = ArcValue::from(json::parse(r#"{ "db": { "pool": 5 }, "features": [1, 2] }"#)?);
+    ///let mut tenant = base.clone();
+    ///
+    ///*tenant.pointer_mut("/db/pool").unwrap() = ArcValue::Number(20.0);
+    ///assert_eq!(base.pointer("/db/pool"), Some(&ArcValue::Number(5.0)));
+    ///assert_eq!(tenant.pointer("/db/pool"), Some(&ArcValue::Number(20.0)));
+    ///assert!(base.get("features").unwrap().ptr_eq(tenant.get("features").unwrap()));
+    ///# Ok(())
+    ///# }
+    /// ```
+    pub fn pointer_mut<P: AsPointer + ?Sized>(&mut self, pointer: &P) -> Option<&mut ArcValue> {
+        self.pointer(pointer)?;
+        let mut current = self;
+        for token in pointer.pointer_tokens().ok()?.iter() {
+            current = match current {
+                ArcValue::Object(_) => current.get_mut(token)?,
+                ArcValue::Array(values) => Arc::make_mut(values).get_mut(array_index(token)?)?,
+                _ => return None,
+            };
+        }
+        Some(current)
+    }
+}
+
+impl PartialEq for ArcValue {
+    fn eq(&self, other: &Self) -> bool {
+        if self.ptr_eq(other) {
+            return true;
+        }
+        match (self, other) {
+            (ArcValue::Object(a), ArcValue::Object(b)) => {
+                a.len() == b.len() && a.iter().all(|(key, value)| other.get(key) == Some(value))
+            }
+            (ArcValue::Array(a), ArcValue::Array(b)) => a == b,
+            (ArcValue::String(a), ArcValue::String(b)) => a == b,
+            (ArcValue::Number(a), ArcValue::Number(b)) => Value::Number(*a) == Value::Number(*b),
+            (ArcValue::Bool(a), ArcValue::Bool(b)) => a == b,
+            (ArcValue::Null, ArcValue::Null) => true,
+            _ => false,
+        }
+    }
+}
+
+impl From<Value> for ArcValue {
+    fn from(value: Value) -> Self {
+        match value {
+            Value::Null => ArcValue::Null,
+            Value::Bool(value) => ArcValue::Bool(value),
+            Value::Number(value) => ArcValue::Number(value),
+            Value::String(value) => ArcValue::String(value.into()),
+            Value::Array(values) => {
+                ArcValue::Array(Arc::new(values.into_iter().map(ArcValue::from).collect()))
+            }
+            Value::Object(object) => ArcValue::Object(Arc::new(
+                object
+                    .into_iter()
+                    .map(|(key, value)| (key.into(), ArcValue::from(value)))
+                    .collect(),
+            )),
+        }
+    }
+}
+
+/// Deep copies a shared value back into an owned, mutable [`Value`].
+impl From<&ArcValue> for Value {
+    fn from(value: &ArcValue) -> Self {
+        match value {
+            ArcValue::Null => Value::Null,
+            ArcValue::Bool(value) => Value::Bool(*value),
+            ArcValue::Number(value) => Value::Number(*value),
+            ArcValue::String(value) => Value::String(value.to_string()),
+            ArcValue::Array(values) => Value::Array(values.iter().map(Value::from).collect()),
+            ArcValue::Object(entries) => Value::Object(
+                entries
+                    .iter()
+                    .map(|(key, value)| (key.to_string(), Value::from(value)))
+                    .collect::<JsonObject>(),
+            ),
+        }
+    }
+}
+
+/// Shares identical strings and subtrees between the values it interns,
+/// which shrinks documents made of many repetitive records. A pool can be
+/// kept around to share across documents, e.g. every line of a log file.
+///
+/// # Examples
+///
+/// ```
+///# fn main() -> Result<(),String> {
+///use json::shared::InternPool;
+///
+///let mut pool = InternPool::new();
+///let first = pool.parse(r#"{ "level": "info", "tags": ["web", "eu"] }"#)?;
+///let second = pool.parse(r#"{ "level": "info", "tags": ["web", "eu"] }"#)?;
+///
+///assert!(first.ptr_eq(&second));
+///# Ok(())
+///# }
+/// ```
+#[derive(Debug, Default)]
+pub struct InternPool {
+    strings: HashSet<Arc<str>>,
+    nodes: HashMap<NodeKey, ArcValue>,
+}
+
+/// Identifies a container by its children. Children are interned first, so
+/// identical subtrees are already the same allocation and comparing their
+/// addresses is enough.
+#[derive(Debug, PartialEq, Eq, Hash)]
+enum NodeKey {
+    Array(Vec<ChildKey>),
+    Object(Vec<(usize, ChildKey)>),
+}
+
+#[derive(Debug, PartialEq, Eq, Hash)]
+enum ChildKey {
+    Null,
+    Bool(bool),
+    Number(u64),
+    Shared(usize),
+}
+
+impl ChildKey {
+    fn of(value: &ArcValue) -> Self {
+        match value {
+            ArcValue::Null => ChildKey::Null,
+            ArcValue::Bool(value) => ChildKey::Bool(*value),
+            ArcValue::Number(value) => ChildKey::Number(value.to_bits()),
+            ArcValue::String(value) => ChildKey::Shared(Arc::as_ptr(value) as *const u8 as usize),
+            ArcValue::Array(values) => ChildKey::Shared(Arc::as_ptr(values) as usize),
+            ArcValue::Object(entries) => ChildKey::Shared(Arc::as_ptr(entries) as usize),
+        }
+    }
+}
+
+impl InternPool {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Number of distinct strings and containers held by the pool.
+    pub fn len(&self) -> usize {
+        self.strings.len() + self.nodes.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    pub fn parse(&mut self, raw: &str) -> Result<ArcValue, String> {
+        crate::parse(raw).map(|value| self.intern(&ArcValue::from(value)))
+    }
+
+    /// Returns a value equal to `value` whose strings and subtrees are shared
+    /// with everything previously interned. Object keys keep their order.
+    pub fn intern(&mut self, value: &ArcValue) -> ArcValue {
+        match value {
+            ArcValue::String(value) => ArcValue::String(self.intern_str(value)),
+            ArcValue::Array(values) => {
+                let values: Vec<_> = values.iter().map(|value| self.intern(value)).collect();
+                let key = NodeKey::Array(values.iter().map(ChildKey::of).collect());
+                self.nodes
+                    .entry(key)
+                    .or_insert_with(|| ArcValue::Array(Arc::new(values)))
+                    .clone()
+            }
+            ArcValue::Object(entries) => {
+                let entries: Vec<_> = entries
+                    .iter()
+                    .map(|(key, value)| (self.intern_str(key), self.intern(value)))
+                    .collect();
+                let key = NodeKey::Object(
+                    entries
+                        .iter()
+                        .map(|(key, value)| {
+                            (Arc::as_ptr(key) as *const u8 as usize, ChildKey::of(value))
+                        })
+                        .collect(),
+                );
+                self.nodes
+                    .entry(key)
+                    .or_insert_with(|| ArcValue::Object(Arc::new(entries)))
+                    .clone()
+            }
+            value => value.clone(),
+        }
+    }
+
+    fn intern_str(&mut self, value: &Arc<str>) -> Arc<str> {
+        match self.strings.get(value) {
+            Some(existing) => existing.clone(),
+            None => {
+                self.strings.insert(value.clone());
+                value.clone()
+            }
+        }
+    }
+}
+
+impl ArcValue {
+    /// Shares the identical strings and subtrees found within this value.
+    pub fn dedup(&mut self) {
+        *self = InternPool::new().intern(self);
+    }
+}
@@ -0,0 +1,145 @@
+use super::{ArcValue, InternPool};
+use crate::{parse, Value};
+use std::thread;
+
+#[test]
+fn round_trip() {
+    let value = parse(r#"{ "a": [1, "two", null], "b": { "c": true } }"#).unwrap();
+    let shared = ArcValue::from(value.clone());
+    assert_eq!(Value::from(&shared), value);
+    assert_eq!(
+        shared.pointer("/a/1").and_then(ArcValue::as_str),
+        Some("two")
+    );
+    assert_eq!(
+        shared.pointer("/b/c").and_then(ArcValue::as_bool),
+        Some(true)
+    );
+    assert_eq!(shared.pointer("/a/3"), None);
+    assert_eq!(
+        shared.entries().map(|(k, _)| k).collect::<Vec<_>>(),
+        vec!["a", "b"]
+    );
+}
+
+#[test]
+fn clones_share_subtrees() {
+    let shared = ArcValue::from(parse(r#"{ "a": [1, 2] }"#).unwrap());
+    let clone = shared.clone();
+    assert!(shared.ptr_eq(&clone));
+    assert!(shared.get("a").unwrap().ptr_eq(clone.get("a").unwrap()));
+    assert!(!ArcValue::Null.ptr_eq(&ArcValue::Null));
+}
+
+#[test]
+fn equality_ignores_key_order() {
+    let a = ArcValue::from(parse(r#"{ "a": 1, "b": [2] }"#).unwrap());
+    let b = ArcValue::from(parse(r#"{ "b": [2], "a": 1 }"#).unwrap());
+    assert_eq!(a, b);
+    assert_ne!(a, ArcValue::from(parse(r#"{ "a": 1 }"#).unwrap()));
+}
+
+#[test]
+fn send_to_threads() {
+    let shared = ArcValue::from(parse(r#"{ "n": 21 }"#).unwrap());
+    let handles: Vec<_> = (0..2)
+        .map(|_| {
+            let shared = shared.clone();
+            thread::spawn(move || shared.get("n").and_then(ArcValue::as_f64).unwrap() * 2.0)
+        })
+        .collect();
+    for handle in handles {
+        assert_eq!(handle.join().unwrap(), 42.0);
+    }
+}
+
+#[test]
+fn copy_on_write() {
+    let base = ArcValue::from(
+        parse(r#"{ "a": { "b": [1, { "c": 2 }], "d": [3] }, "e": { "f": 4 } }"#).unwrap(),
+    );
+    let mut tenant = base.clone();
+    *tenant.pointer_mut("/a/b/1/c").unwrap() = ArcValue::Bool(true);
+    tenant.insert("g", ArcValue::Null).unwrap();
+    tenant
+        .get_mut("e")
+        .unwrap()
+        .insert("f", ArcValue::Number(5.0))
+        .unwrap();
+
+    assert_eq!(
+        Value::from(&base),
+        parse(r#"{ "a": { "b": [1, { "c": 2 }], "d": [3] }, "e": { "f": 4 } }"#).unwrap()
+    );
+    assert_eq!(
+        Value::from(&tenant),
+        parse(r#"{ "a": { "b": [1, { "c": true }], "d": [3] }, "e": { "f": 5 }, "g": null }"#)
+            .unwrap()
+    );
+    assert!(!base.ptr_eq(&tenant));
+    assert!(base
+        .pointer("/a/d")
+        .unwrap()
+        .ptr_eq(tenant.pointer("/a/d").unwrap()));
+}
+
+#[test]
+fn unshared_values_are_not_copied() {
+    let mut value = ArcValue::from(parse(r#"{ "a": [1] }"#).unwrap());
+    let before = value.get("a").unwrap().as_array().unwrap().as_ptr();
+    value
+        .pointer_mut("/a")
+        .unwrap()
+        .as_array_mut()
+        .unwrap()
+        .push(ArcValue::Null);
+    assert_eq!(value.get("a").unwrap().as_array().unwrap().as_ptr(), before);
+    assert_eq!(value.pointer_mut("/missing/path"), None);
+    assert_eq!(
+        ArcValue::Null.insert("a", ArcValue::Null),
+        Err("not a json object".to_string())
+    );
+}
+
+#[test]
+fn dedup_shares_identical_subtrees() {
+    let mut value = ArcValue::from(
+        parse(
+            r#"[
+                { "host": "a", "tags": ["x", "y"] },
+                { "host": "b", "tags": ["x", "y"] },
+                { "tags": ["x", "y"], "host": "a" }
+            ]"#,
+        )
+        .unwrap(),
+    );
+    let original = value.clone();
+    value.dedup();
+    assert_eq!(value, original);
+    let records = value.as_array().unwrap();
+    assert!(records[0]
+        .get("tags")
+        .unwrap()
+        .ptr_eq(records[1].get("tags").unwrap()));
+    assert!(records[0]
+        .get("host")
+        .unwrap()
+        .ptr_eq(records[2].get("host").unwrap()));
+    assert!(!records[0].ptr_eq(&records[2]));
+}
+
+#[test]
+fn pool_is_shared_across_documents() {
+    let mut pool = InternPool::new();
+    let first = pool.parse(r#"{ "a": [1, { "b": null }] }"#).unwrap();
+    let size = pool.len();
+    let second = pool.parse(r#"{ "a": [1, { "b": null }] }"#).unwrap();
+    assert!(first.ptr_eq(&second));
+    assert_eq!(pool.len(), size);
+    let third = pool.parse(r#"{ "a": [2, { "b": null }] }"#).unwrap();
+    assert!(first
+        .pointer("/a/1")
+        .unwrap()
+        .ptr_eq(third.pointer("/a/1").unwrap()));
+    assert!(pool.parse("[").is_err());
+}
@@ -0,0 +1,239 @@
+#[cfg(test)]
+mod tests;
+
+use crate::highlight::{highlight, TokenClass};
+use crate::pointer::AsPointer;
+use crate::{unescape_str, Value};
+use std::cmp::Ordering;
+use std::ops::Range;
+
+impl Value {
+    /// Recursively reorders the entries of every object by key.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    ///# fn main() -> Result<(),String> {
+    ///let mut value = json::parse(r#"{ "b": 1, "a": [{ "d": 2, "c": 3 }] }"#)?;
+    ///
+    ///value.sort_keys();
+    ///assert_eq!(json::to_string(&value), r#"{"a":[{"c":3,"d":2}],"b":1}"#);
+    ///# Ok(())
+    ///# }
+    /// ```
+    pub fn sort_keys(&mut self) {
+        match self {
+            Value::Array(values) => values.iter_mut().for_each(Value::sort_keys),
+            Value::Object(object) => {
+                object.sort_by(|a, b| a.cmp(b));
+                object.iter_mut().for_each(|(_, value)| value.sort_keys());
+            }
+            _ => {}
+        }
+    }
+}
+
+impl Value {
+    /// Stable-sorts an array by the value each element holds at `path`, a key
+    /// name or a JSON Pointer, given as text starting with `/` or as a
+    /// [`JsonPointer`](crate::JsonPointer). Values are compared with the
+    /// [`Ord`] implementation of [`Value`], so mixed types sort by type first;
+    /// elements missing the path sort last.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    ///# fn main() -> Result<(),String> {
+    ///let mut users = json::parse(r#"[{ "name": "b" }, { "id": 1 }, { "name": "a" }]"#)?;
+    ///
+    ///users.sort_array_by_key("name")?;
+    ///assert_eq!(users, json::parse(r#"[{ "name": "a" }, { "name": "b" }, { "id": 1 }]"#)?);
+    ///# Ok(())
+    ///# }
+    /// ```
+    pub fn sort_array_by_key<P: AsPointer + ?Sized>(&mut self, path: &P) -> Result<(), String> {
+        self.sort_array_by(|a, b| match (a.field(path), b.field(path)) {
+            (Some(a), Some(b)) => a.cmp(b),
+            (Some(_), None) => Ordering::Less,
+            (None, Some(_)) => Ordering::Greater,
+            (None, None) => Ordering::Equal,
+        })
+    }
+
+    pub fn sort_array_by<F: FnMut(&Value, &Value) -> Ordering>(
+        &mut self,
+        compare: F,
+    ) -> Result<(), String> {
+        match self {
+            Value::Array(values) => {
+                values.sort_by(compare);
+                Ok(())
+            }
+            _ => Err("not a json array".to_string()),
+        }
+    }
+}
+
+/// Sorts the members of every object in `input` by key, like
+/// [`Value::sort_keys`], while keeping the text of every member as it was:
+/// whitespace, comments and number spellings are left alone. The separators
+/// between members stay in place, and the comments before a member move
+/// with it.
+///
+/// # Examples
+///
+/// ```
+///# fn main() -> Result<(),String> {
+///let input = r#"{
+///    "name": "api",
+///    // Listening port.
+///    "port": 8080,
+///    "env": { "DEBUG": 1.0, "A": true }
+///}"#;
+///
+///assert_eq!(
+///    json::sort_keys_textual(input)?,
+///    r#"{
+///    "env": { "A": true, "DEBUG": 1.0 },
+///    "name": "api",
+///    // Listening port.
+///    "port": 8080
+///}"#
+///);
+///# Ok(())
+///# }
+/// ```
+pub fn sort_keys_textual(input: &str) -> Result<String, String> {
+    let mut tokens = Tokens {
+        input,
+        tokens: highlight(input),
+        index: 0,
+    };
+    let (span, text) = tokens.value()?;
+    if let Some((span, _)) = tokens.next() {
+        return Err(format!(
+            "unexpected text after value at offset {}",
+            span.start
+        ));
+    }
+    Ok(format!(
+        "{}{}{}",
+        &input[..span.start],
+        text,
+        &input[span.end..]
+    ))
+}
+
+/// The tokens of a document, read past comments.
+struct Tokens<'a> {
+    input: &'a str,
+    tokens: Vec<(Range<usize>, TokenClass)>,
+    index: usize,
+}
+
+impl<'a> Tokens<'a> {
+    /// The index of the next token that isn't a comment.
+    fn skip_comments(&self) -> usize {
+        let mut index = self.index;
+        while let Some((_, TokenClass::Comment)) = self.tokens.get(index) {
+            index += 1;
+        }
+        index
+    }
+
+    /// Where the next token, comments included, starts.
+    fn offset(&self) -> usize {
+        self.tokens
+            .get(self.index)
+            .map_or(self.input.len(), |(span, _)| span.start)
+    }
+
+    fn peek(&self) -> Option<&'a str> {
+        let input = self.input;
+        self.tokens
+            .get(self.skip_comments())
+            .map(|(span, _)| &input[span.clone()])
+    }
+
+    fn next(&mut self) -> Option<(Range<usize>, TokenClass)> {
+        self.index = self.skip_comments();
+        let token = self.tokens.get(self.index).cloned();
+        self.index += 1;
+        token
+    }
+
+    fn expect(&mut self, expected: &str) -> Result<Range<usize>, String> {
+        match self.next() {
+            Some((span, _)) if &self.input[span.clone()] == expected => Ok(span),
+            Some((span, _)) => Err(format!("expected {} at offset {}", expected, span.start)),
+            None => Err(format!("expected {}, found the end of the input", expected)),
+        }
+    }
+
+    /// Reads a value, returning its span and its text once sorted.
+    fn value(&mut self) -> Result<(Range<usize>, String), String> {
+        let (span, class) = self
+            .next()
+            .ok_or_else(|| "expected a value, found the end of the input".to_string())?;
+        let text = &self.input[span.clone()];
+        match (class, text) {
+            (TokenClass::Punctuation, "{") => self.object(span),
+            (TokenClass::Punctuation, "[") => self.array(span),
+            (TokenClass::String, _) | (TokenClass::Number, _) | (TokenClass::Literal, _) => {
+                Ok((span, text.to_string()))
+            }
+            _ => Err(format!("unexpected {} at offset {}", text, span.start)),
+        }
+    }
+
+    fn object(&mut self, open: Range<usize>) -> Result<(Range<usize>, String), String> {
+        let mut gaps = Vec::new();
+        let mut members = Vec::new();
+        let mut end = open.end;
+        while self.peek() != Some("}") {
+            let start = self.offset();
+            let key = match self.next() {
+                Some((span, TokenClass::Key)) => &self.input[span.start + 1..span.end - 1],
+                Some((span, _)) => return Err(format!("expected a key at offset {}", span.start)),
+                None => return Err("expected a key, found the end of the input".to_string()),
+            };
+            self.expect(":")?;
+            let (value, text) = self.value()?;
+            gaps.push(&self.input[end..start]);
+            members.push((
+                unescape_str(key)?,
+                format!("{}{}", &self.input[start..value.start], text),
+            ));
+            end = value.end;
+            if self.peek() != Some("}") {
+                self.expect(",")?;
+            }
+        }
+        let close = self.expect("}")?;
+        members.sort_by(|(a, _), (b, _)| a.cmp(b));
+        let mut text = "{".to_string();
+        for (gap, (_, member)) in gaps.into_iter().zip(members) {
+            text.push_str(gap);
+            text.push_str(&member);
+        }
+        text.push_str(&self.input[end..close.end]);
+        Ok((open.start..close.end, text))
+    }
+
+    fn array(&mut self, open: Range<usize>) -> Result<(Range<usize>, String), String> {
+        let mut text = "[".to_string();
+        let mut end = open.end;
+        while self.peek() != Some("]") {
+            let (value, sorted) = self.value()?;
+            text.push_str(&self.input[end..value.start]);
+            text.push_str(&sorted);
+            end = value.end;
+            if self.peek() != Some("]") {
+                self.expect(",")?;
+            }
+        }
+        let close = self.expect("]")?;
+        text.push_str(&self.input[end..close.end]);
+        Ok((open.start..close.end, text))
+    }
+}
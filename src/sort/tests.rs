@@ -0,0 +1,69 @@
+use super::sort_keys_textual;
+use crate::{parse, to_string};
+
+#[test]
+fn sort_keys() {
+    let mut value =
+        parse(r#"[{ "z": { "y": 1, "x": 2 }, "a": null, "m": [{ "c": 1, "b": 2 }] }]"#).unwrap();
+    value.sort_keys();
+    assert_eq!(
+        to_string(&value),
+        r#"[{"a":null,"m":[{"b":2,"c":1}],"z":{"x":2,"y":1}}]"#
+    );
+    assert_eq!(value.pointer("/0/z/y"), Some(&crate::Value::Number(1.0)));
+}
+
+#[test]
+fn sort_array_by_key() {
+    let mut value = parse(
+        r#"[
+            { "id": 1, "user": { "age": 30 } },
+            { "id": 2 },
+            { "id": 3, "user": { "age": "unknown" } },
+            { "id": 4, "user": { "age": 20 } },
+            { "id": 5, "user": { "age": null } }
+        ]"#,
+    )
+    .unwrap();
+    value.sort_array_by_key("/user/age").unwrap();
+    let ids: Vec<String> = (0..5)
+        .map(|i| to_string(value.pointer(&format!("/{}/id", i)).unwrap()))
+        .collect();
+    assert_eq!(ids, vec!["5", "4", "1", "3", "2"]);
+    assert_eq!(
+        crate::Value::Null.sort_array_by_key("id"),
+        Err("not a json array".to_string())
+    );
+}
+
+#[test]
+fn sort_array_by() {
+    let mut value = parse("[1, 3, 2]").unwrap();
+    value.sort_array_by(|a, b| b.cmp(a)).unwrap();
+    assert_eq!(value, parse("[3, 2, 1]").unwrap());
+}
+
+#[test]
+fn sort_keys_textual_keeps_text() {
+    let input = "// header\n{\"b\" :[ {\"y\":1e0,\"x\":null} ], /* a */ \"a\":\"\\u0062\",}\n";
+    assert_eq!(
+        sort_keys_textual(input),
+        Ok(
+            "// header\n{/* a */ \"a\":\"\\u0062\", \"b\" :[ {\"x\":null,\"y\":1e0} ],}\n"
+                .to_string()
+        )
+    );
+    assert_eq!(sort_keys_textual(" [] "), Ok(" [] ".to_string()));
+    assert_eq!(
+        sort_keys_textual("{\"a\": 1 \"b\": 2}"),
+        Err("expected , at offset 8".to_string())
+    );
+    assert_eq!(
+        sort_keys_textual("{\"a\": [1}"),
+        Err("expected , at offset 8".to_string())
+    );
+    assert_eq!(
+        sort_keys_textual("1 2"),
+        Err("unexpected text after value at offset 2".to_string())
+    );
+}
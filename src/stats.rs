@@ -0,0 +1,140 @@
+#[cfg(test)]
+mod tests;
+
+use crate::{JsonObject, Value};
+use std::collections::HashMap;
+use std::mem::size_of;
+
+/// Summary of a document returned by [`Value::stats`].
+#[derive(Debug, PartialEq, Eq, Clone, Copy, Default)]
+pub struct JsonStats {
+    pub nulls: usize,
+    pub bools: usize,
+    pub numbers: usize,
+    pub strings: usize,
+    pub arrays: usize,
+    pub objects: usize,
+    /// Nesting depth of containers: `0` for a scalar, `1` for `[1]`.
+    pub max_depth: usize,
+    /// Bytes of all string values, object keys excluded.
+    pub string_bytes: usize,
+    /// Bytes of all object keys.
+    pub key_bytes: usize,
+    /// Length of the longest array.
+    pub largest_array: usize,
+    /// Number of entries of the largest object.
+    pub largest_object: usize,
+}
+
+impl JsonStats {
+    /// Total number of values, containers included.
+    pub fn nodes(&self) -> usize {
+        self.nulls + self.bools + self.numbers + self.strings + self.arrays + self.objects
+    }
+}
+
+impl Value {
+    /// # Examples
+    ///
+    /// ```
+    ///# fn main() -> Result<(),String> {
+    ///let value = json::parse(r#"{ "users": [{ "name": "John" }, { "name": "Jane" }] }"#)?;
+    ///let stats = value.stats();
+    ///
+    ///assert_eq!(stats.objects, 3);
+    ///assert_eq!(stats.strings, 2);
+    ///assert_eq!(stats.max_depth, 3);
+    ///assert_eq!(stats.largest_array, 2);
+    ///if stats.max_depth > 64 {
+    ///    return Err("document too deep".to_string());
+    ///}
+    ///# Ok(())
+    ///# }
+    /// ```
+    pub fn stats(&self) -> JsonStats {
+        let mut stats = JsonStats::default();
+        collect_stats(self, 0, &mut stats);
+        stats
+    }
+}
+
+fn collect_stats(value: &Value, depth: usize, stats: &mut JsonStats) {
+    stats.max_depth = stats.max_depth.max(depth);
+    match value {
+        Value::Null => stats.nulls += 1,
+        Value::Bool(_) => stats.bools += 1,
+        Value::Number(_) => stats.numbers += 1,
+        Value::String(value) => {
+            stats.strings += 1;
+            stats.string_bytes += value.len();
+        }
+        Value::Array(values) => {
+            stats.arrays += 1;
+            stats.max_depth = stats.max_depth.max(depth + 1);
+            stats.largest_array = stats.largest_array.max(values.len());
+            for value in values {
+                collect_stats(value, depth + 1, stats);
+            }
+        }
+        Value::Object(object) => {
+            stats.objects += 1;
+            stats.max_depth = stats.max_depth.max(depth + 1);
+            stats.largest_object = stats.largest_object.max(object.len());
+            for (key, value) in object.iter() {
+                stats.key_bytes += key.len();
+                collect_stats(value, depth + 1, stats);
+            }
+        }
+    }
+}
+
+impl Value {
+    /// Estimates the bytes this value occupies, counting the value itself,
+    /// the capacity of every string and vector and the lookup index of large
+    /// objects. Allocator overhead is not included.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    ///# fn main() -> Result<(),String> {
+    ///let small = json::parse(r#"{ "a": 1 }"#)?;
+    ///let large = json::parse(r#"{ "a": 1, "b": "a longer string value" }"#)?;
+    ///
+    ///assert!(small.approximate_memory_usage() < large.approximate_memory_usage());
+    ///# Ok(())
+    ///# }
+    /// ```
+    pub fn approximate_memory_usage(&self) -> usize {
+        size_of::<Value>() + self.heap_usage()
+    }
+
+    fn heap_usage(&self) -> usize {
+        match self {
+            Value::String(value) => value.capacity(),
+            Value::Array(values) => {
+                values.capacity() * size_of::<Value>()
+                    + values.iter().map(Value::heap_usage).sum::<usize>()
+            }
+            Value::Object(object) => object.heap_usage(),
+            _ => 0,
+        }
+    }
+}
+
+impl JsonObject {
+    fn heap_usage(&self) -> usize {
+        let entries = self.entries.capacity() * size_of::<(String, Value)>()
+            + self
+                .entries
+                .iter()
+                .map(|(key, value)| key.capacity() + value.heap_usage())
+                .sum::<usize>();
+        // Each bucket of the index holds an entry plus one control byte.
+        let index = self.index.as_ref().map_or(0, |index| {
+            size_of::<HashMap<String, usize>>()
+                + index.capacity() * (size_of::<(String, usize)>() + 1)
+                + index.keys().map(String::capacity).sum::<usize>()
+        });
+        entries + index
+    }
+}
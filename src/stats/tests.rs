@@ -0,0 +1,54 @@
+use super::JsonStats;
+use crate::{parse, Value};
+use std::mem::size_of;
+
+#[test]
+fn scalars() {
+    assert_eq!(Value::Null.approximate_memory_usage(), size_of::<Value>());
+    let value = Value::String(String::with_capacity(100));
+    assert_eq!(value.approximate_memory_usage(), size_of::<Value>() + 100);
+}
+
+#[test]
+fn containers() {
+    let mut values = Vec::with_capacity(4);
+    values.push(Value::String("abc".to_string()));
+    let array = Value::Array(values);
+    assert_eq!(
+        array.approximate_memory_usage(),
+        size_of::<Value>() + 4 * size_of::<Value>() + 3
+    );
+
+    let small = parse(r#"{ "a": 1 }"#).unwrap();
+    let large = (0..100)
+        .map(|i| (i.to_string(), Value::Null))
+        .collect::<crate::JsonObject>();
+    let large = Value::Object(large);
+    assert!(small.approximate_memory_usage() > size_of::<Value>() + size_of::<(String, Value)>());
+    assert!(large.approximate_memory_usage() > 100 * size_of::<(String, Value)>());
+}
+
+#[test]
+fn stats() {
+    let stats = parse(r#"{ "a": [1, 2, [true, null]], "bc": { "d": "xyz" }, "e": {} }"#)
+        .unwrap()
+        .stats();
+    assert_eq!(
+        stats,
+        JsonStats {
+            nulls: 1,
+            bools: 1,
+            numbers: 2,
+            strings: 1,
+            arrays: 2,
+            objects: 3,
+            max_depth: 3,
+            string_bytes: 3,
+            key_bytes: 5,
+            largest_array: 3,
+            largest_object: 3,
+        }
+    );
+    assert_eq!(stats.nodes(), 10);
+    assert_eq!(Value::Null.stats().max_depth, 0);
+}
@@ -0,0 +1,52 @@
+#[cfg(test)]
+mod tests;
+
+use crate::{JsonObject, Value};
+use proptest::prelude::*;
+
+/// Strategy producing scalars only: null, booleans, finite numbers and
+/// strings.
+pub fn scalar() -> impl Strategy<Value = Value> {
+    prop_oneof![
+        Just(Value::Null),
+        any::<bool>().prop_map(Value::Bool),
+        any::<f64>()
+            .prop_filter("JSON numbers are finite", |n| n.is_finite())
+            .prop_map(Value::Number),
+        ".*".prop_map(Value::String),
+    ]
+}
+
+/// Strategy producing documents nested at most `depth` levels, with
+/// containers of at most `size` elements.
+///
+/// # Examples
+///
+/// ```
+///use json::strategy::value;
+///use proptest::prelude::*;
+///
+///proptest!(|(document in value(3, 8))| {
+///    let text = json::to_string(&document);
+///    prop_assert!(json::parse(text.as_str()).is_ok());
+///});
+/// ```
+pub fn value(depth: u32, size: usize) -> impl Strategy<Value = Value> {
+    scalar().prop_recursive(
+        depth,
+        (size * depth as usize) as u32,
+        size as u32,
+        move |inner| {
+            prop_oneof![
+                prop::collection::vec(inner.clone(), 0..=size).prop_map(Value::Array),
+                prop::collection::vec((".*", inner), 0..=size)
+                    .prop_map(|entries| Value::Object(entries.into_iter().collect::<JsonObject>())),
+            ]
+        },
+    )
+}
+
+/// Shorthand for [`value`] with a depth of 4 and up to 8 elements.
+pub fn any_value() -> impl Strategy<Value = Value> {
+    value(4, 8)
+}
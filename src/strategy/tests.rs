@@ -0,0 +1,14 @@
+use super::{any_value, value};
+use proptest::prelude::*;
+
+proptest! {
+    #[test]
+    fn depth_is_bounded(document in value(2, 4)) {
+        prop_assert!(document.stats().max_depth <= 2);
+    }
+
+    #[test]
+    fn equals_its_clone(document in any_value()) {
+        prop_assert_eq!(document.clone(), document);
+    }
+}
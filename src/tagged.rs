@@ -0,0 +1,115 @@
+#[cfg(test)]
+mod tests;
+
+use crate::convert::{at, mismatch};
+use crate::Value;
+
+/// Decodes one variant of a polymorphic value.
+pub type Decoder<'a, T> = &'a dyn Fn(&Value) -> Result<T, String>;
+
+impl Value {
+    /// Decodes an internally tagged object: the string found under `tag`
+    /// selects which decoder receives the whole object.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    ///# fn main() -> Result<(),String> {
+    ///#[derive(Debug, PartialEq)]
+    ///enum Shape {
+    ///    Circle(f64),
+    ///    Rect(f64, f64),
+    ///}
+    ///
+    ///let decode = |value: &json::Value| {
+    ///    value.match_tagged(
+    ///        "type",
+    ///        &[
+    ///            ("circle", &|v: &json::Value| Ok(Shape::Circle(v.require("r")?.parse()?))),
+    ///            ("rect", &|v: &json::Value| {
+    ///                Ok(Shape::Rect(v.require("w")?.parse()?, v.require("h")?.parse()?))
+    ///            }),
+    ///        ],
+    ///    )
+    ///};
+    ///
+    ///assert_eq!(decode(&json::parse(r#"{ "type": "circle", "r": 2 }"#)?), Ok(Shape::Circle(2.0)));
+    ///assert_eq!(
+    ///    decode(&json::parse(r#"{ "type": "square" }"#)?),
+    ///    Err("/type: unknown variant square, expected one of circle, rect".to_string())
+    ///);
+    ///# Ok(())
+    ///# }
+    /// ```
+    pub fn match_tagged<T>(
+        &self,
+        tag: &str,
+        variants: &[(&str, Decoder<'_, T>)],
+    ) -> Result<T, String> {
+        let object = match self {
+            Value::Object(object) => object,
+            value => return Err(mismatch("object", value)),
+        };
+        let name = match object.get(tag) {
+            Some(Value::String(name)) => name,
+            Some(value) => return Err(at(tag, mismatch("string", value))),
+            None => return Err(at(tag, "missing".to_string())),
+        };
+        match variants.iter().find(|(variant, _)| variant == name) {
+            Some((_, decode)) => decode(self),
+            None => {
+                let expected: Vec<&str> = variants.iter().map(|(variant, _)| *variant).collect();
+                Err(at(
+                    tag,
+                    format!(
+                        "unknown variant {}, expected one of {}",
+                        name,
+                        expected.join(", ")
+                    ),
+                ))
+            }
+        }
+    }
+
+    /// Decodes an untagged value with the first decoder that succeeds. When
+    /// none does, the error lists why each one failed.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    ///# fn main() -> Result<(),String> {
+    ///use json::FromJson;
+    ///
+    ///#[derive(Debug, PartialEq)]
+    ///enum Id {
+    ///    Number(u64),
+    ///    Name(String),
+    ///}
+    ///
+    ///let decode = |value: &json::Value| {
+    ///    value.match_untagged(&[
+    ///        &|v: &json::Value| u64::from_json(v).map(Id::Number),
+    ///        &|v: &json::Value| String::from_json(v).map(Id::Name),
+    ///    ])
+    ///};
+    ///
+    ///assert_eq!(decode(&json::parse("42")?), Ok(Id::Number(42)));
+    ///assert_eq!(decode(&json::parse(r#""abc""#)?), Ok(Id::Name("abc".to_string())));
+    ///assert_eq!(
+    ///    decode(&json::parse("true")?),
+    ///    Err("no variant matched: expected u64, found bool; expected string, found bool".to_string())
+    ///);
+    ///# Ok(())
+    ///# }
+    /// ```
+    pub fn match_untagged<T>(&self, variants: &[Decoder<'_, T>]) -> Result<T, String> {
+        let mut errors = Vec::new();
+        for decode in variants {
+            match decode(self) {
+                Ok(value) => return Ok(value),
+                Err(error) => errors.push(error),
+            }
+        }
+        Err(format!("no variant matched: {}", errors.join("; ")))
+    }
+}
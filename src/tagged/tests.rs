@@ -0,0 +1,60 @@
+use crate::{parse, FromJson, Value};
+
+#[test]
+fn tagged() {
+    let decode = |value: &Value| {
+        value.match_tagged(
+            "kind",
+            &[
+                ("a", &|v: &Value| {
+                    Ok(format!("a{}", v.require("n")?.as_f64()?))
+                }),
+                ("b", &|_: &Value| Ok("b".to_string())),
+            ],
+        )
+    };
+    assert_eq!(
+        decode(&parse(r#"{ "kind": "a", "n": 1 }"#).unwrap()),
+        Ok("a1".to_string())
+    );
+    assert_eq!(
+        decode(&parse(r#"{ "kind": "b" }"#).unwrap()),
+        Ok("b".to_string())
+    );
+    assert_eq!(
+        decode(&parse(r#"{ "kind": "a" }"#).unwrap()),
+        Err("/n: missing".to_string())
+    );
+    assert_eq!(
+        decode(&parse(r#"{ "n": 1 }"#).unwrap()),
+        Err("/kind: missing".to_string())
+    );
+    assert_eq!(
+        decode(&parse(r#"{ "kind": 1 }"#).unwrap()),
+        Err("/kind: expected string, found number".to_string())
+    );
+    assert_eq!(
+        decode(&parse("[]").unwrap()),
+        Err("expected object, found array".to_string())
+    );
+}
+
+#[test]
+fn untagged() {
+    let decode = |value: &Value| {
+        value.match_untagged(&[
+            &|v: &Value| <Vec<u8>>::from_json(v).map(|v| v.len()),
+            &|v: &Value| bool::from_json(v).map(|_| 0),
+        ])
+    };
+    assert_eq!(decode(&parse("[1, 2]").unwrap()), Ok(2));
+    assert_eq!(decode(&parse("false").unwrap()), Ok(0));
+    assert_eq!(
+        decode(&parse("[1, -1]").unwrap()),
+        Err(
+            "no variant matched: /1: expected u8, found number -1; expected bool, found array"
+                .to_string()
+        )
+    );
+    assert!(Value::Null.match_untagged::<()>(&[]).is_err());
+}
@@ -0,0 +1,124 @@
+//! Assertions for tests handling JSON, reporting failures as a list of
+//! differing paths instead of two `Debug` dumps.
+
+#[cfg(test)]
+mod tests;
+
+use crate::diff::Difference;
+use crate::{parse, to_string_pretty, Value};
+use std::{env, fs, path::Path};
+
+/// Set to `1` to overwrite existing snapshots with the current values.
+pub const UPDATE_SNAPSHOTS: &str = "JSON_SNAPSHOT_UPDATE";
+
+/// Asserts that two values are equal, as [`Value`]'s `PartialEq` does.
+///
+/// # Examples
+///
+/// ```should_panic
+///let actual = json::parse(r#"{ "name": "John", "age": 42 }"#).unwrap();
+///
+///// panics with:
+///// assertion failed: json values are not equal
+///// /age: changed from 42 to 43
+///json::assert_json_eq!(actual, json::parse(r#"{ "name": "John", "age": 43 }"#).unwrap());
+/// ```
+#[macro_export]
+macro_rules! assert_json_eq {
+    ($left:expr, $right:expr $(,)?) => {
+        $crate::testing::assert_eq(&$left, &$right)
+    };
+}
+
+/// Asserts that every key and array element of `expected` is found in
+/// `actual`, which may hold more.
+///
+/// # Examples
+///
+/// ```
+///let actual = json::parse(r#"{ "id": 7, "user": { "name": "John", "age": 42 } }"#).unwrap();
+///
+///json::assert_json_include!(actual, json::parse(r#"{ "user": { "name": "John" } }"#).unwrap());
+/// ```
+#[macro_export]
+macro_rules! assert_json_include {
+    ($actual:expr, $expected:expr $(,)?) => {
+        $crate::testing::assert_include(&$actual, &$expected)
+    };
+}
+
+/// Compares a value with the snapshot stored in
+/// `snapshots/<name>.json` under the crate being tested. A missing snapshot
+/// is written and the assertion passes; set the `JSON_SNAPSHOT_UPDATE`
+/// environment variable to `1` to overwrite a snapshot that no longer
+/// matches. Snapshots are pretty printed with sorted keys, so that they
+/// review well.
+///
+/// ```no_run
+///let response = json::parse(r#"{ "id": 7, "name": "John" }"#).unwrap();
+///
+///json::assert_json_snapshot!(response, "user_response");
+/// ```
+#[macro_export]
+macro_rules! assert_json_snapshot {
+    ($value:expr, $name:expr $(,)?) => {
+        $crate::testing::assert_snapshot(
+            &$value,
+            &::std::path::Path::new(env!("CARGO_MANIFEST_DIR"))
+                .join("snapshots")
+                .join(format!("{}.json", $name)),
+        )
+    };
+}
+
+#[track_caller]
+pub fn assert_eq(left: &Value, right: &Value) {
+    if left != right {
+        fail("json values are not equal", left.diff(right));
+    }
+}
+
+#[track_caller]
+pub fn assert_include(actual: &Value, expected: &Value) {
+    let differences = actual.include_diff(expected);
+    if !differences.is_empty() {
+        fail("json value does not include the expected one", differences);
+    }
+}
+
+#[track_caller]
+fn fail(message: &str, differences: Vec<Difference>) {
+    let mut report = format!("assertion failed: {}", message);
+    for difference in differences {
+        report.push('\n');
+        report.push_str(&difference.to_string());
+    }
+    panic!("{}", report);
+}
+
+#[track_caller]
+pub fn assert_snapshot(value: &Value, path: &Path) {
+    let mut value = value.clone();
+    value.sort_keys();
+    let serialized = to_string_pretty(&value) + "\n";
+    let update = env::var(UPDATE_SNAPSHOTS).is_ok_and(|update| update == "1");
+    match fs::read_to_string(path) {
+        Ok(stored) if stored == serialized => {}
+        Ok(stored) if !update => match parse(stored.as_str()) {
+            Ok(stored) => fail(
+                &format!("value does not match snapshot {}", path.display()),
+                stored.diff(&value),
+            ),
+            Err(error) => panic!("invalid snapshot {}: {}", path.display(), error),
+        },
+        _ => {
+            let written = path
+                .parent()
+                .map_or(Ok(()), fs::create_dir_all)
+                .and_then(|_| fs::write(path, serialized));
+            if let Err(error) = written {
+                panic!("cannot write snapshot {}: {}", path.display(), error);
+            }
+        }
+    }
+}
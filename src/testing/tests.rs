@@ -0,0 +1,61 @@
+use super::assert_snapshot;
+use crate::{assert_json_eq, assert_json_include, parse};
+use std::{env, fs, panic};
+
+#[test]
+fn equal_values() {
+    assert_json_eq!(
+        parse(r#"{ "a": 1, "b": 2 }"#).unwrap(),
+        parse(r#"{ "b": 2, "a": 1 }"#).unwrap()
+    );
+}
+
+#[test]
+#[should_panic(
+    expected = "assertion failed: json values are not equal\n/a/0: changed from 1 to 2\n/b: added true"
+)]
+fn different_values() {
+    assert_json_eq!(
+        parse(r#"{ "a": [1] }"#).unwrap(),
+        parse(r#"{ "a": [2], "b": true }"#).unwrap()
+    );
+}
+
+#[test]
+fn included_values() {
+    let actual = parse(r#"{ "a": [1, 2, { "b": 3, "c": 4 }], "d": null }"#).unwrap();
+    assert_json_include!(actual, parse(r#"{ "a": [1] }"#).unwrap());
+    assert_json_include!(actual, parse(r#"{ "a": [1, 2, { "c": 4 }] }"#).unwrap());
+}
+
+#[test]
+#[should_panic(
+    expected = "json value does not include the expected one\n/a/1: removed 2\n/d: expected string \"x\", found null null"
+)]
+fn missing_values() {
+    assert_json_include!(
+        parse(r#"{ "a": [1], "d": null }"#).unwrap(),
+        parse(r#"{ "a": [1, 2], "d": "x" }"#).unwrap()
+    );
+}
+
+#[test]
+fn snapshots() {
+    let directory = env::temp_dir().join(format!("json-snapshots-{}", std::process::id()));
+    let path = directory.join("nested").join("value.json");
+    let value = parse(r#"{ "b": [1, 2], "a": null }"#).unwrap();
+
+    assert_snapshot(&value, &path);
+    assert_eq!(
+        fs::read_to_string(&path).unwrap(),
+        "{\n  \"a\": null,\n  \"b\": [\n    1,\n    2\n  ]\n}\n"
+    );
+    assert_snapshot(&parse(r#"{ "a": null, "b": [1, 2] }"#).unwrap(), &path);
+
+    let changed = parse(r#"{ "a": null, "b": [1, 3] }"#).unwrap();
+    let failure = panic::catch_unwind(|| assert_snapshot(&changed, &path)).unwrap_err();
+    let message = failure.downcast_ref::<String>().unwrap();
+    assert!(message.ends_with("\n/b/1: changed from 2 to 3"));
+
+    fs::remove_dir_all(directory).unwrap();
+}
@@ -1,6 +1,6 @@
 use crate::JsonObject;
 
-use super::{parse, Value::*};
+use super::{parse, parse_located, Value::*};
 use std::collections::HashMap;
 
 #[test]
@@ -13,6 +13,19 @@ fn null_err() {
     assert_eq!(parse("nulz"), Err("expected null".to_string()));
 }
 
+#[test]
+fn located_err() {
+    assert_eq!(
+        parse_located("[1, 2"),
+        Err("line 1, column 6: unable to parse array".to_string())
+    );
+    assert_eq!(
+        parse_located("\n\n  x"),
+        Err("line 3, column 3: malformed json".to_string())
+    );
+    assert_eq!(parse_located(" null "), Ok(Null));
+}
+
 #[test]
 fn bool() {
     assert_eq!(parse("true"), Ok(Bool(true)));
@@ -50,11 +63,25 @@ fn string_escape() {
 }
 
 #[test]
-fn string_escape_at_end_of_text() {
+fn string_escape_sequences() {
     assert_eq!(
-        parse(r#""err\"#),
-        Err("no char to escape".to_string())
-    )
+        parse(r#""a\nb\t\/\\ \u00e9\ud83d\ude00""#),
+        Ok(String("a\nb\t/\\ \u{e9}\u{1f600}".to_string()))
+    );
+    assert_eq!(parse(r#""\x""#), Err("invalid escape \\x".to_string()));
+    assert_eq!(
+        parse(r#""\u12g4""#),
+        Err("invalid unicode escape \\u12g4".to_string())
+    );
+    assert_eq!(
+        parse(r#""\ud83d!""#),
+        Err("unpaired surrogate \\ud83d".to_string())
+    );
+}
+
+#[test]
+fn string_escape_at_end_of_text() {
+    assert_eq!(parse(r#""err\"#), Err("no char to escape".to_string()))
 }
 
 #[test]
@@ -90,7 +117,7 @@ fn object() {
             map.insert("boolean".to_string(), Bool(false));
             map.insert("text".to_string(), String("text value".to_string()));
             map.insert("number".to_string(), Number(42.42));
-            JsonObject(map)
+            JsonObject::from(map)
         }))
     );
 }
@@ -111,7 +138,7 @@ fn object_with_nested_array() {
                 "array".to_string(),
                 Array(vec![Bool(true), Bool(false), String("hello".to_string())]),
             );
-            JsonObject(map)
+            JsonObject::from(map)
         }))
     );
 }
@@ -147,11 +174,11 @@ fn nesting() {
                             "nested array".to_string(),
                             Array(vec![Null, Bool(false), Bool(true)]),
                         );
-                        JsonObject(map)
+                        JsonObject::from(map)
                     }),
                 ]),
             );
-            JsonObject(map)
+            JsonObject::from(map)
         }))
     );
 }
@@ -161,3 +188,120 @@ fn unexpected_text_after() {
     let json = "[null] invalid";
     assert_eq!(parse(json), Err("unexpected text after value".to_string()))
 }
+
+#[test]
+fn empty_array() {
+    assert_eq!(parse("[]"), Ok(Array(vec![])));
+    assert_eq!(
+        parse("[[ ], 1]"),
+        Ok(Array(vec![Array(vec![]), Number(1.0)]))
+    );
+}
+
+#[test]
+fn object_preserves_order() {
+    let object = parse(r#"{ "b": 1, "a": 2, "c": 3, "a": 4 }"#)
+        .unwrap()
+        .to_object()
+        .unwrap();
+    let keys: Vec<&std::string::String> = object.iter().map(|(key, _)| key).collect();
+    assert_eq!(keys, vec!["b", "a", "c"]);
+    assert_eq!(object.get("a"), Some(&Number(4.0)));
+}
+
+#[test]
+fn object_remove_keeps_order() {
+    let mut object = parse(r#"{ "a": 1, "b": 2, "c": 3 }"#)
+        .unwrap()
+        .to_object()
+        .unwrap();
+    assert_eq!(object.remove("a"), Some(Number(1.0)));
+    assert_eq!(object.get("c"), Some(&Number(3.0)));
+    object.insert("a".to_string(), Null);
+    let keys: Vec<&std::string::String> = object.iter().map(|(key, _)| key).collect();
+    assert_eq!(keys, vec!["b", "c", "a"]);
+}
+
+#[test]
+fn large_object() {
+    let mut object = JsonObject::new();
+    for i in 0..40 {
+        object.insert(format!("k{}", i), Number(i as f64));
+    }
+    assert_eq!(object.remove("k3"), Some(Number(3.0)));
+    object.insert("k0".to_string(), Null);
+    assert_eq!(object.len(), 39);
+    assert_eq!(object.get("k39"), Some(&Number(39.0)));
+    assert_eq!(object.get("k0"), Some(&Null));
+    assert_eq!(
+        object.iter().nth(3).map(|(key, _)| key.as_str()),
+        Some("k4")
+    );
+    object.sort_by(|a, b| b.cmp(a));
+    assert_eq!(
+        object.iter().next().map(|(key, _)| key.as_str()),
+        Some("k9")
+    );
+    assert_eq!(object.get("k9"), Some(&Number(9.0)));
+}
+
+#[test]
+fn iterators() {
+    let object = parse(r#"{ "a": 1, "b": [2] }"#).unwrap();
+    let array = parse("[true, null]").unwrap();
+    assert_eq!(object.keys().collect::<Vec<_>>(), vec!["a", "b"]);
+    assert_eq!(object.values().count(), 2);
+    assert_eq!(
+        object.entries().next(),
+        Some((&"a".to_string(), &Number(1.0)))
+    );
+    assert_eq!(array.keys().count(), 0);
+    assert_eq!(array.values().collect::<Vec<_>>(), vec![&Bool(true), &Null]);
+    assert_eq!(array.entries().count(), 0);
+    assert_eq!(Null.values().count(), 0);
+}
+
+#[test]
+fn retain() {
+    let mut object = JsonObject::new();
+    for i in 0..40 {
+        object.insert(format!("k{}", i), Number(i as f64));
+    }
+    object.retain(|_, value| match value {
+        Number(n) => {
+            *n += 1.0;
+            *n > 30.0
+        }
+        _ => true,
+    });
+    assert_eq!(object.len(), 10);
+    assert_eq!(object.get("k30"), Some(&Number(31.0)));
+    assert_eq!(object.get("k29"), None);
+    object.insert("k0".to_string(), Null);
+    assert_eq!(
+        object.iter().last().map(|(key, _)| key.as_str()),
+        Some("k0")
+    );
+
+    let mut array = parse("[1, 2, 3]").unwrap();
+    array.retain_array(|value| *value != Number(2.0));
+    assert_eq!(array, parse("[1, 3]").unwrap());
+    let mut object = parse(r#"{ "a": 1 }"#).unwrap();
+    object.retain_array(|_| false);
+    assert_eq!(object, parse(r#"{ "a": 1 }"#).unwrap());
+}
+
+#[test]
+fn pairs() {
+    let pairs: Vec<(std::string::String, crate::Value)> = (0..20)
+        .rev()
+        .map(|i| (format!("k{}", i % 18), Number(i as f64)))
+        .collect();
+    let object = JsonObject::from(pairs);
+    assert_eq!(object.len(), 18);
+    assert_eq!(object.get("k1"), Some(&Number(1.0)));
+    let pairs: Vec<_> = object.into();
+    assert_eq!(pairs[0], ("k1".to_string(), Number(1.0)));
+    assert_eq!(pairs[1], ("k0".to_string(), Number(0.0)));
+    assert_eq!(pairs[17], ("k2".to_string(), Number(2.0)));
+}
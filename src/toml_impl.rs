@@ -0,0 +1,103 @@
+#[cfg(test)]
+mod tests;
+
+use crate::convert::{at, mismatch};
+use crate::{JsonObject, Value};
+use toml::{Table, Value as Toml};
+
+/// Parses a TOML document into an object. Dates and times, which JSON has
+/// no type for, become strings in their TOML form, e.g.
+/// `"1979-05-27T07:32:00Z"`, and non-finite floats become `null`.
+///
+/// # Examples
+///
+/// ```
+///# fn main() -> Result<(),String> {
+///let value = json::from_toml_str("name = \"app\"\n[server]\nport = 8080\nstarted = 1979-05-27T07:32:00Z")?;
+///
+///assert_eq!(
+///    value,
+///    json::parse(r#"{ "name": "app", "server": { "port": 8080, "started": "1979-05-27T07:32:00Z" } }"#)?
+///);
+///# Ok(())
+///# }
+/// ```
+pub fn from_toml_str(text: &str) -> Result<Value, String> {
+    let table: Table = text
+        .parse()
+        .map_err(|e: toml::de::Error| e.message().to_string())?;
+    Ok(from_table(table))
+}
+
+fn from_table(table: Table) -> Value {
+    Value::Object(
+        table
+            .into_iter()
+            .map(|(key, value)| (key, from_toml(value)))
+            .collect::<JsonObject>(),
+    )
+}
+
+fn from_toml(value: Toml) -> Value {
+    match value {
+        Toml::String(value) => Value::String(value),
+        Toml::Integer(value) => Value::Number(value as f64),
+        Toml::Float(value) if value.is_finite() => Value::Number(value),
+        Toml::Float(_) => Value::Null,
+        Toml::Boolean(value) => Value::Bool(value),
+        Toml::Datetime(value) => Value::String(value.to_string()),
+        Toml::Array(values) => Value::Array(values.into_iter().map(from_toml).collect()),
+        Toml::Table(table) => from_table(table),
+    }
+}
+
+/// Serializes an object as a TOML document. Keys keep their order, except
+/// that tables and arrays of tables come after the plain values of their
+/// parent table, as TOML requires. TOML has no `null`, so the value must
+/// not contain any, and integral numbers become TOML integers.
+///
+/// # Examples
+///
+/// ```
+///# fn main() -> Result<(),String> {
+///let value = json::parse(r#"{ "server": { "port": 8080 }, "name": "app", "tags": ["a"] }"#)?;
+///
+///assert_eq!(json::to_toml_string(&value)?, "name = \"app\"\ntags = [\"a\"]\n\n[server]\nport = 8080\n");
+///assert_eq!(
+///    json::to_toml_string(&json::parse(r#"{ "a": [1, null] }"#)?),
+///    Err("/a/1: TOML has no null".to_string())
+///);
+///# Ok(())
+///# }
+/// ```
+pub fn to_toml_string(value: &Value) -> Result<String, String> {
+    match to_toml(value)? {
+        Toml::Table(table) => toml::to_string(&table).map_err(|e| e.to_string()),
+        _ => Err(mismatch("object", value)),
+    }
+}
+
+fn to_toml(value: &Value) -> Result<Toml, String> {
+    Ok(match value {
+        Value::Null => return Err("TOML has no null".to_string()),
+        Value::Bool(value) => Toml::Boolean(*value),
+        Value::Number(n) if n.fract() == 0.0 && *n >= i64::MIN as f64 && *n < i64::MAX as f64 => {
+            Toml::Integer(*n as i64)
+        }
+        Value::Number(n) => Toml::Float(*n),
+        Value::String(value) => Toml::String(value.clone()),
+        Value::Array(values) => Toml::Array(
+            values
+                .iter()
+                .enumerate()
+                .map(|(i, value)| to_toml(value).map_err(|e| at(&i.to_string(), e)))
+                .collect::<Result<_, _>>()?,
+        ),
+        Value::Object(object) => Toml::Table(
+            object
+                .iter()
+                .map(|(key, value)| Ok((key.clone(), to_toml(value).map_err(|e| at(key, e))?)))
+                .collect::<Result<_, String>>()?,
+        ),
+    })
+}
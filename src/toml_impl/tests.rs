@@ -0,0 +1,42 @@
+use super::{from_toml_str, to_toml_string};
+use crate::parse;
+
+#[test]
+fn round_trip() {
+    let value = parse(
+        r#"{ "title": "x", "owner": { "name": "n", "dob": "1979-05-27" }, "ratio": 0.5,
+             "servers": [{ "ip": "10.0.0.1", "role": ["a", 1, true] }, { "ip": "10.0.0.2" }],
+             "tail": "after tables" }"#,
+    )
+    .unwrap();
+    let text = to_toml_string(&value).unwrap();
+    assert!(text.starts_with("title = \"x\"\nratio = 0.5\ntail = \"after tables\"\n"));
+    assert!(text.contains("[[servers]]"));
+    assert_eq!(from_toml_str(&text), Ok(value));
+}
+
+#[test]
+fn dates_and_floats() {
+    let toml = "at = 1979-05-27T00:32:00.999999-07:00\nday = 1979-05-27\nnan = nan\nbig = 1e300";
+    assert_eq!(
+        from_toml_str(toml),
+        parse(
+            r#"{ "at": "1979-05-27T00:32:00.999999-07:00", "day": "1979-05-27", "nan": null,
+                 "big": 1e300 }"#
+        )
+    );
+}
+
+#[test]
+fn errors() {
+    assert_eq!(
+        to_toml_string(&parse("[1]").unwrap()),
+        Err("expected object, found array".to_string())
+    );
+    assert_eq!(
+        to_toml_string(&parse(r#"{ "a": { "b": null } }"#).unwrap()),
+        Err("/a/b: TOML has no null".to_string())
+    );
+    assert!(from_toml_str("a = ").is_err());
+    assert!(from_toml_str("a = 1\na = 2").is_err());
+}
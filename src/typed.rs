@@ -0,0 +1,416 @@
+#[cfg(test)]
+mod tests;
+
+use crate::convert::{at, mismatch};
+use crate::{JsonObject, Value};
+use std::collections::{BTreeMap, HashMap};
+use std::convert::TryFrom;
+use std::hash::BuildHasher;
+
+/// Types that can be written as a [`Value`].
+///
+/// # Examples
+///
+/// ```
+///use json::{ToJson, Value};
+///use std::collections::BTreeMap;
+///
+///let mut scores = BTreeMap::new();
+///scores.insert("john".to_string(), vec![Some(1), None]);
+///
+///assert_eq!(json::to_string(&scores.to_json()), r#"{"john":[1,null]}"#);
+///assert_eq!(("a", 2.5, true).to_json(), json::parse(r#"["a", 2.5, true]"#).unwrap());
+/// ```
+pub trait ToJson {
+    fn to_json(&self) -> Value;
+}
+
+/// Types that can be read from a [`Value`]. Errors describe the first
+/// mismatch, prefixed by its JSON Pointer when it is nested.
+///
+/// # Examples
+///
+/// ```
+///use json::FromJson;
+///use std::collections::HashMap;
+///
+///let value = json::parse(r#"{ "ports": [80, 443], "name": null }"#).unwrap();
+///
+///let ports = <HashMap<String, Option<Vec<u16>>>>::from_json(&value).unwrap();
+///assert_eq!(ports["ports"], Some(vec![80, 443]));
+///assert_eq!(ports["name"], None);
+///assert_eq!(
+///    <(String, u8)>::from_json(&json::parse(r#"["a", 300]"#).unwrap()),
+///    Err("/1: expected u8, found number 300".to_string())
+///);
+/// ```
+pub trait FromJson: Sized {
+    fn from_json(value: &Value) -> Result<Self, String>;
+}
+
+impl ToJson for Value {
+    fn to_json(&self) -> Value {
+        self.clone()
+    }
+}
+
+impl FromJson for Value {
+    fn from_json(value: &Value) -> Result<Self, String> {
+        Ok(value.clone())
+    }
+}
+
+impl<T: ToJson + ?Sized> ToJson for &T {
+    fn to_json(&self) -> Value {
+        (**self).to_json()
+    }
+}
+
+impl<T: ToJson + ?Sized> ToJson for Box<T> {
+    fn to_json(&self) -> Value {
+        (**self).to_json()
+    }
+}
+
+impl<T: FromJson> FromJson for Box<T> {
+    fn from_json(value: &Value) -> Result<Self, String> {
+        T::from_json(value).map(Box::new)
+    }
+}
+
+impl ToJson for () {
+    fn to_json(&self) -> Value {
+        Value::Null
+    }
+}
+
+impl FromJson for () {
+    fn from_json(value: &Value) -> Result<Self, String> {
+        match value {
+            Value::Null => Ok(()),
+            value => Err(mismatch("null", value)),
+        }
+    }
+}
+
+impl ToJson for bool {
+    fn to_json(&self) -> Value {
+        Value::Bool(*self)
+    }
+}
+
+impl ToJson for str {
+    fn to_json(&self) -> Value {
+        Value::String(self.to_string())
+    }
+}
+
+impl ToJson for String {
+    fn to_json(&self) -> Value {
+        Value::String(self.clone())
+    }
+}
+
+impl ToJson for char {
+    fn to_json(&self) -> Value {
+        Value::String(self.to_string())
+    }
+}
+
+impl FromJson for char {
+    fn from_json(value: &Value) -> Result<Self, String> {
+        let string = String::from_json(value)?;
+        let mut chars = string.chars();
+        match (chars.next(), chars.next()) {
+            (Some(c), None) => Ok(c),
+            _ => Err(format!(
+                "expected char, found string of length {}",
+                string.chars().count()
+            )),
+        }
+    }
+}
+
+impl ToJson for f32 {
+    fn to_json(&self) -> Value {
+        Value::Number(*self as f64)
+    }
+}
+
+impl FromJson for f32 {
+    fn from_json(value: &Value) -> Result<Self, String> {
+        f64::from_json(value).map(|number| number as f32)
+    }
+}
+
+/// Types whose `TryFrom<&Value>` conversion already has the right semantics.
+macro_rules! from_json_via_try_from {
+    ($($type:ty),*) => {
+        $(
+            impl FromJson for $type {
+                fn from_json(value: &Value) -> Result<Self, String> {
+                    <$type>::try_from(value)
+                }
+            }
+        )*
+    };
+}
+
+from_json_via_try_from!(bool, f64, String, i8, i16, i32, i64, isize, u8, u16, u32, u64, usize);
+
+/// Integers wider than 53 bits lose precision, as they do in JavaScript.
+macro_rules! to_json_number {
+    ($($type:ty),*) => {
+        $(
+            impl ToJson for $type {
+                fn to_json(&self) -> Value {
+                    Value::Number(*self as f64)
+                }
+            }
+        )*
+    };
+}
+
+to_json_number!(f64, i8, i16, i32, i64, isize, u8, u16, u32, u64, usize);
+
+impl<T: ToJson> ToJson for Option<T> {
+    fn to_json(&self) -> Value {
+        self.as_ref().map_or(Value::Null, T::to_json)
+    }
+}
+
+impl<T: FromJson> FromJson for Option<T> {
+    fn from_json(value: &Value) -> Result<Self, String> {
+        match value {
+            Value::Null => Ok(None),
+            value => T::from_json(value).map(Some),
+        }
+    }
+}
+
+impl<T: ToJson> ToJson for [T] {
+    fn to_json(&self) -> Value {
+        Value::Array(self.iter().map(T::to_json).collect())
+    }
+}
+
+impl<T: ToJson> ToJson for Vec<T> {
+    fn to_json(&self) -> Value {
+        self.as_slice().to_json()
+    }
+}
+
+impl<T: FromJson> FromJson for Vec<T> {
+    fn from_json(value: &Value) -> Result<Self, String> {
+        match value {
+            Value::Array(values) => values
+                .iter()
+                .enumerate()
+                .map(|(i, value)| T::from_json(value).map_err(|e| at(&i.to_string(), e)))
+                .collect(),
+            value => Err(mismatch("array", value)),
+        }
+    }
+}
+
+impl<T: ToJson, const N: usize> ToJson for [T; N] {
+    fn to_json(&self) -> Value {
+        self.as_slice().to_json()
+    }
+}
+
+impl<T: FromJson, const N: usize> FromJson for [T; N] {
+    fn from_json(value: &Value) -> Result<Self, String> {
+        let values = Vec::<T>::from_json(value)?;
+        let length = values.len();
+        <[T; N]>::try_from(values)
+            .map_err(|_| format!("expected array of length {}, found {}", N, length))
+    }
+}
+
+impl<T: ToJson, S: BuildHasher> ToJson for HashMap<String, T, S> {
+    fn to_json(&self) -> Value {
+        Value::Object(self.iter().map(|(k, v)| (k.clone(), v.to_json())).collect())
+    }
+}
+
+impl<T: FromJson, S: BuildHasher + Default> FromJson for HashMap<String, T, S> {
+    fn from_json(value: &Value) -> Result<Self, String> {
+        from_object(value)
+    }
+}
+
+impl<T: ToJson> ToJson for BTreeMap<String, T> {
+    fn to_json(&self) -> Value {
+        Value::Object(self.iter().map(|(k, v)| (k.clone(), v.to_json())).collect())
+    }
+}
+
+impl<T: FromJson> FromJson for BTreeMap<String, T> {
+    fn from_json(value: &Value) -> Result<Self, String> {
+        from_object(value)
+    }
+}
+
+impl ToJson for JsonObject {
+    fn to_json(&self) -> Value {
+        Value::Object(self.clone())
+    }
+}
+
+impl FromJson for JsonObject {
+    fn from_json(value: &Value) -> Result<Self, String> {
+        match value {
+            Value::Object(object) => Ok(object.clone()),
+            value => Err(mismatch("object", value)),
+        }
+    }
+}
+
+fn from_object<T: FromJson, C: std::iter::FromIterator<(String, T)>>(
+    value: &Value,
+) -> Result<C, String> {
+    match value {
+        Value::Object(object) => object
+            .iter()
+            .map(|(key, value)| match T::from_json(value) {
+                Ok(value) => Ok((key.clone(), value)),
+                Err(e) => Err(at(key, e)),
+            })
+            .collect(),
+        value => Err(mismatch("object", value)),
+    }
+}
+
+/// Tuples are written as arrays of the same length.
+macro_rules! tuple {
+    ($length:expr => $($name:ident $index:tt),+) => {
+        impl<$($name: ToJson),+> ToJson for ($($name,)+) {
+            fn to_json(&self) -> Value {
+                Value::Array(vec![$(self.$index.to_json()),+])
+            }
+        }
+
+        impl<$($name: FromJson),+> FromJson for ($($name,)+) {
+            fn from_json(value: &Value) -> Result<Self, String> {
+                match value {
+                    Value::Array(values) if values.len() == $length => Ok(($(
+                        $name::from_json(&values[$index])
+                            .map_err(|e| at(stringify!($index), e))?,
+                    )+)),
+                    Value::Array(values) => Err(format!(
+                        "expected array of length {}, found {}",
+                        $length,
+                        values.len()
+                    )),
+                    value => Err(mismatch("array", value)),
+                }
+            }
+        }
+    };
+}
+
+tuple!(1 => A 0);
+tuple!(2 => A 0, B 1);
+tuple!(3 => A 0, B 1, C 2);
+tuple!(4 => A 0, B 1, C 2, D 3);
+tuple!(5 => A 0, B 1, C 2, D 3, E 4);
+tuple!(6 => A 0, B 1, C 2, D 3, E 4, F 5);
+
+/// Support functions called by the code `json-derive` generates. Not part of
+/// the public API.
+#[doc(hidden)]
+pub mod private {
+    use super::{at, mismatch, FromJson};
+    use crate::{JsonObject, Value};
+
+    pub fn object<'a>(value: &'a Value, name: &str) -> Result<&'a JsonObject, String> {
+        match value {
+            Value::Object(object) => Ok(object),
+            value => Err(mismatch(&format!("object {}", name), value)),
+        }
+    }
+
+    pub fn array<'a>(value: &'a Value, name: &str, length: usize) -> Result<&'a [Value], String> {
+        match value {
+            Value::Array(values) if values.len() == length => Ok(values),
+            Value::Array(values) => Err(format!(
+                "expected array of length {}, found {}",
+                length,
+                values.len()
+            )),
+            value => Err(mismatch(&format!("array {}", name), value)),
+        }
+    }
+
+    pub fn null(value: &Value, name: &str) -> Result<(), String> {
+        match value {
+            Value::Null => Ok(()),
+            value => Err(mismatch(&format!("null {}", name), value)),
+        }
+    }
+
+    /// Reads a field, treating a missing one as `null` so that optional
+    /// fields may be omitted.
+    pub fn field<T: FromJson>(object: &JsonObject, key: &str) -> Result<T, String> {
+        match object.get(key) {
+            Some(value) => T::from_json(value).map_err(|e| at(key, e)),
+            None => T::from_json(&Value::Null).map_err(|_| at(key, "missing".to_string())),
+        }
+    }
+
+    /// Reads a field, falling back to `T::default()` when it is missing.
+    pub fn field_or_default<T: FromJson + Default>(
+        object: &JsonObject,
+        key: &str,
+    ) -> Result<T, String> {
+        match object.get(key) {
+            Some(value) => T::from_json(value).map_err(|e| at(key, e)),
+            None => Ok(T::default()),
+        }
+    }
+
+    /// Copies the entries of a flattened field into the enclosing object.
+    pub fn flatten(object: &mut JsonObject, value: Value) {
+        if let Value::Object(entries) = value {
+            for (key, value) in entries {
+                object.insert(key, value);
+            }
+        }
+    }
+
+    pub fn element<T: FromJson>(values: &[Value], index: usize) -> Result<T, String> {
+        T::from_json(&values[index]).map_err(|e| at(&index.to_string(), e))
+    }
+
+    /// Splits an externally tagged enum into the name of its variant and
+    /// its content: `"Unit"` or `{ "Variant": content }`.
+    pub fn variant<'a>(
+        value: &'a Value,
+        name: &str,
+    ) -> Result<(&'a str, Option<&'a Value>), String> {
+        match value {
+            Value::String(variant) => Ok((variant, None)),
+            Value::Object(object) if object.len() == 1 => {
+                let (variant, content) = object.iter().next().unwrap();
+                Ok((variant, Some(content)))
+            }
+            value => Err(mismatch(&format!("variant of {}", name), value)),
+        }
+    }
+
+    /// Locates an error found in the content of a variant.
+    pub fn nested(token: &str, error: String) -> String {
+        at(token, error)
+    }
+
+    pub fn unknown_variant(variant: &str, name: &str) -> String {
+        format!("unknown variant {} of {}", variant, name)
+    }
+
+    pub fn tagged(variant: &str, content: Value) -> Value {
+        let mut object = JsonObject::new();
+        object.insert(variant.to_string(), content);
+        Value::Object(object)
+    }
+}
@@ -0,0 +1,226 @@
+use super::{FromJson, ToJson};
+use crate::{parse, Value};
+use std::collections::{BTreeMap, HashMap};
+
+#[test]
+fn round_trips() {
+    let value = vec![(1u8, "a".to_string()), (2, "b".to_string())];
+    assert_eq!(value.to_json(), parse(r#"[[1, "a"], [2, "b"]]"#).unwrap());
+    assert_eq!(<Vec<(u8, String)>>::from_json(&value.to_json()), Ok(value));
+
+    let mut map = HashMap::new();
+    map.insert("a".to_string(), [Some(true), None]);
+    assert_eq!(map.to_json(), parse(r#"{ "a": [true, null] }"#).unwrap());
+    assert_eq!(
+        <HashMap<String, [Option<bool>; 2]>>::from_json(&map.to_json()),
+        Ok(map)
+    );
+
+    let tree: BTreeMap<String, char> = vec![("k".to_string(), 'x')].into_iter().collect();
+    assert_eq!(
+        <BTreeMap<String, char>>::from_json(&tree.to_json()),
+        Ok(tree)
+    );
+    assert_eq!(().to_json(), Value::Null);
+    assert_eq!(Box::new(1.5f32).to_json(), Value::Number(1.5));
+}
+
+#[test]
+fn errors() {
+    assert_eq!(
+        <Vec<Option<u8>>>::from_json(&parse("[1, null, -1]").unwrap()),
+        Err("/2: expected u8, found number -1".to_string())
+    );
+    assert_eq!(
+        <(bool, bool)>::from_json(&parse("[true]").unwrap()),
+        Err("expected array of length 2, found 1".to_string())
+    );
+    assert_eq!(
+        <[u8; 3]>::from_json(&parse("[1, 2]").unwrap()),
+        Err("expected array of length 3, found 2".to_string())
+    );
+    assert_eq!(
+        <BTreeMap<String, Vec<bool>>>::from_json(&parse(r#"{ "a/b": [1] }"#).unwrap()),
+        Err("/a~1b/0: expected bool, found number".to_string())
+    );
+    assert_eq!(
+        char::from_json(&parse(r#""ab""#).unwrap()),
+        Err("expected char, found string of length 2".to_string())
+    );
+    assert_eq!(
+        <()>::from_json(&Value::Bool(true)),
+        Err("expected null, found bool".to_string())
+    );
+}
+
+#[cfg(feature = "derive")]
+mod derive {
+    use crate::{parse, FromJson, ToJson, Value};
+
+    #[derive(Debug, PartialEq, ToJson, FromJson)]
+    struct User {
+        name: String,
+        age: Option<u8>,
+        roles: Vec<Role>,
+    }
+
+    #[derive(Debug, PartialEq, ToJson, FromJson)]
+    enum Role {
+        Admin,
+        Member(String),
+        Guest { until: u64 },
+        Pair(u8, u8),
+    }
+
+    #[derive(Debug, PartialEq, ToJson, FromJson)]
+    struct Wrapper<T>(T);
+
+    #[derive(Debug, PartialEq, ToJson, FromJson)]
+    struct Point(f64, f64);
+
+    #[derive(Debug, PartialEq, ToJson, FromJson)]
+    struct Marker;
+
+    #[test]
+    fn structs_and_enums() {
+        let user = User {
+            name: "John".to_string(),
+            age: None,
+            roles: vec![
+                Role::Admin,
+                Role::Member("dev".to_string()),
+                Role::Guest { until: 7 },
+                Role::Pair(1, 2),
+            ],
+        };
+        let value = parse(
+            r#"{ "name": "John", "age": null, "roles": [
+                "Admin", { "Member": "dev" }, { "Guest": { "until": 7 } }, { "Pair": [1, 2] }
+            ] }"#,
+        )
+        .unwrap();
+        assert_eq!(user.to_json(), value);
+        assert_eq!(User::from_json(&value), Ok(user));
+        assert_eq!(
+            User::from_json(&parse(r#"{ "name": "Jane", "roles": [] }"#).unwrap()),
+            Ok(User {
+                name: "Jane".to_string(),
+                age: None,
+                roles: Vec::new()
+            })
+        );
+
+        assert_eq!(Wrapper(3u8).to_json(), Value::Number(3.0));
+        assert_eq!(
+            Wrapper::<u8>::from_json(&Value::Number(3.0)),
+            Ok(Wrapper(3))
+        );
+        assert_eq!(Point(1.0, 2.0).to_json(), parse("[1, 2]").unwrap());
+        assert_eq!(Marker.to_json(), Value::Null);
+        assert_eq!(Marker::from_json(&Value::Null), Ok(Marker));
+    }
+
+    #[test]
+    fn errors() {
+        assert_eq!(
+            User::from_json(&parse(r#"{ "roles": [] }"#).unwrap()),
+            Err("/name: missing".to_string())
+        );
+        assert_eq!(
+            User::from_json(&parse(r#"{ "name": "a", "roles": [{ "Guest": {} }] }"#).unwrap()),
+            Err("/roles/0/Guest/until: missing".to_string())
+        );
+        assert_eq!(
+            Role::from_json(&parse(r#""Owner""#).unwrap()),
+            Err("unknown variant Owner of Role".to_string())
+        );
+        assert_eq!(
+            Point::from_json(&parse("[1]").unwrap()),
+            Err("expected array of length 2, found 1".to_string())
+        );
+        assert_eq!(
+            User::from_json(&Value::Null),
+            Err("expected object User, found null".to_string())
+        );
+    }
+}
+
+#[cfg(feature = "derive")]
+mod attributes {
+    use crate::{parse, FromJson, ToJson};
+
+    #[derive(Debug, PartialEq, Default, ToJson, FromJson)]
+    #[json(rename_all = "camelCase")]
+    struct Request {
+        request_id: String,
+        #[json(rename = "X-Trace")]
+        trace: Option<String>,
+        #[json(default, skip_serializing_if = "Vec::is_empty")]
+        retry_delays: Vec<u32>,
+        #[json(flatten)]
+        paging: Paging,
+    }
+
+    #[derive(Debug, PartialEq, Default, ToJson, FromJson)]
+    struct Paging {
+        page: u32,
+    }
+
+    #[derive(Debug, PartialEq, ToJson, FromJson)]
+    #[json(rename_all = "SCREAMING_SNAKE_CASE")]
+    enum Status {
+        InProgress,
+        #[json(rename = "done")]
+        Done,
+        FailedWith {
+            error_code: u16,
+        },
+    }
+
+    #[test]
+    fn renames_and_defaults() {
+        let value = parse(r#"{ "requestId": "a", "X-Trace": null, "page": 2 }"#).unwrap();
+        let request = Request {
+            request_id: "a".to_string(),
+            trace: None,
+            retry_delays: Vec::new(),
+            paging: Paging { page: 2 },
+        };
+        assert_eq!(Request::from_json(&value), Ok(request));
+        assert_eq!(
+            Request::from_json(&value).unwrap().to_json(),
+            parse(r#"{ "requestId": "a", "X-Trace": null, "page": 2 }"#).unwrap()
+        );
+        let retried = Request {
+            retry_delays: vec![10],
+            ..Request::default()
+        };
+        assert_eq!(
+            retried.to_json(),
+            parse(r#"{ "requestId": "", "X-Trace": null, "retryDelays": [10], "page": 0 }"#)
+                .unwrap()
+        );
+        assert_eq!(
+            Request::from_json(&parse(r#"{ "requestId": "a" }"#).unwrap()),
+            Err("/page: missing".to_string())
+        );
+    }
+
+    #[test]
+    fn renamed_variants() {
+        assert_eq!(
+            Status::InProgress.to_json(),
+            parse(r#""IN_PROGRESS""#).unwrap()
+        );
+        assert_eq!(
+            Status::from_json(&parse(r#""done""#).unwrap()),
+            Ok(Status::Done)
+        );
+        let failed = parse(r#"{ "FAILED_WITH": { "error_code": 500 } }"#).unwrap();
+        assert_eq!(
+            Status::from_json(&failed),
+            Ok(Status::FailedWith { error_code: 500 })
+        );
+        assert_eq!(Status::FailedWith { error_code: 500 }.to_json(), failed);
+    }
+}
@@ -0,0 +1,50 @@
+#[cfg(test)]
+mod tests;
+
+use crate::convert::mismatch;
+use crate::{FromJson, ToJson, Value};
+use uuid::Uuid;
+
+impl Value {
+    /// Parses a UUID string in any of the forms [`Uuid::parse_str`]
+    /// accepts, e.g. hyphenated or braced.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    ///# fn main() -> Result<(),String> {
+    ///let user = json::parse(r#"{ "id": "67e55044-10b1-426f-9247-bb680e5fe0c8" }"#)?;
+    ///let id = user.pointer("/id").unwrap().as_uuid()?;
+    ///
+    ///assert_eq!(id.get_version_num(), 4);
+    ///assert_eq!(json::Value::from(id), user.pointer("/id").unwrap().clone());
+    ///# Ok(())
+    ///# }
+    /// ```
+    pub fn as_uuid(&self) -> Result<Uuid, String> {
+        match self {
+            Value::String(value) => Uuid::parse_str(value)
+                .map_err(|e| format!("expected uuid, found {:?}: {}", value, e)),
+            value => Err(mismatch("uuid", value)),
+        }
+    }
+}
+
+/// Written in the lowercase, hyphenated form.
+impl From<Uuid> for Value {
+    fn from(value: Uuid) -> Self {
+        Value::String(value.hyphenated().to_string())
+    }
+}
+
+impl ToJson for Uuid {
+    fn to_json(&self) -> Value {
+        Value::from(*self)
+    }
+}
+
+impl FromJson for Uuid {
+    fn from_json(value: &Value) -> Result<Self, String> {
+        value.as_uuid()
+    }
+}
@@ -0,0 +1,27 @@
+use crate::{parse, FromJson, ToJson, Value};
+use uuid::Uuid;
+
+#[test]
+fn round_trip() {
+    let id = Uuid::from_u128(0x67E5_5044_10B1_426F_9247_BB68_0E5F_E0C8);
+    assert_eq!(
+        id.to_json(),
+        parse(r#""67e55044-10b1-426f-9247-bb680e5fe0c8""#).unwrap()
+    );
+    assert_eq!(Uuid::from_json(&id.to_json()), Ok(id));
+    let braced = parse(r#""{67e55044-10b1-426f-9247-bb680e5fe0c8}""#).unwrap();
+    assert_eq!(braced.as_uuid(), Ok(id));
+}
+
+#[test]
+fn errors() {
+    assert_eq!(
+        Value::Number(1.0).as_uuid(),
+        Err("expected uuid, found number".to_string())
+    );
+    assert!(parse(r#""67e55044""#)
+        .unwrap()
+        .as_uuid()
+        .unwrap_err()
+        .starts_with(r#"expected uuid, found "67e55044": "#));
+}
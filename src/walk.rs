@@ -0,0 +1,313 @@
+#[cfg(test)]
+mod tests;
+
+use crate::glob::PathPattern;
+use crate::{JsonPointer, Value};
+
+/// Pre-order iterator over the nodes of a value, created by [`Value::walk`]
+/// and [`Value::leaves`].
+pub struct Walk<'a> {
+    stack: Vec<(JsonPointer, &'a Value)>,
+    leaves_only: bool,
+}
+
+impl<'a> Iterator for Walk<'a> {
+    type Item = (JsonPointer, &'a Value);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        while let Some((pointer, value)) = self.stack.pop() {
+            let length = self.stack.len();
+            match value {
+                Value::Array(values) => {
+                    for (i, child) in values.iter().enumerate() {
+                        let mut child_pointer = pointer.clone();
+                        child_pointer.push(i.to_string());
+                        self.stack.push((child_pointer, child));
+                    }
+                }
+                Value::Object(object) => {
+                    for (key, child) in object.iter() {
+                        let mut child_pointer = pointer.clone();
+                        child_pointer.push(key.as_str());
+                        self.stack.push((child_pointer, child));
+                    }
+                }
+                _ => {}
+            }
+            let has_children = self.stack.len() > length;
+            self.stack[length..].reverse();
+            if !(self.leaves_only && has_children) {
+                return Some((pointer, value));
+            }
+        }
+        None
+    }
+}
+
+impl Value {
+    /// Visits every node, starting with `self` at the root pointer, parents
+    /// before their children.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    ///# fn main() -> Result<(),String> {
+    ///let value = json::parse(r#"{ "tags": ["a", "b"] }"#)?;
+    ///
+    ///let paths: Vec<String> = value.walk().map(|(path, _)| path.to_string()).collect();
+    ///assert_eq!(paths, vec!["", "/tags", "/tags/0", "/tags/1"]);
+    ///# Ok(())
+    ///# }
+    /// ```
+    pub fn walk(&self) -> Walk<'_> {
+        Walk {
+            stack: vec![(JsonPointer::root(), self)],
+            leaves_only: false,
+        }
+    }
+
+    /// Like [`Value::walk`] but only yields nodes without children: scalars
+    /// and empty arrays or objects.
+    pub fn leaves(&self) -> Walk<'_> {
+        Walk {
+            stack: vec![(JsonPointer::root(), self)],
+            leaves_only: true,
+        }
+    }
+
+    /// Lists the pointer of every node [`Value::walk`] visits, or of every
+    /// node [`Value::leaves`] visits when `leaves_only` is set.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    ///# fn main() -> Result<(),String> {
+    ///let value = json::parse(r#"{ "name": "John", "tags": ["a"] }"#)?;
+    ///
+    ///let all: Vec<String> = value.pointers(false).iter().map(|p| p.to_string()).collect();
+    ///assert_eq!(all, vec!["", "/name", "/tags", "/tags/0"]);
+    ///let leaves: Vec<String> = value.pointers(true).iter().map(|p| p.to_string()).collect();
+    ///assert_eq!(leaves, vec!["/name", "/tags/0"]);
+    ///# Ok(())
+    ///# }
+    /// ```
+    pub fn pointers(&self, leaves_only: bool) -> Vec<JsonPointer> {
+        let walk = if leaves_only {
+            self.leaves()
+        } else {
+            self.walk()
+        };
+        walk.map(|(pointer, _)| pointer).collect()
+    }
+}
+
+/// What [`Value::visit_mut`] does after a visitor has seen a node.
+#[derive(Debug, PartialEq, Clone, Copy)]
+pub enum Visit {
+    /// Visit the children of the node, as they are after the visitor ran.
+    Continue,
+    /// Leave the children of the node unvisited.
+    Skip,
+    /// Remove the node from its parent. The root is replaced with `null`.
+    Remove,
+}
+
+pub trait VisitorMut {
+    fn visit(&mut self, path: &JsonPointer, value: &mut Value) -> Visit;
+}
+
+impl<F: FnMut(&JsonPointer, &mut Value) -> Visit> VisitorMut for F {
+    fn visit(&mut self, path: &JsonPointer, value: &mut Value) -> Visit {
+        self(path, value)
+    }
+}
+
+impl Value {
+    /// Calls `f` on every node, parents before their children, letting it
+    /// rewrite each node in place. Children added by `f` are visited too.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    ///# fn main() -> Result<(),String> {
+    ///let mut value = json::parse(r#"{ "name": " John ", "tags": [" a "] }"#)?;
+    ///
+    ///value.transform(|_, node| {
+    ///    if let json::Value::String(text) = node {
+    ///        *text = text.trim().to_string();
+    ///    }
+    ///});
+    ///assert_eq!(value, json::parse(r#"{ "name": "John", "tags": ["a"] }"#)?);
+    ///# Ok(())
+    ///# }
+    /// ```
+    pub fn transform<F: FnMut(&JsonPointer, &mut Value)>(&mut self, mut f: F) {
+        self.visit_mut(&mut |path: &JsonPointer, value: &mut Value| {
+            f(path, value);
+            Visit::Continue
+        })
+    }
+
+    /// Runs `visitor` on every node like [`Value::transform`], letting it also
+    /// prune subtrees or remove nodes. Array indexes in paths are those the
+    /// elements had before any of their siblings was removed.
+    pub fn visit_mut<V: VisitorMut>(&mut self, visitor: &mut V) {
+        if visit_node(&mut JsonPointer::root(), self, visitor) {
+            *self = Value::Null;
+        }
+    }
+}
+
+impl Value {
+    /// Keeps, throughout the tree, only the object members and array
+    /// elements for which `f` returns `true`. Parents are seen before their
+    /// children, and the children of a removed node are not seen at all.
+    /// Array indexes in paths are those from before any removal.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    ///# fn main() -> Result<(),String> {
+    ///let mut value = json::parse(r#"{ "id": 1, "secret": "x", "items": [{ "id": 2, "secret": "y" }] }"#)?;
+    ///value.retain_deep(|path, _| path.tokens().last().map_or(true, |key| key != "secret"));
+    ///
+    ///assert_eq!(value, json::parse(r#"{ "id": 1, "items": [{ "id": 2 }] }"#)?);
+    ///# Ok(())
+    ///# }
+    /// ```
+    pub fn retain_deep<F: FnMut(&JsonPointer, &Value) -> bool>(&mut self, mut f: F) {
+        self.visit_mut(&mut |path: &JsonPointer, value: &mut Value| {
+            if path.is_root() || f(path, value) {
+                Visit::Continue
+            } else {
+                Visit::Remove
+            }
+        })
+    }
+}
+
+/// Returns `true` when the node has to be removed from its parent.
+fn visit_node<V: VisitorMut>(path: &mut JsonPointer, value: &mut Value, visitor: &mut V) -> bool {
+    match visitor.visit(path, value) {
+        Visit::Remove => return true,
+        Visit::Skip => return false,
+        Visit::Continue => {}
+    }
+    match value {
+        Value::Array(values) => {
+            // Paths keep the index each element had before any removal.
+            let (mut i, mut original) = (0, 0);
+            while i < values.len() {
+                path.push(original.to_string());
+                let remove = visit_node(path, &mut values[i], visitor);
+                path.pop();
+                if remove {
+                    values.remove(i);
+                } else {
+                    i += 1;
+                }
+                original += 1;
+            }
+        }
+        Value::Object(object) => {
+            let keys: Vec<String> = object.iter().map(|(key, _)| key.clone()).collect();
+            for key in keys {
+                path.push(key.as_str());
+                let remove = visit_node(path, object.get_mut(&key).unwrap(), visitor);
+                path.pop();
+                if remove {
+                    object.remove(&key);
+                }
+            }
+        }
+        _ => {}
+    }
+    false
+}
+
+impl Value {
+    /// Collects every object entry named `key`, at any depth, in pre-order.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    ///# fn main() -> Result<(),String> {
+    ///let value = json::parse(r#"{ "users": [{ "email": "a@b.c" }, { "contact": { "email": null } }] }"#)?;
+    ///
+    ///let paths: Vec<String> = value
+    ///    .find_key_all("email")
+    ///    .into_iter()
+    ///    .map(|(path, _)| path.to_string())
+    ///    .collect();
+    ///assert_eq!(paths, vec!["/users/0/email", "/users/1/contact/email"]);
+    ///# Ok(())
+    ///# }
+    /// ```
+    pub fn find_key_all(&self, key: &str) -> Vec<(JsonPointer, &Value)> {
+        let mut found = Vec::new();
+        find_key_at(&mut JsonPointer::root(), self, key, &mut found);
+        found
+    }
+}
+
+fn find_key_at<'a>(
+    path: &mut JsonPointer,
+    value: &'a Value,
+    key: &str,
+    found: &mut Vec<(JsonPointer, &'a Value)>,
+) {
+    match value {
+        Value::Array(values) => {
+            for (i, child) in values.iter().enumerate() {
+                path.push(i.to_string());
+                find_key_at(path, child, key, found);
+                path.pop();
+            }
+        }
+        Value::Object(object) => {
+            for (name, child) in object.iter() {
+                path.push(name.as_str());
+                if name == key {
+                    found.push((path.clone(), child));
+                }
+                find_key_at(path, child, key, found);
+                path.pop();
+            }
+        }
+        _ => {}
+    }
+}
+
+impl Value {
+    /// Collects, in pre-order, every node whose path matches `pattern`, such
+    /// as `users[*].name`, `/items/*/id` or `**.email`. Segments are
+    /// separated by `/` or `.` or written within `[...]`; `*` matches any
+    /// single key or index and `**` any number of them.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    ///# fn main() -> Result<(),String> {
+    ///let value = json::parse(r#"{ "users": [{ "name": "John" }, { "name": "Jane" }] }"#)?;
+    ///
+    ///let names: Vec<String> = value
+    ///    .select("users[*].name")?
+    ///    .into_iter()
+    ///    .map(|(path, name)| format!("{} {}", path, json::to_string(name)))
+    ///    .collect();
+    ///assert_eq!(names, vec![r#"/users/0/name "John""#, r#"/users/1/name "Jane""#]);
+    ///# Ok(())
+    ///# }
+    /// ```
+    pub fn select(&self, pattern: &str) -> Result<Vec<(JsonPointer, &Value)>, String> {
+        Ok(self.select_pattern(&PathPattern::parse(pattern)?))
+    }
+
+    /// Like [`Value::select`], with a pattern parsed beforehand, such as one
+    /// read from a JSON Pointer with [`PathPattern::from_pointer`].
+    pub fn select_pattern(&self, pattern: &PathPattern) -> Vec<(JsonPointer, &Value)> {
+        self.walk()
+            .filter(|(path, _)| pattern.matches(path.tokens()))
+            .collect()
+    }
+}
@@ -0,0 +1,145 @@
+use super::Visit;
+use crate::{parse, PathPattern, Value};
+
+fn paths<'a>(iter: impl Iterator<Item = (crate::JsonPointer, &'a Value)>) -> Vec<String> {
+    iter.map(|(path, _)| path.to_string()).collect()
+}
+
+#[test]
+fn walk() {
+    let value = parse(r#"[1, [true, { "a/b": null }], []]"#).unwrap();
+    assert_eq!(
+        paths(value.walk()),
+        vec!["", "/0", "/1", "/1/0", "/1/1", "/1/1/a~1b", "/2"]
+    );
+    assert_eq!(value.walk().nth(5).map(|(_, v)| v), Some(&Value::Null));
+}
+
+#[test]
+fn leaves() {
+    let value = parse(r#"[1, [true, { "a": null }], []]"#).unwrap();
+    assert_eq!(paths(value.leaves()), vec!["/0", "/1/0", "/1/1/a", "/2"]);
+    assert_eq!(paths(Value::Null.leaves()), vec![""]);
+}
+
+#[test]
+fn pointers() {
+    let value = parse(r#"{ "a": { "b~c": [] }, "d": 1 }"#).unwrap();
+    let strings = |pointers: Vec<crate::JsonPointer>| -> Vec<String> {
+        pointers.iter().map(|p| p.to_string()).collect()
+    };
+    assert_eq!(
+        strings(value.pointers(false)),
+        vec!["", "/a", "/a/b~0c", "/d"]
+    );
+    assert_eq!(strings(value.pointers(true)), vec!["/a/b~0c", "/d"]);
+    assert_eq!(value.pointers(true)[0].tokens(), ["a", "b~c"]);
+}
+
+#[test]
+fn transform() {
+    let mut value = parse(r#"{ "a": [1, 2], "b": { "c": 3 } }"#).unwrap();
+    let mut visited = Vec::new();
+    value.transform(|path, node| {
+        visited.push(path.to_string());
+        match node {
+            Value::Number(n) => *n *= 10.0,
+            Value::Object(object) if path.is_root() => {
+                object.insert("d".to_string(), Value::Number(4.0));
+            }
+            _ => {}
+        }
+    });
+    assert_eq!(
+        value,
+        parse(r#"{ "a": [10, 20], "b": { "c": 30 }, "d": 40 }"#).unwrap()
+    );
+    visited.sort();
+    assert_eq!(visited, vec!["", "/a", "/a/0", "/a/1", "/b", "/b/c", "/d"]);
+}
+
+#[test]
+fn visit_mut() {
+    let mut value =
+        parse(r#"{ "keep": [null, 1, null, 2], "drop": null, "skip": [null] }"#).unwrap();
+    let mut paths = Vec::new();
+    value.visit_mut(&mut |path: &crate::JsonPointer, node: &mut Value| {
+        paths.push(path.to_string());
+        match node {
+            Value::Null => Visit::Remove,
+            _ if path.to_string() == "/skip" => Visit::Skip,
+            _ => Visit::Continue,
+        }
+    });
+    assert_eq!(
+        value,
+        parse(r#"{ "keep": [1, 2], "skip": [null] }"#).unwrap()
+    );
+    assert!(paths.contains(&"/keep/1".to_string()));
+    assert!(paths.contains(&"/keep/3".to_string()));
+    assert!(!paths.contains(&"/skip/0".to_string()));
+
+    let mut root = Value::Bool(true);
+    root.visit_mut(&mut |_: &crate::JsonPointer, _: &mut Value| Visit::Remove);
+    assert_eq!(root, Value::Null);
+}
+
+#[test]
+fn find_key_all() {
+    let value = parse(r#"{ "0": 1, "a": { "0": [{ "0": 2 }] }, "b": [3] }"#).unwrap();
+    let mut found: Vec<(String, &Value)> = value
+        .find_key_all("0")
+        .into_iter()
+        .map(|(path, value)| (path.to_string(), value))
+        .collect();
+    found.sort();
+    assert_eq!(
+        found,
+        vec![
+            ("/0".to_string(), &Value::Number(1.0)),
+            ("/a/0".to_string(), &parse(r#"[{ "0": 2 }]"#).unwrap()),
+            ("/a/0/0/0".to_string(), &Value::Number(2.0)),
+        ]
+    );
+    assert!(value.find_key_all("missing").is_empty());
+}
+
+#[test]
+fn select() {
+    let value = parse(r#"{ "a": { "id": 1, "b": [{ "id": 2 }] }, "id": 3 }"#).unwrap();
+    assert_eq!(
+        paths(value.select("**.id").unwrap().into_iter()),
+        vec!["/a/id", "/a/b/0/id", "/id"]
+    );
+    assert_eq!(
+        paths(value.select("a.b[0]").unwrap().into_iter()),
+        vec!["/a/b/0"]
+    );
+    assert_eq!(paths(value.select("").unwrap().into_iter()), vec![""]);
+    assert!(value.select("a[b").is_err());
+}
+
+#[test]
+fn select_pattern() {
+    let value = parse(r#"{ "a.b": [{ "id": 1 }], "a": { "b": [{ "id": 2 }] } }"#).unwrap();
+    let pattern = PathPattern::from_pointer("/a.b/*/id").unwrap();
+    assert_eq!(
+        paths(value.select_pattern(&pattern).into_iter()),
+        vec!["/a.b/0/id"]
+    );
+}
+
+#[test]
+fn retain_deep() {
+    let mut value = parse(r#"{ "a": [1, "x", [2, "y"]], "b": "z", "c": { "d": 3 } }"#).unwrap();
+    let mut seen = Vec::new();
+    value.retain_deep(|path, node| {
+        seen.push(path.to_string());
+        !matches!(node, Value::String(_)) && path.to_string() != "/c"
+    });
+    assert_eq!(value, parse(r#"{ "a": [1, [2]] }"#).unwrap());
+    assert_eq!(
+        seen,
+        vec!["/a", "/a/0", "/a/1", "/a/2", "/a/2/0", "/a/2/1", "/b", "/c"]
+    );
+}
@@ -0,0 +1,303 @@
+#[cfg(test)]
+mod tests;
+
+use crate::convert::{at, mismatch};
+use crate::{to_string, JsonObject, Value};
+use quick_xml::escape::{escape, resolve_predefined_entity};
+use quick_xml::events::{BytesStart, Event};
+use quick_xml::{Reader, XmlVersion};
+
+/// How [`from_xml_str`] and [`to_xml_string`] map XML onto JSON.
+#[derive(Debug, PartialEq, Clone)]
+pub struct XmlOptions {
+    /// Prepended to attribute names to tell them apart from child elements,
+    /// `"@"` by default.
+    pub attribute_prefix: String,
+    /// The key of an element's text when it also has attributes or child
+    /// elements, `"#text"` by default.
+    pub text_key: String,
+    /// Names of the elements that always become arrays, so that a list with
+    /// a single item reads the same as a longer one.
+    pub array_elements: Vec<String>,
+    /// Whether text is trimmed, dropping the whitespace that indents
+    /// elements. On by default.
+    pub trim_text: bool,
+}
+
+impl Default for XmlOptions {
+    fn default() -> Self {
+        XmlOptions {
+            attribute_prefix: "@".to_string(),
+            text_key: "#text".to_string(),
+            array_elements: Vec::new(),
+            trim_text: true,
+        }
+    }
+}
+
+/// Parses an XML document into an object with a single key, the name of
+/// the root element. An element with neither attributes nor child elements
+/// becomes its text, or `null` when it has none. Others become objects of
+/// their attributes, child elements and text, with the children sharing a
+/// name gathered in an array in document order. Names are kept as written,
+/// namespace prefix included, and all text stays a string.
+///
+/// # Examples
+///
+/// ```
+///# fn main() -> Result<(),String> {
+///let xml = r#"<order id="7"><item>tea</item><item sku="c1">cake</item><note/></order>"#;
+///let value = json::from_xml_str(xml, &json::XmlOptions::default())?;
+///
+///assert_eq!(
+///    value,
+///    json::parse(r##"{ "order": { "@id": "7", "item": ["tea", { "@sku": "c1", "#text": "cake" }], "note": null } }"##)?
+///);
+///# Ok(())
+///# }
+/// ```
+pub fn from_xml_str(text: &str, options: &XmlOptions) -> Result<Value, String> {
+    let mut reader = Reader::from_str(text);
+    let mut stack: Vec<Element> = Vec::new();
+    let mut root = None;
+    loop {
+        let position = reader.buffer_position();
+        let event = reader
+            .read_event()
+            .map_err(|e| format!("{} at offset {}", e, reader.error_position()))?;
+        let text = match event {
+            Event::Start(start) | Event::Empty(start) if root.is_some() => {
+                return Err(format!(
+                    "unexpected element <{}> after the root element at offset {}",
+                    start.name().as_ref(),
+                    position
+                ))
+            }
+            Event::Start(start) => {
+                stack.push(Element::new(&start)?);
+                continue;
+            }
+            Event::Empty(start) => {
+                let element = Element::new(&start)?;
+                let (name, value) = element.finish(options);
+                match stack.last_mut() {
+                    Some(parent) => parent.children.push((name, value)),
+                    None => root = Some((name, value)),
+                }
+                continue;
+            }
+            Event::End(_) => {
+                let (name, value) = stack.pop().unwrap().finish(options);
+                match stack.last_mut() {
+                    Some(parent) => parent.children.push((name, value)),
+                    None => root = Some((name, value)),
+                }
+                continue;
+            }
+            Event::Text(text) => text.xml10_content().into_owned(),
+            Event::CData(data) => data.xml10_content().into_owned(),
+            Event::GeneralRef(reference) => match reference.resolve_char_ref() {
+                Ok(Some(c)) => c.to_string(),
+                Ok(None) => {
+                    let name = reference.xml10_content();
+                    match resolve_predefined_entity(&name) {
+                        Some(text) => text.to_string(),
+                        None => {
+                            return Err(format!("unknown entity &{}; at offset {}", name, position))
+                        }
+                    }
+                }
+                Err(e) => return Err(format!("{} at offset {}", e, position)),
+            },
+            Event::Eof => break,
+            Event::Comment(_) | Event::Decl(_) | Event::PI(_) | Event::DocType(_) => continue,
+        };
+        match stack.last_mut() {
+            Some(element) => element.text.push_str(&text),
+            None if text.trim().is_empty() => {}
+            None => {
+                return Err(format!(
+                    "unexpected text outside the root element at offset {}",
+                    position
+                ))
+            }
+        }
+    }
+    if let Some(element) = stack.last() {
+        return Err(format!("unclosed element <{}>", element.name));
+    }
+    match root {
+        Some((name, value)) => Ok(Value::Object(std::iter::once((name, value)).collect())),
+        None => Err("expected a root element".to_string()),
+    }
+}
+
+struct Element {
+    name: String,
+    attributes: Vec<(String, String)>,
+    children: Vec<(String, Value)>,
+    text: String,
+}
+
+impl Element {
+    fn new(start: &BytesStart) -> Result<Self, String> {
+        let mut attributes = Vec::new();
+        for attribute in start.attributes() {
+            let attribute = attribute.map_err(|e| e.to_string())?;
+            let value = attribute
+                .normalized_value(XmlVersion::Implicit1_0)
+                .map_err(|e| e.to_string())?;
+            attributes.push((attribute.key.as_ref().to_string(), value.into_owned()));
+        }
+        Ok(Element {
+            name: start.name().as_ref().to_string(),
+            attributes,
+            children: Vec::new(),
+            text: String::new(),
+        })
+    }
+
+    fn finish(self, options: &XmlOptions) -> (String, Value) {
+        let text = if options.trim_text {
+            self.text.trim()
+        } else {
+            &self.text
+        };
+        if self.attributes.is_empty() && self.children.is_empty() {
+            let value = if text.is_empty() {
+                Value::Null
+            } else {
+                Value::String(text.to_string())
+            };
+            return (self.name, value);
+        }
+        let mut object = JsonObject::new();
+        for (name, value) in self.attributes {
+            object.insert(
+                format!("{}{}", options.attribute_prefix, name),
+                Value::String(value),
+            );
+        }
+        for (name, value) in self.children {
+            match object.get_mut(&name) {
+                Some(Value::Array(values)) => values.push(value),
+                Some(first) => *first = Value::Array(vec![first.clone(), value]),
+                None if options.array_elements.contains(&name) => {
+                    object.insert(name, Value::Array(vec![value]));
+                }
+                None => {
+                    object.insert(name, value);
+                }
+            }
+        }
+        if !text.is_empty() {
+            object.insert(options.text_key.clone(), Value::String(text.to_string()));
+        }
+        (self.name, Value::Object(object))
+    }
+}
+
+/// Serializes an object with a single key, the name of the root element,
+/// as XML, reversing the mapping of [`from_xml_str`]: keys starting with
+/// the attribute prefix become attributes, the text key becomes text, other
+/// keys become child elements, repeated once per item of an array, and
+/// `null` becomes an empty element. Numbers and booleans are written as
+/// their JSON text.
+///
+/// # Examples
+///
+/// ```
+///# fn main() -> Result<(),String> {
+///let value = json::parse(r#"{ "order": { "@id": 7, "item": ["tea", "cake"], "note": null } }"#)?;
+///
+///assert_eq!(
+///    json::to_xml_string(&value, &json::XmlOptions::default())?,
+///    r#"<order id="7"><item>tea</item><item>cake</item><note/></order>"#
+///);
+///# Ok(())
+///# }
+/// ```
+pub fn to_xml_string(value: &Value, options: &XmlOptions) -> Result<String, String> {
+    match value {
+        Value::Object(object) if object.len() == 1 => {
+            let (name, value) = object.iter().next().unwrap();
+            if let Value::Array(_) = value {
+                return Err(at(
+                    name,
+                    "expected a single root element, found array".to_string(),
+                ));
+            }
+            let mut output = String::new();
+            write_element(&mut output, name, value, options).map_err(|e| at(name, e))?;
+            Ok(output)
+        }
+        Value::Object(object) => Err(format!(
+            "expected a single root element, found {} keys",
+            object.len()
+        )),
+        value => Err(mismatch("object", value)),
+    }
+}
+
+fn write_element(
+    output: &mut String,
+    name: &str,
+    value: &Value,
+    options: &XmlOptions,
+) -> Result<(), String> {
+    if name.is_empty()
+        || name.starts_with(|c: char| c.is_ascii_digit() || c == '-' || c == '.')
+        || name.contains(|c: char| c.is_whitespace() || "<>&\"'/=!?".contains(c))
+    {
+        return Err(format!("invalid element name {:?}", name));
+    }
+    let object = match value {
+        Value::Array(values) => {
+            for (i, value) in values.iter().enumerate() {
+                write_element(output, name, value, options).map_err(|e| at(&i.to_string(), e))?;
+            }
+            return Ok(());
+        }
+        Value::Object(object) => object,
+        Value::Null => {
+            output.push_str(&format!("<{}/>", name));
+            return Ok(());
+        }
+        value => {
+            output.push_str(&format!("<{}>{}</{}>", name, escape(text(value)?), name));
+            return Ok(());
+        }
+    };
+    output.push('<');
+    output.push_str(name);
+    let mut content = String::new();
+    for (key, value) in object.iter() {
+        let attribute = key
+            .strip_prefix(options.attribute_prefix.as_str())
+            .filter(|_| !options.attribute_prefix.is_empty());
+        if let Some(attribute) = attribute {
+            let value = text(value).map_err(|e| at(key, e))?;
+            output.push_str(&format!(" {}=\"{}\"", attribute, escape(value)));
+        } else if *key == options.text_key {
+            content.push_str(&escape(text(value).map_err(|e| at(key, e))?));
+        } else {
+            write_element(&mut content, key, value, options).map_err(|e| at(key, e))?;
+        }
+    }
+    if content.is_empty() {
+        output.push_str("/>");
+    } else {
+        output.push_str(&format!(">{}</{}>", content, name));
+    }
+    Ok(())
+}
+
+/// The text of an attribute or text node.
+fn text(value: &Value) -> Result<String, String> {
+    match value {
+        Value::String(value) => Ok(value.clone()),
+        Value::Null => Ok(String::new()),
+        Value::Bool(_) | Value::Number(_) => Ok(to_string(value)),
+        value => Err(mismatch("string, number, boolean or null", value)),
+    }
+}
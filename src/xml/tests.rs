@@ -0,0 +1,82 @@
+use super::{from_xml_str, to_xml_string, XmlOptions};
+use crate::parse;
+
+#[test]
+fn soap_envelope() {
+    let xml = r#"<?xml version="1.0"?>
+<!-- response -->
+<soap:Envelope xmlns:soap="http://schemas.xmlsoap.org/soap/envelope/">
+  <soap:Body>
+    <m:Price xmlns:m="urn:prices">
+      <m:Amount currency="EUR">1&lt;2 &amp; <![CDATA[<raw>]]>&#33;</m:Amount>
+    </m:Price>
+  </soap:Body>
+</soap:Envelope>"#;
+    let value = from_xml_str(xml, &XmlOptions::default()).unwrap();
+    assert_eq!(
+        value.pointer("/soap:Envelope/soap:Body/m:Price/m:Amount"),
+        Some(&parse(r##"{ "@currency": "EUR", "#text": "1<2 & <raw>!" }"##).unwrap())
+    );
+}
+
+#[test]
+fn options() {
+    let options = XmlOptions {
+        attribute_prefix: "-".to_string(),
+        text_key: "$".to_string(),
+        array_elements: vec!["item".to_string()],
+        trim_text: false,
+    };
+    let value = parse(r#"{ "list": { "-n": 1, "item": [{ "$": " a ", "-k": true }] } }"#).unwrap();
+    let xml = to_xml_string(&value, &options).unwrap();
+    assert_eq!(xml, r#"<list n="1"><item k="true"> a </item></list>"#);
+    assert_eq!(
+        from_xml_str(&xml, &options),
+        parse(r#"{ "list": { "-n": "1", "item": [{ "-k": "true", "$": " a " }] } }"#)
+    );
+}
+
+#[test]
+fn round_trip() {
+    let value =
+        parse(r##"{ "a": { "@x": "<\"&'>", "b": ["1", null, { "c": "d" }], "#text": "t & u" } }"##)
+            .unwrap();
+    let options = XmlOptions::default();
+    assert_eq!(
+        from_xml_str(&to_xml_string(&value, &options).unwrap(), &options),
+        Ok(value)
+    );
+}
+
+#[test]
+fn errors() {
+    let options = XmlOptions::default();
+    assert_eq!(
+        from_xml_str("<a><b></a>", &options),
+        Err("ill-formed document: expected `</b>`, but `</a>` was found at offset 6".to_string())
+    );
+    assert_eq!(
+        from_xml_str("<a>", &options),
+        Err("unclosed element <a>".to_string())
+    );
+    assert_eq!(
+        from_xml_str("<a/><b/>", &options),
+        Err("unexpected element <b> after the root element at offset 4".to_string())
+    );
+    assert_eq!(
+        from_xml_str("", &options),
+        Err("expected a root element".to_string())
+    );
+    assert_eq!(
+        to_xml_string(&parse(r#"{ "a": { "1": 2 } }"#).unwrap(), &options),
+        Err(r#"/a/1: invalid element name "1""#.to_string())
+    );
+    assert_eq!(
+        to_xml_string(&parse(r#"{ "a": { "@b": [] } }"#).unwrap(), &options),
+        Err("/a/@b: expected string, number, boolean or null, found array".to_string())
+    );
+    assert_eq!(
+        to_xml_string(&parse(r#"{ "a": 1, "b": 2 }"#).unwrap(), &options),
+        Err("expected a single root element, found 2 keys".to_string())
+    );
+}
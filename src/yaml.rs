@@ -0,0 +1,142 @@
+#[cfg(test)]
+mod tests;
+
+use crate::{to_string, JsonObject, Value};
+use yaml_rust2::{Yaml, YamlEmitter, YamlLoader};
+
+/// Parses a YAML document into a value. Aliases are replaced by a copy of
+/// the node they refer to and `<<` merge keys copy in the entries of the
+/// mappings they name that aren't already present. Mapping keys that
+/// aren't strings become the JSON text of the key, e.g. `1` or `[1, 2]`, and
+/// non-finite floats become `null`.
+///
+/// # Examples
+///
+/// ```
+///# fn main() -> Result<(),String> {
+///let yaml = "base: &base\n  retries: 3\nprod:\n  <<: *base\n  host: db\n404: missing";
+///let value = json::from_yaml_str(yaml)?;
+///
+///assert_eq!(
+///    value,
+///    json::parse(r#"{ "base": { "retries": 3 }, "prod": { "retries": 3, "host": "db" }, "404": "missing" }"#)?
+///);
+///# Ok(())
+///# }
+/// ```
+pub fn from_yaml_str(text: &str) -> Result<Value, String> {
+    let documents = YamlLoader::load_from_str(text).map_err(|e| e.to_string())?;
+    match &documents[..] {
+        [] => Ok(Value::Null),
+        [document] => from_yaml(document),
+        documents => Err(format!(
+            "expected a single document, found {}",
+            documents.len()
+        )),
+    }
+}
+
+fn from_yaml(yaml: &Yaml) -> Result<Value, String> {
+    Ok(match yaml {
+        Yaml::Null => Value::Null,
+        Yaml::Boolean(value) => Value::Bool(*value),
+        Yaml::Integer(value) => Value::Number(*value as f64),
+        Yaml::Real(_) => match yaml.as_f64() {
+            Some(value) if value.is_finite() => Value::Number(value),
+            _ => Value::Null,
+        },
+        Yaml::String(value) => Value::String(value.clone()),
+        Yaml::Array(values) => {
+            Value::Array(values.iter().map(from_yaml).collect::<Result<_, _>>()?)
+        }
+        Yaml::Hash(entries) => {
+            let mut object = JsonObject::new();
+            let mut merged = Vec::new();
+            for (key, value) in entries {
+                match key {
+                    Yaml::String(key) if key == "<<" => merged.push(value),
+                    Yaml::String(key) => {
+                        object.insert(key.clone(), from_yaml(value)?);
+                    }
+                    key => {
+                        object.insert(to_string(&from_yaml(key)?), from_yaml(value)?);
+                    }
+                }
+            }
+            for value in merged {
+                merge(&mut object, value)?;
+            }
+            Value::Object(object)
+        }
+        Yaml::Alias(_) | Yaml::BadValue => return Err("undefined alias".to_string()),
+    })
+}
+
+/// Copies the entries of the mapping, or sequence of mappings, a merge key
+/// names into `object`, earlier mappings taking precedence.
+fn merge(object: &mut JsonObject, value: &Yaml) -> Result<(), String> {
+    let sources = match value {
+        Yaml::Array(values) => values.iter().collect(),
+        value => vec![value],
+    };
+    for source in sources {
+        match from_yaml(source)? {
+            Value::Object(source) => {
+                for (key, value) in source {
+                    if !object.contains_key(&key) {
+                        object.insert(key, value);
+                    }
+                }
+            }
+            source => {
+                return Err(format!(
+                    "expected mapping to merge, found {}",
+                    source.type_name()
+                ))
+            }
+        }
+    }
+    Ok(())
+}
+
+/// Serializes a value as a block style YAML document, quoting the strings
+/// that would otherwise read back as another type.
+///
+/// # Examples
+///
+/// ```
+///# fn main() -> Result<(),String> {
+///let value = json::parse(r#"{ "name": "db", "port": 5432, "tags": ["a", "true"], "opts": {} }"#)?;
+///
+///assert_eq!(json::to_yaml_string(&value), "name: db\nport: 5432\ntags:\n  - a\n  - \"true\"\nopts: {}\n");
+///assert_eq!(json::from_yaml_str(&json::to_yaml_string(&value))?, value);
+///# Ok(())
+///# }
+/// ```
+pub fn to_yaml_string(value: &Value) -> String {
+    let mut output = String::new();
+    YamlEmitter::new(&mut output).dump(&to_yaml(value)).unwrap();
+    let mut output = output.split_off("---\n".len());
+    output.push('\n');
+    output
+}
+
+fn to_yaml(value: &Value) -> Yaml {
+    match value {
+        Value::Null => Yaml::Null,
+        Value::Bool(value) => Yaml::Boolean(*value),
+        Value::Number(n) if n.fract() == 0.0 && *n >= i64::MIN as f64 && *n < i64::MAX as f64 => {
+            Yaml::Integer(*n as i64)
+        }
+        Value::Number(n) if n.is_finite() => Yaml::Real(n.to_string()),
+        Value::Number(_) => Yaml::Null,
+        Value::String(value) => Yaml::String(value.clone()),
+        Value::Array(values) => Yaml::Array(values.iter().map(to_yaml).collect()),
+        Value::Object(object) => Yaml::Hash(
+            object
+                .iter()
+                .map(|(key, value)| (Yaml::String(key.clone()), to_yaml(value)))
+                .collect(),
+        ),
+    }
+}
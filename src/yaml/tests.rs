@@ -0,0 +1,52 @@
+use super::{from_yaml_str, to_yaml_string};
+use crate::parse;
+
+#[test]
+fn round_trip() {
+    let value = parse(
+        r##"{ "s": ["", "1", "null", "a: b", "multi\nline", "#x"], "n": [0, -7, 0.5, 1e20],
+             "b": [true, false, null], "o": { "nested": { "k": [] } } }"##,
+    )
+    .unwrap();
+    assert_eq!(from_yaml_str(&to_yaml_string(&value)), Ok(value));
+    assert_eq!(to_yaml_string(&parse("3").unwrap()), "3\n");
+}
+
+#[test]
+fn keys_and_merges() {
+    let yaml = "
+defaults: &defaults { a: 1, b: 2 }
+extra: &extra { c: 3 }
+item:
+  <<: [*defaults, *extra]
+  b: 20
+1.5: x
+true: y
+[1, 2]: z
+~: w
+";
+    assert_eq!(
+        from_yaml_str(yaml),
+        parse(
+            r#"{ "defaults": { "a": 1, "b": 2 }, "extra": { "c": 3 },
+                 "item": { "b": 20, "a": 1, "c": 3 },
+                 "1.5": "x", "true": "y", "[1,2]": "z", "null": "w" }"#
+        )
+    );
+    assert_eq!(from_yaml_str(".nan"), Ok(crate::Value::Null));
+    assert_eq!(from_yaml_str(""), Ok(crate::Value::Null));
+}
+
+#[test]
+fn errors() {
+    assert_eq!(
+        from_yaml_str("a: 1\n---\nb: 2"),
+        Err("expected a single document, found 2".to_string())
+    );
+    assert_eq!(
+        from_yaml_str("a:\n  <<: 1"),
+        Err("expected mapping to merge, found number".to_string())
+    );
+    assert!(from_yaml_str("a: [1").is_err());
+    assert!(from_yaml_str("a: *missing").is_err());
+}